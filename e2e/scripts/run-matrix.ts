@@ -15,7 +15,7 @@ import BuildManager from './build-apps.js';
 interface TestVariant {
   framework: 'electron' | 'tauri';
   app: 'builder' | 'forge' | 'script' | 'basic';
-  testType: 'standard' | 'window' | 'multiremote' | 'standalone' | 'deeplink';
+  testType: 'standard' | 'window' | 'multiremote' | 'standalone' | 'deeplink' | 'no-global-tauri';
   binary: boolean;
 }
 
@@ -41,12 +41,13 @@ function generateTestVariants(): TestVariant[] {
 
   const electronApps: Array<'builder' | 'forge' | 'script'> = ['builder', 'forge', 'script'];
   const tauriApps: Array<'basic'> = ['basic'];
-  const testTypes: Array<'standard' | 'window' | 'multiremote' | 'standalone' | 'deeplink'> = [
+  const testTypes: Array<'standard' | 'window' | 'multiremote' | 'standalone' | 'deeplink' | 'no-global-tauri'> = [
     'standard',
     'window',
     'multiremote',
     'standalone',
     'deeplink',
+    'no-global-tauri',
   ];
 
   const variants: TestVariant[] = [];
@@ -65,6 +66,11 @@ function generateTestVariants(): TestVariant[] {
           continue;
         }
 
+        // withGlobalTauri is a Tauri config option
+        if (testType === 'no-global-tauri' && framework !== 'tauri') {
+          continue;
+        }
+
         variants.push({
           framework,
           app,
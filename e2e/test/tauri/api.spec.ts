@@ -12,6 +12,22 @@ describe('Tauri API', () => {
     expect(result).toHaveProperty('cpu');
   });
 
+  it('should report real disk space', async () => {
+    type Disk = { mount_point: string; total: number; free: number };
+    const { disk, per_disk } = (await browser.tauri.execute(({ core }) => core.invoke('get_platform_info'))) as {
+      disk: Disk;
+      per_disk: Disk[];
+    };
+
+    expect(disk.free).toBeLessThanOrEqual(disk.total);
+    if (per_disk.length > 0) {
+      expect(per_disk).toContainEqual(disk);
+      expect(disk.total).toBeGreaterThan(0);
+    } else {
+      expect(disk).toEqual({ mount_point: '', total: 0, free: 0 });
+    }
+  });
+
   it('should handle command errors gracefully', async () => {
     // Test error handling for invalid commands
     await expect(browser.tauri.execute(({ core }) => core.invoke('invalid_command'))).rejects.toThrow();
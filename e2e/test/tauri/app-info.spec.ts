@@ -0,0 +1,34 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import { readFileSync } from 'node:fs';
+import path from 'node:path';
+import url from 'node:url';
+
+const __dirname = path.dirname(url.fileURLToPath(import.meta.url));
+
+type AppInfo = { name: string; version: string; tauri_version: string; debug: boolean; pid: number };
+
+describe('Tauri App Info', () => {
+  const tauriConfig = JSON.parse(
+    readFileSync(
+      path.join(__dirname, '..', '..', '..', 'fixtures', 'e2e-apps', 'tauri', 'src-tauri', 'tauri.conf.json'),
+      'utf-8',
+    ),
+  ) as { productName: string; version: string };
+
+  it('should report the name and version from tauri.conf.json', async () => {
+    const info = (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_app_info'))) as AppInfo;
+
+    expect(info.name).toBe(tauriConfig.productName);
+    expect(info.version).toBe(tauriConfig.version);
+  });
+
+  it('should report the tauri version, build profile, and pid', async () => {
+    const info = (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_app_info'))) as AppInfo;
+
+    expect(info.tauri_version).toMatch(/^2\.\d+\.\d+/);
+    // e2e runs use debug builds
+    expect(info.debug).toBe(true);
+    expect(info.pid).toBeGreaterThan(0);
+  });
+});
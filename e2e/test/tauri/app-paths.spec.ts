@@ -0,0 +1,43 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type AppPaths = {
+  app_data_dir: string | null;
+  app_config_dir: string | null;
+  app_cache_dir: string | null;
+  app_log_dir: string | null;
+  temp_dir: string | null;
+  resource_dir: string | null;
+  executable_path: string | null;
+};
+
+describe('Tauri App Paths', () => {
+  it('should resolve the app directories and executable path', async () => {
+    const paths = (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_app_paths'))) as AppPaths;
+
+    expect(paths.app_data_dir).toEqual(expect.any(String));
+    expect(paths.app_log_dir).toEqual(expect.any(String));
+    expect(paths.temp_dir).toEqual(expect.any(String));
+    expect(paths.executable_path).toEqual(expect.any(String));
+  });
+
+  it('should let a file be written under app_data_dir and read back', async () => {
+    const paths = (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_app_paths'))) as AppPaths;
+    const dataDir = paths.app_data_dir as string;
+    const separator = dataDir.includes('\\') ? '\\' : '/';
+    const filePath = `${dataDir}${separator}wdio-app-paths-${Date.now()}.txt`;
+    const contents = 'written under app_data_dir';
+
+    await browser.tauri.execute(
+      ({ core }, target: { path: string; contents: string }) => core.invoke('write_file', target),
+      { path: filePath, contents },
+    );
+
+    try {
+      const read = await browser.tauri.execute(({ core }, path: string) => core.invoke('read_file', { path }), filePath);
+      expect(read).toBe(contents);
+    } finally {
+      await browser.tauri.execute(({ core }, path: string) => core.invoke('delete_file', { path }), filePath);
+    }
+  });
+});
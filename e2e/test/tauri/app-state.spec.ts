@@ -0,0 +1,34 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type FixtureState = { shortcut_count: number; dropped_paths: string[] };
+
+async function getAppState(name: string) {
+  return browser.tauri.execute(({ core }, name) => core.invoke('plugin:wdio|get_app_state', { name }), name);
+}
+
+describe('Tauri App State', () => {
+  it('should return the snapshot built by the registered provider', async () => {
+    const state = (await getAppState('fixture')) as FixtureState;
+
+    expect(typeof state.shortcut_count).toBe('number');
+    expect(Array.isArray(state.dropped_paths)).toBe(true);
+  });
+
+  it('should reflect state changes made by the backend', async () => {
+    const before = ((await getAppState('fixture')) as FixtureState).shortcut_count;
+
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|trigger_global_shortcut', { accelerator: 'CmdOrCtrl+Shift+K' }),
+    );
+
+    await browser.waitUntil(
+      async () => ((await getAppState('fixture')) as FixtureState).shortcut_count === before + 1,
+      { timeout: 5000, timeoutMsg: 'state snapshot did not update' },
+    );
+  });
+
+  it('should reject unknown state names', async () => {
+    await expect(getAppState('missing')).rejects.toThrow(/State provider not found: missing/);
+  });
+});
@@ -0,0 +1,58 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type SystemAppearance = { theme: 'light' | 'dark'; locale: string | null; timezone: string | null };
+
+async function setAppTheme(theme: 'light' | 'dark' | null) {
+  await browser.tauri.execute(({ core }, theme) => core.invoke('plugin:wdio|set_app_theme', { theme }), theme);
+}
+
+async function getAppearance() {
+  return (await browser.tauri.execute(({ core }) =>
+    core.invoke('plugin:wdio|get_system_appearance'),
+  )) as SystemAppearance;
+}
+
+async function waitForColorScheme(scheme: 'light' | 'dark') {
+  await browser.waitUntil(
+    async () =>
+      (await browser.execute(() =>
+        getComputedStyle(document.documentElement).getPropertyValue('--color-scheme').trim(),
+      )) === scheme,
+    { timeout: 5000, timeoutMsg: `prefers-color-scheme never became ${scheme}` },
+  );
+}
+
+describe('Tauri Appearance', () => {
+  after(async () => {
+    await setAppTheme(null);
+  });
+
+  it('should report the theme, locale, and timezone', async () => {
+    const appearance = await getAppearance();
+
+    expect(['light', 'dark']).toContain(appearance.theme);
+    if (appearance.locale !== null) {
+      expect(appearance.locale).toMatch(/^[a-zA-Z]{2,3}(-[a-zA-Z0-9]+)*$/);
+    }
+    if (appearance.timezone !== null) {
+      expect(appearance.timezone.length).toBeGreaterThan(0);
+    }
+  });
+
+  it('should flip prefers-color-scheme when the theme is forced', async () => {
+    await setAppTheme('dark');
+    await waitForColorScheme('dark');
+    expect((await getAppearance()).theme).toBe('dark');
+
+    await setAppTheme('light');
+    await waitForColorScheme('light');
+    expect((await getAppearance()).theme).toBe('light');
+  });
+
+  it('should reject unknown themes', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|set_app_theme', { theme: 'sepia' })),
+    ).rejects.toThrow();
+  });
+});
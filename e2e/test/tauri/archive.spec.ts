@@ -0,0 +1,107 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import crypto from 'node:crypto';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import zlib from 'node:zlib';
+
+async function invoke<T>(command: string, args: Record<string, unknown>) {
+  return (await browser.tauri.execute(
+    ({ core }, command: string, args: Record<string, unknown>) => core.invoke(command, args),
+    command,
+    args,
+  )) as T;
+}
+
+function hashFile(filePath: string, algorithm: 'sha256' | 'md5') {
+  return invoke<string>('hash_file', { path: filePath, algorithm });
+}
+
+// Single stored (uncompressed) entry zip, for names create_zip would never write
+function storedZip(name: string, contents: Buffer) {
+  const nameBytes = Buffer.from(name);
+  const crc = zlib.crc32(contents);
+
+  const local = Buffer.alloc(30);
+  local.writeUInt32LE(0x04034b50, 0);
+  local.writeUInt16LE(20, 4);
+  local.writeUInt32LE(crc, 14);
+  local.writeUInt32LE(contents.length, 18);
+  local.writeUInt32LE(contents.length, 22);
+  local.writeUInt16LE(nameBytes.length, 26);
+
+  const central = Buffer.alloc(46);
+  central.writeUInt32LE(0x02014b50, 0);
+  central.writeUInt16LE(20, 4);
+  central.writeUInt16LE(20, 6);
+  central.writeUInt32LE(crc, 16);
+  central.writeUInt32LE(contents.length, 20);
+  central.writeUInt32LE(contents.length, 24);
+  central.writeUInt16LE(nameBytes.length, 28);
+
+  const localSize = local.length + nameBytes.length + contents.length;
+  const end = Buffer.alloc(22);
+  end.writeUInt32LE(0x06054b50, 0);
+  end.writeUInt16LE(1, 8);
+  end.writeUInt16LE(1, 10);
+  end.writeUInt32LE(central.length + nameBytes.length, 12);
+  end.writeUInt32LE(localSize, 16);
+
+  return Buffer.concat([local, nameBytes, contents, central, nameBytes, end]);
+}
+
+describe('Tauri Fixture Checksums and Archives', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wdio-archive-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('should hash files like node:crypto', async () => {
+    const file = path.join(dir, 'data.bin');
+    const contents = crypto.randomBytes(256 * 1024);
+    fs.writeFileSync(file, contents);
+
+    expect(await hashFile(file, 'sha256')).toBe(crypto.createHash('sha256').update(contents).digest('hex'));
+    expect(await hashFile(file, 'md5')).toBe(crypto.createHash('md5').update(contents).digest('hex'));
+    await expect(invoke('hash_file', { path: file, algorithm: 'sha1' })).rejects.toThrow(/Unsupported hash algorithm/);
+  });
+
+  it('should zip and extract files without changing their contents', async () => {
+    fs.writeFileSync(path.join(dir, 'report.txt'), 'quarterly numbers');
+    fs.mkdirSync(path.join(dir, 'assets', 'icons'), { recursive: true });
+    fs.writeFileSync(path.join(dir, 'assets', 'icons', 'app.bin'), crypto.randomBytes(4096));
+    const zipPath = path.join(dir, 'export.zip');
+    const extractDir = path.join(dir, 'extracted');
+
+    await invoke('create_zip', { paths: [path.join(dir, 'report.txt'), path.join(dir, 'assets')], dest: zipPath });
+    const extracted = await invoke<string[]>('extract_zip', { src: zipPath, destDir: extractDir });
+
+    expect(extracted.map((file) => path.relative(extractDir, file)).sort()).toEqual([
+      path.join('assets', 'icons', 'app.bin'),
+      'report.txt',
+    ]);
+    for (const relative of ['report.txt', path.join('assets', 'icons', 'app.bin')]) {
+      expect(await hashFile(path.join(extractDir, relative), 'sha256')).toBe(
+        await hashFile(path.join(dir, relative), 'sha256'),
+      );
+    }
+  });
+
+  it('should reject entries that escape the destination directory', async () => {
+    const zipPath = path.join(dir, 'evil.zip');
+    const extractDir = path.join(dir, 'extracted');
+    fs.writeFileSync(zipPath, storedZip('../escaped.txt', Buffer.from('pwned')));
+
+    await expect(invoke('extract_zip', { src: zipPath, destDir: extractDir })).rejects.toThrow(
+      /entry '\.\.\/escaped\.txt' escapes the destination directory/,
+    );
+    expect(fs.existsSync(path.join(dir, 'escaped.txt'))).toBe(false);
+    expect(fs.existsSync(extractDir)).toBe(false);
+  });
+});
@@ -0,0 +1,189 @@
+import { $, browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+// Drives each plugin capability through the fixture's capability panel, checking
+// the UI against what the plugin commands report
+
+type LogEntry = { source: 'backend' | 'frontend'; level: string; message: string };
+type EventRecord = { name: string; payload: unknown };
+type InvokeTraceEntry = { command: string; duration_ms: number };
+
+const testId = (id: string) => $(`[data-testid="${id}"]`);
+
+async function plugin<T>(command: string, args?: Record<string, unknown>) {
+  return (await browser.tauri.execute(
+    ({ core }, command: string, args?: Record<string, unknown>) => core.invoke(`plugin:wdio|${command}`, args),
+    command,
+    args,
+  )) as T;
+}
+
+async function fixture<T>(command: string, args?: Record<string, unknown>) {
+  return (await browser.tauri.execute(
+    ({ core }, command: string, args?: Record<string, unknown>) => core.invoke(command, args),
+    command,
+    args,
+  )) as T;
+}
+
+describe('Tauri Capability Panel', () => {
+  after(async () => {
+    await fixture('clear_settings');
+    await plugin('set_app_theme', { theme: null });
+    await plugin('reset_all');
+  });
+
+  it('should render backend events and record them', async () => {
+    const message = `event ${Date.now()}`;
+    await plugin('start_event_recording', { eventNames: ['fixture-event'] });
+
+    await testId('event-message-input').setValue(message);
+    await testId('emit-event-button').click();
+
+    await expect(testId('event-last-payload')).toHaveText(expect.stringContaining(message));
+    const { events } = await plugin<{ events: EventRecord[] }>('get_recorded_events');
+    expect(events.map((event) => event.payload)).toContainEqual(expect.objectContaining({ message }));
+    await plugin('stop_event_recording');
+  });
+
+  it('should render events injected with emit_event', async () => {
+    await plugin('emit_event', { name: 'fixture-event', payload: { seq: 0, message: 'injected' } });
+
+    await expect(testId('event-last-payload')).toHaveText('{"seq":0,"message":"injected"}');
+  });
+
+  it('should capture logs written from Rust and from the console', async () => {
+    const message = `log ${Date.now()}`;
+    await testId('log-level-select').selectByAttribute('value', 'warn');
+    await testId('log-message-input').setValue(message);
+
+    await testId('log-backend-button').click();
+    await expect(testId('log-result')).toHaveText('logged warn in Rust');
+    await testId('log-frontend-button').click();
+    await expect(testId('log-result')).toHaveText('logged warn in console');
+
+    await browser.waitUntil(
+      async () => (await plugin<LogEntry[]>('get_logs', { filter: { contains: message } })).length === 2,
+      { timeout: 5000, timeoutMsg: 'both log entries were never captured' },
+    );
+    const entries = await plugin<LogEntry[]>('get_logs', { filter: { contains: message } });
+    expect(entries.map((entry) => [entry.source, entry.level]).sort()).toEqual([
+      ['backend', 'warn'],
+      ['frontend', 'warn'],
+    ]);
+  });
+
+  it('should save settings the backend exposes as app state', async () => {
+    await fixture('clear_settings');
+    await testId('setting-key-input').setValue('theme');
+    await testId('setting-value-input').setValue('solarized');
+
+    await testId('save-setting-button').click();
+
+    await expect(testId('settings-result')).toHaveText('{"theme":"solarized"}');
+    const state = await plugin<{ settings: Record<string, string> }>('get_app_state', { name: 'fixture' });
+    expect(state.settings).toEqual({ theme: 'solarized' });
+    await fixture('clear_settings');
+    await testId('load-settings-button').click();
+    await expect(testId('settings-result')).toHaveText('{}');
+  });
+
+  it('should show web storage the plugin snapshots and clears', async () => {
+    const key = `capability-${Date.now()}`;
+    await $('#storage-key').setValue(key);
+    await $('#storage-value').setValue('stored value');
+    await $('#storage-submit').click();
+    await expect(testId('storage-result')).toHaveText('Stored');
+
+    const snapshot = await plugin<{ local_storage: Record<string, string> }>('snapshot_web_storage', {
+      label: 'main',
+    });
+    expect(snapshot.local_storage[key]).toBe('stored value');
+
+    await plugin('clear_web_storage', { label: 'main', kinds: ['localStorage'] });
+    await testId('read-storage-button').click();
+    await expect(testId('storage-contents')).toHaveText(expect.stringContaining('"localStorage":{}'));
+  });
+
+  it('should copy and paste through the system clipboard', async () => {
+    const copied = `copied ${Date.now()}`;
+    await testId('clipboard-input').setValue(copied);
+
+    await testId('copy-button').click();
+    await expect(testId('clipboard-result')).toHaveText('copied');
+    expect(await plugin('get_clipboard_text')).toBe(copied);
+
+    const pasted = `pasted ${Date.now()}`;
+    await plugin('set_clipboard_text', { text: pasted });
+    await testId('paste-button').click();
+    await expect(testId('clipboard-result')).toHaveText(pasted);
+  });
+
+  it('should answer dialogs with mocked responses', async () => {
+    await browser.tauri.mockDialog('confirm', true);
+
+    await $('#confirm-dialog-button').click();
+
+    await expect(testId('dialog-result')).toHaveText('confirm: true');
+    await browser.tauri.clearDialogMocks();
+  });
+
+  it('should capture notifications instead of showing them', async () => {
+    await plugin('enable_notification_capture');
+
+    await $('#notify-button').click();
+
+    await expect(testId('notify-result')).toHaveText('notified');
+    const records = await plugin<{ title: string }[]>('get_captured_notifications');
+    expect(records.map((record) => record.title)).toEqual(['WDIO Notification']);
+    await plugin('disable_notification_capture');
+    await plugin('clear_captured_notifications');
+  });
+
+  it('should list windows opened and closed from the UI', async () => {
+    await testId('window-label-input').setValue('child-ui');
+
+    await testId('open-window-button').click();
+    await expect(testId('window-result')).toHaveText('opened child-ui');
+    await expect(testId('open-windows').$('[data-label="child-ui"]')).toBeExisting();
+    const windows = await plugin<{ label: string }[]>('list_windows');
+    expect(windows.map((window) => window.label)).toContain('child-ui');
+
+    await testId('close-window-button').click();
+    await expect(testId('window-result')).toHaveText('closed child-ui');
+    await expect(testId('open-windows').$('[data-label="child-ui"]')).not.toBeExisting();
+  });
+
+  it('should trace invokes made from the UI', async () => {
+    await plugin('start_invoke_tracing');
+
+    await testId('slow-command-button').click();
+
+    await expect(testId('slow-command-result')).toHaveText('done in 100ms');
+    const { entries } = await plugin<{ entries: InvokeTraceEntry[] }>('get_invoke_trace');
+    const slow = entries.find((entry) => entry.command === 'slow_command');
+    expect(slow?.duration_ms).toBeGreaterThanOrEqual(90);
+    await plugin('stop_invoke_tracing');
+  });
+
+  it('should follow the app theme', async () => {
+    await plugin('set_app_theme', { theme: 'dark' });
+    await expect(testId('color-scheme')).toHaveText('dark');
+
+    await plugin('set_app_theme', { theme: 'light' });
+    await expect(testId('color-scheme')).toHaveText('light');
+  });
+
+  it('should render menu, lifecycle, and deep link events', async () => {
+    await plugin('trigger_menu_item', { id: 'view-sidebar' });
+    await expect(testId('last-menu-item')).toHaveText('view-sidebar');
+
+    // low_memory is mobile only; background minimizes the window, so restore it
+    await plugin('simulate_lifecycle', { event: 'background' });
+    await plugin('simulate_lifecycle', { event: 'foreground' });
+    await expect(testId('last-lifecycle-event')).toHaveText('foreground');
+
+    await plugin('simulate_deep_link', { url: 'testapp://capabilities' });
+    await expect(testId('deep-link-url')).toHaveText('testapp://capabilities');
+  });
+});
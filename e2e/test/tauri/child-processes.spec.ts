@@ -0,0 +1,92 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import fs from 'node:fs';
+import os from 'node:os';
+
+type SpawnedOutput = { stdout: string; stderr: string; running: boolean; exit_code: number | null };
+
+async function spawnNode(script: string, cwd?: string) {
+  const { pid } = (await browser.tauri.execute(
+    ({ core }, args: { command: string; args: string[]; cwd?: string }) => core.invoke('spawn_process', args),
+    { command: 'node', args: ['-e', script], cwd },
+  )) as { pid: number };
+  return pid;
+}
+
+async function getOutput(pid: number) {
+  return (await browser.tauri.execute(
+    ({ core }, pid: number) => core.invoke('get_spawned_output', { pid }),
+    pid,
+  )) as SpawnedOutput;
+}
+
+async function killProcess(pid: number, signal?: string) {
+  await browser.tauri.execute(
+    ({ core }, args: { pid: number; signal?: string }) => core.invoke('kill_process', args),
+    { pid, signal },
+  );
+}
+
+async function waitForOutput(pid: number, done: (output: SpawnedOutput) => boolean, description: string) {
+  let output = await getOutput(pid);
+  await browser.waitUntil(
+    async () => {
+      output = await getOutput(pid);
+      return done(output);
+    },
+    { timeout: 10000, timeoutMsg: `process ${pid} never ${description}` },
+  );
+  return output;
+}
+
+describe('Tauri Fixture Child Processes', () => {
+  it('should capture stdout, stderr and the exit code', async () => {
+    const pid = await spawnNode("console.log('hello'); console.error('oops'); process.exit(3)");
+    const output = await waitForOutput(pid, (output) => !output.running, 'exited');
+
+    expect(output.stdout.trim()).toBe('hello');
+    expect(output.stderr.trim()).toBe('oops');
+    expect(output.exit_code).toBe(3);
+  });
+
+  it('should run the process in the given directory', async () => {
+    const cwd = fs.realpathSync(os.tmpdir());
+    const pid = await spawnNode('console.log(process.cwd())', cwd);
+    const output = await waitForOutput(pid, (output) => !output.running, 'exited');
+
+    expect(fs.realpathSync(output.stdout.trim())).toBe(cwd);
+  });
+
+  it('should kill a long-running process', async () => {
+    const pid = await spawnNode("console.log('ready'); setInterval(() => {}, 1000)");
+    await waitForOutput(pid, (output) => output.stdout.includes('ready'), 'started');
+    expect((await getOutput(pid)).running).toBe(true);
+
+    await killProcess(pid);
+    const output = await waitForOutput(pid, (output) => !output.running, 'stopped');
+    expect(output.exit_code).not.toBe(0);
+  });
+
+  it('should deliver a named signal', async function () {
+    if (process.platform === 'win32') {
+      this.skip();
+    }
+    const pid = await spawnNode(
+      "process.on('SIGTERM', () => { console.log('terminated'); process.exit(0) }); " +
+        "console.log('ready'); setInterval(() => {}, 1000)",
+    );
+    await waitForOutput(pid, (output) => output.stdout.includes('ready'), 'started');
+
+    await killProcess(pid, 'SIGTERM');
+    const output = await waitForOutput(pid, (output) => !output.running, 'stopped');
+    expect(output.stdout).toContain('terminated');
+    expect(output.exit_code).toBe(0);
+  });
+
+  it('should reject unknown pids and commands', async () => {
+    await expect(getOutput(4294967295)).rejects.toThrow(/No spawned process with pid/);
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('spawn_process', { command: 'wdio-no-such-command' })),
+    ).rejects.toThrow(/Failed to spawn 'wdio-no-such-command'/);
+  });
+});
@@ -0,0 +1,128 @@
+import { expect } from '@wdio/globals';
+import { browser, withExecuteOptions } from '@wdio/tauri-service';
+
+type RecordedEvents = { events: Array<{ name: string; payload: { label: string } }>; dropped: number };
+
+async function openChildWindow(label: string) {
+  await browser.tauri.execute(({ core }, label: string) => core.invoke('open_child_window', { label }), label);
+  await browser.waitUntil(async () => (await browser.tauri.listWindows()).includes(label), {
+    timeout: 10000,
+    timeoutMsg: `window ${label} never opened`,
+  });
+}
+
+async function closeChildWindow(label: string) {
+  await browser.tauri.execute(({ core }, label: string) => core.invoke('close_child_window', { label }), label);
+  await browser.waitUntil(async () => !(await browser.tauri.listWindows()).includes(label), {
+    timeout: 10000,
+    timeoutMsg: `window ${label} never closed`,
+  });
+}
+
+// Child windows are opened by the fixture's open_child_window command and load child.html
+describe('Tauri Child Windows', () => {
+  afterEach(async () => {
+    for (const label of await browser.tauri.listWindows()) {
+      if (label.startsWith('child-')) {
+        await closeChildWindow(label);
+      }
+    }
+  });
+
+  it('should run execute in a child window that renders its own label', async () => {
+    await openChildWindow('child-a');
+
+    const inChild = withExecuteOptions({ windowLabel: 'child-a' });
+    await browser.waitUntil(
+      async () =>
+        (await browser.tauri.execute(() => document.getElementById('window-label')?.textContent, inChild)) ===
+        'child-a',
+      { timeout: 10000, timeoutMsg: 'child window never rendered its label' },
+    );
+    const label = await browser.tauri.execute(
+      ({ core }) => core.invoke('plugin:wdio|get_active_window_label'),
+      inChild,
+    );
+    expect(label).toBe('child-a');
+  });
+
+  it('should keep several child windows apart', async () => {
+    await openChildWindow('child-a');
+    await openChildWindow('child-b');
+
+    expect(await browser.tauri.listWindows()).toEqual(expect.arrayContaining(['main', 'child-a', 'child-b']));
+
+    await closeChildWindow('child-a');
+    expect(await browser.tauri.listWindows()).toContain('child-b');
+  });
+
+  it('should emit window-created and window-destroyed events', async () => {
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|start_event_recording', { eventNames: ['window-created', 'window-destroyed'] }),
+    );
+    try {
+      await openChildWindow('child-events');
+      await closeChildWindow('child-events');
+
+      await browser.waitUntil(
+        async () => {
+          const { events } = (await browser.tauri.execute(({ core }) =>
+            core.invoke('plugin:wdio|get_recorded_events'),
+          )) as RecordedEvents;
+          return events.some((event) => event.name === 'window-destroyed');
+        },
+        { timeout: 5000, timeoutMsg: 'window-destroyed was never emitted' },
+      );
+      const { events } = (await browser.tauri.execute(({ core }) =>
+        core.invoke('plugin:wdio|get_recorded_events'),
+      )) as RecordedEvents;
+      expect(events.map((event) => [event.name, event.payload.label])).toEqual([
+        ['window-created', 'child-events'],
+        ['window-destroyed', 'child-events'],
+      ]);
+    } finally {
+      await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|stop_event_recording'));
+    }
+  });
+
+  it('should reject duplicate labels and non-child windows', async () => {
+    await openChildWindow('child-a');
+
+    await expect(openChildWindow('child-a')).rejects.toThrow(/Window 'child-a' already exists/);
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('close_child_window', { label: 'main' })),
+    ).rejects.toThrow(/not a child window/);
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('close_child_window', { label: 'child-missing' })),
+    ).rejects.toThrow(/Window 'child-missing' not found/);
+  });
+
+  it('should wait for a window opened later whose label matches a pattern', async () => {
+    const info = (await browser.tauri.execute(async ({ core }) => {
+      setTimeout(() => core.invoke('open_child_window', { label: 'child-popup-7f3a' }), 500);
+      return core.invoke('plugin:wdio|wait_for_window', { label: 'child-popup-*', timeoutMs: 10000 });
+    })) as { label: string; title: string };
+
+    expect(info.label).toBe('child-popup-7f3a');
+    expect(info.title).toBe('Child child-popup-7f3a');
+  });
+
+  it('should wait for a window to close', async () => {
+    await openChildWindow('child-a');
+
+    await browser.tauri.execute(async ({ core }) => {
+      setTimeout(() => core.invoke('close_child_window', { label: 'child-a' }), 500);
+      await core.invoke('plugin:wdio|wait_for_window_close', { label: 'child-a', timeoutMs: 10000 });
+    });
+
+    expect(await browser.tauri.listWindows()).not.toContain('child-a');
+  });
+
+  it('should time out waiting for a window that never opens', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) =>
+        core.invoke('plugin:wdio|wait_for_window', { label: 'child-never-*', timeoutMs: 200 }),
+      ),
+    ).rejects.toThrow(/timed out after 200ms waiting for a window matching 'child-never-\*'/);
+  });
+});
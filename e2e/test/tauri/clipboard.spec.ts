@@ -0,0 +1,82 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+// 2x2 RGBA PNG: red, green / blue, white
+const PNG_2X2 =
+  'iVBORw0KGgoAAAANSUhEUgAAAAIAAAACCAYAAABytg0kAAAAEklEQVR4nGP4z8DwHwyBNBgAAEnICff5q7YNAAAAAElFTkSuQmCC';
+const PNG_SIGNATURE_BASE64 = 'iVBORw0KGgo';
+
+async function invokeSettled(command: string, args?: Record<string, unknown>) {
+  return browser.tauri.execute(
+    ({ core }, cmd: string, cmdArgs?: Record<string, unknown>) =>
+      core.invoke(cmd, cmdArgs).then(
+        (value) => ({ ok: true, value }),
+        (error: unknown) => ({ ok: false, error: String(error) }),
+      ),
+    command,
+    args,
+  ) as Promise<{ ok: true; value: unknown } | { ok: false; error: string }>;
+}
+
+describe('Tauri Clipboard', () => {
+  it('should round-trip text', async () => {
+    const text = `wdio clipboard ${Date.now()} ✓`;
+
+    await browser.tauri.execute(
+      ({ core }, t: string) => core.invoke('plugin:wdio|set_clipboard_text', { text: t }),
+      text,
+    );
+
+    expect(await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_clipboard_text'))).toBe(text);
+  });
+
+  it('should round-trip an image as base64 PNG', async () => {
+    await browser.tauri.execute(
+      ({ core }, image: string) => core.invoke('plugin:wdio|set_clipboard_image', { image }),
+      PNG_2X2,
+    );
+
+    const first = (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_clipboard_image'))) as string;
+    expect(first.startsWith(PNG_SIGNATURE_BASE64)).toBe(true);
+
+    // Re-encoding is stable once the pixels are on the clipboard
+    await browser.tauri.execute(
+      ({ core }, image: string) => core.invoke('plugin:wdio|set_clipboard_image', { image }),
+      first,
+    );
+    expect(await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_clipboard_image'))).toBe(first);
+  });
+
+  it('should accept a data URL image', async () => {
+    const result = await invokeSettled('plugin:wdio|set_clipboard_image', {
+      image: `data:image/png;base64,${PNG_2X2}`,
+    });
+
+    expect(result.ok).toBe(true);
+  });
+
+  it('should report a format mismatch when reading text while an image is on the clipboard', async () => {
+    await invokeSettled('plugin:wdio|set_clipboard_image', { image: PNG_2X2 });
+
+    const result = await invokeSettled('plugin:wdio|get_clipboard_text');
+
+    expect(result.ok).toBe(false);
+    expect(!result.ok && result.error).toContain('Clipboard format mismatch');
+  });
+
+  it('should report a format mismatch when reading an image while text is on the clipboard', async () => {
+    await invokeSettled('plugin:wdio|set_clipboard_text', { text: 'not an image' });
+
+    const result = await invokeSettled('plugin:wdio|get_clipboard_image');
+
+    expect(result.ok).toBe(false);
+    expect(!result.ok && result.error).toContain('Clipboard format mismatch');
+  });
+
+  it('should reject image data that is not a PNG', async () => {
+    const result = await invokeSettled('plugin:wdio|set_clipboard_image', { image: 'bm90IGEgcG5n' });
+
+    expect(result.ok).toBe(false);
+    expect(!result.ok && result.error).toContain('invalid PNG');
+  });
+});
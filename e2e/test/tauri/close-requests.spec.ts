@@ -0,0 +1,80 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type WindowEventRecord = { label: string; kind: string };
+
+async function openWindow(label: string) {
+  await browser.tauri.execute(({ core }, label: string) => core.invoke('open_child_window', { label }), label);
+  await browser.tauri.execute(
+    ({ core }, label: string) => core.invoke('plugin:wdio|wait_for_load', { label, after: 0 }),
+    label,
+  );
+}
+
+async function setPreventClose(prevent: boolean) {
+  await browser.tauri.execute(({ core }, prevent: boolean) => core.invoke('set_prevent_close', { prevent }), prevent);
+}
+
+async function requestClose(label: string) {
+  return (await browser.tauri.execute(
+    ({ core }, label: string) => core.invoke('plugin:wdio|request_window_close', { label }),
+    label,
+  )) as { prevented: boolean };
+}
+
+async function isOpen(label: string) {
+  const windows = (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|list_windows'))) as {
+    label: string;
+  }[];
+  return windows.some((window) => window.label === label);
+}
+
+describe('Tauri Close Requests', () => {
+  afterEach(async () => {
+    await setPreventClose(false);
+    for (const label of ['child-close-allowed', 'child-close-prevented']) {
+      if (await isOpen(label)) {
+        await browser.tauri.execute(
+          ({ core }, label: string) => core.invoke('plugin:wdio|force_close_window', { label }),
+          label,
+        );
+      }
+    }
+  });
+
+  it('should report a close the app allowed', async () => {
+    await openWindow('child-close-allowed');
+
+    expect(await requestClose('child-close-allowed')).toEqual({ prevented: false });
+    expect(await isOpen('child-close-allowed')).toBe(false);
+  });
+
+  it('should report a close the app prevented and record the request', async () => {
+    await openWindow('child-close-prevented');
+    await setPreventClose(true);
+    const since = Date.now();
+
+    expect(await requestClose('child-close-prevented')).toEqual({ prevented: true });
+    expect(await isOpen('child-close-prevented')).toBe(true);
+    const { events } = (await browser.tauri.execute(
+      ({ core }, since: number) =>
+        core.invoke('plugin:wdio|get_window_events', { label: 'child-close-prevented', since }),
+      since,
+    )) as { events: WindowEventRecord[] };
+    expect(events.map((event) => event.kind)).toContain('close_requested');
+  });
+
+  it('should force close a window that prevents close requests', async () => {
+    await openWindow('child-close-prevented');
+    await setPreventClose(true);
+
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|force_close_window', { label: 'child-close-prevented' }),
+    );
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|wait_for_window_close', { label: 'child-close-prevented', timeoutMs: 5000 }),
+    );
+
+    expect(await isOpen('child-close-prevented')).toBe(false);
+  });
+});
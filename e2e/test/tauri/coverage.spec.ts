@@ -0,0 +1,80 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+
+type CoverageResult = { label: string; bytes: number; path: string | null; coverage: unknown };
+
+async function collect(outputPath?: string) {
+  return (await browser.tauri.execute(
+    ({ core }, outputPath) => core.invoke('plugin:wdio|collect_coverage', { label: 'main', outputPath }),
+    outputPath,
+  )) as CoverageResult;
+}
+
+/** Install istanbul-shaped coverage for `files` files, big enough to span several chunks */
+async function instrument(files: number) {
+  await browser.tauri.execute((_, files) => {
+    const coverage: Record<string, unknown> = {};
+    for (let i = 0; i < files; i++) {
+      const name = `/src/components/Widget${i}-ü.tsx`;
+      const statements: Record<string, number> = {};
+      for (let s = 0; s < 200; s++) {
+        statements[s] = (i * s) % 7;
+      }
+      coverage[name] = { path: name, s: statements, f: { 0: i }, b: { 0: [i, 0] } };
+    }
+    (window as unknown as { __coverage__: unknown }).__coverage__ = coverage;
+  }, files);
+}
+
+describe('Tauri Coverage Collection', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wdio-coverage-'));
+  });
+
+  afterEach(async () => {
+    fs.rmSync(dir, { recursive: true, force: true });
+    await browser.tauri.execute(() => {
+      delete (window as unknown as { __coverage__?: unknown }).__coverage__;
+    });
+  });
+
+  it('should write coverage larger than one chunk to the output path intact', async () => {
+    await instrument(500);
+    const output = path.join(dir, 'nested', 'main.json');
+
+    const result = await collect(output);
+
+    expect(result).toEqual({ label: 'main', bytes: expect.any(Number), path: output, coverage: null });
+    const written = fs.readFileSync(output);
+    expect(written.length).toBe(result.bytes);
+    expect(result.bytes).toBeGreaterThan(1024 * 1024);
+    const coverage = JSON.parse(written.toString('utf8'));
+    expect(Object.keys(coverage)).toHaveLength(500);
+    expect(coverage['/src/components/Widget499-ü.tsx'].b).toEqual({ 0: [499, 0] });
+  });
+
+  it('should return the coverage when no output path is given', async () => {
+    await instrument(2);
+
+    const result = await collect();
+
+    expect(result.path).toBeNull();
+    expect(result.bytes).toBeGreaterThan(0);
+    expect(Object.keys(result.coverage as object)).toEqual([
+      '/src/components/Widget0-ü.tsx',
+      '/src/components/Widget1-ü.tsx',
+    ]);
+  });
+
+  it('should report no bytes for a page without coverage', async () => {
+    const output = path.join(dir, 'main.json');
+
+    expect(await collect(output)).toEqual({ label: 'main', bytes: 0, path: null, coverage: null });
+    expect(fs.existsSync(output)).toBe(false);
+  });
+});
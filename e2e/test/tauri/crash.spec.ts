@@ -0,0 +1,65 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type CrashReport = {
+  message: string;
+  location: string | null;
+  backtrace: string;
+  thread: string;
+  timestamp_ms: number;
+};
+
+describe('Tauri Panic Capture', () => {
+  it('should return a crash report after a backend panic on a non-main thread', async () => {
+    const before = Date.now();
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('panic_on_demand', { message: 'e2e panic from crash.spec' }),
+    );
+
+    const report = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_last_crash'),
+    )) as CrashReport | null;
+
+    expect(report).not.toBeNull();
+    expect(report?.message).toBe('e2e panic from crash.spec');
+    expect(report?.thread).toBe('wdio-panic-on-demand');
+    expect(report?.location).toContain('main.rs');
+    expect(report?.timestamp_ms).toBeGreaterThanOrEqual(before - 60_000);
+  });
+
+  it('should add the panic to the log buffer', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('panic_on_demand', { message: 'buffered panic' }));
+
+    const entries = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_logs', { filter: { contains: 'buffered panic' } }),
+    )) as Array<{ level: string; target: string | null }>;
+
+    expect(entries.length).toBeGreaterThan(0);
+    expect(entries[0].level).toBe('error');
+    expect(entries[0].target).toBe('panic');
+  });
+
+  it('should survive a crash_main_process panic on a spawned thread', async () => {
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('crash_main_process', { mode: 'panic', spawnedThread: true }),
+    );
+
+    const report = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_last_crash'),
+    )) as CrashReport | null;
+    expect(report?.message).toBe('crash_main_process triggered');
+    expect(report?.thread).toBe('wdio-crash-on-demand');
+  });
+
+  it('should delay main-thread commands while hang_main_process blocks the event loop', async () => {
+    const elapsed = (await browser.tauri.execute(async ({ core }) => {
+      await core.invoke('hang_main_process', { durationMs: 1500 });
+      const start = performance.now();
+      // Sync fixture commands run on the main thread, so this waits out the hang
+      await core.invoke('get_shortcut_count');
+      return performance.now() - start;
+    })) as number;
+
+    expect(elapsed).toBeGreaterThan(1000);
+  });
+});
@@ -0,0 +1,66 @@
+import { $, browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+
+async function dbExecute(sql: string, params: unknown[] = []) {
+  return (await browser.tauri.execute(
+    ({ core }, sql: string, params: unknown[]) => core.invoke('db_execute', { sql, params }),
+    sql,
+    params,
+  )) as number;
+}
+
+async function dbQuery(sql: string, params: unknown[] = []) {
+  return (await browser.tauri.execute(
+    ({ core }, sql: string, params: unknown[]) => core.invoke('db_query', { sql, params }),
+    sql,
+    params,
+  )) as Record<string, unknown>[];
+}
+
+describe('Tauri Fixture Database', () => {
+  let dir: string;
+
+  beforeEach(async () => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wdio-db-'));
+    const dbPath = path.join(dir, 'state.db');
+    await browser.tauri.execute(({ core }, path: string) => core.invoke('db_open', { path }), dbPath);
+    await dbExecute('CREATE TABLE notes (id INTEGER PRIMARY KEY, body TEXT NOT NULL, attachment BLOB)');
+  });
+
+  afterEach(async () => {
+    // Swap in an in-memory database so the file isn't held open while it's removed
+    await browser.tauri.execute(({ core }) => core.invoke('db_open', { path: ':memory:' }));
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('should store a note saved through the UI', async () => {
+    await $('#note-input').setValue('Buy milk');
+    await $('#note-submit').click();
+    await expect($('#note-result')).toHaveText('Saved');
+
+    expect(await dbQuery('SELECT id, body FROM notes WHERE body = ?1', ['Buy milk'])).toEqual([
+      { id: 1, body: 'Buy milk' },
+    ]);
+  });
+
+  it('should bind parameters instead of interpolating them', async () => {
+    const body = "Robert'); DROP TABLE notes;--";
+    expect(await dbExecute('INSERT INTO notes (body) VALUES (?1)', [body])).toBe(1);
+
+    expect(await dbQuery('SELECT body FROM notes')).toEqual([{ body }]);
+  });
+
+  it('should return BLOB columns as base64', async () => {
+    await dbExecute('INSERT INTO notes (body, attachment) VALUES (?1, ?2)', ['bytes', { base64: 'AAEC/w==' }]);
+
+    const [row] = await dbQuery('SELECT attachment, length(attachment) AS size FROM notes');
+    expect(row).toEqual({ attachment: 'AAEC/w==', size: 4 });
+  });
+
+  it('should reject invalid SQL with the database path', async () => {
+    await expect(dbQuery('SELEC body FROM notes')).rejects.toThrow(/Failed to query '.*state\.db'/);
+  });
+});
@@ -0,0 +1,30 @@
+import { $, browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+async function simulateDeepLink(url: string) {
+  await browser.tauri.execute(({ core }, url) => core.invoke('plugin:wdio|simulate_deep_link', { url }), url);
+}
+
+describe('Tauri Deep Link Simulation', () => {
+  it('should deliver the URL to the deep link handlers', async () => {
+    await simulateDeepLink('testapp://invite/123?ref=wdio');
+
+    await expect($('#deep-link-url')).toHaveText('testapp://invite/123?ref=wdio');
+  });
+
+  it('should deliver each URL while the app is running', async () => {
+    await simulateDeepLink('testapp://first');
+    await expect($('#deep-link-url')).toHaveText('testapp://first');
+
+    await simulateDeepLink('testapp://second/path');
+    await expect($('#deep-link-url')).toHaveText('testapp://second/path');
+  });
+
+  it('should reject malformed URLs without notifying the app', async () => {
+    await simulateDeepLink('testapp://unchanged');
+    await expect($('#deep-link-url')).toHaveText('testapp://unchanged');
+
+    await expect(simulateDeepLink('not a url')).rejects.toThrow(/Invalid deep link/);
+    await expect($('#deep-link-url')).toHaveText('testapp://unchanged');
+  });
+});
@@ -0,0 +1,31 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+const isDevtoolsOpen = async () =>
+  (await browser.tauri.execute(({ core }) =>
+    core.invoke('plugin:wdio|is_devtools_open', { label: 'main' }),
+  )) as boolean;
+
+// e2e runs use debug builds, where devtools are always available
+describe('Tauri Devtools', () => {
+  after(async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|close_devtools', { label: 'main' }));
+  });
+
+  it('should open and close devtools for a window', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|open_devtools', { label: 'main' }));
+    await browser.waitUntil(isDevtoolsOpen, { timeout: 5000, timeoutMsg: 'devtools did not open' });
+
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|close_devtools', { label: 'main' }));
+    await browser.waitUntil(async () => !(await isDevtoolsOpen()), {
+      timeout: 5000,
+      timeoutMsg: 'devtools did not close',
+    });
+  });
+
+  it('should reject unknown labels', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|open_devtools', { label: 'nonexistent-window-42' })),
+    ).rejects.toThrow(/Window not found/);
+  });
+});
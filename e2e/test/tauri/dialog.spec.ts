@@ -0,0 +1,58 @@
+import { $, browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+describe('Tauri Dialog Mocking', () => {
+  afterEach(async () => {
+    await browser.tauri.clearDialogMocks();
+  });
+
+  it('should answer an open dialog with the scripted file path', async () => {
+    await browser.tauri.mockDialog('open', '/tmp/wdio-report.txt');
+
+    await $('#open-dialog-button').click();
+
+    await expect($('#dialog-result')).toHaveText('open: "/tmp/wdio-report.txt"');
+  });
+
+  it('should record the options a dialog was requested with', async () => {
+    await browser.tauri.mockDialog('open', null);
+
+    await $('#open-dialog-button').click();
+    await expect($('#dialog-result')).toHaveText('open: null');
+
+    const invocations = await browser.tauri.getDialogInvocations();
+    expect(invocations).toHaveLength(1);
+    expect(invocations[0]).toEqual({
+      kind: 'open',
+      options: expect.objectContaining({
+        title: 'Pick a file',
+        filters: [{ name: 'Text', extensions: ['txt'] }],
+      }),
+    });
+  });
+
+  it('should use queued responses in order', async () => {
+    await browser.tauri.mockDialog('confirm', true);
+    await browser.tauri.mockDialog('confirm', false);
+
+    await $('#confirm-dialog-button').click();
+    await expect($('#dialog-result')).toHaveText('confirm: true');
+
+    await $('#confirm-dialog-button').click();
+    await expect($('#dialog-result')).toHaveText('confirm: false');
+
+    const invocations = await browser.tauri.getDialogInvocations();
+    expect(invocations.map((invocation) => invocation.kind)).toEqual(['confirm', 'confirm']);
+    expect(invocations[0].options).toEqual(expect.objectContaining({ title: 'Confirm reset', message: 'Reset the counter?' }));
+  });
+
+  it('should forget invocations after clearDialogMocks', async () => {
+    await browser.tauri.mockDialog('confirm', true);
+    await $('#confirm-dialog-button').click();
+    await expect($('#dialog-result')).toHaveText('confirm: true');
+
+    await browser.tauri.clearDialogMocks();
+
+    expect(await browser.tauri.getDialogInvocations()).toEqual([]);
+  });
+});
@@ -0,0 +1,41 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+describe('Tauri Environment', () => {
+  it('should read an allowlisted environment variable', async () => {
+    const env = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_env', { names: ['WDIO_E2E_SENTINEL'] }),
+    )) as Record<string, string>;
+
+    expect(env).toEqual({ WDIO_E2E_SENTINEL: 'wdio-e2e-sentinel' });
+  });
+
+  it('should refuse variables outside the allowlist', async () => {
+    const result = await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_env', { names: ['PATH'] }).then(
+        () => 'resolved',
+        (error: unknown) => String(error),
+      ),
+    );
+
+    expect(result).toContain('Environment access denied');
+  });
+
+  it('should refuse a full environment dump by default', async () => {
+    const result = await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_env').then(
+        () => 'resolved',
+        (error: unknown) => String(error),
+      ),
+    );
+
+    expect(result).toContain('Environment access denied');
+  });
+
+  it('should return the command line arguments the app was launched with', async () => {
+    const args = (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_cli_args'))) as string[];
+
+    expect(args.length).toBeGreaterThan(0);
+    expect(args).toEqual(expect.arrayContaining(['foo', 'bar=baz']));
+  });
+});
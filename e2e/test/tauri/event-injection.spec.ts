@@ -0,0 +1,48 @@
+import { $, browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type EventTarget = { kind: 'all' } | { kind: 'label' | 'window' | 'webview' | 'webview_window'; label: string };
+
+async function emitEvent(name: string, payload: unknown, target?: EventTarget) {
+  await browser.tauri.execute(({ core }, args) => core.invoke('plugin:wdio|emit_event', args), {
+    name,
+    payload,
+    target,
+  });
+}
+
+describe('Tauri Event Injection', () => {
+  it('should deliver the payload to frontend listeners untouched', async () => {
+    const payload = { id: 42, tags: ['a', 'b'], nested: { ok: true, value: null }, text: '{"not":"parsed"}' };
+
+    await emitEvent('wdio-injected', payload);
+
+    await expect($('#last-event-payload')).toHaveText(JSON.stringify(payload));
+  });
+
+  it('should deliver string payloads without extra quoting', async () => {
+    await emitEvent('wdio-injected', 'plain text');
+
+    await expect($('#last-event-payload')).toHaveText('"plain text"');
+  });
+
+  it('should deliver events targeted at the webview window', async () => {
+    await emitEvent('wdio-injected-targeted', { step: 1 }, { kind: 'webview_window', label: 'main' });
+    await expect($('#last-targeted-payload')).toHaveText('{"step":1}');
+
+    await emitEvent('wdio-injected-targeted', { step: 2 }, { kind: 'label', label: 'main' });
+    await expect($('#last-targeted-payload')).toHaveText('{"step":2}');
+  });
+
+  it('should not deliver events targeted elsewhere', async () => {
+    await emitEvent('wdio-injected-targeted', { step: 3 }, { kind: 'webview_window', label: 'main' });
+    await expect($('#last-targeted-payload')).toHaveText('{"step":3}');
+
+    await emitEvent('wdio-injected-targeted', { step: 4 }, { kind: 'webview_window', label: 'other-window' });
+    // Send a marker through the untargeted listener so the previous event had time to arrive
+    await emitEvent('wdio-injected', 'marker');
+    await expect($('#last-event-payload')).toHaveText('"marker"');
+
+    await expect($('#last-targeted-payload')).toHaveText('{"step":3}');
+  });
+});
@@ -0,0 +1,92 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type EventRecord = { name: string; payload: unknown; timestamp_ms: number };
+type RecordedEvents = { events: EventRecord[]; dropped: number };
+
+async function startRecording(eventNames: string[]) {
+  await browser.tauri.execute(
+    ({ core }, eventNames) => core.invoke('plugin:wdio|start_event_recording', { eventNames }),
+    eventNames,
+  );
+}
+
+async function emitEvents(name: string, count: number) {
+  await browser.tauri.execute(({ core }, args) => core.invoke('emit_test_events', args), { name, count });
+}
+
+async function getRecordedEvents(filter: { name?: string; since_timestamp?: number } = {}) {
+  return (await browser.tauri.execute(
+    ({ core }, filter) => core.invoke('plugin:wdio|get_recorded_events', { filter }),
+    filter,
+  )) as RecordedEvents;
+}
+
+describe('Tauri Event Recording', () => {
+  afterEach(async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|stop_event_recording'));
+  });
+
+  it('should record backend events with their payloads', async () => {
+    await startRecording(['sync-complete', 'sync-failed']);
+
+    await emitEvents('sync-complete', 2);
+    await emitEvents('unrelated-event', 1);
+
+    const recorded = await getRecordedEvents();
+    expect(recorded.events.map((event) => event.name)).toEqual(['sync-complete', 'sync-complete']);
+    expect(recorded.events.map((event) => event.payload)).toEqual([{ index: 0 }, { index: 1 }]);
+    expect(recorded.dropped).toBe(0);
+  });
+
+  it('should filter recorded events by name', async () => {
+    await startRecording(['sync-complete', 'sync-failed']);
+
+    await emitEvents('sync-failed', 1);
+    await emitEvents('sync-complete', 1);
+
+    const failed = await getRecordedEvents({ name: 'sync-failed' });
+    expect(failed.events).toHaveLength(1);
+  });
+
+  it('should keep the records after stopping', async () => {
+    await startRecording(['sync-complete']);
+    await emitEvents('sync-complete', 3);
+
+    const count = await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|stop_event_recording'));
+    await emitEvents('sync-complete', 1);
+
+    expect(count).toBe(3);
+    expect((await getRecordedEvents()).events).toHaveLength(3);
+  });
+
+  it('should keep recording across webview reloads', async () => {
+    await startRecording(['sync-complete']);
+    await emitEvents('sync-complete', 1);
+
+    await browser.refresh();
+    // Round trip through the reloaded page before emitting again
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_recorded_events'));
+    await emitEvents('sync-complete', 1);
+
+    expect((await getRecordedEvents()).events).toHaveLength(2);
+  });
+
+  it('should cap high-frequency events and count the dropped ones', async () => {
+    await startRecording(['tick']);
+
+    // The fixture sets event_recording_capacity to 100
+    await emitEvents('tick', 150);
+
+    const recorded = await getRecordedEvents();
+    expect(recorded.events).toHaveLength(100);
+    expect(recorded.dropped).toBe(50);
+    expect(recorded.events[0].payload).toEqual({ index: 50 });
+  });
+
+  it('should require event names', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|start_event_recording')),
+    ).rejects.toThrow(/at least one event name is required/);
+  });
+});
@@ -62,4 +62,128 @@ describe('Tauri Execute - Advanced Patterns', () => {
   it('should provide clear error for invalid Tauri commands', async () => {
     await expect(browser.tauri.execute(({ core }) => core.invoke('nonexistent_command'))).rejects.toThrow();
   });
+
+  describe('execute preamble', () => {
+    const setPreamble = (script: string) =>
+      browser.tauri.execute(
+        ({ core }, preamble) => core.invoke('plugin:wdio|set_execute_preamble', { script: preamble }),
+        script,
+      );
+
+    afterEach(async () => {
+      await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|clear_execute_preamble'));
+    });
+
+    it('should make preamble helpers visible to scripts with args', async () => {
+      await setPreamble('function double(x) { return x * 2; }');
+
+      // String form: the helper only exists in the app, not in this file
+      const result = await browser.tauri.execute('(tauri, value) => double(value)', 21);
+      expect(result).toBe(42);
+    });
+
+    it('should reject with a preamble error when the preamble has a syntax error', async () => {
+      await setPreamble('function broken( {');
+
+      await expect(browser.tauri.execute(() => 1)).rejects.toThrow(/Execute preamble error/);
+    });
+
+    it('should reject with a preamble error when the preamble throws', async () => {
+      await setPreamble('throw new Error("helpers unavailable");');
+
+      await expect(browser.tauri.execute(() => 1)).rejects.toThrow(/Execute preamble error: helpers unavailable/);
+    });
+  });
+
+  describe('script error positions', () => {
+    type ScriptErrorDetails = { line: number; column: number; snippet: string } | null;
+
+    // Run a plain script through the plugin command, so positions are relative to it
+    async function executeError(script: string) {
+      return (await browser.tauri.execute(
+        ({ core }, script: string) =>
+          core.invoke('plugin:wdio|execute', { request: { script, args: [] } }).then(
+            () => null,
+            (error: { code: string; message: string; details: ScriptErrorDetails }) => error,
+          ),
+        script,
+      )) as { code: string; message: string; details: ScriptErrorDetails } | null;
+    }
+
+    it('should report a runtime error at its line in the script', async () => {
+      const error = await executeError('const list = null;\nreturn list.length;');
+
+      expect(error?.code).toBe('SCRIPT_ERROR');
+      expect(error?.details).toEqual(expect.objectContaining({ line: 2 }));
+      expect(error?.details?.snippet.split('\n')[0]).toBe('return list.length;');
+    });
+
+    it('should report a syntax error instead of timing out', async () => {
+      const started = Date.now();
+      const error = await executeError('const a = ;');
+
+      expect(error?.code).toBe('SCRIPT_ERROR');
+      expect(Date.now() - started).toBeLessThan(5000);
+      // Engines that give syntax errors a position (WebKit) place it in the script
+      if (error?.details) {
+        expect(error.details.line).toBe(1);
+      }
+    });
+
+    it('should reject a result over max_payload_bytes with its size', async () => {
+      // 17MB of text, over the default 16MB limit
+      const error = await executeError("return 'x'.repeat(17 * 1024 * 1024);");
+
+      expect(error?.code).toBe('PAYLOAD_TOO_LARGE');
+      expect(error?.details).toEqual({ actual: expect.any(Number), limit: 16 * 1024 * 1024 });
+      expect((error?.details as unknown as { actual: number }).actual).toBeGreaterThan(17 * 1024 * 1024);
+    });
+  });
+
+  describe('detailed execute', () => {
+    type ExecuteResponse = {
+      value: unknown;
+      eval_started_ms: number;
+      eval_duration_ms: number;
+      total_duration_ms: number;
+      window_label: string;
+    };
+
+    async function executeDetailed(script: string) {
+      return (await browser.tauri.execute(
+        ({ core }, script: string) =>
+          core.invoke('plugin:wdio|execute', { request: { script, args: [], window_label: 'main', detailed: true } }),
+        script,
+      )) as ExecuteResponse;
+    }
+
+    it('should return the value with timings that fit inside the total', async () => {
+      const before = Date.now();
+      const response = await executeDetailed('1 + 1');
+
+      expect(response.value).toBe(2);
+      expect(response.window_label).toBe('main');
+      expect(response.eval_duration_ms).toBeGreaterThanOrEqual(0);
+      expect(response.eval_duration_ms).toBeLessThanOrEqual(response.total_duration_ms);
+      // Clocks may differ by a rounding step
+      expect(response.eval_started_ms).toBeGreaterThanOrEqual(before - 1);
+      expect(response.eval_started_ms).toBeLessThanOrEqual(Date.now() + 1);
+    });
+
+    it('should count a slow script as time spent in the page', async () => {
+      const response = await executeDetailed('await new Promise((resolve) => setTimeout(resolve, 300)); return "done";');
+
+      expect(response.value).toBe('done');
+      expect(response.eval_duration_ms).toBeGreaterThanOrEqual(290);
+      expect(response.total_duration_ms).toBeGreaterThanOrEqual(response.eval_duration_ms);
+    });
+
+    it('should keep returning the bare value without detailed', async () => {
+      const value = await browser.tauri.execute(({ core }) =>
+        core.invoke('plugin:wdio|execute', { request: { script: '1 + 1', args: [] } }),
+      );
+
+      expect(value).toBe(2);
+    });
+  });
 });
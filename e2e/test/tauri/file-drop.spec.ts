@@ -0,0 +1,87 @@
+import { $$, browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import { mkdtempSync, rmSync, writeFileSync } from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+
+type DropArgs = { label: string; paths: string[]; position?: { x: number; y: number }; allowMissing?: boolean };
+
+async function simulateDrop(args: DropArgs) {
+  await browser.tauri.execute(({ core }, args) => core.invoke('plugin:wdio|simulate_file_drop', args), args);
+}
+
+async function getBackendDroppedPaths() {
+  return (await browser.tauri.execute(({ core }) => core.invoke('get_dropped_paths'))) as string[];
+}
+
+async function getDroppedFileNames() {
+  return $$('#dropped-files li').map((item) => item.getText());
+}
+
+describe('Tauri File Drop', () => {
+  let dir: string;
+  let files: string[];
+
+  before(() => {
+    dir = mkdtempSync(path.join(os.tmpdir(), 'wdio-file-drop-'));
+    files = ['notes.txt', 'photo.png'].map((name) => {
+      const file = path.join(dir, name);
+      writeFileSync(file, name);
+      return file;
+    });
+  });
+
+  after(() => {
+    rmSync(dir, { recursive: true, force: true });
+  });
+
+  beforeEach(async () => {
+    await browser.execute(() => {
+      (window as unknown as { dragDropEvents: string[] }).dragDropEvents = [];
+    });
+  });
+
+  it('should list the dropped files in the drop zone', async () => {
+    await simulateDrop({ label: 'main', paths: files });
+
+    await browser.waitUntil(async () => (await getDroppedFileNames()).length === 2, {
+      timeout: 5000,
+      timeoutMsg: 'dropped files were not listed',
+    });
+    expect(await getDroppedFileNames()).toEqual(['notes.txt', 'photo.png']);
+  });
+
+  it('should send enter, over, and drop events in order', async () => {
+    await simulateDrop({ label: 'main', paths: files.slice(0, 1), position: { x: 10, y: 20 } });
+
+    await browser.waitUntil(
+      async () =>
+        (await browser.execute(() => (window as unknown as { dragDropEvents: string[] }).dragDropEvents.length)) === 3,
+      { timeout: 5000, timeoutMsg: 'drag and drop events were not received' },
+    );
+    const events = await browser.execute(() => (window as unknown as { dragDropEvents: string[] }).dragDropEvents);
+    expect(events).toEqual(['enter', 'over', 'drop']);
+  });
+
+  it('should run the Rust drag and drop handlers', async () => {
+    await simulateDrop({ label: 'main', paths: files });
+
+    await browser.waitUntil(async () => (await getBackendDroppedPaths()).length === 2, {
+      timeout: 5000,
+      timeoutMsg: 'backend did not see the drop',
+    });
+    expect((await getBackendDroppedPaths()).map((file) => path.basename(file))).toEqual(['notes.txt', 'photo.png']);
+  });
+
+  it('should reject missing paths unless allowed', async () => {
+    const missing = path.join(dir, 'missing.txt');
+
+    await expect(simulateDrop({ label: 'main', paths: [missing] })).rejects.toThrow(/path does not exist/);
+
+    await simulateDrop({ label: 'main', paths: [missing], allowMissing: true });
+    await browser.waitUntil(async () => (await getDroppedFileNames()).includes('missing.txt'), {
+      timeout: 5000,
+      timeoutMsg: 'missing path was not dropped',
+    });
+  });
+});
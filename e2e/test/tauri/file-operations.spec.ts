@@ -0,0 +1,147 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+
+// 1x1 transparent PNG
+const PNG_BASE64 =
+  'iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=';
+
+type WriteArgs = { path: string; contents: string; options?: { encoding: string } };
+
+async function writeFile(args: WriteArgs) {
+  await browser.tauri.execute(({ core }, args: WriteArgs) => core.invoke('write_file', args), args);
+}
+
+async function readFile(filePath: string, encoding?: string) {
+  return (await browser.tauri.execute(
+    ({ core }, args) => core.invoke('read_file', args),
+    encoding ? { path: filePath, options: { encoding } } : { path: filePath },
+  )) as string;
+}
+
+describe('Tauri Fixture File Operations', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wdio-files-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('should round-trip a binary file through base64 byte for byte', async () => {
+    const file = path.join(dir, 'pixel.png');
+    await writeFile({ path: file, contents: PNG_BASE64, options: { encoding: 'base64' } });
+
+    expect(fs.readFileSync(file).equals(Buffer.from(PNG_BASE64, 'base64'))).toBe(true);
+    expect(await readFile(file, 'base64')).toBe(PNG_BASE64);
+  });
+
+  it('should keep multi-byte UTF-8 text intact by default', async () => {
+    const file = path.join(dir, 'text.txt');
+    const text = 'héllo wörld – 日本語 🚀';
+    await writeFile({ path: file, contents: text });
+
+    expect(fs.readFileSync(file, 'utf8')).toBe(text);
+    expect(await readFile(file)).toBe(text);
+  });
+
+  it('should fail to read a binary file as UTF-8', async () => {
+    const file = path.join(dir, 'pixel.png');
+    fs.writeFileSync(file, Buffer.from(PNG_BASE64, 'base64'));
+
+    await expect(readFile(file)).rejects.toThrow(/not valid UTF-8/);
+  });
+
+  it('should map bytes one to one with latin1', async () => {
+    const file = path.join(dir, 'latin1.txt');
+    fs.writeFileSync(file, Buffer.from([0x63, 0x61, 0x66, 0xe9]));
+
+    expect(await readFile(file, 'latin1')).toBe('café');
+  });
+
+  it('should reject invalid base64 contents', async () => {
+    const file = path.join(dir, 'invalid.bin');
+
+    await expect(writeFile({ path: file, contents: 'not base64!', options: { encoding: 'base64' } })).rejects.toThrow(
+      /invalid base64 contents/,
+    );
+    expect(fs.existsSync(file)).toBe(false);
+  });
+});
+
+type DirEntry = { name: string; is_dir: boolean; is_symlink: boolean; size: number; modified_ms: number | null };
+type FileStat = Omit<DirEntry, 'name'> & { exists: boolean; readonly: boolean };
+
+async function invoke<T>(command: string, args: Record<string, unknown>) {
+  return (await browser.tauri.execute(
+    ({ core }, { command, args }: { command: string; args: Record<string, unknown> }) => core.invoke(command, args),
+    { command, args },
+  )) as T;
+}
+
+describe('Tauri Fixture Directory Operations', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wdio-dirs-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('should create, list and remove a nested tree', async () => {
+    const root = path.join(dir, 'tree');
+    await invoke('create_dir', { path: path.join(root, 'nested', 'deeper'), recursive: true });
+    await writeFile({ path: path.join(root, 'top.txt'), contents: 'top' });
+    await writeFile({ path: path.join(root, 'nested', 'inner.txt'), contents: 'inner file' });
+
+    const entries = await invoke<DirEntry[]>('list_dir', { path: root });
+    expect(entries.map((entry) => [entry.name, entry.is_dir])).toEqual([
+      ['nested', true],
+      ['top.txt', false],
+    ]);
+    expect(entries[1].size).toBe(3);
+    expect(entries[1].modified_ms).toEqual(expect.any(Number));
+
+    const nested = await invoke<DirEntry[]>('list_dir', { path: path.join(root, 'nested') });
+    expect(nested.map((entry) => entry.name)).toEqual(['deeper', 'inner.txt']);
+
+    const stat = await invoke<FileStat>('stat', { path: path.join(root, 'nested', 'inner.txt') });
+    expect(stat).toMatchObject({ exists: true, is_dir: false, is_symlink: false, size: 10, readonly: false });
+
+    await expect(invoke('remove_dir', { path: root, recursive: false })).rejects.toThrow(root);
+    await invoke('remove_dir', { path: root, recursive: true });
+    expect((await invoke<FileStat>('stat', { path: root })).exists).toBe(false);
+  });
+
+  it('should name the offending path in errors', async () => {
+    const missing = path.join(dir, 'missing', 'child');
+
+    await expect(invoke('create_dir', { path: missing, recursive: false })).rejects.toThrow(missing);
+    await expect(invoke('list_dir', { path: missing })).rejects.toThrow(missing);
+    await expect(invoke('remove_dir', { path: missing, recursive: true })).rejects.toThrow(missing);
+  });
+
+  it('should report symlinks without following them when removing', async function () {
+    if (process.platform === 'win32') {
+      this.skip();
+    }
+    const target = path.join(dir, 'target');
+    const link = path.join(dir, 'link');
+    fs.mkdirSync(target);
+    fs.writeFileSync(path.join(target, 'kept.txt'), 'kept');
+    fs.symlinkSync(target, link);
+
+    const entries = await invoke<DirEntry[]>('list_dir', { path: dir });
+    expect(entries.find((entry) => entry.name === 'link')).toMatchObject({ is_dir: false, is_symlink: true });
+
+    await invoke('remove_dir', { path: link, recursive: true });
+    expect(fs.existsSync(link)).toBe(false);
+    expect(fs.readFileSync(path.join(target, 'kept.txt'), 'utf8')).toBe('kept');
+  });
+});
@@ -0,0 +1,101 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+
+type FsChange = { watcher_id: number; kind: string; paths: string[] };
+
+async function watchPath(target: string, recursive: boolean) {
+  return (await browser.tauri.execute(
+    ({ core }, args: { path: string; recursive: boolean }) => core.invoke('watch_path', args),
+    { path: target, recursive },
+  )) as number;
+}
+
+async function unwatch(watcherId: number) {
+  return (await browser.tauri.execute(
+    ({ core }, watcherId: number) => core.invoke('unwatch', { watcherId }),
+    watcherId,
+  )) as boolean;
+}
+
+async function getChanges(watcherId: number) {
+  const changes = (await browser.execute(
+    () => (window as unknown as { fsChanges: FsChange[] }).fsChanges,
+  )) as FsChange[];
+  return changes.filter((change) => change.watcher_id === watcherId);
+}
+
+describe('Tauri Fixture File Watcher', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    // Watchers report resolved paths, e.g. /private/var on macOS
+    dir = fs.realpathSync(fs.mkdtempSync(path.join(os.tmpdir(), 'wdio-watch-')));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('should deliver an fs-change event to the frontend when a file is written', async () => {
+    const watcherId = await watchPath(dir, false);
+    const file = path.join(dir, 'watched.txt');
+
+    try {
+      await browser.tauri.execute(
+        ({ core }, args: { path: string; contents: string }) => core.invoke('write_file', args),
+        { path: file, contents: 'changed' },
+      );
+
+      await browser.waitUntil(
+        async () => (await getChanges(watcherId)).some((change) => change.paths.includes(file)),
+        { timeout: 5000, timeoutMsg: 'no fs-change event for the written file' },
+      );
+      const kinds = (await getChanges(watcherId)).map((change) => change.kind);
+      expect(kinds.some((kind) => kind === 'create' || kind === 'modify')).toBe(true);
+    } finally {
+      await unwatch(watcherId);
+    }
+  });
+
+  it('should merge a burst of writes into one event per kind', async () => {
+    const watcherId = await watchPath(dir, false);
+
+    try {
+      // Written back to back, well inside the 100ms debounce window
+      for (let i = 0; i < 5; i++) {
+        fs.writeFileSync(path.join(dir, `burst-${i}.txt`), String(i));
+      }
+
+      await browser.waitUntil(async () => (await getChanges(watcherId)).length > 0, {
+        timeout: 5000,
+        timeoutMsg: 'no fs-change event for the burst',
+      });
+      await browser.pause(500);
+      const changes = await getChanges(watcherId);
+      const kinds = changes.map((change) => change.kind);
+      expect(new Set(kinds).size).toBe(kinds.length);
+      expect(changes.flatMap((change) => change.paths)).toContain(path.join(dir, 'burst-4.txt'));
+    } finally {
+      await unwatch(watcherId);
+    }
+  });
+
+  it('should stop emitting after unwatch', async () => {
+    const watcherId = await watchPath(dir, true);
+
+    expect(await unwatch(watcherId)).toBe(true);
+    expect(await unwatch(watcherId)).toBe(false);
+    fs.writeFileSync(path.join(dir, 'after-unwatch.txt'), 'ignored');
+    await browser.pause(500);
+    expect(await getChanges(watcherId)).toEqual([]);
+  });
+
+  it('should reject paths that cannot be watched', async () => {
+    const missing = path.join(dir, 'missing');
+
+    await expect(watchPath(missing, false)).rejects.toThrow(`Failed to watch '${missing}'`);
+  });
+});
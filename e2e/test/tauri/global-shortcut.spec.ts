@@ -0,0 +1,52 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type ShortcutInfo = { accelerator: string; registered: boolean };
+
+async function getShortcutCount() {
+  return (await browser.tauri.execute(({ core }) => core.invoke('get_shortcut_count'))) as number;
+}
+
+async function triggerShortcut(accelerator: string) {
+  await browser.tauri.execute(
+    ({ core }, accelerator) => core.invoke('plugin:wdio|trigger_global_shortcut', { accelerator }),
+    accelerator,
+  );
+}
+
+describe('Tauri Global Shortcuts', () => {
+  it('should list the shortcut registered by the app', async () => {
+    const shortcuts = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|list_global_shortcuts'),
+    )) as ShortcutInfo[];
+
+    expect(shortcuts).toHaveLength(1);
+    expect(shortcuts[0].accelerator).toMatch(/^shift\+(control|super)\+KeyK$/);
+  });
+
+  it('should invoke the handler when triggered', async () => {
+    const before = await getShortcutCount();
+
+    await triggerShortcut('CmdOrCtrl+Shift+K');
+
+    await browser.waitUntil(async () => (await getShortcutCount()) === before + 1, {
+      timeout: 5000,
+      timeoutMsg: 'shortcut handler did not run',
+    });
+  });
+
+  it('should match the accelerator regardless of spelling', async () => {
+    const before = await getShortcutCount();
+
+    await triggerShortcut(process.platform === 'darwin' ? 'shift+cmd+k' : 'Shift+Control+K');
+
+    await browser.waitUntil(async () => (await getShortcutCount()) === before + 1, {
+      timeout: 5000,
+      timeoutMsg: 'shortcut handler did not run',
+    });
+  });
+
+  it('should reject shortcuts the app did not register', async () => {
+    await expect(triggerShortcut('CmdOrCtrl+Alt+F12')).rejects.toThrow(/Global shortcut not registered/);
+  });
+});
@@ -0,0 +1,52 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type HealthStatus = { ready: boolean; windows_loaded: string[]; pending_executions: number; uptime_ms: number };
+type LogEntry = { message: string };
+
+async function healthCheck() {
+  return (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|health_check'))) as HealthStatus;
+}
+
+describe('Tauri Health Check', () => {
+  afterEach(async () => {
+    if ((await browser.tauri.listWindows()).includes('child-health')) {
+      await browser.tauri.execute(({ core }) => core.invoke('close_child_window', { label: 'child-health' }));
+    }
+  });
+
+  it('should report the loaded main window once the app is up', async () => {
+    const first = await healthCheck();
+    expect(first.ready).toBe(true);
+    expect(first.windows_loaded).toContain('main');
+    expect(first.pending_executions).toBe(0);
+
+    const second = await healthCheck();
+    expect(second.uptime_ms).toBeGreaterThanOrEqual(first.uptime_ms);
+  });
+
+  it('should list a child window only after it has loaded', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('open_child_window', { label: 'child-health' }));
+    await browser.waitUntil(async () => (await healthCheck()).windows_loaded.includes('child-health'), {
+      timeout: 10000,
+      timeoutMsg: 'child-health never finished loading',
+    });
+
+    await browser.tauri.execute(({ core }) => core.invoke('close_child_window', { label: 'child-health' }));
+    await browser.waitUntil(async () => !(await healthCheck()).windows_loaded.includes('child-health'), {
+      timeout: 10000,
+      timeoutMsg: 'closed window is still listed',
+    });
+    expect((await healthCheck()).windows_loaded).toContain('main');
+  });
+
+  it('should log wdio:ready once', async () => {
+    const entries = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_logs', { filter: { contains: 'wdio:ready' } }),
+    )) as LogEntry[];
+    const ready = entries.filter((entry) => entry.message.startsWith('wdio:ready:'));
+
+    expect(ready).toHaveLength(1);
+    expect(ready[0].message).toMatch(/window '.+' finished loading/);
+  });
+});
@@ -0,0 +1,56 @@
+import { $, browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type Echo = { method: string; path: string; query: Record<string, string>; headers: Record<string, string> };
+
+async function fetchFromUi(url: string) {
+  await $('#http-url').setValue(url);
+  await $('#http-fetch-button').click();
+}
+
+describe('Tauri Fixture HTTP Server', () => {
+  let port: number;
+
+  beforeEach(async () => {
+    ({ port } = (await browser.tauri.execute(({ core }) => core.invoke('start_http_server'))) as { port: number });
+  });
+
+  afterEach(async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('stop_http_server'));
+  });
+
+  it('should bind a free port when none is given', async () => {
+    expect(port).toBeGreaterThan(0);
+    await expect(
+      browser.tauri.execute(({ core }, port: number) => core.invoke('start_http_server', { port }), port),
+    ).rejects.toThrow(/already running/);
+  });
+
+  it('should render the echoed request', async () => {
+    await fetchFromUi(`http://127.0.0.1:${port}/items/42?color=blue`);
+
+    const result = await $('#http-result');
+    await expect(result).toHaveAttribute('data-state', 'loaded');
+    const echo = JSON.parse(await result.getText()) as Echo;
+    expect(echo).toMatchObject({ method: 'GET', path: '/items/42', query: { color: 'blue' } });
+    expect(echo.headers['x-fixture']).toBe('tauri-e2e-app');
+  });
+
+  it('should show the loading state while a slow response is pending', async () => {
+    await fetchFromUi(`http://127.0.0.1:${port}/slow?delay_ms=2000`);
+
+    const result = await $('#http-result');
+    await expect(result).toHaveAttribute('data-state', 'loading');
+    await expect(result).toHaveText('Loading...');
+    await expect(result).toHaveAttribute('data-state', 'loaded', { wait: 10000 });
+    expect((JSON.parse(await result.getText()) as Echo).path).toBe('/slow');
+  });
+
+  it('should stop serving after stop_http_server', async () => {
+    expect(await browser.tauri.execute(({ core }) => core.invoke('stop_http_server'))).toBe(true);
+    expect(await browser.tauri.execute(({ core }) => core.invoke('stop_http_server'))).toBe(false);
+
+    await fetchFromUi(`http://127.0.0.1:${port}/gone`);
+    await expect($('#http-result')).toHaveAttribute('data-state', 'error');
+  });
+});
@@ -0,0 +1,109 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type InvokeConditions = {
+  latency_ms?: number;
+  jitter_ms?: number;
+  failure_rate?: number;
+  failure_error?: unknown;
+  command_filter?: string[];
+  seed?: number;
+};
+
+async function setConditions(conditions: InvokeConditions) {
+  await browser.tauri.execute(
+    ({ core }, conditions) => core.invoke('plugin:wdio|set_invoke_conditions', { conditions }),
+    conditions,
+  );
+}
+
+/** Invoke `command` and resolve with 'ok' or the value it rejected with */
+async function outcome(command: string, args: Record<string, unknown> = {}) {
+  return browser.tauri.execute(
+    ({ core }, command, args) =>
+      core.invoke(command, args).then(
+        () => 'ok',
+        (error: unknown) => error,
+      ),
+    command,
+    args,
+  );
+}
+
+describe('Tauri Invoke Conditions', () => {
+  afterEach(async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|clear_invoke_conditions'));
+    await browser.tauri.restoreAllMocks();
+  });
+
+  it('should reject every filtered invoke at a failure rate of 1 and let the rest through', async () => {
+    await setConditions({ failure_rate: 1, failure_error: { code: 'OFFLINE' }, command_filter: ['get_platform_info'] });
+
+    for (let i = 0; i < 3; i++) {
+      expect(await outcome('get_platform_info')).toEqual({ code: 'OFFLINE' });
+    }
+    expect(await outcome('get_current_dir')).toBe('ok');
+    expect(await outcome('plugin:wdio|get_logs')).toBe('ok');
+  });
+
+  it('should fail mocked commands too', async () => {
+    const mock = await browser.tauri.mock('get_platform_info');
+    await mock.mockReturnValue({ os: 'mocked' });
+    await setConditions({ failure_rate: 1, command_filter: ['get_platform_info'] });
+
+    // Call through the app's own invoke, as app code would
+    const result = await browser.execute(() => {
+      type Core = { invoke: (cmd: string) => Promise<unknown> };
+      const { core } = (window as unknown as { __TAURI__: { core: Core } }).__TAURI__;
+      return core.invoke('get_platform_info').then(
+        () => 'ok',
+        (error: unknown) => error,
+      );
+    });
+
+    expect(result).toBe('Injected invoke failure: get_platform_info');
+    await mock.update();
+    expect(mock).not.toHaveBeenCalled();
+  });
+
+  it('should delay matching invokes by the latency', async () => {
+    await setConditions({ latency_ms: 500, command_filter: ['get_platform_info'] });
+
+    const elapsed = async (command: string) => {
+      const start = Date.now();
+      expect(await outcome(command)).toBe('ok');
+      return Date.now() - start;
+    };
+
+    expect(await elapsed('get_platform_info')).toBeGreaterThanOrEqual(500);
+    expect(await elapsed('get_current_dir')).toBeLessThan(500);
+  });
+
+  it('should repeat the same failures for the same seed', async () => {
+    const run = async () => {
+      await setConditions({ failure_rate: 0.5, seed: 42, command_filter: ['get_platform_info'] });
+      const results: unknown[] = [];
+      for (let i = 0; i < 10; i++) {
+        results.push(await outcome('get_platform_info'));
+      }
+      return results;
+    };
+
+    const first = await run();
+    expect(await run()).toEqual(first);
+    expect(first).toContain('ok');
+  });
+
+  it('should stop applying conditions once cleared', async () => {
+    await setConditions({ failure_rate: 1 });
+    expect(await outcome('get_platform_info')).not.toBe('ok');
+
+    const cleared = await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|clear_invoke_conditions'));
+    expect(cleared).toBe(true);
+    expect(await outcome('get_platform_info')).toBe('ok');
+  });
+
+  it('should reject a failure rate outside 0 to 1', async () => {
+    await expect(setConditions({ failure_rate: 2 })).rejects.toThrow(/failure_rate must be between 0 and 1/);
+  });
+});
@@ -0,0 +1,102 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+
+type RecordedInvocation = { command: string; args_hash: string; outcome: 'ok' | 'error'; result: unknown };
+
+async function invoke(command: string, args: Record<string, unknown> = {}) {
+  return browser.tauri.execute(({ core }, command, args) => core.invoke(command, args), command, args);
+}
+
+/** Invoke `command` and resolve with its result or the message it rejected with */
+async function settle(command: string, args: Record<string, unknown> = {}) {
+  return browser.tauri.execute(
+    ({ core }, command, args) =>
+      core.invoke(command, args).then(
+        (result: unknown) => ({ result }),
+        (error: unknown) => ({ error: String((error as Error)?.message ?? error) }),
+      ),
+    command,
+    args,
+  );
+}
+
+describe('Tauri Invoke Record and Replay', () => {
+  let dir: string;
+  let recording: string;
+  let note: string;
+
+  before(async () => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wdio-replay-'));
+    recording = path.join(dir, 'golden.json');
+    note = path.join(dir, 'note.txt');
+
+    await invoke('plugin:wdio|start_recording', { path: recording });
+    await invoke('write_file', { path: note, contents: 'first draft' });
+    await invoke('read_file', { path: note });
+    await invoke('write_file', { path: note, contents: 'second draft' });
+    await invoke('read_file', { path: note });
+    expect(await settle('read_file', { path: path.join(dir, 'missing.txt') })).toHaveProperty('error');
+    expect(await invoke('plugin:wdio|stop_recording')).toBe(5);
+  });
+
+  afterEach(async () => {
+    await invoke('plugin:wdio|disable_replay');
+  });
+
+  after(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('should write every settled invoke to the recording', () => {
+    const { version, invocations } = JSON.parse(fs.readFileSync(recording, 'utf8')) as {
+      version: number;
+      invocations: RecordedInvocation[];
+    };
+
+    expect(version).toBe(1);
+    expect(invocations.map(({ command, outcome, result }) => [command, outcome, result])).toEqual([
+      ['write_file', 'ok', null],
+      ['read_file', 'ok', 'first draft'],
+      ['write_file', 'ok', null],
+      ['read_file', 'ok', 'second draft'],
+      ['read_file', 'error', expect.stringContaining('Failed to read file')],
+    ]);
+    expect(invocations[1].args_hash).toBe(invocations[3].args_hash);
+    expect(invocations[1].args_hash).not.toBe(invocations[4].args_hash);
+  });
+
+  it('should replay repeated invokes in the order they were recorded without reaching the backend', async () => {
+    fs.rmSync(note, { force: true });
+    expect(await invoke('plugin:wdio|enable_replay', { path: recording, strict: true })).toBe(5);
+
+    await invoke('write_file', { path: note, contents: 'first draft' });
+    expect(await invoke('read_file', { path: note })).toBe('first draft');
+    expect(await invoke('read_file', { path: note })).toBe('second draft');
+    expect(await invoke('read_file', { path: note })).toBe('second draft');
+    expect(await settle('read_file', { path: path.join(dir, 'missing.txt') })).toEqual({
+      error: expect.stringContaining('Failed to read file'),
+    });
+    expect(fs.existsSync(note)).toBe(false);
+  });
+
+  it('should reject unrecorded invokes in strict mode', async () => {
+    await invoke('plugin:wdio|enable_replay', { path: recording, strict: true });
+
+    expect(await settle('get_platform_info')).toEqual({
+      error: expect.stringMatching(/No recorded result for get_platform_info/),
+    });
+  });
+
+  it('should let unrecorded invokes reach the backend in lenient mode', async () => {
+    await invoke('plugin:wdio|enable_replay', { path: recording, strict: false });
+    const other = path.join(dir, 'other.txt');
+
+    await invoke('write_file', { path: other, contents: 'live' });
+
+    expect(fs.readFileSync(other, 'utf8')).toBe('live');
+    expect(await invoke('read_file', { path: other })).toBe('live');
+  });
+});
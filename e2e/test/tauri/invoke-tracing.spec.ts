@@ -0,0 +1,119 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+
+type InvokeTraceEntry = {
+  command: string;
+  args_summary: string;
+  args_truncated: boolean;
+  started_at_ms: number;
+  duration_ms: number;
+  outcome: 'ok' | 'error' | 'mocked';
+  window_label: string;
+};
+type InvokeTrace = { entries: InvokeTraceEntry[]; dropped: number };
+type InvokeTraceExport = { path: string | null; document: unknown; entry_count: number };
+type Har = {
+  log: {
+    creator: { name: string; version: string };
+    entries: { startedDateTime: string; time: number; request: { url: string }; response: { statusText: string } }[];
+  };
+};
+
+async function getTrace() {
+  return (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_invoke_trace'))) as InvokeTrace;
+}
+
+describe('Tauri Invoke Tracing', () => {
+  afterEach(async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|stop_invoke_tracing'));
+    await browser.tauri.restoreAllMocks();
+  });
+
+  it('should record calls in start order with plausible durations', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|start_invoke_tracing'));
+
+    // The slow call starts first but finishes last
+    await browser.tauri.execute(async ({ core }) => {
+      await Promise.all([core.invoke('slow_command', { delayMs: 300 }), core.invoke('get_platform_info')]);
+    });
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|stop_invoke_tracing'));
+
+    const { entries } = await getTrace();
+    const commands = entries.map((entry) => entry.command);
+    const slow = entries.find((entry) => entry.command === 'slow_command');
+    const fast = entries.find((entry) => entry.command === 'get_platform_info');
+
+    expect(commands.indexOf('slow_command')).toBeLessThan(commands.indexOf('get_platform_info'));
+    expect(slow).toMatchObject({ outcome: 'ok', args_summary: '{"delayMs":300}', window_label: 'main' });
+    expect(slow?.duration_ms).toBeGreaterThanOrEqual(290);
+    expect(slow?.duration_ms).toBeLessThan(10_000);
+    expect(fast?.duration_ms).toBeLessThan(slow?.duration_ms ?? 0);
+    expect(commands).not.toContain('plugin:wdio|record_invoke');
+  });
+
+  it('should truncate arguments to the requested budget', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|start_invoke_tracing', { argsMaxBytes: 16 }));
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|emit_event', { name: 'trace-test', payload: 'x'.repeat(1000) }),
+    );
+
+    const emit = (await getTrace()).entries.find((entry) => entry.command === 'plugin:wdio|emit_event');
+    expect(emit?.args_summary.length).toBeLessThanOrEqual(16);
+    expect(emit?.args_truncated).toBe(true);
+  });
+
+  it('should mark app calls answered by a mock', async () => {
+    const mock = await browser.tauri.mock('get_platform_info');
+    await mock.mockReturnValue({ os: 'mocked' });
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|start_invoke_tracing'));
+
+    // Call through the app's own invoke, as app code would
+    await browser.execute(() => {
+      type Core = { invoke: (cmd: string) => Promise<unknown> };
+      return (window as unknown as { __TAURI__: { core: Core } }).__TAURI__.core.invoke('get_platform_info');
+    });
+
+    const entry = (await getTrace()).entries.find((e) => e.command === 'get_platform_info');
+    expect(entry?.outcome).toBe('mocked');
+  });
+
+  describe('export', () => {
+    beforeEach(async () => {
+      await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|start_invoke_tracing'));
+      await browser.tauri.execute(({ core }) => core.invoke('slow_command', { delayMs: 50 }));
+      await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|stop_invoke_tracing'));
+    });
+
+    it('should return a HAR document with the app as creator', async () => {
+      const exported = (await browser.tauri.execute(({ core }) =>
+        core.invoke('plugin:wdio|export_invoke_trace', { format: 'har' }),
+      )) as InvokeTraceExport;
+      const { log } = exported.document as Har;
+      const slow = log.entries.find((entry) => entry.request.url === 'ipc://localhost/slow_command');
+
+      expect(exported.path).toBeNull();
+      expect(log.creator.name).toBeTruthy();
+      expect(log.creator.version).toMatch(/^\d+\.\d+\.\d+/);
+      expect(log.entries).toHaveLength(exported.entry_count);
+      expect(slow?.response.statusText).toBe('ok');
+      expect(slow?.time).toBeGreaterThanOrEqual(40);
+      expect(Number.isNaN(Date.parse(slow?.startedDateTime ?? ''))).toBe(false);
+    });
+
+    it('should stream the export to a file path', async () => {
+      const file = path.join(fs.mkdtempSync(path.join(os.tmpdir(), 'wdio-trace-')), 'trace.har');
+      const exported = (await browser.tauri.execute(
+        ({ core }, filePath) => core.invoke('plugin:wdio|export_invoke_trace', { format: 'har', path: filePath }),
+        file,
+      )) as InvokeTraceExport;
+
+      expect(exported).toMatchObject({ path: file, document: null });
+      const har = JSON.parse(fs.readFileSync(file, 'utf8')) as Har;
+      expect(har.log.entries).toHaveLength(exported.entry_count);
+      fs.rmSync(path.dirname(file), { recursive: true, force: true });
+    });
+  });
+});
@@ -0,0 +1,61 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+async function simulateLifecycle(event: string) {
+  await browser.tauri.execute(
+    ({ core }, event: string) => core.invoke('plugin:wdio|simulate_lifecycle', { event }),
+    event,
+  );
+}
+
+async function lifecycleEvents() {
+  return (await browser.execute(
+    () => (window as unknown as { lifecycleEvents: string[] }).lifecycleEvents,
+  )) as string[];
+}
+
+async function isMinimized() {
+  const info = (await browser.tauri.execute(({ core }) =>
+    core.invoke('plugin:wdio|get_window_info', { label: 'main' }),
+  )) as { minimized: boolean };
+  return info.minimized;
+}
+
+// Desktop maps background/foreground to minimizing and restoring the window
+describe('Tauri Lifecycle Simulation', () => {
+  beforeEach(async () => {
+    await browser.execute(() => {
+      (window as unknown as { lifecycleEvents: string[] }).lifecycleEvents = [];
+    });
+  });
+
+  afterEach(async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|focus_window', { label: 'main' }));
+  });
+
+  it('should background and foreground the window and notify the frontend', async () => {
+    await simulateLifecycle('background');
+    await browser.waitUntil(isMinimized, { timeout: 5000, timeoutMsg: 'window was never minimized' });
+
+    await simulateLifecycle('foreground');
+    await browser.waitUntil(async () => !(await isMinimized()), {
+      timeout: 5000,
+      timeoutMsg: 'window was never restored',
+    });
+
+    await browser.waitUntil(async () => (await lifecycleEvents()).length === 2, {
+      timeout: 5000,
+      timeoutMsg: 'lifecycle events never reached the frontend',
+    });
+    expect(await lifecycleEvents()).toEqual(['background', 'foreground']);
+  });
+
+  it('should reject low_memory on desktop', async () => {
+    await expect(simulateLifecycle('low_memory')).rejects.toThrow(/Unsupported on this platform/);
+    expect(await lifecycleEvents()).toEqual([]);
+  });
+
+  it('should reject unknown events', async () => {
+    await expect(simulateLifecycle('hibernate')).rejects.toThrow(/unknown variant/);
+  });
+});
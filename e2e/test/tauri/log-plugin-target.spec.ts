@@ -0,0 +1,34 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type LogEntry = { source: string; level: string; message: string; target: string | null };
+
+// The fixture routes backend logs through tauri_plugin_log with wdio_log_target()
+describe('Tauri Log Plugin Target', () => {
+  beforeEach(async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|clear_logs'));
+  });
+
+  it('should buffer each backend record exactly once', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('generate_test_logs'));
+
+    const entries = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_logs', { filter: { source: 'backend', contains: 'level log' } }),
+    )) as LogEntry[];
+
+    for (const message of ['This is an INFO level log', 'This is a WARN level log', 'This is an ERROR level log']) {
+      expect(entries.filter((e) => e.message === message)).toHaveLength(1);
+    }
+  });
+
+  it('should respect the log plugin level filter', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('generate_test_logs'));
+
+    const entries = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_logs', { filter: { source: 'backend', contains: 'level log' } }),
+    )) as LogEntry[];
+
+    expect(entries.some((e) => e.message.includes('TRACE level log'))).toBe(false);
+    expect(entries.some((e) => e.message.includes('DEBUG level log'))).toBe(false);
+  });
+});
@@ -0,0 +1,86 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type LogEvent = {
+  subscription_id: string;
+  seq: number;
+  entry: { source: string; level: string; message: string };
+};
+
+describe('Tauri Log Subscription', () => {
+  beforeEach(async () => {
+    await browser.tauri.execute(async ({ core, event }) => {
+      const w = window as unknown as { __wdioLogEvents?: unknown[]; __wdioUnlistenLog?: () => void };
+      w.__wdioLogEvents = [];
+      w.__wdioUnlistenLog = await event.listen('wdio:log', (e: { payload: unknown }) => {
+        w.__wdioLogEvents?.push(e.payload);
+      });
+      await core.invoke('plugin:wdio|clear_logs');
+    });
+  });
+
+  afterEach(async () => {
+    await browser.tauri.execute(() => {
+      const w = window as unknown as { __wdioUnlistenLog?: () => void };
+      w.__wdioUnlistenLog?.();
+    });
+  });
+
+  it('should deliver matching entries in order as wdio:log events', async () => {
+    const subscriptionId = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|subscribe_logs', { filter: { contains: 'level log' } }),
+    )) as string;
+
+    await browser.tauri.execute(({ core }) => core.invoke('generate_test_logs'));
+
+    await browser.waitUntil(
+      async () => {
+        const events = (await browser.execute(
+          () => (window as unknown as { __wdioLogEvents: unknown[] }).__wdioLogEvents,
+        )) as LogEvent[];
+        return events.filter((e) => e.subscription_id === subscriptionId).length >= 3;
+      },
+      { timeout: 5000, timeoutMsg: 'wdio:log events not received' },
+    );
+
+    const events = ((await browser.execute(
+      () => (window as unknown as { __wdioLogEvents: unknown[] }).__wdioLogEvents,
+    )) as LogEvent[]).filter((e) => e.subscription_id === subscriptionId);
+
+    const seqs = events.map((e) => e.seq);
+    expect(seqs).toEqual([...seqs].sort((a, b) => a - b));
+    expect(seqs[0]).toBe(1);
+
+    const messages = events.map((e) => e.entry.message);
+    const info = messages.findIndex((m) => m.includes('INFO level log'));
+    const warn = messages.findIndex((m) => m.includes('WARN level log'));
+    const error = messages.findIndex((m) => m.includes('ERROR level log'));
+    expect(info).toBeGreaterThanOrEqual(0);
+    expect(info).toBeLessThan(warn);
+    expect(warn).toBeLessThan(error);
+
+    const removed = await browser.tauri.execute(
+      ({ core }, id) => core.invoke('plugin:wdio|unsubscribe_logs', { subscriptionId: id }),
+      subscriptionId,
+    );
+    expect(removed).toBe(true);
+  });
+
+  it('should stop delivering entries after unsubscribe', async () => {
+    const subscriptionId = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|subscribe_logs', { filter: { contains: 'level log' } }),
+    )) as string;
+    await browser.tauri.execute(
+      ({ core }, id) => core.invoke('plugin:wdio|unsubscribe_logs', { subscriptionId: id }),
+      subscriptionId,
+    );
+
+    await browser.tauri.execute(({ core }) => core.invoke('generate_test_logs'));
+    await browser.pause(500);
+
+    const events = ((await browser.execute(
+      () => (window as unknown as { __wdioLogEvents: unknown[] }).__wdioLogEvents,
+    )) as LogEvent[]).filter((e) => e.subscription_id === subscriptionId);
+    expect(events).toHaveLength(0);
+  });
+});
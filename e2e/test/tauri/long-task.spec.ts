@@ -0,0 +1,91 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+async function startLongTask(durationMs: number, steps: number) {
+  return (await browser.tauri.execute(
+    ({ core }, args: { durationMs: number; steps: number }) => core.invoke('start_long_task', args),
+    { durationMs, steps },
+  )) as number;
+}
+
+async function cancelTask(taskId: number) {
+  const cancelled = await browser.tauri.execute(
+    ({ core }, taskId: number) => core.invoke('cancel_task', { taskId }),
+    taskId,
+  );
+  return cancelled as boolean;
+}
+
+async function waitForTask(taskId: number, status: string) {
+  const item = await browser.$(`#task-${taskId}`);
+  await browser.waitUntil(
+    async () => (await item.isExisting()) && (await item.getAttribute('data-status')) === status,
+    { timeout: 10000, timeoutMsg: `task ${taskId} never reached ${status}` },
+  );
+  return item;
+}
+
+async function clock(command: 'clock_freeze' | 'clock_advance' | 'clock_reset', args: Record<string, number> = {}) {
+  return (await browser.tauri.execute(
+    ({ core }, command: string, args: Record<string, number>) => core.invoke(`plugin:wdio|${command}`, args),
+    command,
+    args,
+  )) as { now_ms: number; frozen: boolean };
+}
+
+describe('Tauri Long Running Tasks', () => {
+  afterEach(async () => {
+    await clock('clock_reset');
+  });
+
+  it('should render progress until the task completes', async () => {
+    const taskId = await startLongTask(600, 3);
+    const item = await browser.$(`#task-${taskId}`);
+
+    await browser.waitUntil(async () => (await item.isExisting()) && (await item.getText()) === '1/3', {
+      timeout: 5000,
+      timeoutMsg: 'first progress step was not rendered',
+    });
+    await waitForTask(taskId, 'complete');
+    expect(await cancelTask(taskId)).toBe(false);
+  });
+
+  it('should run concurrent tasks under unique ids', async () => {
+    const ids = await Promise.all([startLongTask(300, 2), startLongTask(400, 4), startLongTask(200, 1)]);
+
+    expect(new Set(ids).size).toBe(ids.length);
+    for (const id of ids) {
+      await waitForTask(id, 'complete');
+    }
+  });
+
+  it('should stop a cancelled task from making further progress', async () => {
+    const taskId = await startLongTask(10000, 10);
+    await waitForTask(taskId, 'running');
+
+    expect(await cancelTask(taskId)).toBe(true);
+    const item = await waitForTask(taskId, 'cancelled');
+
+    await browser.pause(1500);
+    expect(await item.getAttribute('data-status')).toBe('cancelled');
+    expect(await cancelTask(taskId)).toBe(false);
+  });
+
+  it('should complete as soon as the backend clock is advanced past the end', async () => {
+    const frozen = await clock('clock_freeze');
+    expect(frozen.frozen).toBe(true);
+
+    const taskId = await startLongTask(600000, 3);
+    // No progress while the clock is frozen
+    await browser.pause(500);
+    expect(await browser.$(`#task-${taskId}`).isExisting()).toBe(false);
+
+    const advanced = await clock('clock_advance', { ms: 600000 });
+    expect(advanced.now_ms).toBe(frozen.now_ms + 600000);
+    await waitForTask(taskId, 'complete');
+  });
+
+  it('should reject a task without steps', async () => {
+    await expect(startLongTask(100, 0)).rejects.toThrow(/steps must be at least 1/);
+  });
+});
@@ -0,0 +1,73 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type MenuItemInfo = {
+  id: string;
+  label: string;
+  kind: 'normal' | 'check' | 'icon' | 'predefined' | 'submenu';
+  enabled: boolean;
+  checked: boolean | null;
+  children: MenuItemInfo[];
+};
+
+async function getMenuStructure() {
+  return (await browser.tauri.execute(({ core }) =>
+    core.invoke('plugin:wdio|get_menu_structure'),
+  )) as MenuItemInfo[];
+}
+
+async function triggerMenuItem(target: { id?: string; path?: string[] }) {
+  return (await browser.tauri.execute(
+    ({ core }, args) => core.invoke('plugin:wdio|trigger_menu_item', args),
+    target,
+  )) as MenuItemInfo;
+}
+
+async function getMenuEvents() {
+  return (await browser.execute(() => (window as unknown as { menuEvents: string[] }).menuEvents)) as string[];
+}
+
+describe('Tauri Menu', () => {
+  it('should describe the app menu tree', async () => {
+    const menu = await getMenuStructure();
+
+    expect(menu.map((item) => item.label)).toEqual(['File', 'View', 'Help']);
+    expect(menu[0].kind).toBe('submenu');
+    expect(menu[0].children[0]).toMatchObject({ id: 'file-save', label: 'Save', kind: 'normal', enabled: true });
+    expect(menu[1].children[0]).toMatchObject({ id: 'view-sidebar', kind: 'check', checked: false });
+  });
+
+  it('should flip the checked state when triggering a check item', async () => {
+    const before = (await getMenuStructure())[1].children[0].checked;
+
+    const toggled = await triggerMenuItem({ id: 'view-sidebar' });
+    expect(toggled.checked).toBe(!before);
+    expect((await getMenuStructure())[1].children[0].checked).toBe(!before);
+
+    const restored = await triggerMenuItem({ id: 'view-sidebar' });
+    expect(restored.checked).toBe(before);
+  });
+
+  it('should deliver the menu event to the app', async () => {
+    const count = (await getMenuEvents()).length;
+
+    await triggerMenuItem({ id: 'file-save' });
+
+    await browser.waitUntil(async () => (await getMenuEvents()).length > count, {
+      timeout: 5000,
+      timeoutMsg: 'menu-clicked event was not received',
+    });
+    expect((await getMenuEvents()).slice(count)).toEqual(['file-save']);
+  });
+
+  it('should address an item without an id by its label path', async () => {
+    const about = await triggerMenuItem({ path: ['Help', 'About'] });
+
+    expect(about.label).toBe('About');
+    expect(about.id).toBe((await getMenuStructure())[2].children[0].id);
+  });
+
+  it('should reject unknown items', async () => {
+    await expect(triggerMenuItem({ path: ['Help', 'Missing'] })).rejects.toThrow(/Menu item not found/);
+  });
+});
@@ -0,0 +1,86 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type ProcessMetrics = {
+  rss_bytes: number;
+  virtual_bytes: number;
+  cpu_percent: number;
+  num_threads: number | null;
+  uptime_ms: number;
+  timestamp_ms: number;
+};
+
+// The e2e fixture enables the plugin's `metrics` feature
+describe('Tauri Process Metrics', () => {
+  it('should report the app process memory usage', async () => {
+    const metrics = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_process_metrics'),
+    )) as ProcessMetrics;
+
+    expect(metrics.rss_bytes).toBeGreaterThan(0);
+    expect(metrics.virtual_bytes).toBeGreaterThanOrEqual(metrics.rss_bytes);
+    expect(metrics.uptime_ms).toBeGreaterThan(0);
+  });
+
+  it('should record a time series between start and stop', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|start_metrics_sampling', { intervalMs: 100 }));
+    await browser.pause(1000);
+    const count = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|stop_metrics_sampling'),
+    )) as number;
+
+    const samples = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_metrics_samples'),
+    )) as ProcessMetrics[];
+
+    expect(samples).toHaveLength(count);
+    expect(samples.length).toBeGreaterThanOrEqual(3);
+    for (let i = 1; i < samples.length; i++) {
+      expect(samples[i].timestamp_ms).toBeGreaterThanOrEqual(samples[i - 1].timestamp_ms);
+    }
+  });
+});
+
+// allocate_memory and burn_cpu are fixture commands that generate reproducible load
+describe('Tauri Process Metrics Under Load', () => {
+  const MiB = 1024 * 1024;
+
+  it('should see memory held by allocate_memory', async () => {
+    type MemoryLoad = { allocated_bytes: number; rss_before_bytes: number; rss_delta_bytes: number };
+    const { load, during } = (await browser.tauri.execute(async ({ core }) => {
+      const pending = core.invoke('allocate_memory', { mb: 128, holdMs: 1500 });
+      await new Promise((resolve) => setTimeout(resolve, 700));
+      const during = await core.invoke('plugin:wdio|get_process_metrics');
+      return { load: await pending, during };
+    })) as { load: MemoryLoad; during: ProcessMetrics };
+
+    expect(load.allocated_bytes).toBe(128 * MiB);
+    expect(load.rss_delta_bytes).toBeGreaterThan(100 * MiB);
+    expect(during.rss_bytes).toBeGreaterThan(load.rss_before_bytes + 100 * MiB);
+  });
+
+  it('should see CPU burned by burn_cpu', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|start_metrics_sampling', { intervalMs: 100 }));
+    const load = (await browser.tauri.execute(({ core }) =>
+      core.invoke('burn_cpu', { threads: 2, durationMs: 1000 }),
+    )) as { threads: number; duration_ms: number; iterations: number };
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|stop_metrics_sampling'));
+    const samples = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_metrics_samples'),
+    )) as ProcessMetrics[];
+
+    expect(load.threads).toBe(2);
+    expect(load.duration_ms).toBeGreaterThanOrEqual(1000);
+    expect(load.iterations).toBeGreaterThan(0);
+    expect(Math.max(...samples.map((sample) => sample.cpu_percent))).toBeGreaterThan(50);
+  });
+
+  it('should reject loads beyond the guard rails', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('allocate_memory', { mb: 1_000_000, holdMs: 0 })),
+    ).rejects.toThrow(/Refusing to allocate 1000000 MB/);
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('burn_cpu', { threads: 0, durationMs: 100 })),
+    ).rejects.toThrow(/threads must be between 1 and 64/);
+  });
+});
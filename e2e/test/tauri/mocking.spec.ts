@@ -16,29 +16,29 @@ describe('Tauri Mocking', () => {
     });
 
     it('should mock a command with arguments', async () => {
-      const mockWriteClipboard = await browser.tauri.mock('write_clipboard');
+      const mockWriteClipboard = await browser.tauri.mock('plugin:wdio|set_clipboard_text');
 
       await browser.tauri.execute(async ({ core }) => {
-        await core.invoke('write_clipboard', { content: 'test content' });
+        await core.invoke('plugin:wdio|set_clipboard_text', { text: 'test content' });
       });
 
       await mockWriteClipboard.update();
 
       expect(mockWriteClipboard).toHaveBeenCalledTimes(1);
-      expect(mockWriteClipboard).toHaveBeenCalledWith({ content: 'test content' });
+      expect(mockWriteClipboard).toHaveBeenCalledWith({ text: 'test content' });
     });
   });
 
   describe('browser.tauri.clearAllMocks', () => {
     it('should clear existing mocks', async () => {
-      const mockReadClipboard = await browser.tauri.mock('read_clipboard');
-      const mockWriteClipboard = await browser.tauri.mock('write_clipboard');
+      const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
+      const mockWriteClipboard = await browser.tauri.mock('plugin:wdio|set_clipboard_text');
 
       await browser.tauri.execute(async ({ core }) => {
-        await core.invoke('read_clipboard');
+        await core.invoke('plugin:wdio|get_clipboard_text');
       });
       await browser.tauri.execute(async ({ core }) => {
-        await core.invoke('write_clipboard', { content: 'test content' });
+        await core.invoke('plugin:wdio|set_clipboard_text', { text: 'test content' });
       });
 
       await mockReadClipboard.update();
@@ -58,7 +58,7 @@ describe('Tauri Mocking', () => {
     });
 
     it('should not reset existing mocks', async () => {
-      const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+      const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
       const mockGetPlatformInfo = await browser.tauri.mock('get_platform_info');
 
       await mockReadClipboard.mockReturnValue('mocked clipboard content');
@@ -66,7 +66,9 @@ describe('Tauri Mocking', () => {
 
       await browser.tauri.clearAllMocks();
 
-      const clipboardContent = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+      const clipboardContent = await browser.tauri.execute(
+        async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+      );
       const platformInfo = await browser.tauri.execute(async ({ core }) => await core.invoke('get_platform_info'));
 
       expect(clipboardContent).toBe('mocked clipboard content');
@@ -76,14 +78,14 @@ describe('Tauri Mocking', () => {
 
   describe('browser.tauri.resetAllMocks', () => {
     it('should clear existing mocks', async () => {
-      const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+      const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
       const mockGetPlatformInfo = await browser.tauri.mock('get_platform_info');
 
       await mockReadClipboard.mockReturnValue('mocked clipboard');
       await mockGetPlatformInfo.mockReturnValue({ os: 'mock_os' });
 
       await browser.tauri.execute(async ({ core }) => {
-        await core.invoke('read_clipboard');
+        await core.invoke('plugin:wdio|get_clipboard_text');
       });
       await browser.tauri.execute(async ({ core }) => {
         await core.invoke('get_platform_info');
@@ -106,7 +108,7 @@ describe('Tauri Mocking', () => {
     });
 
     it('should reset existing mocks', async () => {
-      const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+      const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
       const mockGetPlatformInfo = await browser.tauri.mock('get_platform_info');
 
       await mockReadClipboard.mockReturnValue('mocked clipboard');
@@ -114,7 +116,9 @@ describe('Tauri Mocking', () => {
 
       await browser.tauri.resetAllMocks();
 
-      const clipboardContent = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+      const clipboardContent = await browser.tauri.execute(
+        async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+      );
       const platformInfo = await browser.tauri.execute(async ({ core }) => await core.invoke('get_platform_info'));
 
       expect(clipboardContent).toBeUndefined();
@@ -124,7 +128,7 @@ describe('Tauri Mocking', () => {
 
   describe('browser.tauri.restoreAllMocks', () => {
     it('should restore existing mocks', async () => {
-      const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+      const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
       const mockGetPlatformInfo = await browser.tauri.mock('get_platform_info');
 
       await mockReadClipboard.mockReturnValue('mocked clipboard');
@@ -132,12 +136,14 @@ describe('Tauri Mocking', () => {
 
       await browser.tauri.restoreAllMocks();
 
-      // Write to clipboard AFTER restoring — any write_clipboard mock from prior tests is now gone
+      // Write to clipboard AFTER restoring — any set_clipboard_text mock from prior tests is now gone
       await browser.tauri.execute(async ({ core }) => {
-        await core.invoke('write_clipboard', { content: 'real clipboard text' });
+        await core.invoke('plugin:wdio|set_clipboard_text', { text: 'real clipboard text' });
       });
 
-      const clipboardContent = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+      const clipboardContent = await browser.tauri.execute(
+        async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+      );
       const platformInfo = await browser.tauri.execute(async ({ core }) => await core.invoke('get_platform_info'));
 
       // After restore, should return real values
@@ -150,9 +156,9 @@ describe('Tauri Mocking', () => {
   describe('browser.tauri.isMockFunction', () => {
     it('should return true when provided with a tauri mock', async () => {
       // Create mock to register it in the store
-      void (await browser.tauri.mock('read_clipboard'));
+      void (await browser.tauri.mock('plugin:wdio|get_clipboard_text'));
 
-      expect(await browser.tauri.isMockFunction('read_clipboard')).toBe(true);
+      expect(await browser.tauri.isMockFunction('plugin:wdio|get_clipboard_text')).toBe(true);
     });
 
     it('should return false when provided with a non-mocked command', async () => {
@@ -163,11 +169,13 @@ describe('Tauri Mocking', () => {
   describe('mock object functionality', () => {
     describe('mockImplementation', () => {
       it('should use the specified implementation for an existing mock', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         await mockReadClipboard.mockImplementation(() => 'mocked clipboard value');
 
-        const result = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        const result = await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+        );
 
         expect(mockReadClipboard.mock.calls).toHaveLength(1);
         expect(result).toBe('mocked clipboard value');
@@ -176,36 +184,40 @@ describe('Tauri Mocking', () => {
 
     describe('mockImplementationOnce', () => {
       it('should use the specified implementation for an existing mock once', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         await mockReadClipboard.mockImplementation(() => 'default mocked clipboard');
         await mockReadClipboard.mockImplementationOnce(() => 'first mocked clipboard');
         await mockReadClipboard.mockImplementationOnce(() => 'second mocked clipboard');
         await mockReadClipboard.mockImplementationOnce(() => 'third mocked clipboard');
 
-        let content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        let content = await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+        );
         expect(content).toBe('first mocked clipboard');
 
-        content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        content = await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
         expect(content).toBe('second mocked clipboard');
 
-        content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        content = await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
         expect(content).toBe('third mocked clipboard');
 
-        content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        content = await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
         expect(content).toBe('default mocked clipboard');
 
-        content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        content = await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
         expect(content).toBe('default mocked clipboard');
       });
     });
 
     describe('mockReturnValue', () => {
       it('should return the specified value from an existing mock', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         await mockReadClipboard.mockReturnValue('This is a mock');
 
-        const content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        const content = await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+        );
 
         expect(content).toBe('This is a mock');
       });
@@ -213,26 +225,28 @@ describe('Tauri Mocking', () => {
 
     describe('mockReturnValueOnce', () => {
       it('should return the specified value from an existing mock once', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         await mockReadClipboard.mockReturnValue('default mocked clipboard');
         await mockReadClipboard.mockReturnValueOnce('first mocked clipboard');
         await mockReadClipboard.mockReturnValueOnce('second mocked clipboard');
         await mockReadClipboard.mockReturnValueOnce('third mocked clipboard');
 
-        let content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        let content = await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+        );
         expect(content).toBe('first mocked clipboard');
 
-        content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        content = await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
         expect(content).toBe('second mocked clipboard');
 
-        content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        content = await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
         expect(content).toBe('third mocked clipboard');
 
-        content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        content = await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
         expect(content).toBe('default mocked clipboard');
 
-        content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        content = await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
         expect(content).toBe('default mocked clipboard');
       });
     });
@@ -364,12 +378,12 @@ describe('Tauri Mocking', () => {
 
     describe('mockClear', () => {
       it('should clear an existing mock', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         await mockReadClipboard.mockReturnValue('mocked clipboard');
 
-        await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
-        await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
-        await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
+        await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
+        await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
 
         await mockReadClipboard.update();
         await mockReadClipboard.mockClear();
@@ -383,46 +397,52 @@ describe('Tauri Mocking', () => {
 
     describe('mockReset', () => {
       it('should reset the implementation of an existing mock', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         await mockReadClipboard.mockReturnValue('mocked clipboard');
 
         await mockReadClipboard.mockReset();
 
-        const content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        const content = await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+        );
         expect(content).toBeUndefined();
       });
 
       it('should reset mockReturnValueOnce implementations of an existing mock', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         await mockReadClipboard.mockReturnValueOnce('first');
         await mockReadClipboard.mockReturnValueOnce('second');
         await mockReadClipboard.mockReturnValueOnce('third');
 
         await mockReadClipboard.mockReset();
 
-        const content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        const content = await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+        );
         expect(content).toBeUndefined();
       });
 
       it('should reset mockImplementationOnce implementations of an existing mock', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         await mockReadClipboard.mockImplementationOnce(() => 'first');
         await mockReadClipboard.mockImplementationOnce(() => 'second');
         await mockReadClipboard.mockImplementationOnce(() => 'third');
 
         await mockReadClipboard.mockReset();
 
-        const content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        const content = await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+        );
         expect(content).toBeUndefined();
       });
 
       it('should clear the history of an existing mock', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         await mockReadClipboard.mockReturnValue('mocked clipboard');
 
-        await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
-        await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
-        await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
+        await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
+        await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
 
         await mockReadClipboard.update();
         await mockReadClipboard.mockReset();
@@ -438,15 +458,17 @@ describe('Tauri Mocking', () => {
       it('should restore an existing mock', async () => {
         // First set a real clipboard value
         await browser.tauri.execute(async ({ core }) => {
-          await core.invoke('write_clipboard', { content: 'real clipboard text' });
+          await core.invoke('plugin:wdio|set_clipboard_text', { text: 'real clipboard text' });
         });
 
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         await mockReadClipboard.mockReturnValue('mocked clipboard');
 
         await mockReadClipboard.mockRestore();
 
-        const content = await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        const content = await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'),
+        );
 
         // After restore, should return real clipboard value
         expect(content).toBe('real clipboard text');
@@ -455,7 +477,7 @@ describe('Tauri Mocking', () => {
 
     describe('getMockName', () => {
       it('should retrieve the mock name', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         expect(mockReadClipboard.getMockName()).toBe('tauri.read_clipboard');
       });
@@ -463,7 +485,7 @@ describe('Tauri Mocking', () => {
 
     describe('mockName', () => {
       it('should set the mock name', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         mockReadClipboard.mockName('my first mock');
 
         expect(mockReadClipboard.getMockName()).toBe('my first mock');
@@ -472,7 +494,7 @@ describe('Tauri Mocking', () => {
 
     describe('getMockImplementation', () => {
       it('should retrieve the mock implementation', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         await mockReadClipboard.mockImplementation(() => 'mocked clipboard');
         const mockImpl = mockReadClipboard.getMockImplementation() as () => string;
 
@@ -480,7 +502,7 @@ describe('Tauri Mocking', () => {
       });
 
       it('should retrieve an empty mock implementation', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         const mockImpl = mockReadClipboard.getMockImplementation() as () => undefined;
 
         expect(mockImpl).toBeUndefined();
@@ -489,13 +511,13 @@ describe('Tauri Mocking', () => {
 
     describe('mock.calls', () => {
       it('should return the calls of the mock execution', async () => {
-        const mockWriteClipboard = await browser.tauri.mock('write_clipboard');
+        const mockWriteClipboard = await browser.tauri.mock('plugin:wdio|set_clipboard_text');
 
         await browser.tauri.execute(
-          async ({ core }) => await core.invoke('write_clipboard', { content: 'first content' }),
+          async ({ core }) => await core.invoke('plugin:wdio|set_clipboard_text', { text: 'first content' }),
         );
         await browser.tauri.execute(
-          async ({ core }) => await core.invoke('write_clipboard', { content: 'second content' }),
+          async ({ core }) => await core.invoke('plugin:wdio|set_clipboard_text', { text: 'second content' }),
         );
 
         await mockWriteClipboard.update();
@@ -504,7 +526,7 @@ describe('Tauri Mocking', () => {
       });
 
       it('should return an empty array when the mock was never invoked', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         expect(mockReadClipboard.mock.calls).toStrictEqual([]);
       });
@@ -512,23 +534,29 @@ describe('Tauri Mocking', () => {
 
     describe('mock.lastCall', () => {
       it('should return the last call of the mock execution', async () => {
-        const mockWriteClipboard = await browser.tauri.mock('write_clipboard');
+        const mockWriteClipboard = await browser.tauri.mock('plugin:wdio|set_clipboard_text');
 
-        await browser.tauri.execute(async ({ core }) => await core.invoke('write_clipboard', { content: 'first' }));
+        await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|set_clipboard_text', { text: 'first' }),
+        );
         await mockWriteClipboard.update();
         expect(mockWriteClipboard.mock.lastCall).toBeDefined();
 
-        await browser.tauri.execute(async ({ core }) => await core.invoke('write_clipboard', { content: 'second' }));
+        await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|set_clipboard_text', { text: 'second' }),
+        );
         await mockWriteClipboard.update();
         expect(mockWriteClipboard.mock.lastCall).toBeDefined();
 
-        await browser.tauri.execute(async ({ core }) => await core.invoke('write_clipboard', { content: 'third' }));
+        await browser.tauri.execute(
+          async ({ core }) => await core.invoke('plugin:wdio|set_clipboard_text', { text: 'third' }),
+        );
         await mockWriteClipboard.update();
         expect(mockWriteClipboard.mock.lastCall).toBeDefined();
       });
 
       it('should return undefined when the mock was never invoked', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         expect(mockReadClipboard.mock.lastCall).toBeUndefined();
       });
@@ -536,11 +564,11 @@ describe('Tauri Mocking', () => {
 
     describe('mock.results', () => {
       it('should return the results of the mock execution', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         await mockReadClipboard.mockImplementation(() => 'result');
 
-        await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
 
         await mockReadClipboard.update();
 
@@ -553,7 +581,7 @@ describe('Tauri Mocking', () => {
       });
 
       it('should return an empty array when the mock was never invoked', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         expect(mockReadClipboard.mock.results).toStrictEqual([]);
       });
@@ -561,12 +589,12 @@ describe('Tauri Mocking', () => {
 
     describe('mock.invocationCallOrder', () => {
       it('should return the order of execution', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         const mockGetPlatformInfo = await browser.tauri.mock('get_platform_info');
 
-        await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
         await browser.tauri.execute(async ({ core }) => await core.invoke('get_platform_info'));
-        await browser.tauri.execute(async ({ core }) => await core.invoke('read_clipboard'));
+        await browser.tauri.execute(async ({ core }) => await core.invoke('plugin:wdio|get_clipboard_text'));
 
         await mockReadClipboard.update();
         await mockGetPlatformInfo.update();
@@ -581,7 +609,7 @@ describe('Tauri Mocking', () => {
       });
 
       it('should return an empty array when the mock was never invoked', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         expect(mockReadClipboard.mock.invocationCallOrder).toStrictEqual([]);
       });
@@ -589,12 +617,12 @@ describe('Tauri Mocking', () => {
 
     describe('synchronization', () => {
       it('should synchronize mock calls after update', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         // Use browser.execute (raw WebDriver, no auto-sync) to invoke the inner mock directly
         await browser.execute(() => {
           // @ts-expect-error - window is available in browser context
-          const mockFn = window.__wdio_mocks__?.read_clipboard;
+          const mockFn = window.__wdio_mocks__?.['plugin:wdio|get_clipboard_text'];
           if (typeof mockFn === 'function') {
             mockFn();
             mockFn();
@@ -611,11 +639,11 @@ describe('Tauri Mocking', () => {
       });
 
       it('should auto-synchronize mock calls after execute', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
 
         await browser.tauri.execute(async ({ core }) => {
-          await core.invoke('read_clipboard');
-          await core.invoke('read_clipboard');
+          await core.invoke('plugin:wdio|get_clipboard_text');
+          await core.invoke('plugin:wdio|get_clipboard_text');
         });
 
         // After tauri.execute (auto-synced), calls should be synchronized without calling update()
@@ -623,11 +651,11 @@ describe('Tauri Mocking', () => {
       });
 
       it('should synchronize mock results after update', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         await mockReadClipboard.mockReturnValue('mocked result');
 
         await browser.tauri.execute(async ({ core }) => {
-          await core.invoke('read_clipboard');
+          await core.invoke('plugin:wdio|get_clipboard_text');
         });
 
         await mockReadClipboard.update();
@@ -636,10 +664,10 @@ describe('Tauri Mocking', () => {
       });
 
       it('should synchronize lastCall after update', async () => {
-        const mockWriteClipboard = await browser.tauri.mock('write_clipboard');
+        const mockWriteClipboard = await browser.tauri.mock('plugin:wdio|set_clipboard_text');
 
         await browser.tauri.execute(async ({ core }) => {
-          await core.invoke('write_clipboard', { content: 'test' });
+          await core.invoke('plugin:wdio|set_clipboard_text', { text: 'test' });
         });
 
         await mockWriteClipboard.update();
@@ -650,12 +678,12 @@ describe('Tauri Mocking', () => {
 
     describe('mock state persistence', () => {
       it('should maintain mock implementation across invocations', async () => {
-        const mockReadClipboard = await browser.tauri.mock('read_clipboard');
+        const mockReadClipboard = await browser.tauri.mock('plugin:wdio|get_clipboard_text');
         await mockReadClipboard.mockReturnValue('persistent result');
 
         // First call
         const result1 = await browser.tauri.execute(async ({ core }) => {
-          return await core.invoke('read_clipboard');
+          return await core.invoke('plugin:wdio|get_clipboard_text');
         });
 
         await mockReadClipboard.update();
@@ -663,7 +691,7 @@ describe('Tauri Mocking', () => {
 
         // Second call should return same result
         const result2 = await browser.tauri.execute(async ({ core }) => {
-          return await core.invoke('read_clipboard');
+          return await core.invoke('plugin:wdio|get_clipboard_text');
         });
 
         await mockReadClipboard.update();
@@ -0,0 +1,86 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type MonitorInfo = {
+  name: string | null;
+  position: { x: number; y: number };
+  size: { width: number; height: number };
+  scale_factor: number;
+  is_primary: boolean;
+};
+
+const getMonitors = async () =>
+  (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_monitors'))) as MonitorInfo[];
+
+const getCurrentMonitor = async () =>
+  (await browser.tauri.execute(({ core }) =>
+    core.invoke('plugin:wdio|get_current_monitor', { label: 'main' }),
+  )) as MonitorInfo | null;
+
+describe('Tauri Monitors', () => {
+  it('should list at least one monitor with exactly one primary', async () => {
+    const monitors = await getMonitors();
+
+    expect(monitors.length).toBeGreaterThanOrEqual(1);
+    expect(monitors.filter((m) => m.is_primary)).toHaveLength(1);
+    for (const monitor of monitors) {
+      expect(monitor.size.width).toBeGreaterThan(0);
+      expect(monitor.scale_factor).toBeGreaterThan(0);
+    }
+  });
+
+  it('should report the monitor the main window is on', async () => {
+    const current = await getCurrentMonitor();
+    const monitors = await getMonitors();
+
+    expect(current).not.toBeNull();
+    expect(monitors.some((m) => m.position.x === current?.position.x && m.position.y === current?.position.y)).toBe(
+      true,
+    );
+  });
+
+  it('should follow the window to another monitor', async function () {
+    const monitors = await getMonitors();
+    if (monitors.length < 2) {
+      this.skip(); // Single-monitor CI
+    }
+
+    const original = await getCurrentMonitor();
+    const target = monitors.find((m) => m.position.x !== original?.position.x || m.position.y !== original?.position.y);
+    if (!target) {
+      this.skip();
+      return;
+    }
+
+    try {
+      await browser.tauri.execute(
+        ({ core }, t) =>
+          core.invoke('plugin:wdio|set_window_bounds', {
+            label: 'main',
+            bounds: { x: t.position.x + 50, y: t.position.y + 50, width: 400, height: 300 },
+          }),
+        target,
+      );
+
+      await browser.waitUntil(
+        async () => {
+          const current = await getCurrentMonitor();
+          return current?.position.x === target.position.x && current?.position.y === target.position.y;
+        },
+        { timeout: 5000, timeoutMsg: 'get_current_monitor did not follow the window' },
+      );
+      expect((await getCurrentMonitor())?.scale_factor).toBe(target.scale_factor);
+    } finally {
+      if (original) {
+        await browser.tauri.execute(
+          ({ core }, o) =>
+            core.invoke('plugin:wdio|set_window_bounds', {
+              label: 'main',
+              bounds: { x: o.position.x + 50, y: o.position.y + 50, width: 600, height: 400 },
+            }),
+          original,
+        );
+      }
+    }
+  });
+});
@@ -0,0 +1,52 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type AccessibilityNode = {
+  role: string;
+  name: string | null;
+  states: string[];
+  child_count: number;
+  children: AccessibilityNode[];
+};
+
+type Snapshot = { tree: AccessibilityNode } | { error: { code?: string; message?: string } };
+
+async function snapshot(maxDepth?: number) {
+  return (await browser.tauri.execute(
+    ({ core }, maxDepth) =>
+      core.invoke('plugin:wdio|get_native_accessibility_tree', { label: 'main', maxDepth }).then(
+        (tree: unknown) => ({ tree }),
+        (error: { code?: string; message?: string }) => ({ error }),
+      ),
+    maxDepth,
+  )) as Snapshot;
+}
+
+describe('Tauri Native Accessibility', () => {
+  it('should snapshot the main window frame on Linux', async function () {
+    if (process.platform !== 'linux') {
+      this.skip();
+    }
+    const result = await snapshot(2);
+    // The accessibility bus isn't always running under a bare xvfb session
+    if ('error' in result && result.error.code === 'ACCESSIBILITY_ERROR') {
+      this.skip();
+    }
+
+    expect(result).toHaveProperty('tree');
+    const { tree } = result as { tree: AccessibilityNode };
+    expect(tree.role).toBe('frame');
+    expect(tree.name).toBe('Tauri E2E Test App');
+    expect(tree.children.length).toBeLessThanOrEqual(tree.child_count);
+  });
+
+  it('should report UNSUPPORTED_PLATFORM outside Linux', async function () {
+    if (process.platform === 'linux') {
+      this.skip();
+    }
+
+    const result = await snapshot();
+
+    expect(result).toEqual({ error: expect.objectContaining({ code: 'UNSUPPORTED_PLATFORM' }) });
+  });
+});
@@ -0,0 +1,69 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type KeyChord = { key: string; modifiers?: string[] };
+
+async function getSaveCount() {
+  return (await browser.tauri.execute(({ core }) => core.invoke('get_save_count'))) as number;
+}
+
+async function sendKeys(keys: KeyChord[]) {
+  return browser.tauri.execute(
+    ({ core }, keys) =>
+      core.invoke('plugin:wdio|send_native_keys', { label: 'main', keys }).then(
+        () => null,
+        (error: { code?: string }) => error.code,
+      ),
+    keys,
+  );
+}
+
+const commandKey = process.platform === 'darwin' ? 'meta' : 'control';
+
+describe('Tauri Native Input', () => {
+  it('should reach a menu accelerator handled in Rust', async () => {
+    const before = await getSaveCount();
+
+    expect(await sendKeys([{ key: 's', modifiers: [commandKey] }])).toBeNull();
+
+    await browser.waitUntil(async () => (await getSaveCount()) === before + 1, {
+      timeout: 5000,
+      timeoutMsg: 'CmdOrCtrl+S never reached the File > Save accelerator',
+    });
+  });
+
+  it('should reject an unknown key without sending any', async () => {
+    const before = await getSaveCount();
+
+    const code = await sendKeys([
+      { key: 's', modifiers: [commandKey] },
+      { key: 'Hyper', modifiers: [] },
+    ]);
+
+    expect(code).toBe('NATIVE_INPUT_ERROR');
+    await browser.pause(200);
+    expect(await getSaveCount()).toBe(before);
+  });
+
+  it('should click inside the page at window-relative coordinates', async () => {
+    await browser.tauri.execute(() => {
+      const w = window as unknown as { __nativeClicks: number[] };
+      w.__nativeClicks = [];
+      document.addEventListener('mousedown', (event) => w.__nativeClicks.push(event.button), { once: true });
+    });
+
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|send_native_click', { label: 'main', x: 300, y: 250 }),
+    );
+
+    await browser.waitUntil(
+      async () =>
+        ((await browser.execute(() => (window as unknown as { __nativeClicks: number[] }).__nativeClicks)) as number[])
+          .length > 0,
+      { timeout: 5000, timeoutMsg: 'The page never saw the native click' },
+    );
+    expect(await browser.execute(() => (window as unknown as { __nativeClicks: number[] }).__nativeClicks)).toEqual([
+      0,
+    ]);
+  });
+});
@@ -0,0 +1,84 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+describe('Tauri Navigation', () => {
+  it('should reload the page and wait for it to load', async () => {
+    await browser.execute(() => {
+      (window as unknown as { __beforeReload?: boolean }).__beforeReload = true;
+    });
+
+    const before = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|reload', { label: 'main' }),
+    )) as number;
+    const after = (await browser.tauri.execute(
+      ({ core }, count) => core.invoke('plugin:wdio|wait_for_load', { label: 'main', timeoutMs: 10000, after: count }),
+      before,
+    )) as number;
+
+    expect(after).toBeGreaterThan(before);
+    const marker = await browser.execute(() => (window as unknown as { __beforeReload?: boolean }).__beforeReload);
+    expect(marker).toBeFalsy();
+  });
+
+  it('should clean up an execute whose page navigates away', async () => {
+    type Pending = { id: string; window_label: string };
+    const getPending = async () =>
+      (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_pending_executions'))) as Pending[];
+
+    // A script that never settles; only the reload below can end it
+    await browser.execute(() => {
+      type Internals = { invoke: (cmd: string, args: unknown) => Promise<unknown> };
+      const internals = (window as unknown as { __TAURI_INTERNALS__: Internals }).__TAURI_INTERNALS__;
+      void internals.invoke('plugin:wdio|execute', { request: { script: 'new Promise(() => {})' } }).catch(() => {});
+    });
+    let stuck: string[] = [];
+    await browser.waitUntil(
+      async () => {
+        stuck = (await getPending()).filter((p) => p.window_label === 'main').map((p) => p.id);
+        return stuck.length > 0;
+      },
+      { timeout: 5000, timeoutMsg: 'execute was never registered as pending' },
+    );
+
+    const before = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|reload', { label: 'main' }),
+    )) as number;
+    await browser.tauri.execute(
+      ({ core }, count) => core.invoke('plugin:wdio|wait_for_load', { label: 'main', after: count }),
+      before,
+    );
+
+    // Well inside the 30s execute timeout
+    await browser.waitUntil(async () => (await getPending()).every((p) => !stuck.includes(p.id)), {
+      timeout: 5000,
+      timeoutMsg: 'execute stayed pending after its page navigated',
+    });
+  });
+
+  it('should navigate to a route relative to the current page', async () => {
+    const before = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|navigate', { label: 'main', url: 'index.html?from=navigate' }),
+    )) as number;
+    await browser.tauri.execute(
+      ({ core }, count) => core.invoke('plugin:wdio|wait_for_load', { label: 'main', after: count }),
+      before,
+    );
+
+    const search = await browser.execute(() => window.location.search);
+    expect(search).toBe('?from=navigate');
+  });
+
+  it('should deny navigation outside the app origins', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) =>
+        core.invoke('plugin:wdio|navigate', { label: 'main', url: 'https://example.invalid/' }),
+      ),
+    ).rejects.toThrow(/Navigation denied/);
+  });
+
+  it('should time out when no load happens', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|wait_for_load', { label: 'main', timeoutMs: 200 })),
+    ).rejects.toThrow(/timed out after 200ms/);
+  });
+});
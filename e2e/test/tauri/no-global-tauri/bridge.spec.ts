@@ -0,0 +1,67 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type ExecuteOutcome = { value: unknown } | { error: { code?: string; message?: string } };
+
+// Runs with the fixture built from tauri.no-global-tauri.conf.json, so pages
+// only get window.__TAURI__ if they define it themselves (index.html does)
+
+async function openWindow(label: string) {
+  await browser.tauri.execute(({ core }, label: string) => core.invoke('open_child_window', { label }), label);
+  await browser.tauri.execute(
+    ({ core }, label: string) => core.invoke('plugin:wdio|wait_for_load', { label, after: 0 }),
+    label,
+  );
+}
+
+async function closeWindow(label: string) {
+  await browser.tauri.execute(({ core }, label: string) => core.invoke('close_child_window', { label }), label);
+}
+
+// Execute `script` in window `label` through the plugin command directly
+async function executeIn(label: string, script: string) {
+  return (await browser.tauri.execute(
+    ({ core }, label: string, script: string) =>
+      core.invoke('plugin:wdio|execute', { request: { script, args: [], window_label: label } }).then(
+        (value: unknown) => ({ value }),
+        (error: { code?: string; message?: string }) => ({ error }),
+      ),
+    label,
+    script,
+  )) as ExecuteOutcome;
+}
+
+describe('Tauri Execute Bridge without withGlobalTauri', () => {
+  it('should report results from a page without window.__TAURI__', async () => {
+    // child-* windows get the default capability
+    await openWindow('child-no-global');
+
+    try {
+      expect(await executeIn('child-no-global', 'typeof window.__TAURI__')).toEqual({ value: 'undefined' });
+      expect(await executeIn('child-no-global', 'document.title')).toEqual({ value: 'Child Window' });
+    } finally {
+      await closeWindow('child-no-global');
+    }
+  });
+
+  it('should fail fast with BRIDGE_UNAVAILABLE in a window that cannot reach the plugin', async () => {
+    // No capability lists this label, so the bridge's check-in is denied
+    await openWindow('no-capability');
+
+    try {
+      const started = Date.now();
+      const outcome = await executeIn('no-capability', 'document.title');
+
+      expect(outcome).toEqual({
+        error: expect.objectContaining({ code: 'BRIDGE_UNAVAILABLE', details: { label: 'no-capability' } }),
+      });
+      expect(Date.now() - started).toBeLessThan(5000);
+    } finally {
+      await closeWindow('no-capability');
+    }
+  });
+
+  it('should keep execute working in the main window', async () => {
+    expect(await browser.tauri.execute(() => document.title)).toBe('Tauri E2E Test App');
+  });
+});
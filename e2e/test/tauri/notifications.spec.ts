@@ -0,0 +1,68 @@
+import { $, browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type NotificationRecord = { title: string; body: string | null; timestamp_ms: number };
+
+async function getCapturedNotifications() {
+  return (await browser.tauri.execute(({ core }) =>
+    core.invoke('plugin:wdio|get_captured_notifications'),
+  )) as NotificationRecord[];
+}
+
+describe('Tauri Notification Capture', () => {
+  afterEach(async () => {
+    await browser.tauri.execute(async ({ core }) => {
+      await core.invoke('plugin:wdio|disable_notification_capture');
+      await core.invoke('plugin:wdio|clear_captured_notifications');
+    });
+    await browser.tauri.restoreAllMocks('plugin:notification|');
+  });
+
+  it('should record the notification instead of showing it', async () => {
+    const before = Date.now();
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|enable_notification_capture'));
+
+    await $('#notify-button').click();
+    await expect($('#notify-result')).toHaveText('notified');
+
+    const records = await getCapturedNotifications();
+    expect(records).toHaveLength(1);
+    expect(records[0].title).toBe('WDIO Notification');
+    expect(records[0].body).toBe('Counter is 0');
+    expect(records[0].timestamp_ms).toBeGreaterThanOrEqual(before - 1000);
+  });
+
+  it('should not reach the notification plugin while capturing', async () => {
+    const notify = await browser.tauri.mock('plugin:notification|notify');
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|enable_notification_capture'));
+
+    await $('#notify-button').click();
+    await expect($('#notify-result')).toHaveText('notified');
+
+    await notify.update();
+    expect(notify).not.toHaveBeenCalled();
+  });
+
+  it('should pass notifications through untouched when capture is disabled', async () => {
+    // The mock stands in for the notification plugin so nothing is shown on the CI desktop
+    const notify = await browser.tauri.mock('plugin:notification|notify');
+
+    await $('#notify-button').click();
+    await expect($('#notify-result')).toHaveText('notified');
+
+    await notify.update();
+    expect(notify).toHaveBeenCalledWith({ options: { title: 'WDIO Notification', body: 'Counter is 0' } });
+    expect(await getCapturedNotifications()).toEqual([]);
+  });
+
+  it('should clear captured notifications', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|enable_notification_capture'));
+    await $('#notify-button').click();
+    await expect($('#notify-result')).toHaveText('notified');
+
+    const cleared = await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|clear_captured_notifications'));
+
+    expect(cleared).toBe(1);
+    expect(await getCapturedNotifications()).toEqual([]);
+  });
+});
@@ -0,0 +1,103 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type InvokeOutcome = { ok: true; value: unknown } | { ok: false; error: string };
+type SelfTestReport = { commands: { command: string; permitted: boolean }[]; ipc_event_roundtrip_ok: boolean };
+
+// Windows labelled readonly-* only get wdio:readonly (capabilities/readonly.json)
+const READONLY_WINDOW = 'readonly-probe';
+
+// Invoke straight from the current window's page, so its own capability applies
+async function invokeHere(command: string, args: Record<string, unknown> = {}) {
+  return (await browser.executeAsync(
+    (command: string, args: Record<string, unknown>, done: (outcome: InvokeOutcome) => void) => {
+      const tauri = (window as unknown as { __TAURI__: { core: { invoke: (...a: unknown[]) => Promise<unknown> } } })
+        .__TAURI__;
+      tauri.core.invoke(command, args).then(
+        (value) => done({ ok: true, value }),
+        (error) => done({ ok: false, error: String(error) }),
+      );
+    },
+    command,
+    args,
+  )) as InvokeOutcome;
+}
+
+describe('Tauri Plugin Permission Sets', () => {
+  before(async () => {
+    await browser.tauri.execute(
+      ({ core }, label: string) => core.invoke('open_child_window', { label }),
+      READONLY_WINDOW,
+    );
+    await browser.waitUntil(async () => (await browser.tauri.listWindows()).includes(READONLY_WINDOW), {
+      timeout: 10000,
+      timeoutMsg: 'read-only window never opened',
+    });
+    await browser.tauri.switchWindow(READONLY_WINDOW);
+  });
+
+  after(async () => {
+    await browser.tauri.switchWindow('main');
+    await browser.tauri.execute(
+      ({ core }, label: string) => core.invoke('close_child_window', { label }),
+      READONLY_WINDOW,
+    );
+  });
+
+  it('should allow query commands under wdio:readonly', async () => {
+    expect(await invokeHere('plugin:wdio|get_active_window_label')).toEqual({ ok: true, value: READONLY_WINDOW });
+    expect(await invokeHere('plugin:wdio|get_app_info')).toMatchObject({ ok: true });
+    expect(await invokeHere('plugin:wdio|get_logs')).toMatchObject({ ok: true });
+  });
+
+  it('should reject mutating commands with an ACL error naming the granting permission', async () => {
+    for (const [command, args] of [
+      ['set_window_bounds', { label: 'main', bounds: { x: 0, y: 0, width: 800, height: 600 } }],
+      ['emit_event', { name: 'wdio-injected', payload: null }],
+      ['execute', { request: { script: '1 + 1' } }],
+    ] as const) {
+      const outcome = await invokeHere(`plugin:wdio|${command}`, args);
+      expect(outcome.ok).toBe(false);
+      const { error } = outcome as { error: string };
+      expect(error).toContain(`wdio.${command} not allowed on window "${READONLY_WINDOW}"`);
+      expect(error).toContain('permission: wdio:default');
+    }
+  });
+
+  it('should report the commands wdio:readonly leaves out in the self test', async () => {
+    const outcome = await invokeHere('plugin:wdio|self_test');
+
+    expect(outcome.ok).toBe(true);
+    const report = (outcome as { value: SelfTestReport }).value;
+    expect(report.ipc_event_roundtrip_ok).toBe(true);
+    const permitted = Object.fromEntries(report.commands.map(({ command, permitted }) => [command, permitted]));
+    expect(permitted).toMatchObject({
+      get_app_info: true,
+      get_logs: true,
+      self_test: true,
+      execute: false,
+      resolve_execute: false,
+      set_window_bounds: false,
+      capture_window: false,
+      snapshot_web_storage: false,
+      quit_app: false,
+    });
+  });
+});
+
+describe('Tauri Plugin Self Test', () => {
+  it('should find every command permitted in the main window', async () => {
+    const report = (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|self_test'))) as SelfTestReport;
+
+    expect(report.ipc_event_roundtrip_ok).toBe(true);
+    expect(report.commands.length).toBeGreaterThan(100);
+    expect(report.commands.filter(({ permitted }) => !permitted)).toEqual([]);
+  });
+
+  it('should not run the probed commands', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|self_test'));
+
+    // Running quit_app rather than probing it would have ended the session
+    expect(await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|is_enabled'))).toBe(true);
+  });
+});
@@ -0,0 +1,32 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type PluginCapabilities = { plugin_version: string; protocol_version: number; features: string[] };
+
+describe('Tauri Plugin Capabilities', () => {
+  it('should report the plugin and protocol versions', async () => {
+    const capabilities = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_plugin_capabilities'),
+    )) as PluginCapabilities;
+
+    expect(capabilities.plugin_version).toMatch(/^\d+\.\d+\.\d+/);
+    expect(capabilities.protocol_version).toBeGreaterThanOrEqual(1);
+  });
+
+  it('should list the features the fixture enables', async () => {
+    const { features } = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_plugin_capabilities'),
+    )) as PluginCapabilities;
+
+    expect(features).toEqual(
+      expect.arrayContaining(['metrics', 'clipboard', 'tray', 'global-shortcut', 'deep-link', 'a11y', 'native-input']),
+    );
+    expect(features).not.toContain('control-server');
+  });
+
+  it('should report the plugin as enabled in the debug fixture build', async () => {
+    const enabled = await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|is_enabled'));
+
+    expect(enabled).toBe(true);
+  });
+});
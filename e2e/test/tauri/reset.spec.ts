@@ -0,0 +1,91 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type ResetSummary = {
+  logs_cleared: number;
+  invoke_trace_cleared: number;
+  session_ended: string | null;
+  preamble_cleared: boolean;
+  executions_cancelled: number;
+  notifications_cleared: number;
+  recorded_events_cleared: number;
+  invoke_conditions_cleared: boolean;
+  invoke_replay_stopped: boolean;
+  pages_reset: number;
+};
+
+async function invoke(command: string, args: Record<string, unknown> = {}) {
+  return browser.tauri.execute(({ core }, command, args) => core.invoke(`plugin:wdio|${command}`, args), command, args);
+}
+
+async function resetAll() {
+  return (await invoke('reset_all')) as ResetSummary;
+}
+
+describe('Tauri Reset All', () => {
+  afterEach(async () => {
+    await resetAll();
+  });
+
+  it('should clear every kind of test state', async () => {
+    const mock = await browser.tauri.mock('get_platform_info');
+    await mock.mockReturnValue({ os: 'mocked' });
+    await invoke('begin_session', { sessionId: 'reset-spec' });
+    await invoke('log_frontend', { level: 'info', message: 'before reset' });
+    await invoke('start_invoke_tracing');
+    await invoke('emit_event', { name: 'reset-test', payload: null });
+    await invoke('set_execute_preamble', { script: 'const resetHelper = 1;' });
+    await invoke('enable_notification_capture');
+    await $('#notify-button').click();
+    await invoke('start_event_recording', { eventNames: ['reset-test'] });
+    await invoke('emit_event', { name: 'reset-test', payload: 1 });
+    await invoke('set_invoke_conditions', { conditions: { latency_ms: 0 } });
+
+    const summary = await resetAll();
+
+    expect(summary).toEqual(
+      expect.objectContaining({
+        session_ended: 'reset-spec',
+        preamble_cleared: true,
+        notifications_cleared: 1,
+        recorded_events_cleared: 1,
+        invoke_conditions_cleared: true,
+        invoke_replay_stopped: false,
+        executions_cancelled: 0,
+      }),
+    );
+    expect(summary.logs_cleared).toBeGreaterThanOrEqual(1);
+    expect(summary.invoke_trace_cleared).toBeGreaterThanOrEqual(1);
+    expect(summary.pages_reset).toBeGreaterThanOrEqual(1);
+
+    expect(await invoke('get_logs', { filter: { contains: 'before reset' } })).toEqual([]);
+    expect(((await invoke('get_invoke_trace')) as { entries: unknown[] }).entries).toEqual([]);
+    expect(((await invoke('get_recorded_events')) as { events: unknown[] }).events).toEqual([]);
+    expect(await invoke('get_captured_notifications')).toEqual([]);
+    expect(await invoke('execute', { request: { script: 'typeof resetHelper', args: [] } })).toBe('undefined');
+    expect(await browser.execute(() => Object.keys((window as { __wdio_mocks__?: object }).__wdio_mocks__ ?? {}))).toEqual(
+      [],
+    );
+    // The session ended, so a new one can begin
+    await invoke('begin_session', { sessionId: 'after-reset' });
+  });
+
+  it('should report nothing cleared when there is nothing to clear', async () => {
+    await resetAll();
+
+    const summary = await resetAll();
+
+    expect(summary).toEqual(
+      expect.objectContaining({
+        session_ended: null,
+        preamble_cleared: false,
+        notifications_cleared: 0,
+        recorded_events_cleared: 0,
+        invoke_trace_cleared: 0,
+        invoke_conditions_cleared: false,
+        invoke_replay_stopped: false,
+        executions_cancelled: 0,
+      }),
+    );
+  });
+});
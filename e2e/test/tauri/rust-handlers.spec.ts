@@ -0,0 +1,40 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+async function executeRust(name: string, args?: unknown) {
+  return browser.tauri.execute(
+    ({ core }, name, args) => core.invoke('plugin:wdio|execute_rust', { name, args }),
+    name,
+    args,
+  );
+}
+
+describe('Tauri Rust Handlers', () => {
+  it('should pass the args to the handler and return its result', async () => {
+    const args = { nested: { list: [1, 'two', null] }, flag: true };
+
+    expect(await executeRust('echo', args)).toEqual(args);
+    expect(await executeRust('echo')).toBeNull();
+  });
+
+  it('should let a handler change backend state between calls', async () => {
+    const { total: before } = (await executeRust('add_to_counter', { amount: 2 })) as { total: number };
+    const { total: after } = (await executeRust('add_to_counter', { amount: 3 })) as { total: number };
+
+    expect(after).toBe(before + 3);
+  });
+
+  it('should reject with the error a handler returns', async () => {
+    await expect(executeRust('fail')).rejects.toThrow(/Rust handler failed: fail handler failed/);
+  });
+
+  it('should turn a handler panic into an error and keep the app running', async () => {
+    await expect(executeRust('fail', { panic: true })).rejects.toThrow(/fail panicked: fail handler panicked/);
+
+    expect(await executeRust('echo', 'still alive')).toBe('still alive');
+  });
+
+  it('should reject unknown handler names', async () => {
+    await expect(executeRust('missing')).rejects.toThrow(/Rust handler not found: missing/);
+  });
+});
@@ -0,0 +1,98 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+
+// Width and height live at fixed offsets in the PNG IHDR chunk
+function pngDimensions(dataUrl: string): { width: number; height: number } {
+  const buffer = Buffer.from(dataUrl.replace(/^data:image\/png;base64,/, ''), 'base64');
+  return { width: buffer.readUInt32BE(16), height: buffer.readUInt32BE(20) };
+}
+
+// Width and height follow the marker of the first start-of-frame segment
+function jpegDimensions(buffer: Buffer): { width: number; height: number } {
+  let offset = 2;
+  while (offset < buffer.length) {
+    const marker = buffer.readUInt16BE(offset);
+    if (marker >= 0xffc0 && marker <= 0xffc3) {
+      return { height: buffer.readUInt16BE(offset + 5), width: buffer.readUInt16BE(offset + 7) };
+    }
+    offset += 2 + buffer.readUInt16BE(offset + 2);
+  }
+  throw new Error('no JPEG start-of-frame segment');
+}
+
+describe('Tauri Window Capture', () => {
+  it('should capture the window as a PNG matching the viewport size', async () => {
+    const dataUrl = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|capture_window', { label: 'main' }),
+    )) as string;
+    expect(dataUrl).toMatch(/^data:image\/png;base64,/);
+
+    const viewport = (await browser.execute(() => ({
+      width: window.innerWidth,
+      height: window.innerHeight,
+      scale: window.devicePixelRatio,
+    }))) as { width: number; height: number; scale: number };
+    const { width, height } = pngDimensions(dataUrl);
+
+    // Snapshots are either in CSS or device pixels depending on the compositor
+    const matches = (actual: number, css: number) =>
+      Math.abs(actual - css) <= 2 || Math.abs(actual - css * viewport.scale) <= 2;
+    expect(matches(width, viewport.width)).toBe(true);
+    expect(matches(height, viewport.height)).toBe(true);
+  });
+
+  it('should encode JPEG when requested', async () => {
+    const dataUrl = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|capture_window', { label: 'main', options: { format: 'jpeg', quality: 70 } }),
+    )) as string;
+
+    expect(dataUrl).toMatch(/^data:image\/jpeg;base64,\/9j\//);
+  });
+
+  it('should go through the plugin from the fixture take_screenshot command', async () => {
+    const dataUrl = (await browser.tauri.execute(({ core }) => core.invoke('take_screenshot'))) as string;
+
+    expect(pngDimensions(dataUrl).width).toBeGreaterThan(1);
+  });
+
+  it('should write a JPEG with real dimensions to the given path', async () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'wdio-screenshot-'));
+    const file = path.join(dir, 'window.jpg');
+    try {
+      const written = await browser.tauri.execute(
+        ({ core }, filePath) =>
+          core.invoke('take_screenshot', { options: { format: 'jpeg', quality: 80, path: filePath } }),
+        file,
+      );
+      expect(written).toBe(file);
+
+      const { width, height } = jpegDimensions(fs.readFileSync(file));
+      expect(width).toBeGreaterThan(100);
+      expect(height).toBeGreaterThan(100);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+
+  it('should reject a JPEG quality above 100', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) =>
+        core.invoke('take_screenshot', { options: { format: 'jpeg', quality: 101 } }),
+      ),
+    ).rejects.toThrow(/invalid JPEG quality 101/);
+  });
+
+  it('should fail for minimized windows', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|minimize_window', { label: 'main' }));
+    try {
+      await expect(
+        browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|capture_window', { label: 'main' })),
+      ).rejects.toThrow(/Screenshot failed: window 'main' is minimized/);
+    } finally {
+      await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|restore_window', { label: 'main' }));
+    }
+  });
+});
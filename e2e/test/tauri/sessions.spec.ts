@@ -0,0 +1,88 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type LogEntry = { message: string; session_id: string | null };
+type EndedSession = { session_id: string; log_entries: number; invoke_trace_entries: number };
+
+async function beginSession(sessionId: string) {
+  await browser.tauri.execute(({ core }, id) => core.invoke('plugin:wdio|begin_session', { sessionId: id }), sessionId);
+}
+
+async function endSession(sessionId: string) {
+  return (await browser.tauri.execute(
+    ({ core }, id) => core.invoke('plugin:wdio|end_session', { sessionId: id }),
+    sessionId,
+  )) as EndedSession;
+}
+
+async function logFrontend(message: string) {
+  await browser.tauri.execute(
+    ({ core }, text) => core.invoke('plugin:wdio|log_frontend', { level: 'info', message: text }),
+    message,
+  );
+}
+
+async function loggedMessages(marker: string) {
+  const entries = (await browser.tauri.execute(
+    ({ core }, contains) => core.invoke('plugin:wdio|get_logs', { filter: { contains } }),
+    marker,
+  )) as LogEntry[];
+  return entries.map((entry) => ({ message: entry.message, session_id: entry.session_id }));
+}
+
+async function mockedCommands() {
+  return browser.execute(() => Object.keys((window as { __wdio_mocks__?: object }).__wdio_mocks__ ?? {}).sort());
+}
+
+describe('Tauri Test Sessions', () => {
+  afterEach(async () => {
+    await endSession('session-a').catch(() => undefined);
+    await browser.tauri.restoreAllMocks();
+  });
+
+  it('should remove logs tagged with the session and keep the rest', async () => {
+    const marker = `session-logs-${Date.now()}`;
+    await logFrontend(`${marker} before`);
+    await beginSession('session-a');
+    await logFrontend(`${marker} during`);
+
+    const tagged = await loggedMessages(marker);
+    expect(tagged.find((entry) => entry.message.endsWith(`${marker} before`))?.session_id).toBeNull();
+    expect(tagged.find((entry) => entry.message.endsWith(`${marker} during`))?.session_id).toBe('session-a');
+
+    const ended = await endSession('session-a');
+    expect(ended.session_id).toBe('session-a');
+    expect(ended.log_entries).toBeGreaterThanOrEqual(1);
+
+    const remaining = (await loggedMessages(marker)).map((entry) => entry.message);
+    expect(remaining.some((message) => message.endsWith(`${marker} before`))).toBe(true);
+    expect(remaining.some((message) => message.endsWith(`${marker} during`))).toBe(false);
+  });
+
+  it('should reject overlapping sessions', async () => {
+    await beginSession('session-a');
+
+    await expect(beginSession('session-b')).rejects.toThrow(/SESSION_CONFLICT|Another session is active/);
+    await expect(endSession('session-b')).rejects.toThrow(/SESSION_NOT_ACTIVE|Session is not active/);
+  });
+
+  it('should remove mocks created during the session and keep earlier ones', async () => {
+    const earlier = await browser.tauri.mock('get_platform_info');
+    await earlier.mockReturnValue({ os: 'mocked' });
+    await browser.tauri.execute(({ core }) => core.invoke('get_platform_info'));
+
+    await beginSession('session-a');
+    await browser.tauri.mock('plugin:wdio|get_clipboard_text');
+    await browser.tauri.execute(({ core }) => core.invoke('get_platform_info'));
+    expect(await mockedCommands()).toEqual(['get_platform_info', 'plugin:wdio|get_clipboard_text']);
+
+    await endSession('session-a');
+
+    expect(await mockedCommands()).toEqual(['get_platform_info']);
+    // The call made during the session is dropped from the earlier mock
+    await earlier.update();
+    expect(earlier).toHaveBeenCalledTimes(1);
+    const result = await browser.tauri.execute(({ core }) => core.invoke('get_platform_info'));
+    expect(result).toEqual({ os: 'mocked' });
+  });
+});
@@ -0,0 +1,60 @@
+import { spawn } from 'node:child_process';
+import fs from 'node:fs';
+import path from 'node:path';
+import process from 'node:process';
+import url from 'node:url';
+import { getTauriBinaryPath } from '@wdio/tauri-service';
+import { xvfb } from '@wdio/xvfb';
+
+const __dirname = path.dirname(url.fileURLToPath(import.meta.url));
+
+const appDir = path.join(__dirname, '..', '..', '..', '..', 'fixtures', 'e2e-apps', 'tauri');
+if (!fs.existsSync(appDir)) {
+  throw new Error(`Tauri app directory not found: ${appDir}`);
+}
+const appBinaryPath = await getTauriBinaryPath(appDir);
+
+if (process.platform === 'linux') {
+  await xvfb.init();
+}
+
+type Exit = { code: number | null; signal: NodeJS.Signals | null };
+
+// The fixture calls crash_main_process over IPC with WDIO_CRASH_ON_LOAD as its arguments
+function runCrash(args: Record<string, unknown>) {
+  return new Promise<Exit>((resolve, reject) => {
+    const child = spawn(appBinaryPath, [], {
+      env: { ...process.env, WDIO_CRASH_ON_LOAD: JSON.stringify(args) },
+      stdio: 'inherit',
+    });
+    const timer = setTimeout(() => {
+      child.kill();
+      reject(new Error(`App did not crash with ${JSON.stringify(args)} within 30s`));
+    }, 30_000);
+    child.on('error', reject);
+    child.on('exit', (code, signal) => {
+      clearTimeout(timer);
+      resolve({ code, signal });
+    });
+  });
+}
+
+const scenarios: Array<{ args: Record<string, unknown>; check: (exit: Exit) => boolean }> = [
+  { args: { mode: 'exit', code: 9, respondFirst: true }, check: ({ code }) => code === 9 },
+  { args: { mode: 'exit', code: 4 }, check: ({ code }) => code === 4 },
+  {
+    args: { mode: 'abort' },
+    check: ({ code, signal }) => (process.platform === 'win32' ? code !== 0 : signal === 'SIGABRT'),
+  },
+  { args: { mode: 'panic', respondFirst: true }, check: ({ code, signal }) => code !== 0 || signal !== null },
+];
+
+for (const { args, check } of scenarios) {
+  const exit = await runCrash(args);
+  if (!check(exit)) {
+    throw new Error(`Crash test failed for ${JSON.stringify(args)}: got ${JSON.stringify(exit)}`);
+  }
+  console.log(`✅ ${JSON.stringify(args)} ended the app with ${JSON.stringify(exit)}`);
+}
+
+process.exit();
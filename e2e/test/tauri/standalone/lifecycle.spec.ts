@@ -0,0 +1,45 @@
+import { spawn } from 'node:child_process';
+import fs from 'node:fs';
+import path from 'node:path';
+import process from 'node:process';
+import url from 'node:url';
+import { getTauriBinaryPath } from '@wdio/tauri-service';
+import { xvfb } from '@wdio/xvfb';
+
+const __dirname = path.dirname(url.fileURLToPath(import.meta.url));
+
+// The fixture calls plugin:wdio|quit_app over IPC with WDIO_QUIT_ON_LOAD as the exit code
+const expectedExitCode = 7;
+
+const appDir = path.join(__dirname, '..', '..', '..', '..', 'fixtures', 'e2e-apps', 'tauri');
+if (!fs.existsSync(appDir)) {
+  throw new Error(`Tauri app directory not found: ${appDir}`);
+}
+const appBinaryPath = await getTauriBinaryPath(appDir);
+
+if (process.platform === 'linux') {
+  await xvfb.init();
+}
+
+const exitCode = await new Promise<number | null>((resolve, reject) => {
+  const child = spawn(appBinaryPath, [], {
+    env: { ...process.env, WDIO_QUIT_ON_LOAD: String(expectedExitCode) },
+    stdio: 'inherit',
+  });
+  const timer = setTimeout(() => {
+    child.kill();
+    reject(new Error('App did not exit via quit_app within 30s'));
+  }, 30_000);
+  child.on('error', reject);
+  child.on('exit', (code) => {
+    clearTimeout(timer);
+    resolve(code);
+  });
+});
+
+if (exitCode !== expectedExitCode) {
+  throw new Error(`Lifecycle test failed: expected exit code ${expectedExitCode}, got ${exitCode}`);
+}
+
+console.log(`✅ quit_app exit code observed: ${exitCode}`);
+process.exit();
@@ -0,0 +1,75 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type TrayInfo = { id: string; menu: { id: string; label: string; checked: boolean | null }[] };
+
+async function isMainVisible() {
+  const info = (await browser.tauri.execute(({ core }) =>
+    core.invoke('plugin:wdio|get_window_info', { label: 'main' }),
+  )) as { visible: boolean };
+  return info.visible;
+}
+
+async function waitForMainVisible(visible: boolean) {
+  await browser.waitUntil(async () => (await isMainVisible()) === visible, {
+    timeout: 5000,
+    timeoutMsg: `main window did not become ${visible ? 'visible' : 'hidden'}`,
+  });
+}
+
+describe('Tauri Tray', () => {
+  afterEach(async () => {
+    if (!(await isMainVisible())) {
+      await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|simulate_tray_click'));
+      await waitForMainVisible(true);
+    }
+  });
+
+  it('should list the registered tray and its menu', async () => {
+    const trays = (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_tray_items'))) as TrayInfo[];
+
+    expect(trays).toHaveLength(1);
+    expect(trays[0].id).toBe('main-tray');
+    expect(trays[0].menu.map((item) => item.id)).toEqual(['tray-toggle', 'tray-pin']);
+  });
+
+  it('should toggle the main window on a left click', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|simulate_tray_click', { button: 'left' }));
+    await waitForMainVisible(false);
+
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|simulate_tray_click', { button: 'left' }));
+    await waitForMainVisible(true);
+  });
+
+  it('should ignore a right click', async () => {
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|simulate_tray_click', {
+        trayId: 'main-tray',
+        button: 'right',
+        position: { x: 5, y: 5 },
+      }),
+    );
+    // Give the main thread time to run the handlers before checking nothing changed
+    await browser.pause(500);
+
+    expect(await isMainVisible()).toBe(true);
+  });
+
+  it('should run tray menu items', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|trigger_tray_menu_item', { id: 'tray-toggle' }));
+    await waitForMainVisible(false);
+
+    const togglePin = async () =>
+      (await browser.tauri.execute(({ core }) =>
+        core.invoke('plugin:wdio|trigger_tray_menu_item', { path: ['Pin'] }),
+      )) as { checked: boolean };
+    expect((await togglePin()).checked).toBe(true);
+    expect((await togglePin()).checked).toBe(false);
+  });
+
+  it('should reject unknown trays', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|simulate_tray_click', { trayId: 'missing' })),
+    ).rejects.toThrow(/Tray icon not found: missing/);
+  });
+});
@@ -0,0 +1,75 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type WaitArgs = { name: string; timeoutMs: number; payloadFilter?: unknown };
+
+/**
+ * Start waiting, then emit `count` test events once the wait's listener has
+ * had time to register; resolves with the payload or the error message
+ */
+async function waitWhileEmitting(wait: WaitArgs, emit: { name: string; count: number } | null) {
+  return browser.tauri.execute(
+    async ({ core }, wait, emit) => {
+      const waiting = core.invoke('plugin:wdio|wait_for_event', wait).then(
+        (payload: unknown) => ({ payload }),
+        (error: { message?: string }) => ({ error: error?.message ?? String(error) }),
+      );
+      if (emit) {
+        await new Promise((resolve) => setTimeout(resolve, 100));
+        await core.invoke('emit_test_events', emit);
+      }
+      return waiting;
+    },
+    wait,
+    emit,
+  );
+}
+
+describe('Tauri Wait For Event', () => {
+  afterEach(async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|stop_event_recording'));
+  });
+
+  it('should resolve with the payload of an event emitted while waiting', async () => {
+    const result = await waitWhileEmitting(
+      { name: 'sync-complete', timeoutMs: 5000 },
+      { name: 'sync-complete', count: 1 },
+    );
+
+    expect(result).toEqual({ payload: { index: 0 } });
+  });
+
+  it('should skip events whose payload does not contain the filter', async () => {
+    const result = await waitWhileEmitting(
+      { name: 'sync-complete', timeoutMs: 5000, payloadFilter: { index: 2 } },
+      { name: 'sync-complete', count: 3 },
+    );
+
+    expect(result).toEqual({ payload: { index: 2 } });
+  });
+
+  it('should match a recorded event emitted before its listener was registered', async () => {
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|start_event_recording', { eventNames: ['sync-complete'] }),
+    );
+
+    // Emitted in the same tick as the wait is dispatched, so it may land first
+    const result = await browser.tauri.execute(async ({ core }) => {
+      const waiting = core.invoke('plugin:wdio|wait_for_event', { name: 'sync-complete', timeoutMs: 5000 });
+      const emitting = core.invoke('emit_test_events', { name: 'sync-complete', count: 1 });
+      const [payload] = await Promise.all([waiting, emitting]);
+      return payload;
+    });
+
+    expect(result).toEqual({ index: 0 });
+  });
+
+  it('should fail with WAIT_TIMEOUT when no event matches in time', async () => {
+    const result = await waitWhileEmitting(
+      { name: 'sync-complete', timeoutMs: 200, payloadFilter: { index: 99 } },
+      { name: 'sync-complete', count: 2 },
+    );
+
+    expect(result).toEqual({ error: "No 'sync-complete' event within 200ms" });
+  });
+});
@@ -0,0 +1,95 @@
+import { $, browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type WebStorageKind = 'localStorage' | 'sessionStorage' | 'indexedDb' | 'cookies';
+type WebStorageResult = { kind: WebStorageKind; count: number; error: string | null };
+type WebStorageSnapshot = {
+  origin: string;
+  local_storage: Record<string, string> | null;
+  session_storage: Record<string, string> | null;
+  indexed_db: { name: string; stores: { name: string; records: unknown[] }[] }[] | null;
+  cookies: Record<string, string> | null;
+  errors: Partial<Record<WebStorageKind, string>>;
+};
+
+async function snapshot() {
+  return (await browser.tauri.execute(({ core }) =>
+    core.invoke('plugin:wdio|snapshot_web_storage', { label: 'main' }),
+  )) as WebStorageSnapshot;
+}
+
+async function clear(kinds?: WebStorageKind[]) {
+  return (await browser.tauri.execute(
+    ({ core }, kinds) => core.invoke('plugin:wdio|clear_web_storage', { label: 'main', kinds }),
+    kinds,
+  )) as WebStorageResult[];
+}
+
+async function restore(snapshot: WebStorageSnapshot) {
+  return (await browser.tauri.execute(
+    ({ core }, snapshot) => core.invoke('plugin:wdio|restore_web_storage', { label: 'main', snapshot }),
+    snapshot,
+  )) as WebStorageResult[];
+}
+
+async function storeThroughUi(key: string, value: string) {
+  await $('#storage-key').setValue(key);
+  await $('#storage-value').setValue(value);
+  await $('#storage-submit').click();
+  await expect($('#storage-result')).toHaveText('Stored');
+}
+
+function fixtureRecords(snapshot: WebStorageSnapshot) {
+  const database = snapshot.indexed_db?.find((database) => database.name === 'fixture-storage');
+  return database?.stores.find((store) => store.name === 'entries')?.records ?? [];
+}
+
+describe('Tauri Web Storage', () => {
+  after(async () => {
+    await clear();
+  });
+
+  it('should snapshot, clear, and restore what the app stored', async () => {
+    await storeThroughUi('theme', 'dark');
+    await storeThroughUi('draft', 'hello world');
+
+    const before = await snapshot();
+    expect(before.errors).toEqual({});
+    expect(before.local_storage).toMatchObject({ theme: 'dark', draft: 'hello world' });
+    expect(before.session_storage).toMatchObject({ theme: 'dark', draft: 'hello world' });
+    expect(before.cookies).toMatchObject({ theme: 'dark', draft: 'hello%20world' });
+    expect(fixtureRecords(before)).toHaveLength(2);
+
+    const cleared = await clear();
+    expect(cleared.map((result) => result.error)).toEqual([null, null, null, null]);
+
+    const empty = await snapshot();
+    expect(empty.local_storage).toEqual({});
+    expect(empty.session_storage).toEqual({});
+    expect(empty.cookies).toEqual({});
+    expect(fixtureRecords(empty)).toEqual([]);
+
+    const restored = await restore(before);
+    expect(restored.every((result) => result.error === null)).toBe(true);
+    expect(await snapshot()).toEqual(before);
+  });
+
+  it('should only clear the requested kinds', async () => {
+    await storeThroughUi('scope', 'kept');
+
+    const results = await clear(['localStorage']);
+
+    expect(results).toEqual([{ kind: 'localStorage', count: expect.any(Number), error: null }]);
+    const after = await snapshot();
+    expect(after.local_storage).toEqual({});
+    expect(after.session_storage).toMatchObject({ scope: 'kept' });
+  });
+
+  it('should reject restoring a snapshot from another origin', async () => {
+    const current = await snapshot();
+
+    await expect(restore({ ...current, origin: 'https://elsewhere.example' })).rejects.toThrow(
+      /snapshot was taken at https:\/\/elsewhere\.example/,
+    );
+  });
+});
@@ -0,0 +1,72 @@
+import { browser, expect } from '@wdio/globals';
+import '@wdio/native-types';
+
+type WindowEventRecord = {
+  label: string;
+  kind: 'moved' | 'resized' | 'focused' | 'scale_factor_changed' | 'close_requested';
+  detail: Record<string, unknown> | null;
+  timestamp_ms: number;
+};
+type WindowEvents = { events: WindowEventRecord[]; dropped: number };
+
+async function getWindowEvents(label?: string, since?: number) {
+  return (await browser.tauri.execute(
+    ({ core }, label, since) => core.invoke('plugin:wdio|get_window_events', { label, since }),
+    label,
+    since,
+  )) as WindowEvents;
+}
+
+async function setBounds(width: number, height: number) {
+  await browser.tauri.execute(
+    ({ core }, width, height) =>
+      core.invoke('plugin:wdio|set_window_bounds', {
+        label: 'main',
+        bounds: { x: 40, y: 50, width, height, unit: 'physical' },
+      }),
+    width,
+    height,
+  );
+}
+
+describe('Tauri Window Events', () => {
+  let originalSize: { width: number; height: number };
+
+  before(async () => {
+    const info = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_window_info', { label: 'main' }),
+    )) as { bounds: { width: number; height: number } };
+    originalSize = info.bounds;
+  });
+
+  after(async () => {
+    await setBounds(originalSize.width, originalSize.height);
+  });
+
+  it('should record a resize with the new size', async () => {
+    const since = Date.now();
+    await setBounds(660, 500);
+
+    await browser.waitUntil(
+      async () =>
+        (await getWindowEvents('main', since)).events.some(
+          (event) => event.kind === 'resized' && event.detail?.width === 660 && event.detail?.height === 500,
+        ),
+      { timeout: 5000, timeoutMsg: 'no resized event with the new size was recorded' },
+    );
+
+    const { events } = await getWindowEvents('main', since);
+    expect(events.every((event) => event.label === 'main' && event.timestamp_ms >= since)).toBe(true);
+    expect(events.filter((event) => event.kind === 'resized').at(-1)?.detail).toEqual({ width: 660, height: 500 });
+  });
+
+  it('should clear the recorded events', async () => {
+    await setBounds(680, 520);
+    await browser.waitUntil(async () => (await getWindowEvents('main')).events.length > 0, { timeout: 5000 });
+
+    const cleared = await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|clear_window_events'));
+
+    expect(cleared).toBeGreaterThan(0);
+    expect((await getWindowEvents()).events).toEqual([]);
+  });
+});
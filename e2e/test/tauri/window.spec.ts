@@ -69,6 +69,114 @@ describe('Multi-Window Support', () => {
   });
 });
 
+type WindowInfo = {
+  label: string;
+  title: string;
+  focused: boolean;
+  visible: boolean;
+  maximized: boolean;
+  minimized: boolean;
+  fullscreen: boolean;
+  bounds: { x: number; y: number; width: number; height: number } | null;
+};
+
+describe('plugin window commands', () => {
+  it('should list window info for every window', async () => {
+    const windows = (await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|list_windows'))) as WindowInfo[];
+
+    const main = windows.find((w) => w.label === 'main');
+    expect(main).toBeDefined();
+    expect(main?.title).toMatch(/Tauri.*E2E Test App/);
+    expect(main?.bounds?.width).toBeGreaterThan(0);
+    expect(main?.bounds?.height).toBeGreaterThan(0);
+
+    const splash = windows.find((w) => w.label === 'splash');
+    if (splash) {
+      expect(splash.title).toBe('Splash Screen');
+    }
+  });
+
+  it('should get info for a single window', async () => {
+    const info = (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_window_info', { label: 'main' }),
+    )) as WindowInfo;
+
+    expect(info.label).toBe('main');
+    expect(info.fullscreen).toBe(false);
+  });
+
+  it('should reject unknown labels with WindowNotFound', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|get_window_info', { label: 'nonexistent-window-42' })),
+    ).rejects.toThrow(/Window not found: nonexistent-window-42/);
+    await expect(
+      browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|focus_window', { label: 'nonexistent-window-42' })),
+    ).rejects.toThrow(/Window not found: nonexistent-window-42/);
+  });
+
+  it('should focus another window', async () => {
+    const labels = await browser.tauri.listWindows();
+    if (!labels.includes('splash')) {
+      console.log('[SKIP] Splash window not available');
+      return;
+    }
+
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|focus_window', { label: 'splash' }));
+    await browser.waitUntil(
+      async () => {
+        const info = (await browser.tauri.execute(({ core }) =>
+          core.invoke('plugin:wdio|get_window_info', { label: 'splash' }),
+        )) as WindowInfo;
+        return info.focused && info.visible;
+      },
+      { timeout: 5000, timeoutMsg: 'splash window was not focused' },
+    );
+
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|focus_window', { label: 'main' }));
+  });
+});
+
+describe('plugin window mutation commands', () => {
+  const getInfo = async () =>
+    (await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|get_window_info', { label: 'main' }),
+    )) as WindowInfo;
+
+  afterEach(async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|restore_window', { label: 'main' }));
+  });
+
+  it('should set bounds in physical pixels', async () => {
+    await browser.tauri.execute(({ core }) =>
+      core.invoke('plugin:wdio|set_window_bounds', {
+        label: 'main',
+        bounds: { x: 40, y: 50, width: 640, height: 480, unit: 'physical' },
+      }),
+    );
+
+    await browser.waitUntil(async () => (await getInfo()).bounds?.width === 640, {
+      timeout: 5000,
+      timeoutMsg: 'window was not resized',
+    });
+  });
+
+  it('should maximize and restore', async () => {
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|maximize_window', { label: 'main' }));
+    await browser.waitUntil(async () => (await getInfo()).maximized, { timeout: 5000 });
+
+    await browser.tauri.execute(({ core }) => core.invoke('plugin:wdio|restore_window', { label: 'main' }));
+    await browser.waitUntil(async () => !(await getInfo()).maximized, { timeout: 5000 });
+  });
+
+  it('should reject unknown labels', async () => {
+    await expect(
+      browser.tauri.execute(({ core }) =>
+        core.invoke('plugin:wdio|set_always_on_top', { label: 'nonexistent-window-42', alwaysOnTop: true }),
+      ),
+    ).rejects.toThrow(/Window not found/);
+  });
+});
+
 describe('per-call windowLabel option', () => {
   it('should execute in main window without switching session default', async () => {
     // Execute with explicit windowLabel
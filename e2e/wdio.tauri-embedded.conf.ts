@@ -66,7 +66,11 @@ async function getTauriEmbeddedConfigContext(): Promise<TauriEmbeddedConfigConte
   console.log('🔍 Setting up Tauri Embedded test with app binary path');
 
   // Use debug builds for testing (includes tauri-plugin-automation for CrabNebula macOS)
-  const tauriTargetDir = join(appPath, 'src-tauri', 'target', 'debug');
+  // The withGlobalTauri: false variant is built into its own target dir (build:no-global-tauri)
+  const tauriTargetDir =
+    envContext.testType === 'no-global-tauri'
+      ? join(appPath, 'src-tauri', 'target', 'no-global-tauri', 'debug')
+      : join(appPath, 'src-tauri', 'target', 'debug');
   const tauriConfigPath = join(appPath, 'src-tauri', 'tauri.conf.json');
 
   if (!fileExists(tauriConfigPath)) {
@@ -132,6 +136,10 @@ switch (envContext.testType) {
     specs = ['./test/tauri/deeplink.spec.ts'];
     maxInstances = 1;
     break;
+  case 'no-global-tauri':
+    // Bridge tests against the variant built with withGlobalTauri: false
+    specs = ['./test/tauri/no-global-tauri/*.spec.ts'];
+    break;
   default:
     // Standard tests - core functionality without specialized test modes
     specs = ['./test/tauri/*.spec.ts'];
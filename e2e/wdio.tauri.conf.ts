@@ -72,7 +72,11 @@ async function getTauriConfigContext(): Promise<TauriConfigContext> {
   console.log('🔍 Setting up Tauri test with app binary path');
 
   // Use debug builds for testing (includes tauri-plugin-automation for CrabNebula macOS)
-  const tauriTargetDir = join(appPath, 'src-tauri', 'target', 'debug');
+  // The withGlobalTauri: false variant is built into its own target dir (build:no-global-tauri)
+  const tauriTargetDir =
+    envContext.testType === 'no-global-tauri'
+      ? join(appPath, 'src-tauri', 'target', 'no-global-tauri', 'debug')
+      : join(appPath, 'src-tauri', 'target', 'debug');
   const tauriConfigPath = join(appPath, 'src-tauri', 'tauri.conf.json');
 
   if (!fileExists(tauriConfigPath)) {
@@ -140,6 +144,10 @@ switch (envContext.testType) {
     specs = ['./test/tauri/deeplink.spec.ts'];
     maxInstances = 1;
     break;
+  case 'no-global-tauri':
+    // Bridge tests against the variant built with withGlobalTauri: false
+    specs = ['./test/tauri/no-global-tauri/*.spec.ts'];
+    break;
   default:
     // Standard tests - core functionality without specialized test modes
     specs = ['./test/tauri/*.spec.ts'];
@@ -249,7 +257,10 @@ if (envContext.isMultiremote) {
     },
   };
 } else {
-  const baseEnv: Record<string, string> = {};
+  const baseEnv: Record<string, string> = {
+    // Read back by the environment spec through get_env
+    WDIO_E2E_SENTINEL: 'wdio-e2e-sentinel',
+  };
   if (envContext.isSplashEnabled) {
     baseEnv.ENABLE_SPLASH_WINDOW = 'true';
   }
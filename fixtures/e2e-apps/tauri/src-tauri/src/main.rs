@@ -3,11 +3,55 @@
 // E2E tests use debug builds on Windows to preserve stdout/stderr for logging tests.
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use std::sync::Mutex;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use once_cell::sync::Lazy;
 
 static DEEP_LINKS: Lazy<Mutex<Vec<String>>> = Lazy::new(|| Mutex::new(Vec::new()));
 
+/// Times the CmdOrCtrl+Shift+K global shortcut was pressed
+static SHORTCUT_COUNT: AtomicU32 = AtomicU32::new(0);
+
+/// Times File > Save was activated, including through its CmdOrCtrl+S accelerator
+static SAVE_COUNT: AtomicU32 = AtomicU32::new(0);
+
+/// Running total kept by the add_to_counter Rust handler
+static RUST_HANDLER_COUNT: AtomicU32 = AtomicU32::new(0);
+
+/// Whether child windows refuse close requests, as an app with unsaved changes would
+static PREVENT_CHILD_CLOSE: AtomicBool = AtomicBool::new(false);
+
+/// Sequence number of the last `fixture-event` sent by emit_fixture_event
+static FIXTURE_EVENT_SEQ: AtomicU32 = AtomicU32::new(0);
+
+/// Paths of the last file drop seen by the backend
+static DROPPED_PATHS: Lazy<Mutex<Vec<String>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Running start_long_task tasks by id, so cancel_task can abort them
+static TASKS: Lazy<Mutex<HashMap<u32, tauri::async_runtime::JoinHandle<()>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_TASK_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Children started by spawn_process by pid; killed on exit so CI doesn't collect zombies
+static SPAWNED: Lazy<Mutex<HashMap<u32, SpawnedProcess>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Filesystem watchers started by watch_path by id; dropping one stops it
+static WATCHERS: Lazy<Mutex<HashMap<u32, notify::RecommendedWatcher>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_WATCHER_ID: AtomicU32 = AtomicU32::new(1);
+
+/// SQLite database opened by db_open, with its path for error messages
+static DATABASE: Lazy<Mutex<Option<(String, rusqlite::Connection)>>> = Lazy::new(|| Mutex::new(None));
+
+/// Port and shutdown signal of the echo server started by start_http_server
+static HTTP_SERVER: Lazy<Mutex<Option<(u16, tokio::sync::oneshot::Sender<()>)>>> = Lazy::new(|| Mutex::new(None));
+
+/// Longest `?delay_ms=` the echo server honours
+const MAX_HTTP_DELAY_MS: u64 = 30_000;
+
+/// Quiet period that ends a batch of filesystem events, so a single write's
+/// create/modify bursts arrive as one fs-change per kind
+const FS_CHANGE_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(100);
+
 fn collect_deep_links_from_args() -> Vec<String> {
     let mut deep_links = Vec::new();
     for arg in std::env::args().skip(1) {
@@ -19,9 +63,9 @@ fn collect_deep_links_from_args() -> Vec<String> {
 }
 
 use tauri::{PhysicalPosition, PhysicalSize, Window, Emitter, Manager};
+use base64::Engine;
 use serde::{Serialize, Deserialize};
-use sysinfo::System;
-use clipboard::{ClipboardProvider, ClipboardContext};
+use sysinfo::{Disks, System};
 
 #[derive(Debug, Serialize, Deserialize)]
 struct WindowBounds {
@@ -32,15 +76,27 @@ struct WindowBounds {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct ScreenshotOptions {
-    format: Option<String>,
-    quality: Option<u8>,
-    path: Option<String>,
+struct FileOperationOptions {
+    encoding: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct FileOperationOptions {
-    encoding: Option<String>,
+struct DirEntry {
+    name: String,
+    is_dir: bool,
+    is_symlink: bool,
+    size: u64,
+    modified_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FileStat {
+    exists: bool,
+    is_dir: bool,
+    is_symlink: bool,
+    size: u64,
+    modified_ms: Option<u64>,
+    readonly: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -51,7 +107,9 @@ struct PlatformInfo {
     hostname: String,
     memory: MemoryInfo,
     cpu: CpuInfo,
+    /// The disk holding the current working directory
     disk: DiskInfo,
+    per_disk: Vec<DiskInfo>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -66,8 +124,9 @@ struct CpuInfo {
     frequency: u64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct DiskInfo {
+    mount_point: String,
     total: u64,
     free: u64,
 }
@@ -117,18 +176,29 @@ async fn close_window(window: Window) -> Result<(), String> {
 }
 
 #[tauri::command]
-async fn take_screenshot(_options: Option<ScreenshotOptions>) -> Result<String, String> {
-    Ok("data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==".to_string())
+async fn take_screenshot(
+    window: tauri::WebviewWindow,
+    options: Option<tauri_plugin_wdio::ScreenshotOptions>,
+) -> Result<String, String> {
+    tauri_plugin_wdio::capture_window(&window, &options.unwrap_or_default())
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn read_file(path: String, _options: Option<FileOperationOptions>) -> Result<String, String> {
-    std::fs::read_to_string(&path).map_err(|e| format!("Failed to read file '{}': {}", path, e))
+async fn read_file(path: String, options: Option<FileOperationOptions>) -> Result<String, String> {
+    let bytes = std::fs::read(&path).map_err(|e| format!("Failed to read file '{}': {}", path, e))?;
+    decode_contents(&path, bytes, encoding(&options))
 }
 
 #[tauri::command]
-async fn write_file(path: String, contents: String, _options: Option<FileOperationOptions>) -> Result<(), String> {
-    std::fs::write(&path, contents).map_err(|e| format!("Failed to write file '{}': {}", path, e))?;
+async fn write_file(path: String, contents: String, options: Option<FileOperationOptions>) -> Result<(), String> {
+    let bytes = encode_contents(&path, contents, encoding(&options))?;
+    // App directories such as appDataDir don't exist until something is written there
+    if let Some(parent) = std::path::Path::new(&path).parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory for '{}': {}", path, e))?;
+    }
+    std::fs::write(&path, bytes).map_err(|e| format!("Failed to write file '{}': {}", path, e))?;
     Ok(())
 }
 
@@ -138,6 +208,119 @@ async fn delete_file(path: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Turn file bytes into read_file's result: `utf8` (default) text, `base64`
+/// for binary files, or `latin1`, mapping each byte to one character
+fn decode_contents(path: &str, bytes: Vec<u8>, encoding: &str) -> Result<String, String> {
+    match encoding {
+        "utf8" => String::from_utf8(bytes).map_err(|_| {
+            format!("Failed to read file '{}': not valid UTF-8; read binary files with encoding 'base64'", path)
+        }),
+        "base64" => Ok(base64::engine::general_purpose::STANDARD.encode(bytes)),
+        "latin1" => Ok(bytes.into_iter().map(char::from).collect()),
+        other => Err(format!("Failed to read file '{}': unsupported encoding '{}'", path, other)),
+    }
+}
+
+/// Turn write_file's contents into file bytes; the inverse of [`decode_contents`].
+/// `latin1` is lossy: characters above U+00FF become `?`.
+fn encode_contents(path: &str, contents: String, encoding: &str) -> Result<Vec<u8>, String> {
+    match encoding {
+        "utf8" => Ok(contents.into_bytes()),
+        "base64" => base64::engine::general_purpose::STANDARD
+            .decode(contents.trim())
+            .map_err(|e| format!("Failed to write file '{}': invalid base64 contents: {}", path, e)),
+        "latin1" => Ok(contents.chars().map(|c| u8::try_from(c).unwrap_or(b'?')).collect()),
+        other => Err(format!("Failed to write file '{}': unsupported encoding '{}'", path, other)),
+    }
+}
+
+fn encoding(options: &Option<FileOperationOptions>) -> &str {
+    options.as_ref().and_then(|o| o.encoding.as_deref()).unwrap_or("utf8")
+}
+
+/// Entries of a directory sorted by name. Symlinks are reported as such
+/// rather than resolved.
+#[tauri::command]
+async fn list_dir(path: String) -> Result<Vec<DirEntry>, String> {
+    let error = |e: std::io::Error| format!("Failed to list directory '{}': {}", path, e);
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(&path).map_err(error)? {
+        let entry = entry.map_err(error)?;
+        let metadata = std::fs::symlink_metadata(entry.path()).map_err(error)?;
+        entries.push(DirEntry {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            is_dir: metadata.is_dir(),
+            is_symlink: metadata.file_type().is_symlink(),
+            size: metadata.len(),
+            modified_ms: modified_ms(&metadata),
+        });
+    }
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+#[tauri::command]
+async fn create_dir(path: String, recursive: bool) -> Result<(), String> {
+    let result = if recursive {
+        std::fs::create_dir_all(&path)
+    } else {
+        std::fs::create_dir(&path)
+    };
+    result.map_err(|e| format!("Failed to create directory '{}': {}", path, e))
+}
+
+/// Remove a directory, with its contents when `recursive`. A symlink is
+/// removed itself; its target is left alone.
+#[tauri::command]
+async fn remove_dir(path: String, recursive: bool) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("Failed to remove directory '{}': {}", path, e);
+    let metadata = std::fs::symlink_metadata(&path).map_err(error)?;
+    if metadata.file_type().is_symlink() {
+        // Directory links on Windows are removed with remove_dir
+        return std::fs::remove_file(&path).or_else(|_| std::fs::remove_dir(&path)).map_err(error);
+    }
+    if !metadata.is_dir() {
+        return Err(format!("Failed to remove directory '{}': not a directory", path));
+    }
+    let result = if recursive {
+        std::fs::remove_dir_all(&path)
+    } else {
+        std::fs::remove_dir(&path)
+    };
+    result.map_err(error)
+}
+
+/// Metadata for `path` without following symlinks; a missing path reports
+/// `exists: false` rather than an error
+#[tauri::command]
+async fn stat(path: String) -> Result<FileStat, String> {
+    match std::fs::symlink_metadata(&path) {
+        Ok(metadata) => Ok(FileStat {
+            exists: true,
+            is_dir: metadata.is_dir(),
+            is_symlink: metadata.file_type().is_symlink(),
+            size: metadata.len(),
+            modified_ms: modified_ms(&metadata),
+            readonly: metadata.permissions().readonly(),
+        }),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(FileStat {
+            exists: false,
+            is_dir: false,
+            is_symlink: false,
+            size: 0,
+            modified_ms: None,
+            readonly: false,
+        }),
+        Err(e) => Err(format!("Failed to stat '{}': {}", path, e)),
+    }
+}
+
+fn modified_ms(metadata: &std::fs::Metadata) -> Option<u64> {
+    let modified = metadata.modified().ok()?;
+    let since_epoch = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(since_epoch.as_millis() as u64)
+}
+
 #[tauri::command]
 async fn get_current_dir() -> Result<String, String> {
     std::env::current_dir()
@@ -145,6 +328,31 @@ async fn get_current_dir() -> Result<String, String> {
         .map_err(|e| e.to_string())
 }
 
+/// Space on every enumerable disk; empty in containers that expose none
+fn disk_info() -> Vec<DiskInfo> {
+    Disks::new_with_refreshed_list()
+        .list()
+        .iter()
+        .map(|disk| DiskInfo {
+            mount_point: disk.mount_point().to_string_lossy().into_owned(),
+            total: disk.total_space(),
+            free: disk.available_space(),
+        })
+        .collect()
+}
+
+/// The disk with the longest mount point containing the working directory,
+/// or zeros when none does
+fn cwd_disk(disks: &[DiskInfo]) -> DiskInfo {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    disks
+        .iter()
+        .filter(|disk| cwd.starts_with(&disk.mount_point))
+        .max_by_key(|disk| disk.mount_point.len())
+        .cloned()
+        .unwrap_or_default()
+}
+
 #[tauri::command]
 async fn get_platform_info() -> Result<PlatformInfo, String> {
     let mut sys = System::new_all();
@@ -153,8 +361,8 @@ async fn get_platform_info() -> Result<PlatformInfo, String> {
     let total_memory = sys.total_memory();
     let free_memory = sys.free_memory();
 
-    let total_disk = 1000000000u64;
-    let free_disk = 500000000u64;
+    let per_disk = disk_info();
+    let disk = cwd_disk(&per_disk);
 
     Ok(PlatformInfo {
         os: System::name().unwrap_or_else(|| "Unknown".to_string()),
@@ -169,46 +377,217 @@ async fn get_platform_info() -> Result<PlatformInfo, String> {
             cores: sys.cpus().len(),
             frequency: sys.cpus().first().map(|c| c.frequency()).unwrap_or(0),
         },
-        disk: DiskInfo {
-            total: total_disk,
-            free: free_disk,
-        },
+        disk,
+        per_disk,
     })
 }
 
-#[tauri::command]
-async fn read_clipboard() -> Result<String, String> {
-    let mut ctx = ClipboardContext::new().map_err(|e| e.to_string())?;
-    ctx.get_contents().map_err(|e| e.to_string())
-}
-
-#[tauri::command]
-async fn write_clipboard(content: String) -> Result<(), String> {
-    let mut ctx = ClipboardContext::new().map_err(|e| e.to_string())?;
-    ctx.set_contents(content).map_err(|e| e.to_string())?;
-    Ok(())
-}
-
 #[tauri::command]
 async fn generate_test_logs(app: tauri::AppHandle) -> Result<(), String> {
     let logs = [
-        ("TRACE", "This is a TRACE level log"),
-        ("DEBUG", "This is a DEBUG level log"),
-        ("INFO", "This is an INFO level log"),
-        ("WARN", "This is a WARN level log"),
-        ("ERROR", "This is an ERROR level log"),
+        (log::Level::Trace, "This is a TRACE level log"),
+        (log::Level::Debug, "This is a DEBUG level log"),
+        (log::Level::Info, "This is an INFO level log"),
+        (log::Level::Warn, "This is a WARN level log"),
+        (log::Level::Error, "This is an ERROR level log"),
     ];
 
     for (level, message) in logs {
+        let level_name = level.as_str();
         // Emit to the main webview window (for frontend listener)
-        let _ = app.emit("backend-log", &format!("[{}] {}", level, message));
+        let _ = app.emit("backend-log", &format!("[{}] {}", level_name, message));
         // Also print to stderr which tauri-driver captures
-        eprintln!("[{}] {}", level, message);
+        eprintln!("[{}] {}", level_name, message);
+        // Record through `log` so the wdio plugin buffers it and streams it to log subscribers
+        log::log!(level, "{}", message);
     }
 
     Ok(())
 }
 
+/// Panic on a named background thread so the plugin's panic hook records a crash
+/// report without taking down the main thread
+#[tauri::command]
+async fn panic_on_demand(message: Option<String>) -> Result<(), String> {
+    let message = message.unwrap_or_else(|| "panic_on_demand triggered".to_string());
+    let handle = std::thread::Builder::new()
+        .name("wdio-panic-on-demand".to_string())
+        .spawn(move || panic!("{}", message))
+        .map_err(|e| e.to_string())?;
+    let _ = handle.join();
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CrashMode {
+    Panic,
+    Abort,
+    Exit,
+}
+
+/// Bring the app down for the crash detection tests: `panic` (on the main
+/// thread, or on a spawned one with `spawned_thread`, which the app survives),
+/// `abort`, or `exit` with `code`. With `respond_first` the call resolves
+/// before the crash; otherwise it never answers.
+#[tauri::command]
+fn crash_main_process(
+    app: tauri::AppHandle,
+    mode: CrashMode,
+    code: Option<i32>,
+    spawned_thread: Option<bool>,
+    respond_first: Option<bool>,
+) -> Result<(), String> {
+    let spawned_thread = spawned_thread.unwrap_or(false);
+    if respond_first.unwrap_or(false) {
+        // Give the IPC response time to reach the webview before crashing
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            let _ = app.run_on_main_thread(move || crash(mode, code, spawned_thread));
+        });
+    } else {
+        // Sync commands run on the main thread
+        crash(mode, code, spawned_thread);
+    }
+    Ok(())
+}
+
+fn crash(mode: CrashMode, code: Option<i32>, spawned_thread: bool) {
+    match mode {
+        CrashMode::Panic if spawned_thread => {
+            let handle = std::thread::Builder::new()
+                .name("wdio-crash-on-demand".to_string())
+                .spawn(|| panic!("crash_main_process triggered"));
+            if let Ok(handle) = handle {
+                let _ = handle.join();
+            }
+        }
+        CrashMode::Panic => panic!("crash_main_process triggered"),
+        CrashMode::Abort => std::process::abort(),
+        CrashMode::Exit => std::process::exit(code.unwrap_or(1)),
+    }
+}
+
+/// Block the main thread for `duration_ms`, freezing the event loop, for the
+/// hang detection tests. Resolves right away; the hang starts just after.
+#[tauri::command]
+async fn hang_main_process(app: tauri::AppHandle, duration_ms: u64) -> Result<(), String> {
+    app.run_on_main_thread(move || std::thread::sleep(std::time::Duration::from_millis(duration_ms)))
+        .map_err(|e| e.to_string())
+}
+
+/// Largest allocate_memory request unless WDIO_FIXTURE_MAX_ALLOCATE_MB says otherwise
+const DEFAULT_MAX_ALLOCATE_MB: u64 = 1024;
+/// Longest hold or burn the load commands accept
+const MAX_LOAD_DURATION_MS: u64 = 60_000;
+const MAX_BURN_THREADS: usize = 64;
+
+#[derive(Debug, Serialize)]
+struct MemoryLoad {
+    allocated_bytes: u64,
+    rss_before_bytes: u64,
+    rss_peak_bytes: u64,
+    rss_delta_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct CpuLoad {
+    threads: usize,
+    duration_ms: u64,
+    iterations: u64,
+}
+
+fn current_rss_bytes() -> u64 {
+    let Ok(pid) = sysinfo::get_current_pid() else {
+        return 0;
+    };
+    let mut sys = System::new();
+    sys.refresh_process(pid);
+    sys.process(pid).map(|process| process.memory()).unwrap_or(0)
+}
+
+/// Allocate and touch `mb` megabytes, hold them for `hold_ms`, then free them,
+/// for the process metrics tests. Requests above the cap are rejected rather
+/// than risking an OOM on CI.
+#[tauri::command]
+async fn allocate_memory(mb: u64, hold_ms: u64) -> Result<MemoryLoad, String> {
+    let max_mb = std::env::var("WDIO_FIXTURE_MAX_ALLOCATE_MB")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_MAX_ALLOCATE_MB);
+    if mb == 0 || mb > max_mb {
+        return Err(format!(
+            "Refusing to allocate {} MB: must be between 1 and {} MB (WDIO_FIXTURE_MAX_ALLOCATE_MB)",
+            mb, max_mb
+        ));
+    }
+    if hold_ms > MAX_LOAD_DURATION_MS {
+        return Err(format!("hold_ms {} exceeds the {} ms limit", hold_ms, MAX_LOAD_DURATION_MS));
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let rss_before_bytes = current_rss_bytes();
+        let size = usize::try_from(mb * 1024 * 1024).map_err(|e| e.to_string())?;
+        let mut block = vec![0u8; size];
+        // Zeroed pages aren't resident until written, so touch every page
+        for page in block.chunks_mut(4096) {
+            page[0] = 1;
+        }
+        let block = std::hint::black_box(block);
+        let rss_peak_bytes = current_rss_bytes();
+        std::thread::sleep(std::time::Duration::from_millis(hold_ms));
+        drop(block);
+        Ok(MemoryLoad {
+            allocated_bytes: size as u64,
+            rss_before_bytes,
+            rss_peak_bytes,
+            rss_delta_bytes: rss_peak_bytes.saturating_sub(rss_before_bytes),
+        })
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Spin `threads` threads for `duration_ms`, for the process metrics tests.
+/// Returns the measured duration and the work done.
+#[tauri::command]
+async fn burn_cpu(threads: usize, duration_ms: u64) -> Result<CpuLoad, String> {
+    if threads == 0 || threads > MAX_BURN_THREADS {
+        return Err(format!("threads must be between 1 and {}, got {}", MAX_BURN_THREADS, threads));
+    }
+    if duration_ms > MAX_LOAD_DURATION_MS {
+        return Err(format!("duration_ms {} exceeds the {} ms limit", duration_ms, MAX_LOAD_DURATION_MS));
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let start = std::time::Instant::now();
+        let deadline = start + std::time::Duration::from_millis(duration_ms);
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                std::thread::spawn(move || {
+                    let mut iterations = 0u64;
+                    let mut value = 1u64;
+                    while std::time::Instant::now() < deadline {
+                        for _ in 0..10_000 {
+                            value = std::hint::black_box(value.wrapping_mul(6364136223846793005).wrapping_add(1));
+                        }
+                        iterations += 10_000;
+                    }
+                    iterations
+                })
+            })
+            .collect();
+        let iterations = workers.into_iter().map(|worker| worker.join().unwrap_or(0)).sum();
+        CpuLoad {
+            threads,
+            duration_ms: start.elapsed().as_millis() as u64,
+            iterations,
+        }
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn get_deep_links(_app: tauri::AppHandle) -> Result<Vec<String>, String> {
     let links = DEEP_LINKS.lock().map_err(|e| e.to_string())?.clone();
@@ -246,6 +625,784 @@ fn create_main_window<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> tauri::We
     window
 }
 
+/// App menu for the menu e2e tests: a plain item, a check item, and an item
+/// without an explicit id (addressed by label path)
+fn build_menu<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> tauri::Result<tauri::menu::Menu<R>> {
+    use tauri::menu::{CheckMenuItemBuilder, MenuBuilder, MenuItemBuilder, SubmenuBuilder};
+
+    let file = SubmenuBuilder::new(app, "File")
+        .item(
+            &MenuItemBuilder::with_id("file-save", "Save")
+                .accelerator("CmdOrCtrl+S")
+                .build(app)?,
+        )
+        .build()?;
+    let view = SubmenuBuilder::new(app, "View")
+        .item(
+            &CheckMenuItemBuilder::with_id("view-sidebar", "Show Sidebar")
+                .checked(false)
+                .build(app)?,
+        )
+        .build()?;
+    let help = SubmenuBuilder::new(app, "Help")
+        .item(&MenuItemBuilder::new("About").build(app)?)
+        .build()?;
+    MenuBuilder::new(app).items(&[&file, &view, &help]).build()
+}
+
+fn toggle_main_window<R: tauri::Runtime>(app: &tauri::AppHandle<R>) {
+    if let Some(main) = app.get_webview_window("main") {
+        if main.is_visible().unwrap_or(false) {
+            let _ = main.hide();
+        } else {
+            let _ = main.show();
+        }
+    }
+}
+
+/// Tray icon for the tray e2e tests: left click or the "Toggle Window" menu
+/// item toggles the main window
+fn build_tray<R: tauri::Runtime>(app: &tauri::App<R>) -> tauri::Result<()> {
+    use tauri::menu::{CheckMenuItemBuilder, MenuBuilder, MenuItemBuilder};
+    use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+    use tauri_plugin_wdio::WdioExt;
+
+    let menu = MenuBuilder::new(app)
+        .item(&MenuItemBuilder::with_id("tray-toggle", "Toggle Window").build(app)?)
+        .item(&CheckMenuItemBuilder::with_id("tray-pin", "Pin").build(app)?)
+        .build()?;
+    let mut tray = TrayIconBuilder::with_id("main-tray")
+        .menu(&menu)
+        .show_menu_on_left_click(false);
+    if let Some(icon) = app.default_window_icon() {
+        tray = tray.icon(icon.clone());
+    }
+    let tray = tray.build(app)?;
+
+    app.wdio().register_tray(&tray, Some(&menu));
+    app.wdio().on_tray_icon_event(|app, event| {
+        if let TrayIconEvent::Click {
+            button: MouseButton::Left,
+            button_state: MouseButtonState::Up,
+            ..
+        } = event
+        {
+            toggle_main_window(app);
+        }
+    });
+    Ok(())
+}
+
+#[tauri::command]
+fn get_shortcut_count() -> u32 {
+    SHORTCUT_COUNT.load(Ordering::SeqCst)
+}
+
+#[tauri::command]
+fn get_save_count() -> u32 {
+    SAVE_COUNT.load(Ordering::SeqCst)
+}
+
+/// Emit `count` `name` events with `{ "index": i }` payloads, for the event recording tests
+#[tauri::command]
+fn emit_test_events(app: tauri::AppHandle, name: String, count: u32) -> Result<(), String> {
+    for index in 0..count {
+        app.emit(&name, serde_json::json!({ "index": index }))
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Resolve after `delay_ms`, for the invoke tracing tests
+#[tauri::command]
+async fn slow_command(delay_ms: u64) -> Result<u64, String> {
+    tauri::async_runtime::spawn_blocking(move || std::thread::sleep(std::time::Duration::from_millis(delay_ms)))
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(delay_ms)
+}
+
+/// Emit a `fixture-event` with `{ seq, message }` for the capability UI, which
+/// renders the last one it received. Returns the event's sequence number.
+#[tauri::command]
+fn emit_fixture_event(app: tauri::AppHandle, message: String) -> Result<u32, String> {
+    let seq = FIXTURE_EVENT_SEQ.fetch_add(1, Ordering::SeqCst) + 1;
+    app.emit("fixture-event", serde_json::json!({ "seq": seq, "message": message }))
+        .map_err(|e| e.to_string())?;
+    Ok(seq)
+}
+
+/// Log `message` through the `log` crate at `level` (trace, debug, info, warn, error)
+#[tauri::command]
+fn log_message(level: String, message: String) -> Result<(), String> {
+    let level: log::Level = level.parse().map_err(|_| format!("Unknown log level '{}'", level))?;
+    log::log!(level, "{}", message);
+    Ok(())
+}
+
+/// settings.json in the app data directory, where the capability UI keeps its settings
+fn settings_path(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("settings.json"))
+        .map_err(|e| e.to_string())
+}
+
+fn read_settings(app: &tauri::AppHandle) -> Result<BTreeMap<String, String>, String> {
+    let path = settings_path(app)?;
+    match std::fs::read_to_string(&path) {
+        Ok(text) => serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+/// Store a setting in settings.json and emit `settings-changed` with all of them
+#[tauri::command]
+fn save_setting(app: tauri::AppHandle, key: String, value: String) -> Result<BTreeMap<String, String>, String> {
+    let mut settings = read_settings(&app)?;
+    settings.insert(key, value);
+    let path = settings_path(&app)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let text = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+    std::fs::write(&path, text).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let _ = app.emit("settings-changed", &settings);
+    Ok(settings)
+}
+
+#[tauri::command]
+fn load_settings(app: tauri::AppHandle) -> Result<BTreeMap<String, String>, String> {
+    read_settings(&app)
+}
+
+/// Delete settings.json; returns whether there was one
+#[tauri::command]
+fn clear_settings(app: tauri::AppHandle) -> Result<bool, String> {
+    let path = settings_path(&app)?;
+    match std::fs::remove_file(&path) {
+        Ok(()) => {
+            let _ = app.emit("settings-changed", BTreeMap::<String, String>::new());
+            Ok(true)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct TaskProgress {
+    task_id: u32,
+    step: u32,
+    total: u32,
+}
+
+/// Start a background task that emits `task-progress` every `duration_ms / steps`
+/// and `task-complete` at the end, for the async waiting tests. Returns the task id.
+/// Time is read from the wdio test clock, so advancing it past the end completes
+/// the task at once.
+#[tauri::command]
+fn start_long_task(app: tauri::AppHandle, duration_ms: u64, steps: u32) -> Result<u32, String> {
+    use tauri_plugin_wdio::WdioExt;
+
+    if steps == 0 {
+        return Err("steps must be at least 1".to_string());
+    }
+    let task_id = NEXT_TASK_ID.fetch_add(1, Ordering::SeqCst);
+    let interval = duration_ms / u64::from(steps);
+    let clock = app.wdio().clock();
+    let started_at = clock.now_ms();
+    // Held until the handle is stored, so the task can't finish before it's tracked
+    let mut tasks = TASKS.lock().unwrap();
+    let handle = tauri::async_runtime::spawn(async move {
+        for step in 1..=steps {
+            clock.sleep_until(started_at + interval * u64::from(step)).await;
+            let _ = app.emit("task-progress", TaskProgress { task_id, step, total: steps });
+        }
+        // A cancel_task that got here first has already reported the task
+        if TASKS.lock().unwrap().remove(&task_id).is_some() {
+            let _ = app.emit("task-complete", serde_json::json!({ "task_id": task_id }));
+        }
+    });
+    tasks.insert(task_id, handle);
+    Ok(task_id)
+}
+
+/// Abort a task started by start_long_task and emit `task-cancelled`; false if
+/// it already finished or never existed
+#[tauri::command]
+fn cancel_task(app: tauri::AppHandle, task_id: u32) -> bool {
+    let Some(handle) = TASKS.lock().unwrap().remove(&task_id) else {
+        return false;
+    };
+    handle.abort();
+    let _ = app.emit("task-cancelled", serde_json::json!({ "task_id": task_id }));
+    true
+}
+
+struct SpawnedProcess {
+    child: std::process::Child,
+    stdout: Arc<Mutex<Vec<u8>>>,
+    stderr: Arc<Mutex<Vec<u8>>>,
+    readers: Vec<std::thread::JoinHandle<()>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SpawnedPid {
+    pid: u32,
+}
+
+#[derive(Debug, Serialize)]
+struct SpawnedOutput {
+    stdout: String,
+    stderr: String,
+    /// Whether the process is still running or its output is still being read
+    running: bool,
+    /// `None` while running, or when the process was ended by a signal
+    exit_code: Option<i32>,
+}
+
+/// Copy `pipe` into `buffer` on a background thread until the child closes it
+fn collect_output(
+    mut pipe: impl std::io::Read + Send + 'static,
+    buffer: Arc<Mutex<Vec<u8>>>,
+) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        let mut chunk = [0u8; 4096];
+        while let Ok(read) = pipe.read(&mut chunk) {
+            if read == 0 {
+                break;
+            }
+            buffer.lock().unwrap().extend_from_slice(&chunk[..read]);
+        }
+    })
+}
+
+/// Start a helper process with piped output, for the child process tests
+#[tauri::command]
+fn spawn_process(command: String, args: Option<Vec<String>>, cwd: Option<String>) -> Result<SpawnedPid, String> {
+    let mut process = std::process::Command::new(&command);
+    process
+        .args(args.unwrap_or_default())
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+    if let Some(cwd) = cwd {
+        process.current_dir(cwd);
+    }
+    let mut child = process
+        .spawn()
+        .map_err(|e| format!("Failed to spawn '{}': {}", command, e))?;
+
+    let stdout = Arc::new(Mutex::new(Vec::new()));
+    let stderr = Arc::new(Mutex::new(Vec::new()));
+    let mut readers = Vec::new();
+    if let Some(pipe) = child.stdout.take() {
+        readers.push(collect_output(pipe, stdout.clone()));
+    }
+    if let Some(pipe) = child.stderr.take() {
+        readers.push(collect_output(pipe, stderr.clone()));
+    }
+    let pid = child.id();
+    SPAWNED.lock().unwrap().insert(pid, SpawnedProcess { child, stdout, stderr, readers });
+    Ok(SpawnedPid { pid })
+}
+
+/// Stop a spawned process. `signal` (e.g. `SIGTERM`) is sent with `kill` on
+/// Unix; the default, and the only option on Windows, is a forced kill.
+#[tauri::command]
+fn kill_process(pid: u32, signal: Option<String>) -> Result<(), String> {
+    let mut spawned = SPAWNED.lock().unwrap();
+    let process = spawned
+        .get_mut(&pid)
+        .ok_or_else(|| format!("No spawned process with pid {}", pid))?;
+    match signal.as_deref().map(|s| s.trim_start_matches("SIG")) {
+        None | Some("KILL") => process.child.kill().map_err(|e| format!("Failed to kill process {}: {}", pid, e)),
+        #[cfg(unix)]
+        Some(signal) => {
+            let status = std::process::Command::new("kill")
+                .args(["-s", signal, &pid.to_string()])
+                .status()
+                .map_err(|e| format!("Failed to signal process {}: {}", pid, e))?;
+            if status.success() {
+                Ok(())
+            } else {
+                Err(format!("Failed to send SIG{} to process {}", signal, pid))
+            }
+        }
+        #[cfg(not(unix))]
+        Some(signal) => Err(format!("Signal SIG{} is not supported on this platform", signal)),
+    }
+}
+
+/// Output captured so far from a spawned process, and its exit code once it
+/// has exited and both pipes are drained
+#[tauri::command]
+fn get_spawned_output(pid: u32) -> Result<SpawnedOutput, String> {
+    let mut spawned = SPAWNED.lock().unwrap();
+    let process = spawned
+        .get_mut(&pid)
+        .ok_or_else(|| format!("No spawned process with pid {}", pid))?;
+    let status = process
+        .child
+        .try_wait()
+        .map_err(|e| format!("Failed to check process {}: {}", pid, e))?;
+    let drained = process.readers.iter().all(|reader| reader.is_finished());
+    let running = status.is_none() || !drained;
+    let stdout = String::from_utf8_lossy(&process.stdout.lock().unwrap()).into_owned();
+    let stderr = String::from_utf8_lossy(&process.stderr.lock().unwrap()).into_owned();
+    Ok(SpawnedOutput {
+        stdout,
+        stderr,
+        running,
+        exit_code: status.filter(|_| !running).and_then(|status| status.code()),
+    })
+}
+
+/// Kill and reap every process still running from spawn_process
+fn kill_spawned_processes() {
+    for (_, mut process) in SPAWNED.lock().unwrap().drain() {
+        if let Ok(None) = process.child.try_wait() {
+            let _ = process.child.kill();
+            let _ = process.child.wait();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FsChange {
+    watcher_id: u32,
+    kind: &'static str,
+    paths: Vec<String>,
+}
+
+/// Watch `path` and emit debounced `fs-change` events for it, for the file
+/// watcher tests. Returns the id to pass to unwatch.
+#[tauri::command]
+fn watch_path(app: tauri::AppHandle, path: String, recursive: bool) -> Result<u32, String> {
+    use notify::Watcher;
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if let Ok(event) = event {
+            let _ = tx.send(event);
+        }
+    })
+    .map_err(|e| format!("Failed to watch '{}': {}", path, e))?;
+    let mode = if recursive {
+        notify::RecursiveMode::Recursive
+    } else {
+        notify::RecursiveMode::NonRecursive
+    };
+    watcher
+        .watch(std::path::Path::new(&path), mode)
+        .map_err(|e| format!("Failed to watch '{}': {}", path, e))?;
+
+    let watcher_id = NEXT_WATCHER_ID.fetch_add(1, Ordering::SeqCst);
+    std::thread::spawn(move || emit_fs_changes(app, watcher_id, rx));
+    WATCHERS.lock().unwrap().insert(watcher_id, watcher);
+    Ok(watcher_id)
+}
+
+/// Stop a watcher started by watch_path; false if there is no such watcher
+#[tauri::command]
+fn unwatch(watcher_id: u32) -> bool {
+    WATCHERS.lock().unwrap().remove(&watcher_id).is_some()
+}
+
+/// Batch events until FS_CHANGE_DEBOUNCE passes without one, then emit an
+/// fs-change per kind. Ends when unwatch drops the watcher and its sender.
+fn emit_fs_changes(app: tauri::AppHandle, watcher_id: u32, rx: std::sync::mpsc::Receiver<notify::Event>) {
+    while let Ok(first) = rx.recv() {
+        let mut batch = vec![first];
+        while let Ok(event) = rx.recv_timeout(FS_CHANGE_DEBOUNCE) {
+            batch.push(event);
+        }
+
+        let mut changes: Vec<FsChange> = Vec::new();
+        for event in batch {
+            let kind = match event.kind {
+                notify::EventKind::Create(_) => "create",
+                notify::EventKind::Modify(_) => "modify",
+                notify::EventKind::Remove(_) => "remove",
+                // Reads and closes would make the event stream depend on the writer
+                notify::EventKind::Access(_) => continue,
+                notify::EventKind::Any | notify::EventKind::Other => "other",
+            };
+            let index = match changes.iter().position(|change| change.kind == kind) {
+                Some(index) => index,
+                None => {
+                    changes.push(FsChange { watcher_id, kind, paths: Vec::new() });
+                    changes.len() - 1
+                }
+            };
+            for path in event.paths {
+                let path = path.to_string_lossy().into_owned();
+                if !changes[index].paths.contains(&path) {
+                    changes[index].paths.push(path);
+                }
+            }
+        }
+        for change in changes {
+            let _ = app.emit("fs-change", change);
+        }
+    }
+}
+
+/// Open (creating if needed) the SQLite database at `path` for db_execute and
+/// db_query, closing any previously opened one
+#[tauri::command]
+fn db_open(path: String) -> Result<(), String> {
+    let connection = rusqlite::Connection::open(&path)
+        .map_err(|e| format!("Failed to open database '{}': {}", path, e))?;
+    *DATABASE.lock().unwrap() = Some((path, connection));
+    Ok(())
+}
+
+/// Run a statement with `params` bound to its `?` placeholders. Returns the
+/// number of rows changed.
+#[tauri::command]
+fn db_execute(sql: String, params: Option<Vec<serde_json::Value>>) -> Result<usize, String> {
+    let database = DATABASE.lock().unwrap();
+    let (path, connection) = database.as_ref().ok_or_else(|| "No database open; call db_open first".to_string())?;
+    let params = sql_params(params.unwrap_or_default())?;
+    connection
+        .execute(&sql, rusqlite::params_from_iter(params))
+        .map_err(|e| format!("Failed to execute on '{}': {}", path, e))
+}
+
+/// Run a query with `params` bound to its `?` placeholders. Each row comes
+/// back as an object keyed by column name, with BLOBs as base64 strings.
+#[tauri::command]
+fn db_query(sql: String, params: Option<Vec<serde_json::Value>>) -> Result<Vec<serde_json::Value>, String> {
+    let database = DATABASE.lock().unwrap();
+    let (path, connection) = database.as_ref().ok_or_else(|| "No database open; call db_open first".to_string())?;
+    let params = sql_params(params.unwrap_or_default())?;
+    let query_error = |e: rusqlite::Error| format!("Failed to query '{}': {}", path, e);
+
+    let mut statement = connection.prepare(&sql).map_err(query_error)?;
+    let columns: Vec<String> = statement.column_names().into_iter().map(String::from).collect();
+    let mut rows = statement.query(rusqlite::params_from_iter(params)).map_err(query_error)?;
+    let mut results = Vec::new();
+    while let Some(row) = rows.next().map_err(query_error)? {
+        let mut object = serde_json::Map::new();
+        for (index, column) in columns.iter().enumerate() {
+            let value = match row.get_ref(index).map_err(query_error)? {
+                rusqlite::types::ValueRef::Null => serde_json::Value::Null,
+                rusqlite::types::ValueRef::Integer(value) => value.into(),
+                rusqlite::types::ValueRef::Real(value) => value.into(),
+                rusqlite::types::ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned().into(),
+                rusqlite::types::ValueRef::Blob(bytes) => base64::engine::general_purpose::STANDARD.encode(bytes).into(),
+            };
+            object.insert(column.clone(), value);
+        }
+        results.push(serde_json::Value::Object(object));
+    }
+    Ok(results)
+}
+
+/// Convert JSON arguments to SQLite values. Booleans bind as 0/1, and
+/// `{ "base64": "..." }` binds as a BLOB.
+fn sql_params(params: Vec<serde_json::Value>) -> Result<Vec<rusqlite::types::Value>, String> {
+    use rusqlite::types::Value;
+
+    params
+        .into_iter()
+        .enumerate()
+        .map(|(index, param)| match param {
+            serde_json::Value::Null => Ok(Value::Null),
+            serde_json::Value::Bool(value) => Ok(Value::Integer(value.into())),
+            serde_json::Value::Number(number) => Ok(match number.as_i64() {
+                Some(value) => Value::Integer(value),
+                None => Value::Real(number.as_f64().unwrap_or(f64::NAN)),
+            }),
+            serde_json::Value::String(text) => Ok(Value::Text(text)),
+            serde_json::Value::Object(object) if object.len() == 1 && object.contains_key("base64") => object["base64"]
+                .as_str()
+                .and_then(|encoded| base64::engine::general_purpose::STANDARD.decode(encoded).ok())
+                .map(Value::Blob)
+                .ok_or_else(|| format!("Parameter {} is not valid base64", index + 1)),
+            other => Err(format!(
+                "Parameter {} must be null, a boolean, number, string, or {{ base64 }} object, got {}",
+                index + 1,
+                other
+            )),
+        })
+        .collect()
+}
+
+#[derive(Debug, Serialize)]
+struct HttpServerInfo {
+    port: u16,
+}
+
+/// Start the local echo server for fetch-driven UI tests on 127.0.0.1:`port`.
+/// Port 0 (the default) picks a free port; the one bound is returned.
+#[tauri::command]
+async fn start_http_server(port: Option<u16>) -> Result<HttpServerInfo, String> {
+    if let Some((running, _)) = HTTP_SERVER.lock().unwrap().as_ref() {
+        return Err(format!("HTTP server already running on port {}", running));
+    }
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", port.unwrap_or(0)))
+        .await
+        .map_err(|e| format!("Failed to bind HTTP server to port {}: {}", port.unwrap_or(0), e))?;
+    let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    {
+        let mut server = HTTP_SERVER.lock().unwrap();
+        if let Some((running, _)) = server.as_ref() {
+            return Err(format!("HTTP server already running on port {}", running));
+        }
+        *server = Some((port, shutdown_tx));
+    }
+    let router = axum::Router::new().fallback(echo_request);
+    tauri::async_runtime::spawn(async move {
+        let shutdown = async {
+            let _ = shutdown_rx.await;
+        };
+        if let Err(e) = axum::serve(listener, router).with_graceful_shutdown(shutdown).await {
+            eprintln!("HTTP server on port {} failed: {}", port, e);
+        }
+    });
+    Ok(HttpServerInfo { port })
+}
+
+/// Stop the echo server; false if none is running
+#[tauri::command]
+fn stop_http_server() -> bool {
+    match HTTP_SERVER.lock().unwrap().take() {
+        Some((_, shutdown_tx)) => {
+            let _ = shutdown_tx.send(());
+            true
+        }
+        None => false,
+    }
+}
+
+/// Echo the request's method, path, query, headers, and body as JSON, after
+/// waiting `?delay_ms=` (capped at MAX_HTTP_DELAY_MS). Any origin may read
+/// the response, since the webview's origin differs from the server's.
+async fn echo_request(
+    method: axum::http::Method,
+    uri: axum::http::Uri,
+    headers: axum::http::HeaderMap,
+    body: axum::body::Bytes,
+) -> axum::response::Response {
+    use axum::http::header;
+    use axum::response::IntoResponse;
+
+    let cors = [
+        (header::ACCESS_CONTROL_ALLOW_ORIGIN, "*"),
+        (header::ACCESS_CONTROL_ALLOW_METHODS, "*"),
+        (header::ACCESS_CONTROL_ALLOW_HEADERS, "*"),
+    ];
+    if method == axum::http::Method::OPTIONS {
+        return (axum::http::StatusCode::NO_CONTENT, cors).into_response();
+    }
+
+    let query: HashMap<String, String> = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (name.to_string(), value.to_string())
+        })
+        .collect();
+    if let Some(delay_ms) = query.get("delay_ms").and_then(|value| value.parse::<u64>().ok()) {
+        tokio::time::sleep(std::time::Duration::from_millis(delay_ms.min(MAX_HTTP_DELAY_MS))).await;
+    }
+
+    let headers: HashMap<String, String> = headers
+        .iter()
+        .map(|(name, value)| (name.to_string(), String::from_utf8_lossy(value.as_bytes()).into_owned()))
+        .collect();
+    let echo = serde_json::json!({
+        "method": method.as_str(),
+        "path": uri.path(),
+        "query": query,
+        "headers": headers,
+        "body": String::from_utf8_lossy(&body),
+    });
+    (cors, [(header::CONTENT_TYPE, "application/json")], echo.to_string()).into_response()
+}
+
+/// Hex digest of the file at `path`, with `algorithm` `sha256` or `md5`
+#[tauri::command]
+fn hash_file(path: String, algorithm: String) -> Result<String, String> {
+    use sha2::Digest;
+
+    fn digest<D: Digest + std::io::Write>(mut hasher: D, path: &str) -> Result<Vec<u8>, String> {
+        let mut file = std::fs::File::open(path).map_err(|e| format!("Failed to hash '{}': {}", path, e))?;
+        std::io::copy(&mut file, &mut hasher).map_err(|e| format!("Failed to hash '{}': {}", path, e))?;
+        Ok(hasher.finalize().to_vec())
+    }
+
+    let bytes = match algorithm.as_str() {
+        "sha256" => digest(sha2::Sha256::new(), &path)?,
+        "md5" => digest(md5::Md5::new(), &path)?,
+        other => return Err(format!("Unsupported hash algorithm '{}': use sha256 or md5", other)),
+    };
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Zip `paths` into `dest`. Files are stored under their file name and
+/// directories recursively under their own name.
+#[tauri::command]
+fn create_zip(paths: Vec<String>, dest: String) -> Result<(), String> {
+    let zip_error = |e: &dyn std::fmt::Display| format!("Failed to create zip '{}': {}", dest, e);
+    let file = std::fs::File::create(&dest).map_err(|e| zip_error(&e))?;
+    let mut writer = zip::ZipWriter::new(file);
+    for path in &paths {
+        let path = std::path::Path::new(path);
+        let name = path
+            .file_name()
+            .ok_or_else(|| zip_error(&format!("'{}' has no file name", path.display())))?;
+        add_to_zip(&mut writer, path, std::path::Path::new(name)).map_err(|e| zip_error(&e))?;
+    }
+    writer.finish().map_err(|e| zip_error(&e))?;
+    Ok(())
+}
+
+fn add_to_zip(
+    writer: &mut zip::ZipWriter<std::fs::File>,
+    path: &std::path::Path,
+    name: &std::path::Path,
+) -> zip::result::ZipResult<()> {
+    // Entry names always use '/', whatever the platform separator
+    let entry_name = name
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let options = zip::write::SimpleFileOptions::default();
+    if path.is_dir() {
+        writer.add_directory(entry_name, options)?;
+        let mut children: Vec<_> = std::fs::read_dir(path)?.collect::<Result<_, _>>()?;
+        children.sort_by_key(|child| child.file_name());
+        for child in children {
+            add_to_zip(writer, &child.path(), &name.join(child.file_name()))?;
+        }
+    } else {
+        writer.start_file(entry_name, options)?;
+        std::io::copy(&mut std::fs::File::open(path)?, writer)?;
+    }
+    Ok(())
+}
+
+/// Extract the zip at `src` into `dest_dir`, returning the extracted file
+/// paths. Every entry is checked before anything is written, so an archive
+/// with an entry escaping `dest_dir` (`../`, absolute paths) writes nothing.
+#[tauri::command]
+fn extract_zip(src: String, dest_dir: String) -> Result<Vec<String>, String> {
+    let zip_error = |e: &dyn std::fmt::Display| format!("Failed to extract zip '{}': {}", src, e);
+    let file = std::fs::File::open(&src).map_err(|e| zip_error(&e))?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| zip_error(&e))?;
+
+    let mut targets = Vec::with_capacity(archive.len());
+    for index in 0..archive.len() {
+        let entry = archive.by_index_raw(index).map_err(|e| zip_error(&e))?;
+        let relative = entry
+            .enclosed_name()
+            .ok_or_else(|| zip_error(&format!("entry '{}' escapes the destination directory", entry.name())))?;
+        targets.push((std::path::Path::new(&dest_dir).join(relative), entry.is_dir()));
+    }
+
+    let mut extracted = Vec::new();
+    for (index, (target, is_dir)) in targets.into_iter().enumerate() {
+        if is_dir {
+            std::fs::create_dir_all(&target).map_err(|e| zip_error(&e))?;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| zip_error(&e))?;
+        }
+        let mut entry = archive.by_index(index).map_err(|e| zip_error(&e))?;
+        let mut out = std::fs::File::create(&target).map_err(|e| zip_error(&e))?;
+        std::io::copy(&mut entry, &mut out).map_err(|e| zip_error(&e))?;
+        extracted.push(target.to_string_lossy().into_owned());
+    }
+    Ok(extracted)
+}
+
+#[tauri::command]
+fn get_dropped_paths() -> Vec<String> {
+    DROPPED_PATHS.lock().map(|paths| paths.clone()).unwrap_or_default()
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ChildWindowOptions {
+    title: Option<String>,
+    width: Option<f64>,
+    height: Option<f64>,
+    visible: Option<bool>,
+}
+
+/// Open a secondary window for the multi-window tests, loading `url` (an app
+/// page, or an absolute http(s) URL; default child.html). Labels starting
+/// with `child-` get the default capability and `readonly-` the read-only
+/// one. Emits `window-created`, and `window-destroyed` once the window is gone.
+/// Close requests are refused while set_prevent_close is on.
+#[tauri::command]
+async fn open_child_window(
+    app: tauri::AppHandle,
+    label: String,
+    url: Option<String>,
+    options: Option<ChildWindowOptions>,
+) -> Result<(), String> {
+    if app.get_webview_window(&label).is_some() {
+        return Err(format!("Window '{}' already exists", label));
+    }
+    let options = options.unwrap_or_default();
+    let url = match url.as_deref().map(str::parse::<tauri::Url>) {
+        Some(Ok(external)) if matches!(external.scheme(), "http" | "https") => tauri::WebviewUrl::External(external),
+        _ => tauri::WebviewUrl::App(url.unwrap_or_else(|| "child.html".to_string()).into()),
+    };
+    let window = tauri::WebviewWindowBuilder::new(&app, &label, url)
+        .title(options.title.unwrap_or_else(|| format!("Child {}", label)))
+        .inner_size(options.width.unwrap_or(400.0), options.height.unwrap_or(300.0))
+        .visible(options.visible.unwrap_or(true))
+        .build()
+        .map_err(|e| format!("Failed to open window '{}': {}", label, e))?;
+
+    let handle = app.clone();
+    let destroyed_label = label.clone();
+    window.on_window_event(move |event| match event {
+        tauri::WindowEvent::CloseRequested { api, .. } if PREVENT_CHILD_CLOSE.load(Ordering::SeqCst) => {
+            api.prevent_close();
+        }
+        tauri::WindowEvent::Destroyed => {
+            let _ = handle.emit("window-destroyed", serde_json::json!({ "label": destroyed_label }));
+        }
+        _ => {}
+    });
+    let _ = app.emit("window-created", serde_json::json!({ "label": label }));
+    Ok(())
+}
+
+/// Make child windows refuse close requests, or accept them again
+#[tauri::command]
+fn set_prevent_close(prevent: bool) {
+    PREVENT_CHILD_CLOSE.store(prevent, Ordering::SeqCst);
+}
+
+/// Close a window opened with open_child_window
+#[tauri::command]
+async fn close_child_window(app: tauri::AppHandle, label: String) -> Result<(), String> {
+    if label == "main" || label == "splash" {
+        return Err(format!("Window '{}' is not a child window", label));
+    }
+    let window = app
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("Window '{}' not found", label))?;
+    window
+        .close()
+        .map_err(|e| format!("Failed to close window '{}': {}", label, e))
+}
+
 #[tauri::command]
 async fn switch_to_main(app: tauri::AppHandle) -> Result<(), String> {
     let main = app.get_webview_window("main")
@@ -277,8 +1434,26 @@ fn main() {
     // CrabNebula uses tauri-driver + test-runner-backend instead
     let use_embedded_server = std::env::var("WDIO_EMBEDDED_SERVER").is_ok();
 
+    // Backend logs go through tauri_plugin_log (which owns the global logger);
+    // the wdio target forwards each record to the wdio plugin exactly once
     let mut builder = tauri::Builder::default()
-        .plugin(tauri_plugin_wdio::init());
+        .plugin(
+            tauri_plugin_log::Builder::new()
+                .clear_targets()
+                .level(log::LevelFilter::Info)
+                .target(tauri_plugin_log::Target::new(
+                    tauri_plugin_log::TargetKind::Dispatch(
+                        fern::Dispatch::new().chain(tauri_plugin_wdio::wdio_log_target()),
+                    ),
+                ))
+                .build(),
+        )
+        .plugin(tauri_plugin_wdio::init_with_config(tauri_plugin_wdio::WdioConfig {
+            env_allowlist: vec!["WDIO_E2E_SENTINEL".to_string()],
+            // Small enough for the event recording e2e test to overflow it
+            event_recording_capacity: 100,
+            ..Default::default()
+        }));
 
     // Conditionally load embedded WebDriver server
     if use_embedded_server {
@@ -308,7 +1483,56 @@ fn main() {
 
     builder
         .plugin(tauri_plugin_deep_link::init())
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .menu(build_menu)
         .setup(move |app| {
+            // Registered through the wdio plugin so plugin:wdio|trigger_menu_item and
+            // plugin:wdio|simulate_file_drop reach them
+            {
+                use tauri_plugin_wdio::WdioExt;
+                // App state e2e test: expose the backend test state as a JSON snapshot
+                app.wdio().register_state_provider("fixture", |app| {
+                    serde_json::json!({
+                        "shortcut_count": SHORTCUT_COUNT.load(Ordering::SeqCst),
+                        "dropped_paths": DROPPED_PATHS.lock().map(|paths| paths.clone()).unwrap_or_default(),
+                        "settings": read_settings(app).unwrap_or_default(),
+                    })
+                });
+                // Rust handlers e2e test: a counter to mutate, an echo of the args, and a panic
+                app.wdio().register_rust_handler("add_to_counter", |_app, args| {
+                    let amount = args["amount"].as_u64().unwrap_or(1) as u32;
+                    let total = RUST_HANDLER_COUNT.fetch_add(amount, Ordering::SeqCst) + amount;
+                    Ok(serde_json::json!({ "total": total }))
+                });
+                app.wdio().register_rust_handler("echo", |_app, args| Ok(args));
+                app.wdio().register_rust_handler("fail", |_app, args| {
+                    if args["panic"].as_bool().unwrap_or(false) {
+                        panic!("fail handler panicked");
+                    }
+                    Err(tauri_plugin_wdio::Error::RustHandlerError("fail handler failed".to_string()))
+                });
+                app.wdio().on_drag_drop_event(|_window, event| {
+                    if let tauri::DragDropEvent::Drop { paths, .. } = event {
+                        if let Ok(mut dropped) = DROPPED_PATHS.lock() {
+                            *dropped = paths.iter().map(|p| p.display().to_string()).collect();
+                        }
+                    }
+                });
+                app.wdio().on_menu_event(|app, event| {
+                    match event.id().as_ref() {
+                        "tray-toggle" => toggle_main_window(app),
+                        // Native input e2e test: the accelerator is handled in Rust, out of the page's reach
+                        "file-save" => {
+                            SAVE_COUNT.fetch_add(1, Ordering::SeqCst);
+                        }
+                        _ => {}
+                    }
+                    let _ = app.emit("menu-clicked", event.id().as_ref());
+                });
+            }
+
             // Collect deep links from CLI args at startup
             let cli_deep_links = collect_deep_links_from_args();
             if !cli_deep_links.is_empty() {
@@ -367,6 +1591,52 @@ fn main() {
             } else {
                 create_main_window(app.handle());
             }
+
+            build_tray(app)?;
+
+            // Global shortcut e2e test: the OS grab can fail (e.g. under Wayland),
+            // which leaves the shortcut unregistered rather than failing startup
+            {
+                use tauri_plugin_global_shortcut::ShortcutState;
+                use tauri_plugin_wdio::WdioExt;
+
+                if let Err(e) = app.wdio().on_global_shortcut("CmdOrCtrl+Shift+K", |_app, _shortcut, event| {
+                    if event.state == ShortcutState::Pressed {
+                        SHORTCUT_COUNT.fetch_add(1, Ordering::SeqCst);
+                    }
+                }) {
+                    eprintln!("[Tauri] Global shortcut registration failed: {}", e);
+                }
+            }
+
+            // Lifecycle e2e test: quit through the plugin's IPC command once the page
+            // has loaded, so the runner can check the exit code
+            if let Ok(code) = std::env::var("WDIO_QUIT_ON_LOAD") {
+                let handle = app.handle().clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(std::time::Duration::from_secs(2));
+                    if let Some(main) = handle.get_webview_window("main") {
+                        let _ = main.eval(&format!(
+                            "window.__TAURI_INTERNALS__.invoke('plugin:wdio|quit_app', {{ exitCode: {}, delayMs: 50 }})",
+                            code.parse::<i32>().unwrap_or(0)
+                        ));
+                    }
+                });
+            }
+            // Crash e2e test: call crash_main_process over IPC once the page has
+            // loaded, with WDIO_CRASH_ON_LOAD as its JSON arguments
+            if let Ok(args) = std::env::var("WDIO_CRASH_ON_LOAD") {
+                let handle = app.handle().clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(std::time::Duration::from_secs(2));
+                    if let Some(main) = handle.get_webview_window("main") {
+                        let _ = main.eval(&format!(
+                            "window.__TAURI_INTERNALS__.invoke('crash_main_process', {})",
+                            args
+                        ));
+                    }
+                });
+            }
             Ok::<(), Box<dyn std::error::Error>>(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -380,15 +1650,55 @@ fn main() {
             read_file,
             write_file,
             delete_file,
+            list_dir,
+            create_dir,
+            remove_dir,
+            stat,
             get_current_dir,
             get_platform_info,
-            read_clipboard,
-            write_clipboard,
             generate_test_logs,
+            panic_on_demand,
+            crash_main_process,
+            hang_main_process,
+            allocate_memory,
+            burn_cpu,
+            watch_path,
+            unwatch,
+            db_open,
+            db_execute,
+            db_query,
+            start_http_server,
+            stop_http_server,
+            hash_file,
+            create_zip,
+            extract_zip,
+            open_child_window,
+            close_child_window,
+            set_prevent_close,
             switch_to_main,
             get_deep_links,
             get_command_line_args,
+            get_shortcut_count,
+            get_save_count,
+            get_dropped_paths,
+            emit_test_events,
+            emit_fixture_event,
+            log_message,
+            save_setting,
+            load_settings,
+            clear_settings,
+            slow_command,
+            start_long_task,
+            cancel_task,
+            spawn_process,
+            kill_process,
+            get_spawned_output,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            if let tauri::RunEvent::Exit = event {
+                kill_spawned_processes();
+            }
+        });
 }
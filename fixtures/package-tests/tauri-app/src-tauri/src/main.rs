@@ -2,9 +2,9 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use tauri::{PhysicalPosition, PhysicalSize, Window};
+use base64::Engine;
 use serde::{Serialize, Deserialize};
-use sysinfo::System;
-use clipboard::{ClipboardProvider, ClipboardContext};
+use sysinfo::{Disks, System};
 
 #[derive(Debug, Serialize, Deserialize)]
 struct WindowBounds {
@@ -15,15 +15,27 @@ struct WindowBounds {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct ScreenshotOptions {
-    format: Option<String>,
-    quality: Option<u8>,
-    path: Option<String>,
+struct FileOperationOptions {
+    encoding: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct FileOperationOptions {
-    encoding: Option<String>,
+struct DirEntry {
+    name: String,
+    is_dir: bool,
+    is_symlink: bool,
+    size: u64,
+    modified_ms: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FileStat {
+    exists: bool,
+    is_dir: bool,
+    is_symlink: bool,
+    size: u64,
+    modified_ms: Option<u64>,
+    readonly: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -34,7 +46,9 @@ struct PlatformInfo {
     hostname: String,
     memory: MemoryInfo,
     cpu: CpuInfo,
+    /// The disk holding the current working directory
     disk: DiskInfo,
+    per_disk: Vec<DiskInfo>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -49,8 +63,9 @@ struct CpuInfo {
     frequency: u64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct DiskInfo {
+    mount_point: String,
     total: u64,
     free: u64,
 }
@@ -100,20 +115,25 @@ async fn close_window(window: Window) -> Result<(), String> {
 }
 
 #[tauri::command]
-async fn take_screenshot(_options: Option<ScreenshotOptions>) -> Result<String, String> {
-    // For now, return a placeholder base64 string
-    // In a real implementation, you would use a screenshot library
-    Ok("data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==".to_string())
+async fn take_screenshot(
+    window: tauri::WebviewWindow,
+    options: Option<tauri_plugin_wdio::ScreenshotOptions>,
+) -> Result<String, String> {
+    tauri_plugin_wdio::capture_window(&window, &options.unwrap_or_default())
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn read_file(path: String, _options: Option<FileOperationOptions>) -> Result<String, String> {
-    std::fs::read_to_string(&path).map_err(|e| format!("Failed to read file '{}': {}", path, e))
+async fn read_file(path: String, options: Option<FileOperationOptions>) -> Result<String, String> {
+    let bytes = std::fs::read(&path).map_err(|e| format!("Failed to read file '{}': {}", path, e))?;
+    decode_contents(&path, bytes, encoding(&options))
 }
 
 #[tauri::command]
-async fn write_file(path: String, contents: String, _options: Option<FileOperationOptions>) -> Result<(), String> {
-    std::fs::write(&path, contents).map_err(|e| format!("Failed to write file '{}': {}", path, e))?;
+async fn write_file(path: String, contents: String, options: Option<FileOperationOptions>) -> Result<(), String> {
+    let bytes = encode_contents(&path, contents, encoding(&options))?;
+    std::fs::write(&path, bytes).map_err(|e| format!("Failed to write file '{}': {}", path, e))?;
     Ok(())
 }
 
@@ -123,6 +143,119 @@ async fn delete_file(path: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Turn file bytes into read_file's result: `utf8` (default) text, `base64`
+/// for binary files, or `latin1`, mapping each byte to one character
+fn decode_contents(path: &str, bytes: Vec<u8>, encoding: &str) -> Result<String, String> {
+    match encoding {
+        "utf8" => String::from_utf8(bytes).map_err(|_| {
+            format!("Failed to read file '{}': not valid UTF-8; read binary files with encoding 'base64'", path)
+        }),
+        "base64" => Ok(base64::engine::general_purpose::STANDARD.encode(bytes)),
+        "latin1" => Ok(bytes.into_iter().map(char::from).collect()),
+        other => Err(format!("Failed to read file '{}': unsupported encoding '{}'", path, other)),
+    }
+}
+
+/// Turn write_file's contents into file bytes; the inverse of [`decode_contents`].
+/// `latin1` is lossy: characters above U+00FF become `?`.
+fn encode_contents(path: &str, contents: String, encoding: &str) -> Result<Vec<u8>, String> {
+    match encoding {
+        "utf8" => Ok(contents.into_bytes()),
+        "base64" => base64::engine::general_purpose::STANDARD
+            .decode(contents.trim())
+            .map_err(|e| format!("Failed to write file '{}': invalid base64 contents: {}", path, e)),
+        "latin1" => Ok(contents.chars().map(|c| u8::try_from(c).unwrap_or(b'?')).collect()),
+        other => Err(format!("Failed to write file '{}': unsupported encoding '{}'", path, other)),
+    }
+}
+
+fn encoding(options: &Option<FileOperationOptions>) -> &str {
+    options.as_ref().and_then(|o| o.encoding.as_deref()).unwrap_or("utf8")
+}
+
+/// Entries of a directory sorted by name. Symlinks are reported as such
+/// rather than resolved.
+#[tauri::command]
+async fn list_dir(path: String) -> Result<Vec<DirEntry>, String> {
+    let error = |e: std::io::Error| format!("Failed to list directory '{}': {}", path, e);
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(&path).map_err(error)? {
+        let entry = entry.map_err(error)?;
+        let metadata = std::fs::symlink_metadata(entry.path()).map_err(error)?;
+        entries.push(DirEntry {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            is_dir: metadata.is_dir(),
+            is_symlink: metadata.file_type().is_symlink(),
+            size: metadata.len(),
+            modified_ms: modified_ms(&metadata),
+        });
+    }
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+#[tauri::command]
+async fn create_dir(path: String, recursive: bool) -> Result<(), String> {
+    let result = if recursive {
+        std::fs::create_dir_all(&path)
+    } else {
+        std::fs::create_dir(&path)
+    };
+    result.map_err(|e| format!("Failed to create directory '{}': {}", path, e))
+}
+
+/// Remove a directory, with its contents when `recursive`. A symlink is
+/// removed itself; its target is left alone.
+#[tauri::command]
+async fn remove_dir(path: String, recursive: bool) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("Failed to remove directory '{}': {}", path, e);
+    let metadata = std::fs::symlink_metadata(&path).map_err(error)?;
+    if metadata.file_type().is_symlink() {
+        // Directory links on Windows are removed with remove_dir
+        return std::fs::remove_file(&path).or_else(|_| std::fs::remove_dir(&path)).map_err(error);
+    }
+    if !metadata.is_dir() {
+        return Err(format!("Failed to remove directory '{}': not a directory", path));
+    }
+    let result = if recursive {
+        std::fs::remove_dir_all(&path)
+    } else {
+        std::fs::remove_dir(&path)
+    };
+    result.map_err(error)
+}
+
+/// Metadata for `path` without following symlinks; a missing path reports
+/// `exists: false` rather than an error
+#[tauri::command]
+async fn stat(path: String) -> Result<FileStat, String> {
+    match std::fs::symlink_metadata(&path) {
+        Ok(metadata) => Ok(FileStat {
+            exists: true,
+            is_dir: metadata.is_dir(),
+            is_symlink: metadata.file_type().is_symlink(),
+            size: metadata.len(),
+            modified_ms: modified_ms(&metadata),
+            readonly: metadata.permissions().readonly(),
+        }),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(FileStat {
+            exists: false,
+            is_dir: false,
+            is_symlink: false,
+            size: 0,
+            modified_ms: None,
+            readonly: false,
+        }),
+        Err(e) => Err(format!("Failed to stat '{}': {}", path, e)),
+    }
+}
+
+fn modified_ms(metadata: &std::fs::Metadata) -> Option<u64> {
+    let modified = metadata.modified().ok()?;
+    let since_epoch = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(since_epoch.as_millis() as u64)
+}
+
 #[tauri::command]
 async fn get_current_dir() -> Result<String, String> {
     std::env::current_dir()
@@ -133,6 +266,31 @@ async fn get_current_dir() -> Result<String, String> {
 // Test functions removed - skipping parameter tests for now
 
 
+/// Space on every enumerable disk; empty in containers that expose none
+fn disk_info() -> Vec<DiskInfo> {
+    Disks::new_with_refreshed_list()
+        .list()
+        .iter()
+        .map(|disk| DiskInfo {
+            mount_point: disk.mount_point().to_string_lossy().into_owned(),
+            total: disk.total_space(),
+            free: disk.available_space(),
+        })
+        .collect()
+}
+
+/// The disk with the longest mount point containing the working directory,
+/// or zeros when none does
+fn cwd_disk(disks: &[DiskInfo]) -> DiskInfo {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    disks
+        .iter()
+        .filter(|disk| cwd.starts_with(&disk.mount_point))
+        .max_by_key(|disk| disk.mount_point.len())
+        .cloned()
+        .unwrap_or_default()
+}
+
 #[tauri::command]
 async fn get_platform_info() -> Result<PlatformInfo, String> {
     let mut sys = System::new_all();
@@ -141,9 +299,8 @@ async fn get_platform_info() -> Result<PlatformInfo, String> {
     let total_memory = sys.total_memory();
     let free_memory = sys.free_memory();
 
-    // Simplified disk info - just return placeholder values for now
-    let total_disk = 1000000000u64; // 1GB placeholder
-    let free_disk = 500000000u64;   // 500MB placeholder
+    let per_disk = disk_info();
+    let disk = cwd_disk(&per_disk);
 
     Ok(PlatformInfo {
         os: System::name().unwrap_or_else(|| "Unknown".to_string()),
@@ -158,26 +315,11 @@ async fn get_platform_info() -> Result<PlatformInfo, String> {
             cores: sys.cpus().len(),
             frequency: sys.cpus().first().map(|c| c.frequency()).unwrap_or(0),
         },
-        disk: DiskInfo {
-            total: total_disk,
-            free: free_disk,
-        },
+        disk,
+        per_disk,
     })
 }
 
-#[tauri::command]
-async fn read_clipboard() -> Result<String, String> {
-    let mut ctx = ClipboardContext::new().map_err(|e| e.to_string())?;
-    ctx.get_contents().map_err(|e| e.to_string())
-}
-
-#[tauri::command]
-async fn write_clipboard(content: String) -> Result<(), String> {
-    let mut ctx = ClipboardContext::new().map_err(|e| e.to_string())?;
-    ctx.set_contents(content).map_err(|e| e.to_string())?;
-    Ok(())
-}
-
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_wdio::init())
@@ -193,10 +335,12 @@ fn main() {
             read_file,
             write_file,
             delete_file,
+            list_dir,
+            create_dir,
+            remove_dir,
+            stat,
             get_current_dir,
-            get_platform_info,
-            read_clipboard,
-            write_clipboard
+            get_platform_info
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -72,6 +72,8 @@ export type {
   TauriAPIs,
   TauriBrowserExtension,
   TauriCapabilities,
+  TauriDialogInvocation,
+  TauriDialogKind,
   TauriExecuteOptions,
   TauriMock,
   TauriMockInstance,
@@ -76,6 +76,23 @@ export interface TauriMock<TArgs extends unknown[] = unknown[], TReturns = unkno
   (...args: TArgs): TReturns;
 }
 
+/**
+ * Dialogs of the official dialog plugin that can be mocked with browser.tauri.mockDialog()
+ */
+export type TauriDialogKind = 'open' | 'save' | 'message' | 'ask' | 'confirm';
+
+/**
+ * A dialog request recorded while dialogs are mocked
+ */
+export interface TauriDialogInvocation {
+  kind: TauriDialogKind;
+  /**
+   * Options the dialog was requested with, e.g. `title` and `filters` for open/save,
+   * or `message` and `title` for message/ask/confirm
+   */
+  options: Record<string, unknown>;
+}
+
 /**
  * Options for browser.tauri.execute() per-call overrides
  * Use withExecuteOptions() from @wdio/tauri-service to create properly-typed options
@@ -219,6 +236,37 @@ export interface TauriServiceAPI {
    */
   restoreAllMocks: (commandPrefix?: string) => Promise<void>;
 
+  /**
+   * Answer the next dialog of the given kind with a scripted response instead of
+   * showing it. Responses queue up, so call once per expected dialog. Dialogs of a
+   * mocked kind invoked with no queued response resolve with `undefined`.
+   *
+   * @param kind - Dialog to intercept: `open`, `save`, `message`, `ask`, or `confirm`
+   * @param response - Value the dialog resolves with (file path(s) or `null` for open/save, boolean for ask/confirm)
+   * @example
+   * ```js
+   * await browser.tauri.mockDialog('open', ['/tmp/report.csv']);
+   * await browser.tauri.mockDialog('confirm', true);
+   * ```
+   */
+  mockDialog: (kind: TauriDialogKind, response: unknown) => Promise<void>;
+
+  /**
+   * Get the dialogs requested while mocked, oldest first.
+   *
+   * @example
+   * ```js
+   * const [invocation] = await browser.tauri.getDialogInvocations();
+   * expect(invocation).toEqual({ kind: 'open', options: expect.objectContaining({ title: 'Pick a file' }) });
+   * ```
+   */
+  getDialogInvocations: () => Promise<TauriDialogInvocation[]>;
+
+  /**
+   * Remove all dialog mocks, dropping queued responses and recorded invocations.
+   */
+  clearDialogMocks: () => Promise<void>;
+
   /**
    * Trigger a deeplink to the Tauri application for testing protocol handlers.
    *
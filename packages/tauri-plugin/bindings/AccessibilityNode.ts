@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A node of a native window's accessibility tree, from get_native_accessibility_tree
+ */
+export type AccessibilityNode = { 
+/**
+ * Platform role, e.g. `frame`, `menu bar`, or `push button` on Linux
+ */
+role: string, name: string | null, 
+/**
+ * Platform states that are set, e.g. `focused`, `enabled`
+ */
+states: Array<string>, 
+/**
+ * Children the platform reports; more than `children` holds when the
+ * walk was pruned here
+ */
+child_count: number, children: Array<AccessibilityNode>, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Application details returned by the get_app_info command
+ */
+export type AppInfo = { 
+/**
+ * Package name from the Tauri config (`productName`)
+ */
+name: string, version: string, tauri_version: string, 
+/**
+ * Whether the binary was built with debug assertions
+ */
+debug: boolean, pid: number, };
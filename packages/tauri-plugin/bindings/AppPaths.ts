@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Tauri app directories returned by the get_app_paths command. A field is
+ * `None` when the path cannot be resolved on the current platform.
+ */
+export type AppPaths = { app_data_dir: string | null, app_config_dir: string | null, app_cache_dir: string | null, app_log_dir: string | null, temp_dir: string | null, resource_dir: string | null, executable_path: string | null, };
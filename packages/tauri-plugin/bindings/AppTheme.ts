@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Window appearance for set_app_theme and get_system_appearance
+ */
+export type AppTheme = "light" | "dark";
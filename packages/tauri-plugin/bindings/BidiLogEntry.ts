@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BidiLogLevel } from "./BidiLogLevel";
+import type { BidiSource } from "./BidiSource";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * A captured [`LogEntry`] in the shape of a WebDriver BiDi `log.entryAdded`
+ * entry, emitted as `wdio:bidi:log.entryAdded` while `bidi_log_events` is on
+ */
+export type BidiLogEntry = { 
+/**
+ * `console` for frontend entries, `generic` for backend ones
+ */
+type: string, level: BidiLogLevel, source: BidiSource, text: string | null, 
+/**
+ * Milliseconds since the Unix epoch
+ */
+timestamp: number, 
+/**
+ * Console method of a console entry
+ */
+method?: string, 
+/**
+ * Arguments of a console entry, as BiDi remote values
+ */
+args?: Array<JsonValue>, };
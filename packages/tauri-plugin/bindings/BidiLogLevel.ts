@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Level of a [`BidiLogEntry`], as WebDriver BiDi names them
+ */
+export type BidiLogLevel = "debug" | "info" | "warn" | "error";
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Realm a [`BidiLogEntry`] came from
+ */
+export type BidiSource = { realm: string, 
+/**
+ * Browsing context, left unset for the service to fill in from its session
+ */
+context?: string, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Time on the backend test clock, returned by the clock_* commands
+ */
+export type ClockStatus = { 
+/**
+ * What `Wdio::clock` reads now, in milliseconds since the Unix epoch
+ */
+now_ms: number, 
+/**
+ * Whether the clock is stopped until a test advances it
+ */
+frozen: boolean, };
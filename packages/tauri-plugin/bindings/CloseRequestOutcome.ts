@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of request_window_close
+ */
+export type CloseRequestOutcome = { 
+/**
+ * Whether the window was still open once the close grace period ran
+ * out, as it is when the app's handler called `api.prevent_close()`
+ */
+prevented: boolean, };
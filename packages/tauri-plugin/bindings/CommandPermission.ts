@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Whether the calling webview may invoke one plugin command, from self_test
+ */
+export type CommandPermission = { command: string, permitted: boolean, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * JavaScript coverage collected from one window by collect_coverage
+ */
+export type CoverageResult = { label: string, 
+/**
+ * Size of the serialized `window.__coverage__`; 0 when the page has none
+ */
+bytes: number, 
+/**
+ * File the coverage was written to, when an output path was given
+ */
+path: string | null, 
+/**
+ * The coverage itself, when no output path was given
+ */
+coverage: JsonValue | null, };
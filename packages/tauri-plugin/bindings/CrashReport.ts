@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Details of the most recent backend panic
+ */
+export type CrashReport = { 
+/**
+ * Panic payload message
+ */
+message: string, 
+/**
+ * Source location as `file:line:column`, if known
+ */
+location: string | null, backtrace: string, 
+/**
+ * Name of the panicking thread (`<unnamed>` if it has none)
+ */
+thread: string, 
+/**
+ * Milliseconds since the Unix epoch
+ */
+timestamp_ms: number, };
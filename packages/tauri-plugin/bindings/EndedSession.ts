@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Entries end_session removed along with the session
+ */
+export type EndedSession = { session_id: string, log_entries: number, invoke_trace_entries: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * An event captured by start_event_recording
+ */
+export type EventRecord = { name: string, 
+/**
+ * Event payload; payloads that aren't JSON are kept as strings
+ */
+payload: JsonValue, timestamp_ms: number, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Filter for the get_recorded_events command; all set fields must match
+ */
+export type EventRecordFilter = { name?: string, 
+/**
+ * Only events at or after this timestamp (ms since epoch)
+ */
+since_timestamp?: number, };
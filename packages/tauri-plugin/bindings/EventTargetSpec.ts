@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Who receives an event sent with emit_event, mirroring [`tauri::EventTarget`]
+ */
+export type EventTargetSpec = { "kind": "all" } | { "kind": "label", "label": string } | { "kind": "window", "label": string } | { "kind": "webview", "label": string } | { "kind": "webview_window", "label": string };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * Execute command request
+ */
+export type ExecuteRequest = { 
+/**
+ * JavaScript code to execute
+ */
+script: string, 
+/**
+ * Arguments to pass to the script
+ */
+args?: Array<JsonValue>, 
+/**
+ * Window label to execute in (optional, uses current window if not specified)
+ */
+window_label?: string, 
+/**
+ * Return an [`ExecuteResponse`] with timings instead of the bare value
+ */
+detailed?: boolean, };
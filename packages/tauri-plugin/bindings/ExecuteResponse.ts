@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * Result of a `detailed` execute: the script's value and where the time went.
+ * `total_duration_ms` less `eval_duration_ms` is the plugin and IPC overhead.
+ */
+export type ExecuteResponse = { 
+/**
+ * The script's value, `{ "__wdio_undefined__": true }` when it returned undefined
+ */
+value: JsonValue, 
+/**
+ * When the script started running in the page, in Unix epoch milliseconds
+ */
+eval_started_ms: number, 
+/**
+ * How long the page spent running the script, awaiting its promise, as
+ * measured there with `performance.now()`
+ */
+eval_duration_ms: number, 
+/**
+ * How long the whole execute took, as measured by the plugin
+ */
+total_duration_ms: number, window_label: string, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Readiness returned by the health_check command
+ */
+export type HealthStatus = { 
+/**
+ * Whether any window has finished loading a page
+ */
+ready: boolean, 
+/**
+ * Open windows that have finished loading a page, by label
+ */
+windows_loaded: Array<string>, 
+/**
+ * Execute calls still waiting for their script's result
+ */
+pending_executions: number, 
+/**
+ * Milliseconds since the plugin was set up
+ */
+uptime_ms: number, };
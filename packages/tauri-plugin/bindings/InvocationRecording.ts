@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecordedInvocation } from "./RecordedInvocation";
+
+/**
+ * File written by stop_recording and read by enable_replay
+ */
+export type InvocationRecording = { version: number, 
+/**
+ * Invocations in the order they settled
+ */
+invocations: Array<RecordedInvocation>, };
@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * Latency and failures that set_invoke_conditions adds to frontend invokes
+ */
+export type InvokeConditions = { 
+/**
+ * Delay before each matching invoke is dispatched
+ */
+latency_ms?: number, 
+/**
+ * Random extra delay of up to this many milliseconds
+ */
+jitter_ms?: number, 
+/**
+ * Share of matching invokes, from 0 to 1, that reject without being dispatched
+ */
+failure_rate?: number, 
+/**
+ * Value failed invokes reject with; a message naming the command when null
+ */
+failure_error?: JsonValue, 
+/**
+ * Commands the conditions apply to; every command when unset
+ */
+command_filter?: Array<string>, 
+/**
+ * Seed for the jitter and failure draws, so a run can be reproduced
+ */
+seed?: number, };
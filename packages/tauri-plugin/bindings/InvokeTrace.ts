@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InvokeTraceEntry } from "./InvokeTraceEntry";
+
+/**
+ * Result of get_invoke_trace
+ */
+export type InvokeTrace = { 
+/**
+ * Recorded calls, ordered by start time
+ */
+entries: Array<InvokeTraceEntry>, 
+/**
+ * Calls dropped because the trace was full
+ */
+dropped: number, };
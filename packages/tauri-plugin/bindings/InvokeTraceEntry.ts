@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InvokeOutcome } from "./InvokeOutcome";
+
+/**
+ * An IPC command call recorded by start_invoke_tracing
+ */
+export type InvokeTraceEntry = { command: string, 
+/**
+ * JSON of the call's arguments, cut to the trace's args budget
+ */
+args_summary: string, args_truncated: boolean, started_at_ms: number, duration_ms: number, outcome: InvokeOutcome, 
+/**
+ * Window the call was made from; set by the backend
+ */
+window_label: string, 
+/**
+ * Test session active when the call was recorded; set by the backend
+ */
+session_id: string | null, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * Result of export_invoke_trace
+ */
+export type InvokeTraceExport = { 
+/**
+ * File the document was written to, when a path was given
+ */
+path: string | null, 
+/**
+ * The document itself, when no path was given
+ */
+document: JsonValue | null, entry_count: number, };
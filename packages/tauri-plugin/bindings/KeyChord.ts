@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { KeyModifier } from "./KeyModifier";
+
+/**
+ * One key press for send_native_keys, with the modifiers held around it
+ */
+export type KeyChord = { 
+/**
+ * A single character, or a `KeyboardEvent.key` name such as `Enter`,
+ * `Escape`, `ArrowUp`, or `F5`
+ */
+key: string, modifiers?: Array<KeyModifier>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Modifier held down for a [`KeyChord`]
+ */
+export type KeyModifier = "shift" | "control" | "alt" | "meta";
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * App lifecycle transition for simulate_lifecycle
+ */
+export type LifecycleEvent = "background" | "foreground" | "low_memory";
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Payload of a `wdio:log-dropped` event, emitted at most once per second
+ * for each subscription that exceeded its rate limit
+ */
+export type LogDropSummary = { subscription_id: string, 
+/**
+ * Entries dropped since the previous summary
+ */
+dropped: number, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogLevel } from "./LogLevel";
+import type { LogSource } from "./LogSource";
+
+/**
+ * A backend log record or forwarded frontend console entry
+ */
+export type LogEntry = { source: LogSource, level: LogLevel, message: string, 
+/**
+ * Module path of the backend record (`None` for frontend entries)
+ */
+target: string | null, 
+/**
+ * Milliseconds since the Unix epoch
+ */
+timestamp_ms: number, 
+/**
+ * Log context set via set_log_context when the entry was captured
+ */
+context: string | null, 
+/**
+ * Test session active when the entry was captured, see begin_session
+ */
+session_id: string | null, };
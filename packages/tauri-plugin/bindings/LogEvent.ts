@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogEntry } from "./LogEntry";
+
+/**
+ * Payload of a `wdio:log` event
+ */
+export type LogEvent = { subscription_id: string, 
+/**
+ * Per-subscription sequence number, starting at 1
+ */
+seq: number, entry: LogEntry, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogLevel } from "./LogLevel";
+import type { LogSource } from "./LogSource";
+
+/**
+ * Filter for the get_logs command; all set fields must match
+ */
+export type LogFilter = { 
+/**
+ * Minimum level to include
+ */
+level?: LogLevel, source?: LogSource, 
+/**
+ * Substring the message must contain
+ */
+contains?: string, 
+/**
+ * Only entries at or after this timestamp (ms since epoch)
+ */
+since_timestamp?: number, };
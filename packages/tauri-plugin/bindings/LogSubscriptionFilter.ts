@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogLevel } from "./LogLevel";
+import type { LogSource } from "./LogSource";
+
+/**
+ * Filter for the subscribe_logs command
+ */
+export type LogSubscriptionFilter = { 
+/**
+ * Deliver events only to this window; all webviews when unset
+ */
+window_label?: string, 
+/**
+ * Minimum level to include
+ */
+level?: LogLevel, source?: LogSource, 
+/**
+ * Substring the message must contain
+ */
+contains?: string, 
+/**
+ * Only entries at or after this timestamp (ms since epoch)
+ */
+since_timestamp?: number, };
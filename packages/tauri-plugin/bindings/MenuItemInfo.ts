@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MenuItemType } from "./MenuItemType";
+
+/**
+ * A menu entry returned by get_menu_structure and trigger_menu_item
+ */
+export type MenuItemInfo = { 
+/**
+ * Tauri menu id; generated by Tauri for items created without one
+ */
+id: string, label: string, kind: MenuItemType, enabled: boolean, 
+/**
+ * Checked state, only set for check items
+ */
+checked: boolean | null, 
+/**
+ * Items of a submenu
+ */
+children: Array<MenuItemInfo>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Kind of entry in a menu, see [`MenuItemInfo`]
+ */
+export type MenuItemType = "normal" | "check" | "icon" | "predefined" | "submenu";
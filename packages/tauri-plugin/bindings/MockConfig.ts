@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * Mock configuration
+ */
+export type MockConfig = { 
+/**
+ * Command name to mock
+ */
+command: string, 
+/**
+ * Mock return value (for mockReturnValue)
+ */
+return_value: JsonValue | null, 
+/**
+ * Mock implementation (for mockImplementation - serialized function string)
+ */
+implementation: string | null, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Dimensions } from "./Dimensions";
+import type { Point } from "./Point";
+
+/**
+ * Display details returned by get_monitors and get_current_monitor
+ */
+export type MonitorInfo = { name: string | null, 
+/**
+ * Top-left corner on the virtual desktop
+ */
+position: Point, size: Dimensions, 
+/**
+ * This monitor's own scale factor, which can differ between monitors
+ */
+scale_factor: number, is_primary: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Mouse button for send_native_click
+ */
+export type MouseButton = "left" | "right" | "middle";
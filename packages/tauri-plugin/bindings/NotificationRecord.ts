@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A notification recorded instead of shown, returned by get_captured_notifications
+ */
+export type NotificationRecord = { title: string, body: string | null, timestamp_ms: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The fields of a `plugin:notification|notify` request that capture mode records
+ */
+export type NotifyOptions = { title?: string, body?: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An execute call waiting for its script's result, see get_pending_executions
+ */
+export type PendingExecution = { 
+/**
+ * Event the script emits its result on
+ */
+id: string, window_label: string, created_at_ms: number, age_ms: number, };
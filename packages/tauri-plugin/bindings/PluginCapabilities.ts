@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Plugin build details returned by the get_plugin_capabilities command
+ */
+export type PluginCapabilities = { plugin_version: string, 
+/**
+ * See [`crate::PROTOCOL_VERSION`]
+ */
+protocol_version: number, 
+/**
+ * Optional capabilities compiled into this build, named after their cargo features
+ */
+features: Array<string>, };
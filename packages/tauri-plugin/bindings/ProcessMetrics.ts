@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Resource usage of the app process, returned by get_process_metrics and get_metrics_samples
+ */
+export type ProcessMetrics = { 
+/**
+ * Resident set size
+ */
+rss_bytes: number, virtual_bytes: number, 
+/**
+ * CPU usage since the previous measurement; 100 = one full core
+ */
+cpu_percent: number, 
+/**
+ * Thread count (`None` where the platform does not report it)
+ */
+num_threads: number | null, uptime_ms: number, 
+/**
+ * Milliseconds since the Unix epoch when the measurement was taken
+ */
+timestamp_ms: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EventRecord } from "./EventRecord";
+
+/**
+ * Result of get_recorded_events
+ */
+export type RecordedEvents = { 
+/**
+ * Matching events, oldest first
+ */
+events: Array<EventRecord>, 
+/**
+ * Events dropped because the recording was full
+ */
+dropped: number, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InvokeOutcome } from "./InvokeOutcome";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * A real command result captured by start_recording
+ */
+export type RecordedInvocation = { command: string, 
+/**
+ * Hash of the invoke arguments that ignores key order
+ */
+args_hash: string, 
+/**
+ * `ok`, or `error` when the command rejected with `result`
+ */
+outcome: InvokeOutcome, result: JsonValue, };
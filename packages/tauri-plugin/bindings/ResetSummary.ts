@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What reset_all cleared
+ */
+export type ResetSummary = { logs_cleared: number, window_events_cleared: number, invoke_trace_cleared: number, 
+/**
+ * Session that was active, ended without removing what it tagged
+ */
+session_ended: string | null, 
+/**
+ * Whether a preamble set with set_execute_preamble was dropped
+ */
+preamble_cleared: boolean, 
+/**
+ * Executions failed, other than those waiting on the calling window
+ */
+executions_cancelled: number, notifications_cleared: number, recorded_events_cleared: number, invoke_conditions_cleared: boolean, 
+/**
+ * Whether an invoke recording, discarded unwritten, or replay was on
+ */
+invoke_replay_stopped: boolean, 
+/**
+ * Pages sent the reset of their mocks, mock calls, and interceptor settings
+ */
+pages_reset: number, };
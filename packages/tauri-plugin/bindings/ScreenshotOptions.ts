@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ImageFormat } from "./ImageFormat";
+
+/**
+ * Options for the capture_window command
+ */
+export type ScreenshotOptions = { format?: ImageFormat, 
+/**
+ * JPEG quality from 0 to 100 (default 90); ignored for PNG
+ */
+quality?: number, 
+/**
+ * Write the image to this file and return the path instead of a data URL
+ */
+path?: string, };
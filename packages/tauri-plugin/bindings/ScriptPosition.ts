@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where an executed script raised an error, relative to the script the
+ * execute command received. Lines and columns are 1-based; columns count
+ * UTF-16 code units, as JavaScript engines do.
+ */
+export type ScriptPosition = { line: number, column: number, 
+/**
+ * The offending line with a caret under the column
+ */
+snippet: string, };
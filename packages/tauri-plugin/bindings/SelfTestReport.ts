@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommandPermission } from "./CommandPermission";
+
+/**
+ * Result of self_test
+ */
+export type SelfTestReport = { 
+/**
+ * Every plugin command and whether the webview's capabilities allow it;
+ * empty when the round trip failed
+ */
+commands: Array<CommandPermission>, 
+/**
+ * Whether a script evaluated in the webview reported back to the plugin
+ */
+ipc_event_roundtrip_ok: boolean, };
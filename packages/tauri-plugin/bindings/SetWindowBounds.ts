@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CoordinateUnit } from "./CoordinateUnit";
+
+/**
+ * Bounds for the set_window_bounds command
+ */
+export type SetWindowBounds = { x: number, y: number, width: number, height: number, unit?: CoordinateUnit, };
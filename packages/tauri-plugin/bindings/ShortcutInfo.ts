@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A global shortcut registered with the plugin, returned by list_global_shortcuts
+ */
+export type ShortcutInfo = { 
+/**
+ * Normalized accelerator, e.g. `shift+control+KeyK`
+ */
+accelerator: string, 
+/**
+ * Whether the OS registration is still active
+ */
+registered: boolean, };
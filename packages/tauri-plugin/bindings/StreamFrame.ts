@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EventRecord } from "./EventRecord";
+import type { InvokeTraceEntry } from "./InvokeTraceEntry";
+import type { LogEntry } from "./LogEntry";
+
+/**
+ * Frame pushed to a control server `/stream` client. Log, event, and invoke
+ * frames share one sequence per connection, so a gap means frames were dropped.
+ */
+export type StreamFrame = { "type": "log", seq: number, entry: LogEntry, } | { "type": "event", seq: number, event: EventRecord, } | { "type": "invoke_trace", seq: number, entry: InvokeTraceEntry, } | { "type": "dropped", count: number, } | { "type": "subscribed", } | { "type": "error", message: string, };
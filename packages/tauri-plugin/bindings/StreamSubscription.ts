@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogFilter } from "./LogFilter";
+
+/**
+ * Subscription a control server `/stream` client sends as a text message.
+ * Each kind of frame is only sent when asked for.
+ */
+export type StreamSubscription = { 
+/**
+ * Log entries matching this filter, as for subscribe_logs
+ */
+logs?: LogFilter, 
+/**
+ * Tauri events with these names, as for start_event_recording
+ */
+event_names?: Array<string>, 
+/**
+ * Calls recorded while invoke tracing is on
+ */
+invoke_trace?: boolean, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppTheme } from "./AppTheme";
+
+/**
+ * Theme, locale, and timezone returned by get_system_appearance. `locale` is
+ * a BCP 47 tag read from `LC_ALL`/`LC_MESSAGES`/`LANG` and `timezone` an IANA
+ * name; either is `None` when the platform doesn't expose it.
+ */
+export type SystemAppearance = { theme: AppTheme, locale: string | null, timezone: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Document format for export_invoke_trace
+ */
+export type TraceExportFormat = "json" | "har";
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MenuItemInfo } from "./MenuItemInfo";
+
+/**
+ * A tray icon registered with the plugin, returned by get_tray_items
+ */
+export type TrayInfo = { id: string, 
+/**
+ * Items of the tray's menu, empty when it has none
+ */
+menu: Array<MenuItemInfo>, };
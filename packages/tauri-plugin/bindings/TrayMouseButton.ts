@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Mouse button for simulate_tray_click
+ */
+export type TrayMouseButton = "left" | "right" | "middle";
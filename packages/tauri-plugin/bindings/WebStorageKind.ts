@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Kind of frontend storage for the web storage commands
+ */
+export type WebStorageKind = "localStorage" | "sessionStorage" | "indexedDb" | "cookies";
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebStorageKind } from "./WebStorageKind";
+
+/**
+ * Outcome of clear_web_storage or restore_web_storage for one kind of storage
+ */
+export type WebStorageResult = { kind: WebStorageKind, 
+/**
+ * Items, databases, or cookies cleared, or items, records, or cookies restored
+ */
+count: number, 
+/**
+ * Why this kind failed; the other kinds are still processed
+ */
+error: string | null, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebStorageKind } from "./WebStorageKind";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * A page's frontend storage, taken by snapshot_web_storage for
+ * restore_web_storage. A kind is `None` when it couldn't be read, with the
+ * reason in `errors`.
+ */
+export type WebStorageSnapshot = { 
+/**
+ * Origin of the page. Storage belongs to an origin, so the snapshot can
+ * only be restored into a page at the same one.
+ */
+origin: string, local_storage: { [key in string]?: string } | null, session_storage: { [key in string]?: string } | null, 
+/**
+ * Databases with their version, object stores, indexes, and records.
+ * Keys and values that aren't JSON, such as dates, are tagged with `$wdio`.
+ */
+indexed_db: Array<JsonValue> | null, 
+/**
+ * Cookies readable by scripts, by name
+ */
+cookies: { [key in string]?: string } | null, errors: { [key in WebStorageKind]?: string }, };
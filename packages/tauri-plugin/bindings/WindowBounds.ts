@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outer position and size of a window in physical pixels
+ */
+export type WindowBounds = { x: number, y: number, width: number, height: number, };
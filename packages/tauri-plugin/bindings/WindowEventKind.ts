@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Kind of window event recorded for get_window_events
+ */
+export type WindowEventKind = "moved" | "resized" | "focused" | "scale_factor_changed" | "close_requested";
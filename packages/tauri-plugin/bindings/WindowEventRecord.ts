@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WindowEventKind } from "./WindowEventKind";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * A window event recorded by the plugin
+ */
+export type WindowEventRecord = { label: string, kind: WindowEventKind, 
+/**
+ * `{ x, y }` when moved, `{ width, height }` when resized, `{ focused }`,
+ * `{ scale_factor, width, height }` when the scale factor changed, and
+ * null when a close was requested; positions and sizes are physical
+ */
+detail: JsonValue, timestamp_ms: number, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WindowEventRecord } from "./WindowEventRecord";
+
+/**
+ * Result of get_window_events
+ */
+export type WindowEvents = { 
+/**
+ * Matching events, oldest first
+ */
+events: Array<WindowEventRecord>, 
+/**
+ * Events dropped because the buffer was full
+ */
+dropped: number, };
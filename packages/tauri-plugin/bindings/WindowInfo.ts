@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WindowBounds } from "./WindowBounds";
+
+/**
+ * Window details returned by list_windows and get_window_info
+ */
+export type WindowInfo = { label: string, title: string, focused: boolean, visible: boolean, maximized: boolean, minimized: boolean, fullscreen: boolean, 
+/**
+ * `None` if the platform cannot report the window geometry
+ */
+bounds: WindowBounds | null, };
@@ -7,27 +7,140 @@
 // This plugin uses tauri_plugin::Builder (not tauri_build::InlinedPlugin) because:
 //
 // 1. InlinedPlugin does not automatically generate ACL permissions for plugins
-// 2. The permissions must be explicitly defined in permissions/default.toml
+// 2. tauri_plugin::Builder generates an `allow-<command>` and `deny-<command>`
+//    permission for every command in COMMANDS (permissions/autogenerated/commands)
 // 3. Using tauri_plugin::Builder properly integrates the permissions system
 // 4. This ensures permissions appear in the generated ACL manifest (gen/schemas/acl-manifests.json)
 //
-// The COMMANDS list below must stay in sync with the invoke_handler in src/lib.rs.
-// Apps using this plugin should reference the bundled `wdio:default` permission.
+// COMMANDS comes from src/command_list.rs, which lib.rs also uses to build
+// the invoke handler, so the two can't drift apart. The `default` permission
+// set and the `readonly` and `full` sets are generated from it too
+// (permissions/autogenerated/default.toml). Apps using this plugin should
+// reference the bundled `wdio:default` permission, or `wdio:readonly` to
+// grant only the query commands.
 //
 // For more details on Tauri v2 plugin permissions, see:
 // https://v2.tauri.app/develop/plugins/develop/#permissions
 
-const COMMANDS: &[&str] = &[
-    "execute",
-    "log_frontend",
+include!("src/command_list.rs");
+
+macro_rules! command_names {
+    ($($name:ident),* $(,)?) => {
+        &[$(stringify!($name)),*]
+    };
+}
+
+const COMMANDS: &[&str] = wdio_commands!(command_names);
+
+/// Commands left out of the `default` set: they terminate the app, so apps
+/// grant them explicitly
+const EXCLUDED_FROM_DEFAULT: &[&str] = &["quit_app", "restart_app"];
+
+/// Commands in the `readonly` set: they report app, window, log, and device
+/// state without changing it or writing files. None runs caller-supplied
+/// scripts; `self_test` evaluates its own fixed probe script so it can check
+/// the window's permissions
+const READONLY_COMMANDS: &[&str] = &[
     "debug_plugin",
     "get_active_window_label",
     "get_window_states",
     "list_windows",
+    "get_window_info",
+    "get_monitors",
+    "get_current_monitor",
+    "is_devtools_open",
+    "wait_for_load",
+    "get_logs",
+    "get_last_crash",
+    "get_log_file_path",
+    "get_app_info",
+    "get_app_paths",
+    "get_process_metrics",
+    "get_metrics_samples",
+    "get_env",
+    "get_cli_args",
+    "get_captured_notifications",
+    "get_clipboard_text",
+    "get_clipboard_image",
+    "get_menu_structure",
+    "get_tray_items",
+    "list_global_shortcuts",
+    "get_app_state",
+    "get_recorded_events",
+    "get_plugin_capabilities",
+    "is_enabled",
+    "get_pending_executions",
+    "get_invoke_trace",
+    "get_system_appearance",
+    "health_check",
+    "wait_for_event",
+    "wait_for_window",
+    "wait_for_window_close",
+    "get_native_accessibility_tree",
+    "self_test",
+    "self_test_report",
+    "get_window_events",
+    "get_command_schemas",
 ];
 
+/// `allow-*` identifiers for `commands`, one quoted TOML array item per line
+fn allow_list<'a>(commands: impl Iterator<Item = &'a &'a str>) -> String {
+    commands
+        .map(|command| format!("  \"allow-{}\"", command.replace('_', "-")))
+        .collect::<Vec<_>>()
+        .join(",\n")
+}
+
+/// Write the `default`, `readonly`, and `full` permission sets. Only
+/// rewritten when it changes, so it doesn't retrigger the permissions rerun
+/// check.
+fn write_permission_sets() {
+    for command in READONLY_COMMANDS.iter().chain(EXCLUDED_FROM_DEFAULT) {
+        assert!(COMMANDS.contains(command), "{} is not in src/command_list.rs", command);
+    }
+    let excluded: Vec<String> = EXCLUDED_FROM_DEFAULT.iter().map(|command| format!("`{}`", command)).collect();
+    let toml = format!(
+        r#"# Automatically generated from src/command_list.rs by build.rs - DO NOT EDIT!
+
+"$schema" = "../schemas/schema.json"
+
+[default]
+description = "Allows all WebDriverIO plugin commands for testing except {excluded}, which apps grant explicitly"
+permissions = [
+{default}
+]
+
+[[set]]
+identifier = "readonly"
+description = "Allows only the commands that read app, window, log, and device state, without changing anything or writing files. No caller-supplied scripts run; `self_test` evaluates its own fixed probe script"
+permissions = [
+{readonly}
+]
+
+[[set]]
+identifier = "full"
+description = "Allows every WebDriverIO plugin command, including {excluded}"
+permissions = [
+{full}
+]
+"#,
+        excluded = excluded.join(" and "),
+        default = allow_list(COMMANDS.iter().filter(|command| !EXCLUDED_FROM_DEFAULT.contains(command))),
+        readonly = allow_list(READONLY_COMMANDS.iter()),
+        full = allow_list(COMMANDS.iter()),
+    );
+
+    let dir = std::path::Path::new("permissions").join("autogenerated");
+    std::fs::create_dir_all(&dir).expect("unable to create the autogenerated permissions dir");
+    let path = dir.join("default.toml");
+    if std::fs::read_to_string(&path).ok().as_deref() != Some(toml.as_str()) {
+        std::fs::write(&path, toml).expect("unable to write the permission sets");
+    }
+}
 
 fn main() {
+    println!("cargo:rerun-if-changed=src/command_list.rs");
+    write_permission_sets();
     tauri_plugin::Builder::new(COMMANDS)
         .android_path("android")
         .ios_path("ios")
@@ -200,9 +200,27 @@ describe('execute', () => {
     vi.restoreAllMocks();
   });
 
-  it('should throw when window.__TAURI__ is not available', async () => {
+  it('should throw when Tauri IPC is not available', async () => {
     delete (window as any).__TAURI__;
-    await expect(execute('return 1')).rejects.toThrow('window.__TAURI__ is not available');
+    delete (window as any).__wdio_original_core__;
+    await expect(execute('return 1')).rejects.toThrow('Tauri IPC is not available');
+  });
+
+  it('should invoke through __TAURI_INTERNALS__ when withGlobalTauri is off', async () => {
+    delete (window as any).__TAURI__;
+    delete (window as any).__wdio_original_core__;
+    const internalInvoke = vi.fn().mockResolvedValue('executed internally');
+    (window as any).__TAURI_INTERNALS__ = { invoke: internalInvoke };
+    vi.resetModules();
+    const mod = await import('../index.js');
+    await mod.init();
+
+    try {
+      expect(await mod.execute('(tauri) => "test"')).toBe('executed internally');
+      expect(internalInvoke).toHaveBeenCalledWith('plugin:wdio|execute', expect.anything());
+    } finally {
+      delete (window as any).__TAURI_INTERNALS__;
+    }
   });
 
   it('should call the original invoke with the wrapped script', async () => {
@@ -240,6 +258,18 @@ describe('execute', () => {
     );
   });
 
+  it('should use the message of structured plugin errors', async () => {
+    originalInvoke.mockRejectedValueOnce({
+      code: 'WINDOW_NOT_FOUND',
+      message: 'Window not found: other',
+      details: { label: 'other' },
+    });
+
+    await expect(execute('(tauri) => tauri.core.invoke("bad")')).rejects.toThrow(
+      'Failed to execute script: Window not found: other',
+    );
+  });
+
   it('should handle non-Error invoke rejections', async () => {
     originalInvoke.mockRejectedValueOnce('string error');
 
@@ -372,6 +402,20 @@ describe('setupConsoleForwarding', () => {
     expect(console.log).not.toBe(logBefore);
   });
 
+  it('should not wrap console methods that are already wrapped by the plugin shim', async () => {
+    vi.resetModules();
+    const wrappedKey = Symbol.for('wdio:console-wrapped');
+    (console as any)[wrappedKey] = true;
+    const logBefore = console.log;
+    (window as any).__TAURI__ = createTauriMock();
+
+    const mod = await import('../index.js');
+    await mod.init();
+
+    expect(console.log).toBe(logBefore);
+    delete (console as any)[wrappedKey];
+  });
+
   it('should call the original console method when wrapped', async () => {
     vi.resetModules();
     const logSpy = vi.fn();
@@ -460,6 +504,9 @@ describe('setupInvokeInterception', () => {
   afterEach(() => {
     delete (window as any).__TAURI__;
     delete (window as any).__wdio_mocks__;
+    delete (window as any).__wdio_invoke_tracing__;
+    delete (window as any).__wdio_invoke_conditions__;
+    delete (window as any).__wdio_invoke_replay__;
     vi.restoreAllMocks();
     vi.resetModules();
   });
@@ -535,6 +582,224 @@ describe('setupInvokeInterception', () => {
     await expect(wrappedInvoke('failing_command')).rejects.toThrow('mock error');
   });
 
+  it('should resolve notify without forwarding it when the backend captures it', async () => {
+    vi.resetModules();
+    const originalInvoke = vi.fn().mockImplementation(async (cmd: string) =>
+      cmd === 'plugin:wdio|capture_notification' ? true : 'shown',
+    );
+    (window as any).__TAURI__ = createTauriMock(originalInvoke);
+
+    const mod = await import('../index.js');
+    await mod.init();
+
+    const wrappedInvoke = (window as any).__TAURI__.core.invoke;
+    const result = await wrappedInvoke('plugin:notification|notify', { options: { title: 'Hi', body: 'there' } });
+
+    expect(result).toBeNull();
+    expect(originalInvoke).toHaveBeenCalledWith('plugin:wdio|capture_notification', {
+      options: { title: 'Hi', body: 'there' },
+    });
+    expect(originalInvoke).not.toHaveBeenCalledWith('plugin:notification|notify', expect.anything());
+  });
+
+  it('should pass notify through untouched when capture is off or fails', async () => {
+    vi.resetModules();
+    vi.spyOn(console, 'warn').mockImplementation(() => {});
+    let captureResponse: () => Promise<unknown> = async () => false;
+    const originalInvoke = vi.fn().mockImplementation(async (cmd: string) =>
+      cmd === 'plugin:wdio|capture_notification' ? captureResponse() : 'shown',
+    );
+    (window as any).__TAURI__ = createTauriMock(originalInvoke);
+
+    const mod = await import('../index.js');
+    await mod.init();
+
+    const wrappedInvoke = (window as any).__TAURI__.core.invoke;
+    const args = { options: { title: 'Hi' } };
+    expect(await wrappedInvoke('plugin:notification|notify', args)).toBe('shown');
+
+    captureResponse = async () => {
+      throw new Error('not allowed');
+    };
+    expect(await wrappedInvoke('plugin:notification|notify', args)).toBe('shown');
+    expect(originalInvoke).toHaveBeenCalledWith('plugin:notification|notify', args);
+  });
+
+  it('should report invokes to the backend while tracing is on', async () => {
+    vi.resetModules();
+    const originalInvoke = vi.fn().mockImplementation(async (cmd: string) => {
+      if (cmd === 'failing_command') {
+        throw new Error('failed');
+      }
+      return 'real-result';
+    });
+    (window as any).__TAURI__ = createTauriMock(originalInvoke);
+    (window as any).__wdio_mocks__ = { mocked_command: vi.fn().mockResolvedValue('mocked-result') };
+
+    const mod = await import('../index.js');
+    await mod.init();
+
+    const wrappedInvoke = (window as any).__TAURI__.core.invoke;
+    await wrappedInvoke('untraced_command');
+    (window as any).__wdio_invoke_tracing__ = { argsMaxBytes: 8 };
+    await wrappedInvoke('some_command', { payload: 'a long argument' });
+    await wrappedInvoke('mocked_command');
+    await expect(wrappedInvoke('failing_command')).rejects.toThrow('failed');
+    delete (window as any).__wdio_invoke_tracing__;
+
+    const entries = originalInvoke.mock.calls
+      .filter((call: unknown[]) => call[0] === 'plugin:wdio|record_invoke')
+      .map((call: unknown[]) => (call[1] as { entry: Record<string, unknown> }).entry);
+    expect(entries.map((entry) => [entry.command, entry.outcome])).toEqual([
+      ['some_command', 'ok'],
+      ['mocked_command', 'mocked'],
+      ['failing_command', 'error'],
+    ]);
+    expect(entries[0]).toMatchObject({ args_summary: '{"payloa', args_truncated: true });
+    expect(entries[0].duration_ms).toBeGreaterThanOrEqual(0);
+  });
+
+  it('should reject filtered invokes at a failure rate of 1 and let the rest through', async () => {
+    vi.resetModules();
+    const originalInvoke = vi.fn().mockResolvedValue('real-result');
+    (window as any).__TAURI__ = createTauriMock(originalInvoke);
+    (window as any).__wdio_mocks__ = { mocked_command: vi.fn().mockResolvedValue('mocked-result') };
+
+    const mod = await import('../index.js');
+    await mod.init();
+
+    (window as any).__wdio_invoke_conditions__ = {
+      latency_ms: 0,
+      jitter_ms: 0,
+      failure_rate: 1,
+      failure_error: { code: 'OFFLINE' },
+      command_filter: ['save_note', 'mocked_command'],
+      seed: null,
+    };
+    const wrappedInvoke = (window as any).__TAURI__.core.invoke;
+    await expect(wrappedInvoke('save_note', { body: 'hi' })).rejects.toEqual({ code: 'OFFLINE' });
+    await expect(wrappedInvoke('mocked_command')).rejects.toEqual({ code: 'OFFLINE' });
+    expect(await wrappedInvoke('load_notes')).toBe('real-result');
+    expect(await wrappedInvoke('plugin:wdio|get_logs')).toBe('real-result');
+
+    expect(originalInvoke).not.toHaveBeenCalledWith('save_note', expect.anything());
+    expect((window as any).__wdio_mocks__.mocked_command).not.toHaveBeenCalled();
+  });
+
+  it('should repeat the same failures for the same seed', async () => {
+    vi.resetModules();
+    (window as any).__TAURI__ = createTauriMock(vi.fn().mockResolvedValue('real-result'));
+
+    const mod = await import('../index.js');
+    await mod.init();
+
+    const wrappedInvoke = (window as any).__TAURI__.core.invoke;
+    const outcomes = async (seed: number) => {
+      (window as any).__wdio_invoke_conditions__ = {
+        latency_ms: 0,
+        jitter_ms: 0,
+        failure_rate: 0.5,
+        failure_error: null,
+        command_filter: null,
+        seed,
+      };
+      const results = [];
+      for (let i = 0; i < 20; i++) {
+        results.push(await wrappedInvoke('flaky_command').then(() => 'ok', (error: unknown) => error));
+      }
+      return results;
+    };
+
+    const first = await outcomes(42);
+    expect(await outcomes(42)).toEqual(first);
+    expect(await outcomes(7)).not.toEqual(first);
+    expect(first).toContain('ok');
+    expect(first).toContain('Injected invoke failure: flaky_command');
+  });
+
+  it('should delay matching invokes by the latency', async () => {
+    vi.resetModules();
+    vi.useFakeTimers();
+    const originalInvoke = vi.fn().mockResolvedValue('real-result');
+    (window as any).__TAURI__ = createTauriMock(originalInvoke);
+
+    const mod = await import('../index.js');
+    await mod.init();
+
+    (window as any).__wdio_invoke_conditions__ = {
+      latency_ms: 1000,
+      jitter_ms: 0,
+      failure_rate: 0,
+      failure_error: null,
+      command_filter: null,
+      seed: null,
+    };
+    const result = (window as any).__TAURI__.core.invoke('slow_command');
+    await vi.advanceTimersByTimeAsync(999);
+    expect(originalInvoke).not.toHaveBeenCalledWith('slow_command', undefined);
+
+    await vi.advanceTimersByTimeAsync(1);
+    expect(await result).toBe('real-result');
+    vi.useRealTimers();
+  });
+
+  it('should report the results of non-mocked invokes while recording', async () => {
+    vi.resetModules();
+    const originalInvoke = vi.fn(async (cmd: string) => {
+      if (cmd === 'delete_note') {
+        throw new Error('note not found');
+      }
+      return cmd === 'load_note' ? { body: 'hi' } : null;
+    });
+    (window as any).__TAURI__ = createTauriMock(originalInvoke);
+    (window as any).__wdio_mocks__ = { mocked_command: vi.fn().mockResolvedValue('mocked-result') };
+
+    const mod = await import('../index.js');
+    await mod.init();
+
+    (window as any).__wdio_invoke_replay__ = 'record';
+    const wrappedInvoke = (window as any).__TAURI__.core.invoke;
+    expect(await wrappedInvoke('load_note', { id: 1 })).toEqual({ body: 'hi' });
+    await expect(wrappedInvoke('delete_note')).rejects.toThrow('note not found');
+    await wrappedInvoke('mocked_command');
+
+    const reports = originalInvoke.mock.calls.filter(([cmd]) => cmd === 'plugin:wdio|record_invocation');
+    expect(reports.map(([, args]) => args)).toEqual([
+      { command: 'load_note', args: { id: 1 }, outcome: 'ok', result: { body: 'hi' } },
+      { command: 'delete_note', args: {}, outcome: 'error', result: 'note not found' },
+    ]);
+  });
+
+  it('should answer invokes from the recording while replaying', async () => {
+    vi.resetModules();
+    const originalInvoke = vi.fn(async (cmd: string, args?: any) => {
+      if (cmd === 'plugin:wdio|replay_invocation') {
+        switch (args.command) {
+          case 'load_note':
+            return { command: 'load_note', args_hash: '', outcome: 'ok', result: 'recorded' };
+          case 'delete_note':
+            return { command: 'delete_note', args_hash: '', outcome: 'error', result: 'not found' };
+          default:
+            return null;
+        }
+      }
+      return 'real-result';
+    });
+    (window as any).__TAURI__ = createTauriMock(originalInvoke);
+
+    const mod = await import('../index.js');
+    await mod.init();
+
+    (window as any).__wdio_invoke_replay__ = 'replay';
+    const wrappedInvoke = (window as any).__TAURI__.core.invoke;
+    expect(await wrappedInvoke('load_note', { id: 1 })).toBe('recorded');
+    await expect(wrappedInvoke('delete_note')).rejects.toBe('not found');
+    expect(await wrappedInvoke('unrecorded')).toBe('real-result');
+
+    expect(originalInvoke).not.toHaveBeenCalledWith('load_note', expect.anything());
+    expect(originalInvoke).toHaveBeenCalledWith('unrecorded', undefined);
+  });
+
   it('should retry when window.__TAURI__.core is not immediately available', async () => {
     vi.resetModules();
     vi.useFakeTimers();
@@ -76,6 +76,18 @@ declare global {
     };
     __wdio_spy__?: typeof nativeSpy;
     __wdio_mocks__?: Record<string, unknown>;
+    /** Set by the backend while invoke tracing is on */
+    __wdio_invoke_tracing__?: { argsMaxBytes: number } | null;
+    /** Set by the backend while invoke conditions are active */
+    __wdio_invoke_conditions__?: InvokeConditions | null;
+    /** Set by the backend while invokes are recorded or replayed */
+    __wdio_invoke_replay__?: 'record' | 'replay' | null;
+    /** Always injected by Tauri, whether or not withGlobalTauri exposes window.__TAURI__ */
+    __TAURI_INTERNALS__?: {
+      invoke?: (cmd: string, args?: InvokeArgs) => Promise<unknown>;
+    };
+    /** Defined by the plugin's init script; reports execute results */
+    __WDIO_EMIT__?: (id: string, report: unknown, viaEvent: boolean) => Promise<unknown>;
     __wdio_original_tauri__?: Window['__TAURI__'];
     __wdio_original_core__?: NonNullable<Window['__TAURI__']>['core'];
   }
@@ -143,8 +155,10 @@ interface ExecuteOptions {
  * @returns Result of the script execution
  */
 export async function execute(script: string, options?: ExecuteOptions, argsJson?: string): Promise<unknown> {
-  if (!window.__TAURI__) {
-    throw new Error('window.__TAURI__ is not available. Make sure withGlobalTauri is enabled in tauri.conf.json');
+  if (!window.__TAURI__ && !window.__wdio_original_core__) {
+    throw new Error(
+      'Tauri IPC is not available. Make sure withGlobalTauri is enabled in tauri.conf.json or the page is served by Tauri',
+    );
   }
 
   const trimmed = script.trim();
@@ -219,8 +233,22 @@ export async function execute(script: string, options?: ExecuteOptions, argsJson
     } as InvokeArgs);
     return result;
   } catch (error) {
-    throw new Error(`Failed to execute script: ${error instanceof Error ? error.message : String(error)}`);
+    throw new Error(`Failed to execute script: ${errorMessage(error)}`);
+  }
+}
+
+/**
+ * Message of an invoke rejection. Plugin commands reject with
+ * `{ code, message, details }` rather than an Error instance.
+ */
+function errorMessage(error: unknown): string {
+  if (error instanceof Error) {
+    return error.message;
+  }
+  if (typeof error === 'object' && error !== null && typeof (error as { message?: unknown }).message === 'string') {
+    return (error as { message: string }).message;
   }
+  return String(error);
 }
 
 // NOTE: Mock commands (setMock, getMock, clearMocks, resetMocks, restoreMocks) removed.
@@ -307,6 +335,12 @@ function setupConsoleForwarding(): void {
     return;
   }
 
+  // The Rust plugin injects its own console shim (and the service may inject a wrapper)
+  // that forwards to log_frontend. Wrapping again would forward every message twice.
+  if ((console as unknown as Record<symbol, unknown>)[Symbol.for('wdio:console-wrapped')]) {
+    return;
+  }
+
   // Helper function to safely forward to Tauri log plugin or WDIO plugin
   // Uses window.__TAURI__.log if available (requires tauri-plugin-log)
   // Falls back to WDIO plugin's log_frontend command (writes directly to stderr)
@@ -426,8 +460,205 @@ function setupConsoleForwarding(): void {
   }
 }
 
+const NOTIFY_COMMAND = 'plugin:notification|notify';
+
+/**
+ * Offer a notify request to the backend's notification capture.
+ * Resolves true when it was recorded and must not be shown; any failure
+ * (e.g. the capture permission is not granted) lets the notification through.
+ */
+async function captureNotification(
+  invoke: (cmd: string, args?: InvokeArgs) => Promise<unknown>,
+  args?: InvokeArgs,
+): Promise<boolean> {
+  const options = (args as { options?: unknown } | undefined)?.options ?? args ?? {};
+  try {
+    return (await invoke('plugin:wdio|capture_notification', { options } as InvokeArgs)) === true;
+  } catch (error) {
+    console.warn('[WDIO Tauri Plugin] Notification capture check failed, passing notification through:', error);
+    return false;
+  }
+}
+
+const RECORD_INVOKE_COMMAND = 'plugin:wdio|record_invoke';
+
+/** Plumbing of the plugin itself, left out of the invoke trace */
+const UNTRACED_COMMANDS = new Set([RECORD_INVOKE_COMMAND, 'plugin:wdio|resolve_execute']);
+
+type InvokeOutcome = 'ok' | 'error' | 'mocked';
+
 /**
- * Setup invoke interception for mocking
+ * Start timing an invoke for the backend's invoke trace. Returns null while
+ * tracing is off; otherwise a function that reports the call once it settles.
+ * Reporting is fire-and-forget so a failure can't affect the traced call.
+ */
+function beginInvokeTrace(
+  invoke: (cmd: string, args?: InvokeArgs) => Promise<unknown>,
+  cmd: string,
+  args?: InvokeArgs,
+): ((outcome: InvokeOutcome) => void) | null {
+  const tracing = window.__wdio_invoke_tracing__;
+  if (!tracing || UNTRACED_COMMANDS.has(cmd)) {
+    return null;
+  }
+  const startedAt = Date.now();
+  const start = performance.now();
+  const { summary, truncated } = summarizeArgs(args, tracing.argsMaxBytes);
+  return (outcome) => {
+    const entry = {
+      command: cmd,
+      args_summary: summary,
+      args_truncated: truncated,
+      started_at_ms: startedAt,
+      duration_ms: performance.now() - start,
+      outcome,
+    };
+    invoke(RECORD_INVOKE_COMMAND, { entry } as InvokeArgs).catch(() => {
+      // Tracing is best-effort
+    });
+  };
+}
+
+/**
+ * JSON of the invoke args, cut to the trace budget so large payloads don't
+ * cross IPC twice. The backend enforces the budget in bytes.
+ */
+function summarizeArgs(args: InvokeArgs | undefined, maxBytes: number): { summary: string; truncated: boolean } {
+  let summary: string;
+  try {
+    summary = JSON.stringify(args ?? null) ?? 'undefined';
+  } catch {
+    summary = String(args);
+  }
+  return summary.length > maxBytes
+    ? { summary: summary.slice(0, maxBytes), truncated: true }
+    : { summary, truncated: false };
+}
+
+/** Latency and failures set with set_invoke_conditions, as the backend serializes them */
+type InvokeConditions = {
+  latency_ms: number;
+  jitter_ms: number;
+  failure_rate: number;
+  failure_error: unknown;
+  command_filter: string[] | null;
+  seed: number | null;
+};
+
+/** The plugin's own commands, which invoke conditions never apply to */
+const WDIO_COMMAND_PREFIX = 'plugin:wdio|';
+
+/**
+ * Draw source per conditions object. The backend sets a new object each time,
+ * so a seeded sequence restarts whenever the conditions are set again.
+ */
+const conditionDraws = new WeakMap<InvokeConditions, () => number>();
+
+/** mulberry32: small, fast, and the same sequence for a seed in every engine */
+function seededRandom(seed: number): () => number {
+  let state = seed >>> 0;
+  return () => {
+    state = (state + 0x6d2b79f5) >>> 0;
+    let t = state;
+    t = Math.imul(t ^ (t >>> 15), t | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+}
+
+/**
+ * Apply the backend's invoke conditions to `cmd` before it is dispatched:
+ * wait out the latency and jitter, then reject at the failure rate. The
+ * plugin's own commands are exempt so tests can still drive the app and
+ * clear the conditions.
+ */
+async function applyInvokeConditions(cmd: string): Promise<void> {
+  const conditions = window.__wdio_invoke_conditions__;
+  if (
+    !conditions ||
+    cmd.startsWith(WDIO_COMMAND_PREFIX) ||
+    (conditions.command_filter && !conditions.command_filter.includes(cmd))
+  ) {
+    return;
+  }
+  let draw = conditionDraws.get(conditions);
+  if (!draw) {
+    draw = conditions.seed === null ? Math.random : seededRandom(conditions.seed);
+    conditionDraws.set(conditions, draw);
+  }
+  const jitter = conditions.jitter_ms > 0 ? Math.floor(draw() * (conditions.jitter_ms + 1)) : 0;
+  const delay = conditions.latency_ms + jitter;
+  if (delay > 0) {
+    await new Promise((resolve) => setTimeout(resolve, delay));
+  }
+  if (draw() < conditions.failure_rate) {
+    throw conditions.failure_error ?? `Injected invoke failure: ${cmd}`;
+  }
+}
+
+/** A recorded invoke result, as returned by `replay_invocation` */
+type RecordedInvocation = {
+  command: string;
+  args_hash: string;
+  outcome: 'ok' | 'error';
+  result: unknown;
+};
+
+/**
+ * Call `cmd` through `callBase`, recording its result or answering it from a
+ * recording when the backend has switched replay on. The plugin's own
+ * commands are exempt so tests can still drive the app and stop recording.
+ */
+async function invokeWithReplay(
+  callBase: (cmd: string, args?: InvokeArgs) => Promise<unknown>,
+  cmd: string,
+  args?: InvokeArgs,
+): Promise<unknown> {
+  const mode = window.__wdio_invoke_replay__;
+  if (!mode || cmd.startsWith(WDIO_COMMAND_PREFIX)) {
+    return callBase(cmd, args);
+  }
+  const recordedArgs = args ?? {};
+
+  if (mode === 'replay') {
+    const recorded = (await callBase(`${WDIO_COMMAND_PREFIX}replay_invocation`, {
+      command: cmd,
+      args: recordedArgs,
+    })) as RecordedInvocation | null;
+    if (recorded) {
+      if (recorded.outcome === 'error') {
+        throw recorded.result;
+      }
+      return recorded.result;
+    }
+    return callBase(cmd, args);
+  }
+
+  let outcome: 'ok' | 'error' = 'ok';
+  let result: unknown;
+  try {
+    result = await callBase(cmd, args);
+    return result;
+  } catch (error) {
+    outcome = 'error';
+    result = error instanceof Error ? error.message : error;
+    throw error;
+  } finally {
+    try {
+      await callBase(`${WDIO_COMMAND_PREFIX}record_invocation`, {
+        command: cmd,
+        args: recordedArgs,
+        outcome,
+        result: result ?? null,
+      });
+    } catch (error) {
+      console.warn(`[WDIO Tauri Plugin] Failed to record invoke of '${cmd}':`, error);
+    }
+  }
+}
+
+/**
+ * Setup invoke interception for mocking and notification capture
  * This wraps window.__TAURI__.core.invoke to check for mocks before calling the real implementation
  * Retries until window.__TAURI__.core.invoke is available (with timeout)
  */
@@ -478,23 +709,7 @@ function setupInvokeInterception(): void {
         ? (core as { invoke: (...args: unknown[]) => Promise<unknown> }).invoke.bind(core)
         : null;
 
-    // Create a wrapped invoke function that always delegates to _baseInvoke for non-mocked commands
-    const wrappedInvoke = async (cmd: string, args?: InvokeArgs): Promise<unknown> => {
-      // Check if there's a mock for this command
-      const mockFn = window.__wdio_mocks__?.[cmd];
-
-      if (mockFn && typeof mockFn === 'function') {
-        console.log(`[WDIO Tauri Plugin] Intercepted invoke for '${cmd}' - using mock`);
-        try {
-          const result = await (mockFn as (args: unknown) => Promise<unknown>)(args);
-          return result;
-        } catch (error) {
-          console.error(`[WDIO Tauri Plugin] Mock error for '${cmd}':`, error);
-          throw error;
-        }
-      }
-
-      // No mock found, call the base invoke if available
+    const callBase = async (cmd: string, args?: InvokeArgs): Promise<unknown> => {
       if (_baseInvoke) {
         return _baseInvoke(cmd, args);
       }
@@ -508,6 +723,46 @@ function setupInvokeInterception(): void {
       }
     };
 
+    // Create a wrapped invoke function that always delegates to _baseInvoke for non-mocked commands
+    const wrappedInvoke = async (cmd: string, args?: InvokeArgs): Promise<unknown> => {
+      const finishTrace = beginInvokeTrace(callBase, cmd, args);
+      let outcome: InvokeOutcome = 'ok';
+      try {
+        await applyInvokeConditions(cmd);
+
+        // A captured notification is recorded instead of reaching mocks or the notification plugin
+        if (cmd === NOTIFY_COMMAND && (await captureNotification(callBase, args))) {
+          return null;
+        }
+
+        // Check if there's a mock for this command
+        const mockFn = window.__wdio_mocks__?.[cmd];
+
+        if (mockFn && typeof mockFn === 'function') {
+          console.log(`[WDIO Tauri Plugin] Intercepted invoke for '${cmd}' - using mock`);
+          outcome = 'mocked';
+          try {
+            const result = await (mockFn as (args: unknown) => Promise<unknown>)(args);
+            return result;
+          } catch (error) {
+            console.error(`[WDIO Tauri Plugin] Mock error for '${cmd}':`, error);
+            throw error;
+          }
+        }
+
+        // No mock found, call the base invoke (or its recorded result)
+        return await invokeWithReplay(callBase, cmd, args);
+      } catch (error) {
+        // A rejecting mock still counts as mocked: the backend was never reached
+        if (outcome === 'ok') {
+          outcome = 'error';
+        }
+        throw error;
+      } finally {
+        finishTrace?.(outcome);
+      }
+    };
+
     // Strategy 1: getter/setter via Object.defineProperty (works on Linux/WebKitGTK)
     try {
       Object.defineProperty(core, 'invoke', {
@@ -700,6 +955,10 @@ export async function init(): Promise<void> {
   if (window.__TAURI__?.core) {
     window.__wdio_original_tauri__ = window.__TAURI__;
     window.__wdio_original_core__ = window.__TAURI__.core;
+  } else if (window.__TAURI_INTERNALS__?.invoke) {
+    // withGlobalTauri is off: execute still reaches commands through Tauri's internal
+    // IPC, but invoke mocking needs window.__TAURI__.core to intercept
+    window.__wdio_original_core__ = { invoke: window.__TAURI_INTERNALS__.invoke.bind(window.__TAURI_INTERNALS__) };
   }
 
   // Setup invoke interception for mocking support
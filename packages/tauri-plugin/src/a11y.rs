@@ -0,0 +1,343 @@
+// The tree walk is only driven by a platform backend, which is compiled in
+// with the `a11y` feature on Linux; elsewhere only the tests use it
+#![cfg_attr(not(all(feature = "a11y", target_os = "linux")), allow(dead_code))]
+
+use crate::models::AccessibilityNode;
+use crate::{Error, Result};
+
+/// Levels below the window walked when the caller doesn't say
+pub(crate) const DEFAULT_MAX_DEPTH: u32 = 10;
+
+/// Nodes walked at most, so a pathological tree can't stall the command
+const MAX_NODES: usize = 5_000;
+
+/// Roles of the web content, whose children aren't walked: WebDriver already
+/// sees the DOM, and it would dwarf the native chrome
+const WEB_CONTENT_ROLES: &[&str] = &["document web"];
+
+/// Roles of the unnamed layout containers toolkits nest widgets in; one with
+/// a single child is replaced by that child
+const LAYOUT_ROLES: &[&str] = &["filler", "panel"];
+
+/// A platform's accessibility tree, read node by node
+pub(crate) trait AccessibleSource {
+    type Node;
+
+    fn role(&self, node: &Self::Node) -> Result<String>;
+    fn name(&self, node: &Self::Node) -> Result<String>;
+    fn states(&self, node: &Self::Node) -> Result<Vec<String>>;
+    fn children(&self, node: &Self::Node) -> Result<Vec<Self::Node>>;
+}
+
+/// Snapshot the accessibility tree of the app's native window titled `title`,
+/// `max_depth` levels deep
+#[cfg(all(feature = "a11y", target_os = "linux"))]
+pub(crate) fn snapshot(title: &str, max_depth: u32) -> Result<AccessibilityNode> {
+    let atspi = atspi::Atspi::connect()?;
+    let window = atspi.window(title)?;
+    walk(&atspi, &window, max_depth)
+}
+
+#[cfg(all(feature = "a11y", not(target_os = "linux")))]
+pub(crate) fn snapshot(_title: &str, _max_depth: u32) -> Result<AccessibilityNode> {
+    Err(Error::UnsupportedPlatform(std::env::consts::OS.to_string()))
+}
+
+#[cfg(not(feature = "a11y"))]
+pub(crate) fn snapshot(_title: &str, _max_depth: u32) -> Result<AccessibilityNode> {
+    Err(Error::UnsupportedInBuild(
+        "the native accessibility tree requires the tauri-plugin-wdio `a11y` feature".to_string(),
+    ))
+}
+
+/// Walk the tree under `root`, pruned to `max_depth` levels, without the web
+/// content's subtree, and with single-child layout containers collapsed.
+/// Children that can't be read (e.g. closed while walking) are skipped.
+pub(crate) fn walk<S: AccessibleSource>(source: &S, root: &S::Node, max_depth: u32) -> Result<AccessibilityNode> {
+    let mut budget = MAX_NODES;
+    walk_node(source, root, 0, max_depth, &mut budget)
+}
+
+fn walk_node<S: AccessibleSource>(
+    source: &S,
+    node: &S::Node,
+    depth: u32,
+    max_depth: u32,
+    budget: &mut usize,
+) -> Result<AccessibilityNode> {
+    *budget = budget.saturating_sub(1);
+    let role = source.role(node)?;
+    let name = source.name(node).unwrap_or_default();
+    let children = source.children(node).unwrap_or_default();
+
+    if depth > 0 && name.is_empty() && children.len() == 1 && LAYOUT_ROLES.contains(&role.as_str()) {
+        return walk_node(source, &children[0], depth, max_depth, budget);
+    }
+
+    let mut walked = Vec::new();
+    if depth < max_depth && !WEB_CONTENT_ROLES.contains(&role.as_str()) {
+        for child in &children {
+            if *budget == 0 {
+                break;
+            }
+            if let Ok(child) = walk_node(source, child, depth + 1, max_depth, budget) {
+                walked.push(child);
+            }
+        }
+    }
+    Ok(AccessibilityNode {
+        role,
+        name: Some(name).filter(|name| !name.is_empty()),
+        states: source.states(node).unwrap_or_default(),
+        child_count: children.len(),
+        children: walked,
+    })
+}
+
+/// AT-SPI state names, by bit position in the state set
+const ATSPI_STATES: &[&str] = &[
+    "invalid",
+    "active",
+    "armed",
+    "busy",
+    "checked",
+    "collapsed",
+    "defunct",
+    "editable",
+    "enabled",
+    "expandable",
+    "expanded",
+    "focusable",
+    "focused",
+    "has tooltip",
+    "horizontal",
+    "iconified",
+    "modal",
+    "multi line",
+    "multiselectable",
+    "opaque",
+    "pressed",
+    "resizable",
+    "selectable",
+    "selected",
+    "sensitive",
+    "showing",
+    "single line",
+    "stale",
+    "transient",
+    "vertical",
+    "visible",
+    "manages descendants",
+    "indeterminate",
+    "required",
+    "truncated",
+    "animated",
+    "invalid entry",
+    "supports autocompletion",
+    "selectable text",
+    "is default",
+    "visited",
+    "checkable",
+    "has popup",
+    "read only",
+];
+
+/// Names of the states set in an AT-SPI state set, sent as 32-bit words
+pub(crate) fn atspi_state_names(words: &[u32]) -> Vec<String> {
+    ATSPI_STATES
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| words.get(bit / 32).is_some_and(|word| word & (1 << (bit % 32)) != 0))
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+#[cfg(all(feature = "a11y", target_os = "linux"))]
+mod atspi {
+    use std::time::Duration;
+
+    use dbus::blocking::stdintf::org_freedesktop_dbus::Properties;
+    use dbus::blocking::Connection;
+    use dbus::channel::Channel;
+    use dbus::Path;
+
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(2);
+    const ACCESSIBLE: &str = "org.a11y.atspi.Accessible";
+
+    /// An accessible object: the bus name of the app exposing it, and its path
+    pub(crate) type Ref = (String, Path<'static>);
+
+    /// The AT-SPI accessibility bus, which GTK apps (and WebKitGTK's webview)
+    /// expose their widgets on
+    pub(crate) struct Atspi {
+        conn: Connection,
+    }
+
+    fn bus_error(e: dbus::Error) -> Error {
+        Error::AccessibilityError(e.message().unwrap_or("D-Bus call failed").to_string())
+    }
+
+    impl Atspi {
+        pub(crate) fn connect() -> Result<Self> {
+            let session = Connection::new_session().map_err(bus_error)?;
+            let (address,): (String,) = session
+                .with_proxy("org.a11y.Bus", "/org/a11y/bus", TIMEOUT)
+                .method_call("org.a11y.Bus", "GetAddress", ())
+                .map_err(bus_error)?;
+            let mut channel = Channel::open_private(&address).map_err(bus_error)?;
+            channel.register().map_err(bus_error)?;
+            Ok(Self {
+                conn: Connection::from(channel),
+            })
+        }
+
+        fn call<T: dbus::arg::ReadAll>(&self, node: &Ref, method: &str) -> Result<T> {
+            self.conn
+                .with_proxy(node.0.as_str(), node.1.clone(), TIMEOUT)
+                .method_call(ACCESSIBLE, method, ())
+                .map_err(bus_error)
+        }
+
+        /// This process's entry among the apps registered with AT-SPI
+        fn application(&self) -> Result<Ref> {
+            let registry = ("org.a11y.atspi.Registry".to_string(), Path::from("/org/a11y/atspi/accessible/root"));
+            let bus = self.conn.with_proxy("org.freedesktop.DBus", "/org/freedesktop/DBus", TIMEOUT);
+            let pid = std::process::id();
+            self.children(&registry)?
+                .into_iter()
+                .find(|(bus_name, _)| {
+                    bus.method_call("org.freedesktop.DBus", "GetConnectionUnixProcessID", (bus_name.as_str(),))
+                        .is_ok_and(|(app_pid,): (u32,)| app_pid == pid)
+                })
+                .ok_or_else(|| {
+                    Error::AccessibilityError(
+                        "the app isn't registered with AT-SPI; is the accessibility bus running?".to_string(),
+                    )
+                })
+        }
+
+        /// The app's top-level window titled `title`
+        pub(crate) fn window(&self, title: &str) -> Result<Ref> {
+            let application = self.application()?;
+            self.children(&application)?
+                .into_iter()
+                .find(|window| self.name(window).is_ok_and(|name| name == title))
+                .ok_or_else(|| Error::AccessibilityError(format!("no accessible window titled '{}'", title)))
+        }
+    }
+
+    impl AccessibleSource for Atspi {
+        type Node = Ref;
+
+        fn role(&self, node: &Ref) -> Result<String> {
+            self.call::<(String,)>(node, "GetRoleName").map(|(role,)| role)
+        }
+
+        fn name(&self, node: &Ref) -> Result<String> {
+            self.conn
+                .with_proxy(node.0.as_str(), node.1.clone(), TIMEOUT)
+                .get(ACCESSIBLE, "Name")
+                .map_err(bus_error)
+        }
+
+        fn states(&self, node: &Ref) -> Result<Vec<String>> {
+            self.call::<(Vec<u32>,)>(node, "GetState").map(|(words,)| atspi_state_names(&words))
+        }
+
+        fn children(&self, node: &Ref) -> Result<Vec<Ref>> {
+            self.call::<(Vec<Ref>,)>(node, "GetChildren").map(|(children,)| children)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    /// Tree of `id -> (role, name, children)`
+    struct FakeTree(HashMap<u32, (&'static str, &'static str, Vec<u32>)>);
+
+    impl AccessibleSource for FakeTree {
+        type Node = u32;
+
+        fn role(&self, node: &u32) -> Result<String> {
+            self.0
+                .get(node)
+                .map(|(role, _, _)| role.to_string())
+                .ok_or_else(|| Error::AccessibilityError("defunct".to_string()))
+        }
+
+        fn name(&self, node: &u32) -> Result<String> {
+            Ok(self.0.get(node).map_or("", |(_, name, _)| name).to_string())
+        }
+
+        fn states(&self, node: &u32) -> Result<Vec<String>> {
+            Ok(if *node == 1 { vec!["active".to_string()] } else { Vec::new() })
+        }
+
+        fn children(&self, node: &u32) -> Result<Vec<u32>> {
+            Ok(self.0.get(node).map(|(_, _, children)| children.clone()).unwrap_or_default())
+        }
+    }
+
+    fn window() -> FakeTree {
+        FakeTree(HashMap::from([
+            (1, ("frame", "Notes", vec![2, 9])),
+            (2, ("filler", "", vec![3])),
+            (3, ("panel", "", vec![4, 6, 99])),
+            (4, ("menu bar", "", vec![5])),
+            (5, ("menu", "File", vec![7])),
+            (7, ("menu item", "Quit", vec![])),
+            (6, ("document web", "Notes", vec![8])),
+            (8, ("paragraph", "", vec![])),
+            (9, ("push button", "Close", vec![])),
+        ]))
+    }
+
+    fn shape(node: &AccessibilityNode) -> String {
+        let label = format!("{}:{}", node.role, node.name.as_deref().unwrap_or(""));
+        if node.children.is_empty() {
+            return label;
+        }
+        let children: Vec<String> = node.children.iter().map(shape).collect();
+        format!("{}[{}]", label, children.join(","))
+    }
+
+    #[test]
+    fn test_walk_collapses_layout_and_skips_web_content() {
+        let tree = walk(&window(), &1, DEFAULT_MAX_DEPTH).unwrap();
+
+        assert_eq!(
+            shape(&tree),
+            "frame:Notes[panel:[menu bar:[menu:File[menu item:Quit]],document web:Notes],push button:Close]"
+        );
+        assert_eq!(tree.states, vec!["active"]);
+        assert_eq!(tree.name.as_deref(), Some("Notes"));
+        // The panel lists a child that went away while walking
+        assert_eq!(tree.children[0].child_count, 3);
+        assert_eq!(tree.children[0].children[1].child_count, 1);
+        assert!(tree.children[0].children[1].children.is_empty());
+    }
+
+    #[test]
+    fn test_walk_stops_at_max_depth() {
+        let tree = walk(&window(), &1, 2).unwrap();
+
+        assert_eq!(shape(&tree), "frame:Notes[panel:[menu bar:,document web:Notes],push button:Close]");
+        assert_eq!(tree.children[0].children[0].child_count, 1);
+        assert_eq!(shape(&walk(&window(), &1, 0).unwrap()), "frame:Notes");
+    }
+
+    #[test]
+    fn test_decodes_atspi_state_sets() {
+        // active (1), enabled (8), focused (12); visible (30); read only (43)
+        let words = [(1 << 1) | (1 << 8) | (1 << 12) | (1 << 30), 1 << (43 - 32)];
+
+        assert_eq!(atspi_state_names(&words), vec!["active", "enabled", "focused", "visible", "read only"]);
+        assert!(atspi_state_names(&[]).is_empty());
+    }
+}
@@ -0,0 +1,79 @@
+use tauri::{Manager, Runtime, Theme};
+
+use crate::models::{AppTheme, SystemAppearance};
+use crate::{Error, Result};
+
+/// Locale variables in POSIX precedence order
+const LOCALE_VARS: &[&str] = &["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// Theme, locale, and timezone as seen from `window`. The theme is the
+/// window's effective one, so it reflects set_app_theme.
+pub(crate) fn system_appearance<R: Runtime>(window: &tauri::WebviewWindow<R>) -> Result<SystemAppearance> {
+    Ok(SystemAppearance {
+        theme: window.theme()?.into(),
+        locale: locale_from_env(|name| std::env::var(name).ok()),
+        timezone: iana_time_zone::get_timezone().ok(),
+    })
+}
+
+/// Force every window to `theme`, or let them follow the OS again with `None`
+pub(crate) fn set_app_theme<R: Runtime>(app: &tauri::AppHandle<R>, theme: Option<AppTheme>) -> Result<()> {
+    if cfg!(mobile) {
+        return Err(Error::UnsupportedPlatform(std::env::consts::OS.to_string()));
+    }
+    for window in app.webview_windows().values() {
+        window.set_theme(theme.map(Into::into))?;
+    }
+    Ok(())
+}
+
+/// BCP 47 tag for the first set locale variable, e.g. `en_US.UTF-8` ->
+/// `en-US`. The `C` and `POSIX` locales name no language, so they give `None`.
+fn locale_from_env(env: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let raw = LOCALE_VARS.iter().filter_map(|name| env(name)).find(|value| !value.trim().is_empty())?;
+    let tag = raw.trim().split(['.', '@']).next().unwrap_or_default().replace('_', "-");
+    match tag.as_str() {
+        "" | "C" | "POSIX" => None,
+        _ => Some(tag),
+    }
+}
+
+impl From<Theme> for AppTheme {
+    fn from(theme: Theme) -> Self {
+        match theme {
+            Theme::Dark => AppTheme::Dark,
+            _ => AppTheme::Light,
+        }
+    }
+}
+
+impl From<AppTheme> for Theme {
+    fn from(theme: AppTheme) -> Self {
+        match theme {
+            AppTheme::Light => Theme::Light,
+            AppTheme::Dark => Theme::Dark,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn locale(vars: &[(&str, &str)]) -> Option<String> {
+        let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+        locale_from_env(|name| vars.get(name).map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn test_locale_from_env() {
+        assert_eq!(locale(&[("LANG", "en_US.UTF-8")]).as_deref(), Some("en-US"));
+        assert_eq!(locale(&[("LANG", "en_US.UTF-8"), ("LC_ALL", "de_DE@euro")]).as_deref(), Some("de-DE"));
+        assert_eq!(locale(&[("LC_ALL", " "), ("LC_MESSAGES", "fr_CA"), ("LANG", "en_US")]).as_deref(), Some("fr-CA"));
+        assert_eq!(locale(&[("LANG", "C.UTF-8")]), None);
+        assert_eq!(locale(&[("LANG", "POSIX")]), None);
+        assert_eq!(locale(&[]), None);
+    }
+}
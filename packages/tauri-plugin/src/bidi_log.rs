@@ -0,0 +1,217 @@
+use std::cell::Cell;
+use std::sync::{Arc, OnceLock};
+
+use crate::log_subscriptions::{EventSink, ReentryGuard};
+use crate::models::{BidiLogEntry, BidiLogLevel, BidiSource, LogEntry, LogLevel, LogSource};
+
+/// Event each captured [`LogEntry`] is re-emitted as while `bidi_log_events` is on
+pub(crate) const BIDI_LOG_EVENT: &str = "wdio:bidi:log.entryAdded";
+
+/// Realms the entries are attributed to; the service maps them onto the
+/// webview's BiDi session
+const BACKEND_REALM: &str = "tauri:backend";
+const FRONTEND_REALM: &str = "tauri:frontend";
+
+thread_local! {
+    // Emitting an event can itself log; never re-emit from the same thread
+    static EMITTING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Re-emits captured log entries in the shape of WebDriver BiDi
+/// `log.entryAdded` events, so the service can forward them into the BiDi
+/// session it keeps for the webview
+#[derive(Clone)]
+pub(crate) struct BidiLogAdapter {
+    enabled: bool,
+    sink: Arc<OnceLock<EventSink>>,
+}
+
+impl BidiLogAdapter {
+    pub(crate) fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            sink: Arc::new(OnceLock::new()),
+        }
+    }
+
+    pub(crate) fn set_sink(&self, sink: EventSink) {
+        let _ = self.sink.set(sink);
+    }
+
+    pub(crate) fn dispatch(&self, entry: &LogEntry) {
+        if !self.enabled {
+            return;
+        }
+        let Some(sink) = self.sink.get() else {
+            return;
+        };
+        let Some(_guard) = ReentryGuard::enter(&EMITTING) else {
+            return;
+        };
+        if let Ok(payload) = serde_json::to_value(bidi_entry(entry)) {
+            sink(None, BIDI_LOG_EVENT, payload);
+        }
+    }
+}
+
+/// `entry` as a BiDi log entry: frontend entries are console entries with
+/// the text as their one argument, backend ones generic entries
+pub(crate) fn bidi_entry(entry: &LogEntry) -> BidiLogEntry {
+    let (entry_type, realm, method, args) = match entry.source {
+        LogSource::Frontend => (
+            "console",
+            FRONTEND_REALM,
+            Some(console_method(entry.level).to_string()),
+            Some(vec![
+                serde_json::json!({ "type": "string", "value": entry.message }),
+            ]),
+        ),
+        LogSource::Backend => ("generic", BACKEND_REALM, None, None),
+    };
+    BidiLogEntry {
+        entry_type: entry_type.to_string(),
+        level: bidi_level(entry.level),
+        source: BidiSource {
+            realm: realm.to_string(),
+            context: None,
+        },
+        text: Some(entry.message.clone()),
+        timestamp: entry.timestamp_ms,
+        method,
+        args,
+    }
+}
+
+/// BiDi has no trace level; trace entries are reported at debug
+fn bidi_level(level: LogLevel) -> BidiLogLevel {
+    match level {
+        LogLevel::Trace | LogLevel::Debug => BidiLogLevel::Debug,
+        LogLevel::Info => BidiLogLevel::Info,
+        LogLevel::Warn => BidiLogLevel::Warn,
+        LogLevel::Error => BidiLogLevel::Error,
+    }
+}
+
+/// Console method a frontend entry's level came from; the console shim
+/// captures `console.log` at trace
+fn console_method(level: LogLevel) -> &'static str {
+    match level {
+        LogLevel::Trace => "log",
+        LogLevel::Debug => "debug",
+        LogLevel::Info => "info",
+        LogLevel::Warn => "warn",
+        LogLevel::Error => "error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn entry(source: LogSource, level: LogLevel, message: &str) -> LogEntry {
+        LogEntry {
+            source,
+            level,
+            message: message.to_string(),
+            target: Some("my_app::commands".to_string()),
+            timestamp_ms: 1_767_225_600_123,
+            context: None,
+            session_id: None,
+        }
+    }
+
+    #[test]
+    fn test_maps_recorded_entries_to_the_bidi_schema() {
+        let backend = entry(LogSource::Backend, LogLevel::Trace, "opening database");
+        let frontend = entry(LogSource::Frontend, LogLevel::Warn, "slow render");
+
+        assert_eq!(
+            serde_json::to_value(bidi_entry(&backend)).unwrap(),
+            json!({
+                "type": "generic",
+                "level": "debug",
+                "source": { "realm": "tauri:backend" },
+                "text": "opening database",
+                "timestamp": 1_767_225_600_123_u64,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(bidi_entry(&frontend)).unwrap(),
+            json!({
+                "type": "console",
+                "level": "warn",
+                "source": { "realm": "tauri:frontend" },
+                "text": "slow render",
+                "timestamp": 1_767_225_600_123_u64,
+                "method": "warn",
+                "args": [{ "type": "string", "value": "slow render" }],
+            })
+        );
+    }
+
+    #[test]
+    fn test_maps_every_level() {
+        let levels: Vec<(BidiLogLevel, &str)> = [
+            LogLevel::Trace,
+            LogLevel::Debug,
+            LogLevel::Info,
+            LogLevel::Warn,
+            LogLevel::Error,
+        ]
+        .into_iter()
+        .map(|level| (bidi_level(level), console_method(level)))
+        .collect();
+
+        assert_eq!(
+            levels,
+            vec![
+                (BidiLogLevel::Debug, "log"),
+                (BidiLogLevel::Debug, "debug"),
+                (BidiLogLevel::Info, "info"),
+                (BidiLogLevel::Warn, "warn"),
+                (BidiLogLevel::Error, "error"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dispatches_only_when_enabled() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let adapters = [BidiLogAdapter::new(false), BidiLogAdapter::new(true)];
+        for adapter in &adapters {
+            let sent = sent.clone();
+            adapter.set_sink(Box::new(move |label, event, payload| {
+                assert_eq!((label, event), (None, BIDI_LOG_EVENT));
+                sent.lock().unwrap().push(payload["text"].clone());
+            }));
+            adapter.dispatch(&entry(LogSource::Backend, LogLevel::Info, "started"));
+        }
+
+        assert_eq!(*sent.lock().unwrap(), vec![json!("started")]);
+    }
+
+    #[test]
+    fn test_keeps_dispatching_after_a_panicking_sink() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let adapter = BidiLogAdapter::new(true);
+        let sink_sent = sent.clone();
+        adapter.set_sink(Box::new(move |_, _, payload| {
+            if payload["text"] == "boom" {
+                panic!("sink failed");
+            }
+            sink_sent.lock().unwrap().push(payload["text"].clone());
+        }));
+
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            adapter.dispatch(&entry(LogSource::Backend, LogLevel::Info, "boom"))
+        }));
+        adapter.dispatch(&entry(LogSource::Backend, LogLevel::Info, "after"));
+
+        assert!(panicked.is_err());
+        assert_eq!(*sent.lock().unwrap(), vec![json!("after")]);
+    }
+}
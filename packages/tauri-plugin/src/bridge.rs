@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::{Error, Result};
+
+/// Init script defining `window.__WDIO_EMIT__` in every page
+const BRIDGE_SCRIPT: &str = include_str!("js/bridge.js");
+
+/// resolve_execute id the bridge checks in under when a page loads
+pub(crate) const READY_ID: &str = "wdio-bridge-ready";
+
+/// The bridge init script, with its check-in id filled in
+pub(crate) fn script() -> String {
+    BRIDGE_SCRIPT.replace("__WDIO_BRIDGE_ID__", READY_ID)
+}
+
+/// How long a loaded page's check-in may trail its load event before
+/// execute gives up on the window
+pub(crate) const CHECK_IN_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Windows whose pages loaded the bridge and could reach the plugin with it
+#[derive(Default)]
+pub(crate) struct Bridges {
+    ready: Mutex<HashSet<String>>,
+}
+
+impl Bridges {
+    /// Record that a page in window `label` checked in
+    pub(crate) fn ready(&self, label: &str) {
+        self.ready.lock().unwrap_or_else(|e| e.into_inner()).insert(label.to_string());
+    }
+
+    /// Fail with [`Error::BridgeUnavailable`] if window `label` has finished
+    /// loading a page (`page_loaded`) without any page in it checking in, as
+    /// a script evaluated there couldn't report its result. Before the first
+    /// load finishes there's no telling yet, so the execution goes ahead.
+    pub(crate) fn check(&self, label: &str, page_loaded: bool) -> Result<()> {
+        let ready = self.ready.lock().unwrap_or_else(|e| e.into_inner()).contains(label);
+        if page_loaded && !ready {
+            Err(Error::BridgeUnavailable(label.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_checks_in_under_the_ready_id() {
+        let script = script();
+
+        assert!(script.contains("emit('wdio-bridge-ready', null, false)"));
+        assert!(!script.contains("__WDIO_BRIDGE_ID__"));
+    }
+
+    #[test]
+    fn test_check_fails_only_for_loaded_windows_that_never_checked_in() {
+        let bridges = Bridges::default();
+
+        assert!(bridges.check("main", false).is_ok());
+        assert!(matches!(bridges.check("main", true), Err(Error::BridgeUnavailable(ref label)) if label == "main"));
+
+        bridges.ready("main");
+        assert!(bridges.check("main", true).is_ok());
+        assert!(bridges.check("other", true).is_err());
+    }
+}
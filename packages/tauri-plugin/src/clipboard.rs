@@ -0,0 +1,207 @@
+use crate::Result;
+
+#[cfg(feature = "clipboard")]
+pub(crate) use system::Clipboard;
+
+#[cfg(not(feature = "clipboard"))]
+pub(crate) use unsupported::Clipboard;
+
+#[cfg(feature = "clipboard")]
+mod system {
+    use std::borrow::Cow;
+    use std::sync::Mutex;
+
+    use base64::Engine;
+
+    use super::*;
+    use crate::Error;
+
+    /// Reads and writes the system clipboard with arboard. The arboard handle is
+    /// kept for the app's lifetime: on Linux, clipboard contents set by a handle
+    /// disappear when it is dropped unless a clipboard manager takes them over.
+    pub(crate) struct Clipboard {
+        inner: Mutex<Option<arboard::Clipboard>>,
+    }
+
+    impl Clipboard {
+        pub(crate) fn new() -> Self {
+            Self {
+                inner: Mutex::new(None),
+            }
+        }
+
+        fn with<T>(&self, f: impl FnOnce(&mut arboard::Clipboard) -> Result<T>) -> Result<T> {
+            let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+            if inner.is_none() {
+                *inner = Some(arboard::Clipboard::new().map_err(clipboard_error)?);
+            }
+            f(inner.as_mut().expect("clipboard initialized above"))
+        }
+
+        /// Text on the clipboard, `None` when it is empty
+        pub(crate) fn text(&self) -> Result<Option<String>> {
+            self.with(|clipboard| match clipboard.get_text() {
+                Ok(text) => Ok(Some(text)),
+                // arboard reports both "empty" and "holds something else" as
+                // ContentNotAvailable, so look for an image to tell them apart
+                Err(arboard::Error::ContentNotAvailable) => match clipboard.get_image() {
+                    Ok(_) => Err(Error::ClipboardFormatMismatch(
+                        "clipboard holds an image, not text".to_string(),
+                    )),
+                    Err(_) => Ok(None),
+                },
+                Err(arboard::Error::ConversionFailure) => Err(Error::ClipboardFormatMismatch(
+                    "clipboard content is not valid text".to_string(),
+                )),
+                Err(e) => Err(clipboard_error(e)),
+            })
+        }
+
+        pub(crate) fn set_text(&self, text: String) -> Result<()> {
+            self.with(|clipboard| clipboard.set_text(text).map_err(clipboard_error))
+        }
+
+        /// Image on the clipboard as base64 PNG, `None` when it is empty
+        pub(crate) fn image(&self) -> Result<Option<String>> {
+            self.with(|clipboard| match clipboard.get_image() {
+                Ok(image) => {
+                    let png = encode_png(image.width, image.height, &image.bytes)?;
+                    Ok(Some(base64::engine::general_purpose::STANDARD.encode(png)))
+                }
+                Err(arboard::Error::ContentNotAvailable) => match clipboard.get_text() {
+                    Ok(_) => Err(Error::ClipboardFormatMismatch(
+                        "clipboard holds text, not an image".to_string(),
+                    )),
+                    Err(_) => Ok(None),
+                },
+                Err(arboard::Error::ConversionFailure) => Err(Error::ClipboardFormatMismatch(
+                    "clipboard image could not be converted".to_string(),
+                )),
+                Err(e) => Err(clipboard_error(e)),
+            })
+        }
+
+        /// Put a base64 PNG (optionally a `data:image/png;base64,` URL) on the clipboard
+        pub(crate) fn set_image(&self, data: &str) -> Result<()> {
+            let encoded = data.strip_prefix("data:image/png;base64,").unwrap_or(data);
+            let png = base64::engine::general_purpose::STANDARD
+                .decode(encoded.trim())
+                .map_err(|e| Error::ClipboardFormatMismatch(format!("invalid base64: {}", e)))?;
+            let (width, height, rgba) = decode_png(&png)?;
+            self.with(|clipboard| {
+                clipboard
+                    .set_image(arboard::ImageData {
+                        width,
+                        height,
+                        bytes: Cow::Owned(rgba),
+                    })
+                    .map_err(clipboard_error)
+            })
+        }
+    }
+
+    fn clipboard_error(e: arboard::Error) -> Error {
+        Error::ClipboardError(e.to_string())
+    }
+
+    fn encode_png(width: usize, height: usize, rgba: &[u8]) -> Result<Vec<u8>> {
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(rgba))
+            .map_err(|e| Error::ClipboardError(format!("failed to encode PNG: {}", e)))?;
+        Ok(png)
+    }
+
+    /// Decode a PNG into 8-bit RGBA, the only layout arboard accepts
+    fn decode_png(png: &[u8]) -> Result<(usize, usize, Vec<u8>)> {
+        let invalid = |e: png::DecodingError| Error::ClipboardFormatMismatch(format!("invalid PNG: {}", e));
+        let mut decoder = png::Decoder::new(png);
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().map_err(invalid)?;
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).map_err(invalid)?;
+        pixels.truncate(info.buffer_size());
+
+        let rgba = match info.color_type {
+            png::ColorType::Rgba => pixels,
+            png::ColorType::Rgb => pixels.chunks_exact(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect(),
+            png::ColorType::GrayscaleAlpha => pixels.chunks_exact(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+            png::ColorType::Grayscale => pixels.iter().flat_map(|&g| [g, g, g, 255]).collect(),
+            png::ColorType::Indexed => {
+                return Err(Error::ClipboardFormatMismatch(
+                    "indexed PNG was not expanded".to_string(),
+                ))
+            }
+        };
+        Ok((info.width as usize, info.height as usize, rgba))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_png_round_trip_preserves_rgba_pixels() {
+            let rgba = vec![255, 0, 0, 255, 0, 255, 0, 128, 0, 0, 255, 0, 10, 20, 30, 40];
+            let png = encode_png(2, 2, &rgba).unwrap();
+
+            assert_eq!(decode_png(&png).unwrap(), (2, 2, rgba));
+        }
+
+        #[test]
+        fn test_rgb_png_gains_opaque_alpha() {
+            let mut png = Vec::new();
+            let mut encoder = png::Encoder::new(&mut png, 1, 1);
+            encoder.set_color(png::ColorType::Rgb);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder.write_header().unwrap().write_image_data(&[1, 2, 3]).unwrap();
+
+            assert_eq!(decode_png(&png).unwrap(), (1, 1, vec![1, 2, 3, 255]));
+            assert!(matches!(
+                decode_png(b"not a png"),
+                Err(Error::ClipboardFormatMismatch(_))
+            ));
+        }
+    }
+}
+
+#[cfg(not(feature = "clipboard"))]
+mod unsupported {
+    use super::*;
+    use crate::Error;
+
+    /// Stand-in used when the `clipboard` feature is disabled
+    pub(crate) struct Clipboard;
+
+    fn unsupported<T>() -> Result<T> {
+        Err(Error::UnsupportedInBuild(
+            "clipboard access requires the tauri-plugin-wdio `clipboard` feature".to_string(),
+        ))
+    }
+
+    impl Clipboard {
+        pub(crate) fn new() -> Self {
+            Self
+        }
+
+        pub(crate) fn text(&self) -> Result<Option<String>> {
+            unsupported()
+        }
+
+        pub(crate) fn set_text(&self, _text: String) -> Result<()> {
+            unsupported()
+        }
+
+        pub(crate) fn image(&self) -> Result<Option<String>> {
+            unsupported()
+        }
+
+        pub(crate) fn set_image(&self, _data: &str) -> Result<()> {
+            unsupported()
+        }
+    }
+}
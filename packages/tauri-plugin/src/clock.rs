@@ -0,0 +1,167 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::sync::watch;
+
+use crate::log_buffer::now_ms;
+use crate::models::ClockStatus;
+
+/// Future returned by [`Clock::sleep`] and [`Clock::sleep_until`]
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Time source for app code that tests need to control, e.g. debounce
+/// windows, scheduled syncs, and token expiry. Read it through
+/// `app.wdio().clock()` instead of `SystemTime::now()` and `tokio::time::sleep`.
+pub trait Clock: Send + Sync {
+    /// Milliseconds since the Unix epoch
+    fn now_ms(&self) -> u64;
+
+    /// Resolve once the clock reads `deadline_ms` or later
+    fn sleep_until(&self, deadline_ms: u64) -> Sleep;
+
+    fn now(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.now_ms())
+    }
+
+    /// Resolve once `duration` has passed on this clock
+    fn sleep(&self, duration: Duration) -> Sleep {
+        self.sleep_until(self.now_ms().saturating_add(duration.as_millis() as u64))
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct ClockState {
+    frozen_at_ms: Option<u64>,
+    offset_ms: i64,
+}
+
+impl ClockState {
+    fn now_ms(&self) -> u64 {
+        self.frozen_at_ms
+            .unwrap_or_else(|| (now_ms() as i64).saturating_add(self.offset_ms).max(0) as u64)
+    }
+}
+
+/// Clock behind `Wdio::clock`. It follows real time until a test freezes or
+/// advances it; sleepers wake as soon as a change puts them past their deadline.
+pub struct TestClock {
+    state: watch::Sender<ClockState>,
+}
+
+impl Default for TestClock {
+    fn default() -> Self {
+        Self {
+            state: watch::channel(ClockState::default()).0,
+        }
+    }
+}
+
+impl TestClock {
+    /// Stop the clock at `at_ms`, or at the current time when `None`
+    pub fn freeze(&self, at_ms: Option<u64>) -> ClockStatus {
+        self.state.send_modify(|state| state.frozen_at_ms = Some(at_ms.unwrap_or_else(|| state.now_ms())));
+        self.status()
+    }
+
+    /// Move the clock forward by `ms`, frozen or not
+    pub fn advance(&self, ms: u64) -> ClockStatus {
+        self.state.send_modify(|state| match state.frozen_at_ms.as_mut() {
+            Some(frozen_at_ms) => *frozen_at_ms = frozen_at_ms.saturating_add(ms),
+            None => state.offset_ms = state.offset_ms.saturating_add(ms as i64),
+        });
+        self.status()
+    }
+
+    /// Go back to real time. Sleepers whose deadline is now in the past wake up.
+    pub fn reset(&self) -> ClockStatus {
+        self.state.send_replace(ClockState::default());
+        self.status()
+    }
+
+    pub fn status(&self) -> ClockStatus {
+        let state = *self.state.borrow();
+        ClockStatus {
+            now_ms: state.now_ms(),
+            frozen: state.frozen_at_ms.is_some(),
+        }
+    }
+}
+
+impl Clock for TestClock {
+    fn now_ms(&self) -> u64 {
+        self.state.borrow().now_ms()
+    }
+
+    fn sleep_until(&self, deadline_ms: u64) -> Sleep {
+        let mut changes = self.state.subscribe();
+        Box::pin(async move {
+            loop {
+                let state = *changes.borrow_and_update();
+                let now = state.now_ms();
+                if now >= deadline_ms {
+                    return;
+                }
+                // A frozen clock only moves when a test changes it; a running
+                // one also reaches the deadline on its own
+                let changed = if state.frozen_at_ms.is_some() {
+                    changes.changed().await
+                } else {
+                    match tokio::time::timeout(Duration::from_millis(deadline_ms - now), changes.changed()).await {
+                        Ok(changed) => changed,
+                        Err(_) => Ok(()),
+                    }
+                };
+                // The clock was dropped, so nothing can move it any more
+                if changed.is_err() {
+                    return;
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn test_freeze_advance_and_reset() {
+        let clock = TestClock::default();
+        assert!(!clock.status().frozen);
+
+        assert_eq!(clock.freeze(Some(1_000)), ClockStatus { now_ms: 1_000, frozen: true });
+        assert_eq!(clock.advance(250).now_ms, 1_250);
+        assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_millis(1_250));
+
+        let real = clock.reset().now_ms;
+        assert!(real.abs_diff(now_ms()) < 1_000);
+        let advanced = clock.advance(60_000);
+        assert!(!advanced.frozen);
+        assert!(advanced.now_ms >= real + 60_000);
+    }
+
+    #[test]
+    fn test_advancing_a_frozen_clock_wakes_sleepers() {
+        let clock = Arc::new(TestClock::default());
+        clock.freeze(Some(0));
+        // The deadline is taken when sleep is called, not when it's polled
+        let sleep = clock.sleep(Duration::from_secs(60));
+        let sleeper = {
+            let clock = clock.clone();
+            tauri::async_runtime::spawn(async move {
+                sleep.await;
+                clock.now_ms()
+            })
+        };
+
+        clock.advance(30_000);
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(!sleeper.inner().is_finished());
+
+        clock.advance(30_000);
+        assert_eq!(tauri::async_runtime::block_on(sleeper).unwrap(), 60_000);
+    }
+}
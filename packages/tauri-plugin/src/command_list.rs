@@ -0,0 +1,123 @@
+// The plugin's commands, listed once. build.rs includes this file to generate
+// the ACL permissions and lib.rs to build the invoke handler, so a command
+// can't be registered without its `allow-*` permission or the other way round.
+
+/// Expands to `$callback!(execute, log_frontend, ...)` with every command name
+macro_rules! wdio_commands {
+    ($callback:ident) => {
+        $callback!(
+            execute,
+            log_frontend,
+            debug_plugin,
+            get_active_window_label,
+            get_window_states,
+            list_windows,
+            get_window_info,
+            focus_window,
+            set_window_bounds,
+            maximize_window,
+            minimize_window,
+            restore_window,
+            set_fullscreen,
+            set_always_on_top,
+            capture_window,
+            get_monitors,
+            get_current_monitor,
+            open_devtools,
+            close_devtools,
+            is_devtools_open,
+            navigate,
+            reload,
+            wait_for_load,
+            get_logs,
+            clear_logs,
+            get_last_crash,
+            subscribe_logs,
+            unsubscribe_logs,
+            get_log_file_path,
+            set_log_context,
+            clear_log_context,
+            get_app_info,
+            get_app_paths,
+            quit_app,
+            restart_app,
+            get_process_metrics,
+            start_metrics_sampling,
+            stop_metrics_sampling,
+            get_metrics_samples,
+            get_env,
+            get_cli_args,
+            capture_notification,
+            enable_notification_capture,
+            disable_notification_capture,
+            get_captured_notifications,
+            clear_captured_notifications,
+            get_clipboard_text,
+            set_clipboard_text,
+            get_clipboard_image,
+            set_clipboard_image,
+            get_menu_structure,
+            trigger_menu_item,
+            get_tray_items,
+            trigger_tray_menu_item,
+            simulate_tray_click,
+            list_global_shortcuts,
+            trigger_global_shortcut,
+            simulate_file_drop,
+            simulate_deep_link,
+            get_app_state,
+            start_event_recording,
+            stop_event_recording,
+            get_recorded_events,
+            emit_event,
+            get_plugin_capabilities,
+            is_enabled,
+            get_pending_executions,
+            resolve_execute,
+            set_execute_preamble,
+            clear_execute_preamble,
+            start_invoke_tracing,
+            stop_invoke_tracing,
+            get_invoke_trace,
+            record_invoke,
+            export_invoke_trace,
+            get_system_appearance,
+            set_app_theme,
+            simulate_lifecycle,
+            begin_session,
+            end_session,
+            health_check,
+            execute_rust,
+            clock_freeze,
+            clock_advance,
+            clock_reset,
+            set_invoke_conditions,
+            clear_invoke_conditions,
+            clear_web_storage,
+            snapshot_web_storage,
+            restore_web_storage,
+            start_recording,
+            stop_recording,
+            record_invocation,
+            enable_replay,
+            disable_replay,
+            replay_invocation,
+            collect_coverage,
+            coverage_chunk,
+            wait_for_event,
+            wait_for_window,
+            wait_for_window_close,
+            get_native_accessibility_tree,
+            send_native_keys,
+            send_native_click,
+            self_test,
+            self_test_report,
+            reset_all,
+            get_window_events,
+            clear_window_events,
+            request_window_close,
+            force_close_window,
+            get_command_schemas
+        )
+    };
+}
@@ -0,0 +1,188 @@
+use std::future::Future;
+use std::time::Duration;
+
+use crate::models::WdioConfig;
+use crate::{Error, Result};
+
+/// Limits for the commands that can stall on a busy machine, used unless
+/// `command_timeouts` names the command
+fn default_limit_ms(command: &str) -> Option<u64> {
+    match command {
+        "capture_window" => Some(30_000),
+        "collect_coverage" => Some(120_000),
+        _ => None,
+    }
+}
+
+/// Limit for `command`: its `command_timeouts` entry over the default;
+/// commands with neither and a limit of 0 wait without one
+pub(crate) fn limit_ms(config: &WdioConfig, command: &str) -> Option<u64> {
+    config
+        .command_timeouts
+        .get(command)
+        .copied()
+        .or_else(|| default_limit_ms(command))
+        .filter(|&ms| ms > 0)
+}
+
+/// Run `future`, failing with [`Error::CommandTimeout`] if `command` has a
+/// limit in `config` and it isn't done within it
+pub(crate) async fn with_timeout<T>(
+    config: &WdioConfig,
+    command: &str,
+    future: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    #[cfg(test)]
+    let future = test_delay::delayed(command, future);
+    let Some(limit_ms) = limit_ms(config, command) else {
+        return future.await;
+    };
+    tokio::time::timeout(Duration::from_millis(limit_ms), future)
+        .await
+        .unwrap_or_else(|_| {
+            Err(Error::CommandTimeout {
+                command: command.to_string(),
+                limit_ms,
+            })
+        })
+}
+
+/// [`with_timeout`] for idempotent reads, running `attempt` again up to
+/// `read_retries` times when it times out. A timed-out attempt is only
+/// abandoned, not cancelled: if it is blocked on a `spawn_blocking` thread,
+/// that thread keeps running alongside the retry, so up to
+/// `read_retries + 1` threads can be tied up by one call.
+pub(crate) async fn with_retries<T, F>(config: &WdioConfig, command: &str, mut attempt: impl FnMut() -> F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    let mut retries = config.read_retries;
+    loop {
+        match with_timeout(config, command, attempt()).await {
+            Err(Error::CommandTimeout { .. }) if retries > 0 => retries -= 1,
+            result => return result,
+        }
+    }
+}
+
+/// Artificial slowness for tests: the next `with_timeout` runs of a command
+/// sleep first, one queued delay each
+#[cfg(test)]
+pub(crate) mod test_delay {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    static DELAYS: Mutex<Vec<(String, u64)>> = Mutex::new(Vec::new());
+
+    /// Delay the next run of `command` by `ms`
+    pub(crate) fn push(command: &str, ms: u64) {
+        DELAYS.lock().unwrap().push((command.to_string(), ms));
+    }
+
+    pub(super) async fn delayed<T>(command: &str, future: impl std::future::Future<Output = T>) -> T {
+        let delay = {
+            let mut delays = DELAYS.lock().unwrap();
+            let index = delays.iter().position(|(name, _)| name == command);
+            index.map(|index| delays.remove(index).1)
+        };
+        if let Some(ms) = delay {
+            tokio::time::sleep(Duration::from_millis(ms)).await;
+        }
+        future.await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    fn config(command: &str, limit_ms: u64, read_retries: u32) -> WdioConfig {
+        WdioConfig {
+            command_timeouts: HashMap::from([(command.to_string(), limit_ms)]),
+            read_retries,
+            ..WdioConfig::default()
+        }
+    }
+
+    fn run<T>(future: impl Future<Output = T>) -> T {
+        tauri::async_runtime::block_on(future)
+    }
+
+    #[test]
+    fn test_times_out_a_stalled_command() {
+        let config = config("stalled_capture", 20, 0);
+        test_delay::push("stalled_capture", 500);
+
+        let result = run(with_timeout(&config, "stalled_capture", async { Ok(1) }));
+
+        assert!(matches!(
+            result,
+            Err(Error::CommandTimeout { ref command, limit_ms: 20 }) if command == "stalled_capture"
+        ));
+    }
+
+    #[test]
+    fn test_runs_commands_without_a_limit_to_completion() {
+        let config = config("stalled_unlimited", 0, 0);
+        test_delay::push("stalled_unlimited", 30);
+        test_delay::push("unnamed", 30);
+
+        assert_eq!(run(with_timeout(&config, "stalled_unlimited", async { Ok(1) })).unwrap(), 1);
+        assert_eq!(run(with_timeout(&config, "unnamed", async { Ok(2) })).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_retries_reads_that_time_out() {
+        let config = config("slow_read", 20, 2);
+        test_delay::push("slow_read", 500);
+        test_delay::push("slow_read", 500);
+        let attempts = AtomicU32::new(0);
+
+        let result = run(with_retries(&config, "slow_read", || {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(attempt) }
+        }));
+
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_gives_up_after_the_retries() {
+        let config = config("stuck_read", 20, 1);
+        test_delay::push("stuck_read", 500);
+        test_delay::push("stuck_read", 500);
+
+        let result = run(with_retries(&config, "stuck_read", || async { Ok(()) }));
+
+        assert!(matches!(result, Err(Error::CommandTimeout { .. })));
+    }
+
+    #[test]
+    fn test_defaults_limit_the_long_running_commands() {
+        let config = WdioConfig::default();
+
+        assert_eq!(limit_ms(&config, "capture_window"), Some(30_000));
+        assert_eq!(limit_ms(&config, "collect_coverage"), Some(120_000));
+        assert_eq!(limit_ms(&config, "get_logs"), None);
+    }
+
+    #[test]
+    fn test_layers_configured_limits_over_the_defaults() {
+        let unlimited = config("collect_coverage", 0, 0);
+        let config = WdioConfig {
+            command_timeouts: HashMap::from([
+                ("capture_window".to_string(), 5_000),
+                ("get_logs".to_string(), 1_000),
+            ]),
+            ..WdioConfig::default()
+        };
+
+        assert_eq!(limit_ms(&config, "capture_window"), Some(5_000));
+        assert_eq!(limit_ms(&config, "get_logs"), Some(1_000));
+        assert_eq!(limit_ms(&config, "collect_coverage"), Some(120_000));
+        assert_eq!(limit_ms(&unlimited, "collect_coverage"), None);
+    }
+}
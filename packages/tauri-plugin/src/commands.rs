@@ -1,14 +1,37 @@
-use tauri::{command, Manager, Runtime, WebviewWindow, Listener};
+use tauri::{command, Emitter, Manager, Runtime, State, WebviewWindow, Listener};
 use serde_json::Value as JsonValue;
 use uuid::Uuid;
-use tokio::sync::oneshot;
 
-use crate::models::ExecuteRequest;
-use crate::Result;
+use crate::bridge;
+use crate::command_timeout;
+use crate::coverage;
+use crate::event_wait;
+use crate::executions;
+use crate::invoke_conditions;
+use crate::invoke_replay;
+use crate::invoke_trace;
+use crate::native_input;
+use crate::navigation;
+use crate::payload;
+use crate::preamble;
+use crate::script_map::{self, ScriptMap};
+use crate::self_test;
+use crate::sessions;
+use crate::state::WdioState;
+use crate::web_storage;
+use crate::window_watch;
+use crate::models::{
+    AccessibilityNode, AppInfo, AppPaths, AppTheme, ClockStatus, CloseRequestOutcome, CoordinateUnit, CoverageResult, CrashReport, Dimensions, KeyChord, MouseButton, EndedSession, EventRecordFilter, HealthStatus, EventTargetSpec, InvokeConditions, InvokeTrace, InvokeTraceEntry, InvokeTraceExport, TraceExportFormat, LifecycleEvent, MenuItemInfo, MonitorInfo, NotificationRecord,
+    NotifyOptions, PendingExecution, PluginCapabilities, RecordedEvents, ResetSummary, SelfTestReport, ShortcutInfo, TrayInfo, TrayMouseButton,
+    Point, ProcessMetrics,
+    ScreenshotOptions, SetWindowBounds, SystemAppearance, RecordedInvocation, InvokeOutcome, WebStorageKind, WebStorageResult, WebStorageSnapshot, WindowBounds, WindowEventKind, WindowEvents, WindowInfo, ExecuteRequest, LogEntry, LogFilter, LogLevel, LogSource, LogSubscriptionFilter,
+};
+use crate::{Result, WdioExt};
 
 /// Window state information for generic window management
 /// Mirrors Electron's window tracking - discover active window without app-specific knowledge
 #[derive(serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct WindowState {
   pub label: String,
   pub title: String,
@@ -28,28 +51,520 @@ pub(crate) async fn debug_plugin<R: Runtime>(_window: WebviewWindow<R>) -> Strin
 #[command]
 pub(crate) async fn log_frontend<R: Runtime>(
     _window: WebviewWindow<R>,
+    state: State<'_, WdioState>,
     message: String,
     level: String,
 ) -> Result<String> {
     // Output with a special marker that the log parser recognizes as frontend
-    // Format: [WDIO-FRONTEND][LEVEL] message
-    eprintln!("[WDIO-FRONTEND][{}] {}", level.to_uppercase(), message);
+    // Format: [WDIO-FRONTEND][LEVEL] message (or a JSON line in json log mode)
+    state.logs.emit(LogSource::Frontend, LogLevel::parse(&level), message.clone(), None);
 
     // Return success indicator
     Ok(format!("logged: {} @ {}", level, message))
 }
 
+/// Get captured log entries from the in-memory buffer, oldest first
+#[command]
+pub(crate) async fn get_logs(
+    state: State<'_, WdioState>,
+    filter: Option<LogFilter>,
+) -> Result<Vec<LogEntry>> {
+    let filter = filter.unwrap_or_default();
+    command_timeout::with_retries(&state.config, "get_logs", || {
+        let buffer = state.logs.buffer().clone();
+        let filter = filter.clone();
+        // Off the async worker, so a buffer stuck behind a busy logger can time out
+        async move { Ok(tauri::async_runtime::spawn_blocking(move || buffer.query(&filter)).await?) }
+    })
+    .await
+}
+
+/// Clear the in-memory log buffer, returning the number of entries removed
+#[command]
+pub(crate) async fn clear_logs(state: State<'_, WdioState>) -> Result<usize> {
+    Ok(state.logs.buffer().clear())
+}
+
+/// Stream log entries matching `filter` as `wdio:log` events, returning the subscription id
+#[command]
+pub(crate) async fn subscribe_logs(
+    state: State<'_, WdioState>,
+    filter: Option<LogSubscriptionFilter>,
+) -> Result<String> {
+    Ok(state.logs.subscriptions().subscribe(filter.unwrap_or_default()))
+}
+
+/// Stop a log subscription; returns false if the id is unknown
+#[command]
+pub(crate) async fn unsubscribe_logs(
+    state: State<'_, WdioState>,
+    subscription_id: String,
+) -> Result<bool> {
+    Ok(state.logs.subscriptions().unsubscribe(&subscription_id))
+}
+
+/// Attach `context` (e.g. the current test title) to every subsequent log entry
+#[command]
+pub(crate) async fn set_log_context(state: State<'_, WdioState>, context: String) -> Result<()> {
+    state.logs.set_context(Some(context));
+    Ok(())
+}
+
+/// Stop attaching a context to log entries
+#[command]
+pub(crate) async fn clear_log_context(state: State<'_, WdioState>) -> Result<()> {
+    state.logs.set_context(None);
+    Ok(())
+}
+
+/// Get the path of the log file, or None if file output is disabled
+#[command]
+pub(crate) async fn get_log_file_path(state: State<'_, WdioState>) -> Result<Option<String>> {
+    Ok(state.logs
+        .log_file_path()
+        .map(|path| path.to_string_lossy().into_owned()))
+}
+
+/// Measure the app's memory, CPU, and thread usage (requires the `metrics` feature)
+#[command]
+pub(crate) async fn get_process_metrics(state: State<'_, WdioState>) -> Result<ProcessMetrics> {
+    state.metrics.snapshot()
+}
+
+/// Start recording process metrics every `interval_ms` (default 1000), discarding earlier samples
+#[command]
+pub(crate) async fn start_metrics_sampling(
+    state: State<'_, WdioState>,
+    interval_ms: Option<u64>,
+) -> Result<()> {
+    let interval = interval_ms.unwrap_or(1_000).max(10);
+    state.metrics.start_sampling(std::time::Duration::from_millis(interval))
+}
+
+/// Stop recording process metrics, returning the number of samples collected
+#[command]
+pub(crate) async fn stop_metrics_sampling(state: State<'_, WdioState>) -> Result<usize> {
+    state.metrics.stop_sampling()
+}
+
+/// Get the samples recorded since start_metrics_sampling, oldest first
+#[command]
+pub(crate) async fn get_metrics_samples(state: State<'_, WdioState>) -> Result<Vec<ProcessMetrics>> {
+    state.metrics.samples()
+}
+
+/// Get environment variables by name, subject to `env_allowlist` / `allow_full_env_dump`
+#[command]
+pub(crate) async fn get_env(
+    state: State<'_, WdioState>,
+    names: Option<Vec<String>>,
+) -> Result<std::collections::BTreeMap<String, String>> {
+    crate::environment::filter_env(std::env::vars(), names.as_deref(), &state.config)
+}
+
+/// Get the command line the app was launched with, including the binary path
+#[command]
+pub(crate) async fn get_cli_args() -> Result<Vec<String>> {
+    Ok(std::env::args().collect())
+}
+
+/// Record a `plugin:notification|notify` request if capture is on. Called by the
+/// guest-js invoke interceptor; returns whether the notification was captured
+#[command]
+pub(crate) async fn capture_notification(
+    state: State<'_, WdioState>,
+    options: NotifyOptions,
+) -> Result<bool> {
+    Ok(state.notifications.capture(options))
+}
+
+/// Record notifications instead of showing them
+#[command]
+pub(crate) async fn enable_notification_capture(state: State<'_, WdioState>) -> Result<()> {
+    state.notifications.set_enabled(true);
+    Ok(())
+}
+
+/// Let notifications through to the notification plugin again
+#[command]
+pub(crate) async fn disable_notification_capture(state: State<'_, WdioState>) -> Result<()> {
+    state.notifications.set_enabled(false);
+    Ok(())
+}
+
+/// Get notifications recorded while capture was on, oldest first
+#[command]
+pub(crate) async fn get_captured_notifications(
+    state: State<'_, WdioState>,
+) -> Result<Vec<NotificationRecord>> {
+    Ok(state.notifications.records())
+}
+
+/// Remove all captured notifications, returning how many were dropped
+#[command]
+pub(crate) async fn clear_captured_notifications(state: State<'_, WdioState>) -> Result<usize> {
+    Ok(state.notifications.clear())
+}
+
+/// Get the text on the clipboard, or null when it is empty
+#[command]
+pub(crate) async fn get_clipboard_text(state: State<'_, WdioState>) -> Result<Option<String>> {
+    state.clipboard.text()
+}
+
+/// Put text on the clipboard
+#[command]
+pub(crate) async fn set_clipboard_text(state: State<'_, WdioState>, text: String) -> Result<()> {
+    state.clipboard.set_text(text)
+}
+
+/// Get the image on the clipboard as base64 PNG, or null when it is empty
+#[command]
+pub(crate) async fn get_clipboard_image(state: State<'_, WdioState>) -> Result<Option<String>> {
+    state.clipboard.image()
+}
+
+/// Put a base64 PNG image on the clipboard
+#[command]
+pub(crate) async fn set_clipboard_image(state: State<'_, WdioState>, image: String) -> Result<()> {
+    state.clipboard.set_image(&image)
+}
+
+/// Record the named events emitted to any target until stop_event_recording,
+/// discarding earlier records
+#[command]
+pub(crate) async fn start_event_recording<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+    event_names: Option<Vec<String>>,
+) -> Result<()> {
+    state.events.start(&app, event_names.unwrap_or_default())
+}
+
+/// Stop recording events, returning the number recorded; the records are kept
+#[command]
+pub(crate) async fn stop_event_recording<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+) -> Result<usize> {
+    Ok(state.events.stop(&app))
+}
+
+/// Recorded events matching the filter, with the number dropped once the
+/// recording was full
+#[command]
+pub(crate) async fn get_recorded_events(
+    state: State<'_, WdioState>,
+    filter: Option<EventRecordFilter>,
+) -> Result<RecordedEvents> {
+    Ok(state.events.query(&filter.unwrap_or_default()))
+}
+
+/// Record every IPC command call made through the frontend's invoke until
+/// stop_invoke_tracing, discarding any earlier trace. Arguments are kept as
+/// JSON cut to `args_max_bytes` (default `WdioConfig::invoke_trace_args_max_bytes`).
+#[command]
+pub(crate) async fn start_invoke_tracing<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+    args_max_bytes: Option<usize>,
+) -> Result<()> {
+    let limit = state.config.max_payload_bytes;
+    let budget = state.invoke_trace.start(args_max_bytes.map(|budget| budget.min(limit)));
+    set_page_tracing(&app, Some(budget));
+    Ok(())
+}
+
+/// Stop tracing invokes, returning the number recorded; the trace is kept
+#[command]
+pub(crate) async fn stop_invoke_tracing<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+) -> Result<usize> {
+    let count = state.invoke_trace.stop();
+    set_page_tracing(&app, None);
+    Ok(count)
+}
+
+/// The recorded invoke trace, ordered by start time
+#[command]
+pub(crate) async fn get_invoke_trace(state: State<'_, WdioState>) -> Result<InvokeTrace> {
+    Ok(state.invoke_trace.trace())
+}
+
+/// Export the invoke trace as `format` (default JSON). With `path` the
+/// document is streamed to that file and only the path is returned, so a
+/// large trace doesn't cross IPC.
+#[command]
+pub(crate) async fn export_invoke_trace<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+    format: Option<TraceExportFormat>,
+    path: Option<std::path::PathBuf>,
+) -> Result<InvokeTraceExport> {
+    let trace = state.invoke_trace.trace();
+    let info = app.package_info();
+    let version = info.version.to_string();
+    let har = crate::har::Har {
+        creator_name: &info.name,
+        creator_version: &version,
+        entries: &trace.entries,
+    };
+    let entry_count = trace.entries.len();
+
+    let Some(path) = path else {
+        let document = match format.unwrap_or_default() {
+            TraceExportFormat::Json => serde_json::to_value(&trace)?,
+            TraceExportFormat::Har => serde_json::to_value(&har)?,
+        };
+        return Ok(InvokeTraceExport {
+            path: None,
+            document: Some(document),
+            entry_count,
+        });
+    };
+
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(&path)?);
+    match format.unwrap_or_default() {
+        TraceExportFormat::Json => serde_json::to_writer(&mut writer, &trace)?,
+        TraceExportFormat::Har => har.write_to(&mut writer)?,
+    }
+    std::io::Write::flush(&mut writer)?;
+    Ok(InvokeTraceExport {
+        path: Some(path.to_string_lossy().into_owned()),
+        document: None,
+        entry_count,
+    })
+}
+
+/// Add a call to the invoke trace and control server streams; invoked by
+/// the guest-js invoke interceptor
+#[command]
+pub(crate) async fn record_invoke<R: Runtime>(
+    webview: tauri::Webview<R>,
+    state: State<'_, WdioState>,
+    mut entry: InvokeTraceEntry,
+) -> Result<bool> {
+    entry.window_label = webview.label().to_string();
+    let Some(entry) = state.invoke_trace.record(entry) else {
+        return Ok(false);
+    };
+    state.logs.stream().publish_invoke(&entry);
+    Ok(true)
+}
+
+/// Switch the invoke interceptor of every open page on or off
+fn set_page_tracing<R: Runtime>(app: &tauri::AppHandle<R>, args_max_bytes: Option<usize>) {
+    let script = invoke_trace::tracing_script(args_max_bytes);
+    for window in app.webview_windows().values() {
+        if let Err(e) = window.eval(&script) {
+            log::warn!("Failed to update invoke tracing in window '{}': {}", window.label(), e);
+        }
+    }
+}
+
+/// Record the result of every non-mocked frontend invoke until
+/// stop_recording, which writes them to `path`. Replaces any recording or
+/// replay in progress.
+#[command]
+pub(crate) async fn start_recording<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+    path: std::path::PathBuf,
+) -> Result<()> {
+    state.invoke_replay.start_recording(path)?;
+    set_page_replay(&app, state.invoke_replay.page_mode());
+    Ok(())
+}
+
+/// Write the recording to its file and stop, returning the number of invocations
+#[command]
+pub(crate) async fn stop_recording<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+) -> Result<usize> {
+    let count = state.invoke_replay.stop_recording()?;
+    set_page_replay(&app, state.invoke_replay.page_mode());
+    Ok(count)
+}
+
+/// Add a settled invoke to the recording; invoked by the guest-js invoke interceptor
+#[command]
+pub(crate) async fn record_invocation(
+    state: State<'_, WdioState>,
+    command: String,
+    args: JsonValue,
+    outcome: InvokeOutcome,
+    result: JsonValue,
+) -> Result<bool> {
+    payload::check(&result, state.config.max_payload_bytes)?;
+    Ok(state.invoke_replay.record(command, &args, outcome, result))
+}
+
+/// Answer non-mocked frontend invokes with the results recorded at `path`
+/// instead of running the commands, until disable_replay. With `strict`, an
+/// invoke that wasn't recorded fails; otherwise it reaches the backend.
+/// Returns the number of invocations loaded.
+#[command]
+pub(crate) async fn enable_replay<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+    path: std::path::PathBuf,
+    strict: bool,
+) -> Result<usize> {
+    let count = state.invoke_replay.enable_replay(&path, strict)?;
+    set_page_replay(&app, state.invoke_replay.page_mode());
+    Ok(count)
+}
+
+/// Let invokes reach the backend again; returns false if replay was off
+#[command]
+pub(crate) async fn disable_replay<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+) -> Result<bool> {
+    let replaying = state.invoke_replay.disable_replay();
+    set_page_replay(&app, state.invoke_replay.page_mode());
+    Ok(replaying)
+}
+
+/// The recorded result for an invoke, or null to let it through; invoked by
+/// the guest-js invoke interceptor
+#[command]
+pub(crate) async fn replay_invocation(
+    state: State<'_, WdioState>,
+    command: String,
+    args: JsonValue,
+) -> Result<Option<RecordedInvocation>> {
+    state.invoke_replay.replay(&command, &args)
+}
+
+/// Switch the invoke interceptor of every open page to recording, replaying, or neither
+fn set_page_replay<R: Runtime>(app: &tauri::AppHandle<R>, mode: Option<&str>) {
+    let script = invoke_replay::mode_script(mode);
+    for window in app.webview_windows().values() {
+        if let Err(e) = window.eval(&script) {
+            log::warn!("Failed to update invoke replay in window '{}': {}", window.label(), e);
+        }
+    }
+}
+
+/// Delay or fail frontend invokes of matching commands, mocked or not, in
+/// every page until clear_invoke_conditions. Replaces earlier conditions.
+#[command]
+pub(crate) async fn set_invoke_conditions<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+    conditions: InvokeConditions,
+) -> Result<()> {
+    let script = invoke_conditions::conditions_script(Some(&conditions));
+    state.invoke_conditions.set(conditions)?;
+    eval_in_pages(&app, &script, "set invoke conditions");
+    Ok(())
+}
+
+/// Stop delaying and failing invokes; returns whether conditions were active
+#[command]
+pub(crate) async fn clear_invoke_conditions<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+) -> Result<bool> {
+    let cleared = state.invoke_conditions.clear();
+    eval_in_pages(&app, &invoke_conditions::conditions_script(None), "clear invoke conditions");
+    Ok(cleared)
+}
+
+/// Wait up to `timeout_ms` for a `name` event, emitted to any target, whose
+/// payload contains `payload_filter`, and return its payload. Events emitted
+/// after the command was dispatched but before its listener was registered
+/// are found in the event recording, when it covers `name`.
+#[command]
+pub(crate) async fn wait_for_event<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+    name: String,
+    timeout_ms: u64,
+    payload_filter: Option<JsonValue>,
+) -> Result<JsonValue> {
+    let dispatched_ms = crate::log_buffer::now_ms();
+    let recorded = || {
+        let filter = EventRecordFilter {
+            name: Some(name.clone()),
+            since_timestamp: Some(dispatched_ms),
+        };
+        state.events.query(&filter).events.into_iter().map(|record| record.payload).collect()
+    };
+    event_wait::wait_for_event(
+        &app,
+        &name,
+        payload_filter.as_ref(),
+        recorded,
+        std::time::Duration::from_millis(timeout_ms),
+    )
+    .await
+}
+
+/// Emit `name` with `payload` as if the backend sent it, to every listener or
+/// only those of `target`. The payload is serialized once, as given.
+#[command]
+pub(crate) async fn emit_event<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    name: String,
+    payload: Option<JsonValue>,
+    target: Option<EventTargetSpec>,
+) -> Result<()> {
+    let payload = payload.unwrap_or(JsonValue::Null);
+    let result = match target.unwrap_or_default() {
+        EventTargetSpec::All => app.emit(&name, payload),
+        target => app.emit_to(tauri::EventTarget::from(target), &name, payload),
+    };
+    result.map_err(|e| crate::Error::EmitError(e.to_string()))
+}
+
+/// Get the most recent backend panic captured by the plugin's panic hook
+#[command]
+pub(crate) async fn get_last_crash(state: State<'_, WdioState>) -> Result<Option<CrashReport>> {
+    Ok(state.crashes.last())
+}
+
 /// Execute JavaScript code in the frontend context
 /// This command is called via invoke from the frontend plugin
 /// It extracts the script from the request, evaluates it, and returns the result
+/// Hooks registered with Wdio::on_before_execute / on_after_execute run around it
 #[command]
 pub(crate) async fn execute<R: Runtime>(
     app: tauri::AppHandle<R>,
     window: WebviewWindow<R>,
     request: ExecuteRequest,
+) -> Result<JsonValue> {
+    run_execute(&app, window, &request).await
+}
+
+/// Run `request` in its window, or else `window`, with the execute hooks
+/// around it. Shared by the execute command and [`crate::Wdio::execute`].
+pub(crate) async fn run_execute<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    window: WebviewWindow<R>,
+    request: &ExecuteRequest,
+) -> Result<JsonValue> {
+    if let Some(wdio) = app.try_state::<crate::desktop::Wdio<R>>() {
+        wdio.execute_hooks().run_before(request);
+    }
+    let result = execute_script(app.clone(), window, request).await;
+    if let Some(wdio) = app.try_state::<crate::desktop::Wdio<R>>() {
+        wdio.execute_hooks().run_after(request, &result);
+    }
+    result
+}
+
+async fn execute_script<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    window: WebviewWindow<R>,
+    request: &ExecuteRequest,
 ) -> Result<JsonValue> {
     log::debug!("Execute command called");
     log::trace!("Script length: {} chars", request.script.len());
+    let started = std::time::Instant::now();
 
     // Determine which window to use for execution
     let target_window = if let Some(ref label) = request.window_label {
@@ -70,13 +585,26 @@ pub(crate) async fn execute<R: Runtime>(
         window
     };
 
-    use std::sync::{Arc, Mutex};
     use std::time::Duration;
 
-    // Use tokio's async oneshot channel for async waiting
-    // Wrap sender in Arc<Mutex<Option>> so the Fn closure can take it once
+    let state = app.state::<WdioState>();
+
+    // Fail fast when the page can't report a result back, rather than timing out
+    let via_events = state.config.execute_results_via_events;
+    if !via_events {
+        let page_loaded = state.page_loads.count(target_window.label()) > 0;
+        let deadline = std::time::Instant::now() + bridge::CHECK_IN_TIMEOUT;
+        while let Err(e) = state.bridges.check(target_window.label(), page_loaded) {
+            if std::time::Instant::now() >= deadline {
+                log::error!("{}", e);
+                return Err(e);
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    }
+
+    // The result arrives through PendingExecutions, from resolve_execute or a result event
     let (tx, rx) = tokio::sync::oneshot::channel::<crate::Result<JsonValue>>();
-    let tx = Arc::new(Mutex::new(Some(tx)));
 
     // Build the script with args if offered.
     // Callable scripts receive Tauri APIs + user args.
@@ -115,10 +643,13 @@ pub(crate) async fn execute<R: Runtime>(
                         b'\'' if !in_double_quote && !in_backtick => in_single_quote = !in_single_quote,
                         b'"' if !in_single_quote && !in_backtick => in_double_quote = !in_double_quote,
                         b'`' if !in_single_quote && !in_double_quote => in_backtick = !in_backtick,
-                        b'=' if !in_single_quote && !in_double_quote && !in_backtick => {
-                            if i + 1 < bytes.len() && bytes[i + 1] == b'>' {
-                                return true;
-                            }
+                        b'=' if !in_single_quote
+                            && !in_double_quote
+                            && !in_backtick
+                            && i + 1 < bytes.len()
+                            && bytes[i + 1] == b'>' =>
+                        {
+                            return true;
                         }
                         _ => {}
                     }
@@ -183,7 +714,7 @@ pub(crate) async fn execute<R: Runtime>(
             let mut i = 0;
             while i < bytes.len() {
                 let b = bytes[i];
-                let top_in_str = tmpl.last().map_or(false, |f| f.in_str);
+                let top_in_str = tmpl.last().is_some_and(|f| f.in_str);
 
                 // Escape: skip next byte when inside a string or template string chars.
                 if b == b'\\' && (in_single_quote || in_double_quote || top_in_str) {
@@ -272,6 +803,9 @@ pub(crate) async fn execute<R: Runtime>(
         || single_param_arrow
         || trimmed.starts_with("(async");
 
+    // What goes ahead of the request's script in `script`, so error positions
+    // can be mapped back onto it
+    let mut script_prefix = "";
     let script = if is_function {
         // Callable/pre-packaged script — pass through as-is.
         // guest-js wraps both function-like and plain-string cases into async IIFEs before
@@ -312,126 +846,191 @@ pub(crate) async fn execute<R: Runtime>(
         };
         let body = if !has_statement && !has_return {
             // Pure expression - add return so it evaluates and returns
+            script_prefix = "(async () => { return ";
             format!("return {};", request.script)
         } else {
             // Has statements or already has return - pass through as-is
+            script_prefix = "(async () => { ";
             request.script.clone()
         };
 
         format!("(async () => {{ {body} }})()")
     };
 
-    // Generate unique event ID for this execution
-    let event_id = format!("wdio-result-{}", Uuid::new_v4());
-    log::trace!("Generated event_id for result: {}", event_id);
+    // Unique, unguessable ID the injected script reports its result under
+    let execution_id = format!("wdio-result-{}", Uuid::new_v4());
+    log::trace!("Generated execution_id for result: {}", execution_id);
 
-    // Helper function to handle events
-    fn handle_event(event: tauri::Event, tx: Arc<Mutex<Option<oneshot::Sender<crate::Result<JsonValue>>>>>) {
-        log::trace!("Received result event payload: {}", event.payload());
+    // Dropped on every return path, removing the pending entry and any listener
+    let _registration = state.executions.register(&app, &execution_id, target_window.label(), tx);
 
-        // Take the sender from the Option (only the first call will succeed)
-        let tx = match tx.lock().ok().and_then(|mut guard| guard.take()) {
-            Some(tx) => tx,
-            None => {
-                log::warn!("Event received but sender already taken, ignoring");
+    let preamble = state.preamble.get();
+    let report_result = if via_events {
+        // Result events are global, so any listener can see them; only used
+        // when WdioConfig::execute_results_via_events asks for it
+        let listener_pending = state.executions.clone();
+        let listener_execution_id = execution_id.clone();
+        let limit = state.config.max_payload_bytes;
+        let listener_id = app.listen(&execution_id, move |event| {
+            log::trace!("Received result event: {}", event.payload());
+            if let Err(e) = payload::check_size(event.payload().len(), limit) {
+                let _ = listener_pending.resolve(&listener_execution_id, None, Err(e));
                 return;
             }
-        };
-
-        if let Ok(payload) = serde_json::from_str::<serde_json::Value>(event.payload()) {
-            if let Some(success) = payload.get("success").and_then(|s| s.as_bool()) {
-                if success {
-                    let is_undefined = payload.get("__wdio_undefined__").and_then(|v| v.as_bool()).unwrap_or(false);
-                    let value: JsonValue = if is_undefined {
-                        serde_json::json!({"__wdio_undefined__": true})
-                    } else {
-                        payload.get("value").unwrap_or(&JsonValue::Null).clone()
-                    };
-                    let _ = tx.send(Ok(value));
-                } else {
-                    let error_msg = payload.get("error")
-                        .and_then(|e| e.as_str())
-                        .unwrap_or("Unknown error")
-                        .to_string();
-                    let _ = tx.send(Err(crate::Error::ExecuteError(error_msg)));
-                }
+            let report = serde_json::from_str::<JsonValue>(event.payload()).unwrap_or_default();
+            if let Some(result) = executions::result_from_report(&report) {
+                let _ = listener_pending.resolve(&listener_execution_id, None, result);
             }
-        }
-    }
+        });
+        state.executions.set_listener(&execution_id, listener_id);
 
-    // Listen for the result event on the app target.
-    // guest-js uses emit() from @tauri-apps/api/event which targets the app scope.
-    let tx_clone: Arc<Mutex<Option<oneshot::Sender<crate::Result<JsonValue>>>>> = Arc::clone(&tx);
-
-    let listener_id = app.listen(&event_id, move |event| {
-        log::trace!("Received result event: {}", event.payload());
-        handle_event(event, tx_clone.clone());
-    });
+        format!(
+            r#"
+            const origTauri = window.__wdio_original_tauri__;
+            if (window.__WDIO_EMIT__) {{
+                await window.__WDIO_EMIT__('{id}', report, true);
+            }} else if (origTauri?.event?.emit) {{
+                await origTauri.event.emit('{id}', report);
+            }} else {{
+                const {{ emit }} = await import('@tauri-apps/api/event');
+                await emit('{id}', report);
+            }}"#,
+            id = execution_id
+        )
+    } else {
+        format!(
+            r#"
+            const args = {{ id: '{id}', report }};
+            const origCore = window.__wdio_original_core__;
+            if (window.__WDIO_EMIT__) {{
+                await window.__WDIO_EMIT__(args.id, report, false);
+            }} else if (origCore?.invoke) {{
+                await origCore.invoke('plugin:wdio|resolve_execute', args);
+            }} else {{
+                await window.__TAURI_INTERNALS__.invoke('plugin:wdio|resolve_execute', args);
+            }}"#,
+            id = execution_id
+        )
+    };
 
     // Wrap the script to:
     // 1. Wait for Tauri core.invoke to be available (handles race condition)
     // 2. Execute the user's script
-    // 3. Emit the result via a Tauri event using the current window's emit
+    // 3. Report the result through resolve_execute (or a result event, see above)
     //
     // NOTE: We use window.__wdio_original_core__ and window.__wdio_original_tauri__ (set by
     // the @wdio/tauri-plugin frontend before any Proxy interception) rather than accessing
     // window.__TAURI__ directly. On macOS/WKWebView the plugin may replace window.__TAURI__
     // with a Proxy; reading non-configurable/non-writable own properties through that Proxy
     // triggers a JavaScript invariant violation. The snapshots are plain objects and are safe.
-    let script_with_result = format!(
+    //
+    // The wrapper names itself with a sourceURL, so the catch can find where in
+    // it the error was raised from the stack. It sets `parsed_flag` as it
+    // starts, telling the syntax check evaluated after it that it parsed.
+    let parsed_flag = format!("{}:parsed", execution_id);
+    // A detailed execute gets the value back with the page's timings
+    let report_success = if request.detailed {
+        r#"
+                await __wdio_report({ success: true, result: {
+                    value: __wdio_result === undefined ? { __wdio_undefined__: true } : __wdio_result,
+                    eval_started_ms: performance.timeOrigin + __wdio_started,
+                    eval_duration_ms: __wdio_duration,
+                } });"#
+    } else {
+        r#"
+                if (__wdio_result === undefined) {
+                    await __wdio_report({ success: true, __wdio_undefined__: true });
+                } else {
+                    await __wdio_report({ success: true, result: __wdio_result });
+                }"#
+    };
+    let source_url = format!("{}.js", execution_id);
+    let wrapper_head = format!(
         r#"
+        window['{parsed_flag}'] = true;
         (async () => {{
-            // Helper: emit a result event via the snapshotted original tauri or dynamic import
-            async function __wdio_emit(eventName, payload) {{
-                const origTauri = window.__wdio_original_tauri__;
-                if (origTauri?.event?.emit) {{
-                    await origTauri.event.emit(eventName, payload);
-                }} else {{
-                    const {{ emit }} = await import('@tauri-apps/api/event');
-                    await emit(eventName, payload);
-                }}
+            // Helper: report the result to the plugin via resolve_execute or a result event,
+            // through the plugin's bridge when the page has it
+            async function __wdio_report(report) {{
+                {report_result}
             }}
 
+            let __wdio_in_preamble = false;
             try {{
-                // Wait for core.invoke using the snapshotted original core (avoids Proxy issues)
+                // Wait for a way to report back: the bridge, or core.invoke through the
+                // snapshotted original core (avoids Proxy issues)
+                const __wdio_can_report = () => window.__WDIO_EMIT__ || window.__wdio_original_core__?.invoke;
                 const maxWait = 5000;
                 const startTime = Date.now();
-                while (!window.__wdio_original_core__?.invoke && (Date.now() - startTime) < maxWait) {{
+                while (!__wdio_can_report() && (Date.now() - startTime) < maxWait) {{
                     await new Promise(r => setTimeout(r, 10));
                 }}
-                if (!window.__wdio_original_core__?.invoke) {{
+                if (!__wdio_can_report()) {{
                     throw new Error('Tauri core.invoke not available after timeout');
                 }}
 
+                // The preamble, if any, shares this block so its helpers are in scope
+                {preamble_block}
+
                 // Execute the user's script (already wrapped in both branches)
                 // Both with-args and no-args paths return a complete async IIFE
-                const __wdio_script = ({});
-                const result = await __wdio_script;
-
-                if (result === undefined) {{
-                    await __wdio_emit('{}', {{ success: true, __wdio_undefined__: true }});
-                }} else {{
-                    await __wdio_emit('{}', {{ success: true, value: result }});
-                }}
+                const __wdio_started = performance.now();
+                const __wdio_script = ("#,
+        parsed_flag = parsed_flag,
+        report_result = report_result,
+        preamble_block = preamble::preamble_block(preamble.as_deref())
+    );
+    let wrapper_tail = format!(
+        r#");
+                const __wdio_result = await __wdio_script;
+                const __wdio_duration = performance.now() - __wdio_started;
+{report_success}
             }} catch (error) {{
                 try {{
-                    await __wdio_emit('{}', {{ success: false, error: error.message || String(error) }});
+                    const message = error.message || String(error);
+                    // Where in this wrapper the error was raised, from the frame naming it
+                    const at = String(error?.stack ?? '').split('{source_url}:')[1]?.match(/^(\d+):(\d+)/);
+                    await __wdio_report(__wdio_in_preamble
+                        ? {{ success: false, preamble_error: message }}
+                        : {{ success: false, error: message, line: at ? Number(at[1]) : null, column: at ? Number(at[2]) : null }});
                 }} catch (emitError) {{
                     console.error('[WDIO Execute] Failed to emit error:', emitError);
                 }}
             }}
         }})();
+        //# sourceURL={source_url}
         "#,
-        script, event_id, event_id, event_id
+        report_success = report_success,
+        source_url = source_url
     );
+    let script_with_result = format!("{}{}{}", wrapper_head, script, wrapper_tail);
+    let script_map = ScriptMap::new(&request.script, &format!("{}{}", wrapper_head, script_prefix));
 
     log::trace!("Executing script via window.eval()");
 
-    // Evaluate the script in the target window
-    if let Err(e) = target_window.eval(&script_with_result) {
-        log::error!("Failed to eval script: {}", e);
-        app.unlisten(listener_id);
-        return Err(crate::Error::ExecuteError(format!("Failed to eval script: {}", e)));
+    // Evaluate the script in the target window, bracketed by the preamble's
+    // syntax probe and fallback when there is one
+    let preamble_flag = format!("{}:preamble", execution_id);
+    let syntax_check = script_map::syntax_check_script(
+        &parsed_flag,
+        preamble.as_ref().map(|_| preamble_flag.as_str()),
+        &script_with_result,
+        &report_result,
+    );
+    let scripts = match &preamble {
+        Some(preamble) => vec![
+            preamble::probe_script(&preamble_flag, preamble),
+            script_with_result,
+            syntax_check,
+            preamble::fallback_script(&preamble_flag, &report_result),
+        ],
+        None => vec![script_with_result, syntax_check],
+    };
+    for script in scripts {
+        if let Err(e) = target_window.eval(&script) {
+            log::error!("Failed to eval script: {}", e);
+            return Err(crate::Error::ExecuteError(format!("Failed to eval script: {}", e)));
+        }
     }
 
     log::trace!("Waiting for execute result (30s timeout)");
@@ -446,35 +1045,261 @@ pub(crate) async fn execute<R: Runtime>(
         Ok(Ok(Ok(result))) => {
             log::debug!("Execute completed successfully");
             log::trace!("Result: {:?}", result);
-            app.unlisten(listener_id);
-            Ok(result)
+            if !request.detailed {
+                return Ok(result);
+            }
+            let response = executions::detailed_response(result, started.elapsed(), &window_label)?;
+            Ok(serde_json::to_value(response)?)
         }
         Ok(Ok(Err(e))) => {
+            let e = script_map.map_error(e);
             log::error!("JS error during execution: {}", e);
-            app.unlisten(listener_id);
             Err(e)
         }
         Ok(Err(_)) => {
             // Channel closed without sending (shouldn't happen)
-            log::error!("Channel closed unexpectedly. Event ID: {}. Window: {}", event_id, window_label);
-            app.unlisten(listener_id);
+            log::error!("Channel closed unexpectedly. Execution ID: {}. Window: {}", execution_id, window_label);
             Err(crate::Error::ExecuteError(format!(
-                "Channel closed unexpectedly. Event ID: {}. Window: {}",
-                event_id, window_label
+                "Channel closed unexpectedly. Execution ID: {}. Window: {}",
+                execution_id, window_label
             )))
         }
         Err(_) => {
-            log::error!("Timeout waiting for execute result after 30s. Event ID: {}. Window: {}",
-                event_id, window_label);
-            app.unlisten(listener_id);
-            Err(crate::Error::ExecuteError(format!(
-                "Script execution timed out after 30s. Event ID: {}. Window: {}",
-                event_id, window_label
+            log::error!("Timeout waiting for execute result after 30s. Execution ID: {}. Window: {}",
+                execution_id, window_label);
+            Err(crate::Error::ExecuteTimeout(format!(
+                "no result after 30s. Execution ID: {}. Window: {}",
+                execution_id, window_label
             )))
         }
     }
 }
 
+/// Run the web storage helper's `operation` in the page of window `label`,
+/// through the same eval-and-report path as execute
+async fn run_web_storage<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    label: &str,
+    operation: &str,
+    input: JsonValue,
+) -> Result<JsonValue> {
+    let window = find_window(app, label)?;
+    let request = ExecuteRequest {
+        script: web_storage::script(operation, &input),
+        args: Vec::new(),
+        window_label: None,
+        detailed: false,
+    };
+    execute_script(app.clone(), window, &request).await.map_err(|e| match e {
+        crate::Error::ExecuteError(message) => crate::Error::WebStorageError(message),
+        e => e,
+    })
+}
+
+/// Collect the JavaScript coverage (`window.__coverage__`, as written by
+/// istanbul instrumentation) of the page in window `label`. It's sent back in
+/// chunks rather than as one execute result, so large objects arrive intact.
+/// With `output_path` the coverage is written there instead of returned.
+#[command]
+pub(crate) async fn collect_coverage<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    label: String,
+    output_path: Option<std::path::PathBuf>,
+) -> Result<CoverageResult> {
+    let window = find_window(&app, &label)?;
+    collect_window_coverage(&app, window, output_path).await
+}
+
+/// Receive the next chunk of a page's coverage; invoked by the collector
+/// script that collect_coverage evaluates
+#[command]
+pub(crate) async fn coverage_chunk<R: Runtime>(
+    window: WebviewWindow<R>,
+    state: State<'_, WdioState>,
+    id: String,
+    index: usize,
+    data: String,
+) -> Result<()> {
+    state.coverage.append(&id, window.label(), index, &data)
+}
+
+/// Collect the coverage of `window` through the collector script, writing it
+/// to `output_path` when given
+pub(crate) async fn collect_window_coverage<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    window: WebviewWindow<R>,
+    output_path: Option<std::path::PathBuf>,
+) -> Result<CoverageResult> {
+    let state = app.state::<WdioState>();
+    let transfers = &state.coverage;
+    let label = window.label().to_string();
+    let id = format!("wdio-coverage-{}", Uuid::new_v4());
+    transfers.begin(&id, &label);
+    let request = ExecuteRequest {
+        script: coverage::collector_script(&id, coverage::CHUNK_CHARS),
+        args: Vec::new(),
+        window_label: None,
+        detailed: false,
+    };
+    // The collector awaits every chunk before it resolves, so they're all in;
+    // on a timeout the partial transfer is still dropped below
+    let collecting = execute_script(app.clone(), window, &request);
+    let collected = command_timeout::with_timeout(&state.config, "collect_coverage", collecting).await;
+    let data = transfers.finish(&id).unwrap_or_default();
+    collected.map_err(|e| match e {
+        crate::Error::ExecuteError(message) => crate::Error::CoverageError(message),
+        e => e,
+    })?;
+
+    if data == "null" {
+        return Ok(CoverageResult {
+            label,
+            bytes: 0,
+            path: None,
+            coverage: None,
+        });
+    }
+    let bytes = data.len();
+    match output_path {
+        Some(path) => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, data)?;
+            Ok(CoverageResult {
+                label,
+                bytes,
+                path: Some(path),
+                coverage: None,
+            })
+        }
+        None => Ok(CoverageResult {
+            label,
+            bytes,
+            path: None,
+            coverage: Some(serde_json::from_str(&data)?),
+        }),
+    }
+}
+
+/// Clear the given kinds of storage, or all of them, in the page of window
+/// `label`. IndexedDB databases are emptied rather than deleted, so an app
+/// holding them open isn't blocked.
+#[command]
+pub(crate) async fn clear_web_storage<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    label: String,
+    kinds: Option<Vec<WebStorageKind>>,
+) -> Result<Vec<WebStorageResult>> {
+    let kinds = kinds.unwrap_or_else(|| {
+        vec![
+            WebStorageKind::LocalStorage,
+            WebStorageKind::SessionStorage,
+            WebStorageKind::IndexedDb,
+            WebStorageKind::Cookies,
+        ]
+    });
+    let report = run_web_storage(&app, &label, "clear", serde_json::json!({ "kinds": kinds })).await?;
+    Ok(serde_json::from_value(report)?)
+}
+
+/// Snapshot the storage of the page in window `label`
+#[command]
+pub(crate) async fn snapshot_web_storage<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    label: String,
+) -> Result<WebStorageSnapshot> {
+    let report = run_web_storage(&app, &label, "snapshot", JsonValue::Null).await?;
+    Ok(serde_json::from_value(report)?)
+}
+
+/// Put the page in window `label` back to `snapshot`. Each kind in the
+/// snapshot replaces the page's current contents; IndexedDB databases that
+/// aren't in it are left alone.
+#[command]
+pub(crate) async fn restore_web_storage<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    label: String,
+    snapshot: WebStorageSnapshot,
+) -> Result<Vec<WebStorageResult>> {
+    let report = run_web_storage(&app, &label, "restore", serde_json::to_value(&snapshot)?).await?;
+    Ok(serde_json::from_value(report)?)
+}
+
+/// Deliver the result of an execute call; invoked by the script execute
+/// injects, from the window it runs in
+#[command]
+pub(crate) async fn resolve_execute<R: Runtime>(
+    webview: tauri::Webview<R>,
+    state: State<'_, WdioState>,
+    id: String,
+    report: JsonValue,
+) -> Result<()> {
+    if id == bridge::READY_ID {
+        state.bridges.ready(webview.label());
+        return Ok(());
+    }
+    // An oversized result fails the execution instead of leaving it to time out
+    let result = match payload::check(&report, state.config.max_payload_bytes) {
+        Ok(()) => executions::result_from_report(&report)
+            .ok_or_else(|| crate::Error::ExecuteError(format!("malformed execute result: {}", report)))?,
+        Err(e) => Err(e),
+    };
+    state.executions.resolve(&id, Some(webview.label()), result)
+}
+
+/// Run `script` ahead of every executed script, in the same scope, so the
+/// helpers it declares are directly visible; replaces any previous preamble
+#[command]
+pub(crate) async fn set_execute_preamble(state: State<'_, WdioState>, script: String) -> Result<()> {
+    state.preamble.set(script);
+    Ok(())
+}
+
+/// Stop running a preamble before executed scripts
+#[command]
+pub(crate) async fn clear_execute_preamble(state: State<'_, WdioState>) -> Result<()> {
+    state.preamble.clear();
+    Ok(())
+}
+
+/// List execute calls still waiting for their script's result, oldest first
+#[command]
+pub(crate) async fn get_pending_executions(state: State<'_, WdioState>) -> Result<Vec<PendingExecution>> {
+    Ok(state.executions.list())
+}
+
+/// Report whether the app is ready for tests: which open windows have
+/// finished loading a page, how many execute calls are pending, and uptime
+#[command]
+pub(crate) async fn health_check<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    state: State<'_, WdioState>,
+) -> Result<HealthStatus> {
+    let open = app.webview_windows();
+    let loaded = state.page_loads.loaded().into_iter().filter(|label| open.contains_key(label)).collect();
+    Ok(state.health.status(loaded, state.executions.list().len()))
+}
+
+/// Stop the backend test clock at `at_ms` (Unix epoch milliseconds), or at
+/// the current time when omitted
+#[command]
+pub(crate) async fn clock_freeze<R: Runtime>(app: tauri::AppHandle<R>, at_ms: Option<u64>) -> Result<ClockStatus> {
+    Ok(app.wdio().test_clock().freeze(at_ms))
+}
+
+/// Move the backend test clock forward by `ms`, waking any sleeps that are now due
+#[command]
+pub(crate) async fn clock_advance<R: Runtime>(app: tauri::AppHandle<R>, ms: u64) -> Result<ClockStatus> {
+    Ok(app.wdio().test_clock().advance(ms))
+}
+
+/// Put the backend test clock back on real time
+#[command]
+pub(crate) async fn clock_reset<R: Runtime>(app: tauri::AppHandle<R>) -> Result<ClockStatus> {
+    Ok(app.wdio().test_clock().reset())
+}
+
 /// Get the label of the window that invoked this command
 #[command]
 pub(crate) async fn get_active_window_label<R: Runtime>(
@@ -483,12 +1308,895 @@ pub(crate) async fn get_active_window_label<R: Runtime>(
   Ok(window.label().to_string())
 }
 
-/// List all window labels in the application
+/// Whether plugin commands are enabled in this build. Always answers, so the
+/// JS service can tell a disabled plugin from a missing one.
+#[command]
+pub(crate) async fn is_enabled(state: State<'_, WdioState>) -> Result<bool> {
+  Ok(state.gate.is_enabled())
+}
+
+/// Report the plugin version, command protocol version, and compiled-in
+/// optional features so the JS service can detect a mismatched plugin
+#[command]
+pub(crate) async fn get_plugin_capabilities() -> Result<PluginCapabilities> {
+  Ok(PluginCapabilities {
+    plugin_version: env!("CARGO_PKG_VERSION").to_string(),
+    protocol_version: crate::PROTOCOL_VERSION,
+    features: crate::compiled_features(),
+  })
+}
+
+/// JSON Schemas of every command's arguments and result, with the permission
+/// that allows it, for clients other than the JS service. Needs the
+/// `json-schema` feature.
+#[command]
+pub(crate) async fn get_command_schemas() -> Result<JsonValue> {
+  crate::schema::command_schemas()
+}
+
+/// Check which plugin commands the calling webview's capabilities allow, by
+/// probing each one from its page, and that a script evaluated there can
+/// report back. Meant to be called once at session start, so a missing
+/// capability entry fails fast instead of deep in a test run.
+#[command]
+pub(crate) async fn self_test<R: Runtime>(
+  window: WebviewWindow<R>,
+  state: State<'_, WdioState>,
+) -> Result<SelfTestReport> {
+  let nonce = Uuid::new_v4().to_string();
+  let report = state.self_tests.begin(&nonce);
+  if let Err(e) = window.eval(self_test::probe_script(&nonce)) {
+    log::warn!("[self_test] could not evaluate the probe script: {}", e);
+  }
+  let permitted = match tokio::time::timeout(self_test::ROUNDTRIP_TIMEOUT, report).await {
+    Ok(Ok(permitted)) => Some(permitted),
+    _ => {
+      log::warn!("[self_test] no report from the page within {:?}", self_test::ROUNDTRIP_TIMEOUT);
+      None
+    }
+  };
+  state.self_tests.cancel(&nonce);
+  Ok(self_test::report(permitted))
+}
+
+/// Receive the probe outcomes of a self test; invoked by the script that
+/// self_test evaluates
+#[command]
+pub(crate) async fn self_test_report(
+  state: State<'_, WdioState>,
+  nonce: String,
+  permitted: Vec<bool>,
+) -> Result<()> {
+  if !state.self_tests.finish(&nonce, permitted) {
+    log::debug!("[self_test] report for unknown or finished self test {}", nonce);
+  }
+  Ok(())
+}
+
+/// Get the application's name, version, Tauri version, build profile, and process id
+#[command]
+pub(crate) async fn get_app_info<R: Runtime>(
+  app: tauri::AppHandle<R>,
+) -> Result<AppInfo> {
+  let info = app.package_info();
+  Ok(AppInfo {
+    name: info.name.clone(),
+    version: info.version.to_string(),
+    tauri_version: tauri::VERSION.to_string(),
+    debug: cfg!(debug_assertions),
+    pid: std::process::id(),
+  })
+}
+
+/// Resolve the app's data, config, cache, log, temp, and resource directories
+/// and the executable path; paths that don't resolve on this platform are null
+#[command]
+pub(crate) async fn get_app_paths<R: Runtime>(
+  app: tauri::AppHandle<R>,
+) -> Result<AppPaths> {
+  let path = app.path();
+  Ok(AppPaths {
+    app_data_dir: path.app_data_dir().ok(),
+    app_config_dir: path.app_config_dir().ok(),
+    app_cache_dir: path.app_cache_dir().ok(),
+    app_log_dir: path.app_log_dir().ok(),
+    temp_dir: path.temp_dir().ok(),
+    resource_dir: path.resource_dir().ok(),
+    executable_path: std::env::current_exe().ok(),
+  })
+}
+
+/// Get the theme of the calling window and the system locale and timezone
+#[command]
+pub(crate) async fn get_system_appearance<R: Runtime>(
+  window: WebviewWindow<R>,
+) -> Result<SystemAppearance> {
+  crate::appearance::system_appearance(&window)
+}
+
+/// Force every window to `theme` so `prefers-color-scheme` follows it, or
+/// return them to the OS theme when `theme` is null
+#[command]
+pub(crate) async fn set_app_theme<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  theme: Option<AppTheme>,
+) -> Result<()> {
+  crate::appearance::set_app_theme(&app, theme)
+}
+
+/// Simulate the app moving to the background or foreground (or, on mobile,
+/// receiving a low-memory warning) for the window with `label`, defaulting
+/// to the calling window. Emits `wdio://lifecycle` to that window afterwards.
+#[command]
+pub(crate) async fn simulate_lifecycle<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  window: WebviewWindow<R>,
+  event: LifecycleEvent,
+  label: Option<String>,
+) -> Result<()> {
+  let window = match label {
+    Some(label) => find_window(&app, &label)?,
+    None => window,
+  };
+  crate::lifecycle::simulate(&window, event)
+}
+
+/// Begin test session `session_id`: log entries, invoke trace entries, and
+/// mocks created until end_session are tagged with it. Fails while another
+/// session is active.
+#[command]
+pub(crate) async fn begin_session<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  state: State<'_, WdioState>,
+  session_id: String,
+) -> Result<()> {
+  state.sessions.begin(&session_id, &state.logs, &state.invoke_trace)?;
+  eval_in_pages(&app, &sessions::begin_script(&session_id), "begin the session");
+  Ok(())
+}
+
+/// End test session `session_id`, removing the log entries, invoke trace
+/// entries, mocks, and mock calls created during it. State from outside the
+/// session is kept.
+#[command]
+pub(crate) async fn end_session<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  state: State<'_, WdioState>,
+  session_id: String,
+) -> Result<EndedSession> {
+  let ended = state.sessions.end(&session_id, &state.logs, &state.invoke_trace)?;
+  eval_in_pages(&app, &sessions::end_script(&session_id), "end the session");
+  Ok(ended)
+}
+
+/// Restore the plugin to its state at startup between spec files: mocks and
+/// their calls, logs, the invoke trace and interceptor settings, the active
+/// session, the preamble, pending executions, captured notifications,
+/// recorded events, and the test clock. Executions waiting on the calling
+/// window are spared, since this call may be running in one of them; what
+/// the app registered with `Wdio` is kept.
+#[command]
+pub(crate) async fn reset_all<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  webview: tauri::Webview<R>,
+  state: State<'_, WdioState>,
+) -> Result<ResetSummary> {
+  let mut summary = state.reset(&app, Some(webview.label()));
+  app.wdio().test_clock().reset();
+  summary.pages_reset = eval_in_pages(&app, &crate::state::page_reset_script(), "reset the page");
+  Ok(summary)
+}
+
+/// Window events recorded for window `label`, or for every window, at or
+/// after `since` (ms since epoch). Bursts of moves or resizes are coalesced
+/// to their first and latest event.
+#[command]
+pub(crate) async fn get_window_events(
+  state: State<'_, WdioState>,
+  label: Option<String>,
+  since: Option<u64>,
+) -> Result<WindowEvents> {
+  Ok(state.window_events.query(label.as_deref(), since))
+}
+
+/// Remove every recorded window event, returning how many there were
+#[command]
+pub(crate) async fn clear_window_events(state: State<'_, WdioState>) -> Result<usize> {
+  Ok(state.window_events.clear())
+}
+
+/// Run `script` in every open page, logging the pages it couldn't reach;
+/// returns the number it reached
+fn eval_in_pages<R: Runtime>(app: &tauri::AppHandle<R>, script: &str, action: &str) -> usize {
+  let mut reached = 0;
+  for window in app.webview_windows().values() {
+    match window.eval(script) {
+      Ok(()) => reached += 1,
+      Err(e) => log::warn!("Failed to {} in window '{}': {}", action, window.label(), e),
+    }
+  }
+  reached
+}
+
+/// Get the menu tree of the window with `label`, or of the app menu when no
+/// label is given. Returns an empty list when there is no menu.
+#[command]
+pub(crate) async fn get_menu_structure<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: Option<String>,
+) -> Result<Vec<MenuItemInfo>> {
+  match menu_for(&app, label.as_deref())? {
+    Some(menu) => crate::menu::describe(&menu.items().map_err(|e| crate::Error::MenuError(e.to_string()))?),
+    None => Ok(Vec::new()),
+  }
+}
+
+/// Activate a menu item as if it were clicked: check items flip their state
+/// and handlers registered with `Wdio::on_menu_event` run. The item is found
+/// by `id`, or by `path` of labels for items created without an explicit id.
+/// Returns the item's state after activation.
+#[command]
+pub(crate) async fn trigger_menu_item<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  id: Option<String>,
+  path: Option<Vec<String>>,
+  label: Option<String>,
+) -> Result<MenuItemInfo> {
+  let menu = menu_for(&app, label.as_deref())?
+    .ok_or_else(|| crate::Error::MenuError("no menu is set".to_string()))?;
+  let items = menu.items().map_err(|e| crate::Error::MenuError(e.to_string()))?;
+  let target = crate::menu::find(&crate::menu::describe(&items)?, id.as_deref(), path.as_deref())?.id.clone();
+  let item = crate::menu::find_kind(items, &tauri::menu::MenuId::new(&target))
+    .ok_or_else(|| crate::Error::MenuItemNotFound(target.clone()))?;
+
+  crate::menu::activate(&item)?;
+  app.wdio().dispatch_menu_event(item.id().clone())?;
+  crate::menu::describe_item(&item)
+}
+
+/// List the tray icons registered with `Wdio::register_tray` and their menus
+#[command]
+pub(crate) async fn get_tray_items<R: Runtime>(app: tauri::AppHandle<R>) -> Result<Vec<TrayInfo>> {
+  app.wdio().trays().items()
+}
+
+/// Activate an item of a tray's menu as if clicked, like `trigger_menu_item`.
+/// `tray_id` defaults to the first registered tray.
+#[command]
+pub(crate) async fn trigger_tray_menu_item<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  tray_id: Option<String>,
+  id: Option<String>,
+  path: Option<Vec<String>>,
+) -> Result<MenuItemInfo> {
+  let item = app
+    .wdio()
+    .trays()
+    .menu_item(tray_id.as_deref(), id.as_deref(), path.as_deref())?;
+
+  crate::menu::activate(&item)?;
+  app.wdio().dispatch_menu_event(item.id().clone())?;
+  crate::menu::describe_item(&item)
+}
+
+/// Send a click on a tray icon (press then release) to the handlers registered
+/// with `Wdio::on_tray_icon_event`. `position` defaults to the icon's center.
+#[command]
+pub(crate) async fn simulate_tray_click<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  tray_id: Option<String>,
+  button: Option<TrayMouseButton>,
+  position: Option<Point>,
+) -> Result<()> {
+  app
+    .wdio()
+    .trays()
+    .click(tray_id.as_deref(), button.unwrap_or_default(), position)
+}
+
+/// List the global shortcuts registered with `Wdio::on_global_shortcut`
+#[command]
+pub(crate) async fn list_global_shortcuts<R: Runtime>(app: tauri::AppHandle<R>) -> Result<Vec<ShortcutInfo>> {
+  app.wdio().shortcuts().list()
+}
+
+/// Invoke the handler of a global shortcut as if its keys were pressed and
+/// released. `accelerator` need not match the registered spelling.
+#[command]
+pub(crate) async fn trigger_global_shortcut<R: Runtime>(app: tauri::AppHandle<R>, accelerator: String) -> Result<()> {
+  app.wdio().shortcuts().trigger(&accelerator)
+}
+
+/// Simulate dragging `paths` onto window `label` and dropping them at
+/// `position` (physical pixels, default the window's center). Paths must
+/// exist unless `allow_missing` is set.
+#[command]
+pub(crate) async fn simulate_file_drop<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+  paths: Vec<String>,
+  position: Option<Point>,
+  allow_missing: Option<bool>,
+) -> Result<()> {
+  let window = find_window(&app, &label)?;
+  let paths = crate::file_drop::validate_paths(&paths, allow_missing.unwrap_or(false))?;
+  crate::file_drop::simulate(&window, paths, position)
+}
+
+/// Deliver `url` to the app's tauri-plugin-deep-link handlers as if the OS
+/// opened it while the app is running
+#[command]
+pub(crate) async fn simulate_deep_link<R: Runtime>(app: tauri::AppHandle<R>, url: String) -> Result<()> {
+  crate::deep_link::simulate(&app, crate::deep_link::parse(&url)?)
+}
+
+/// Snapshot of the app state registered as `name` with
+/// `Wdio::register_state_provider`
+#[command]
+pub(crate) async fn get_app_state<R: Runtime>(app: tauri::AppHandle<R>, name: String) -> Result<JsonValue> {
+  // The provider is cloned out of the registry and runs to completion here,
+  // so no lock is held while the response goes back over IPC
+  let provider = app.wdio().state_providers().get(&name)?;
+  Ok(provider(&app))
+}
+
+/// Run the backend handler registered as `name` with
+/// `Wdio::register_rust_handler`, passing `args` through as JSON
+#[command]
+pub(crate) async fn execute_rust<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  name: String,
+  args: Option<JsonValue>,
+) -> Result<JsonValue> {
+  let handler = app.wdio().rust_handlers().get(&name)?;
+  crate::rust_handlers::guarded(&name, || handler(&app, args.unwrap_or(JsonValue::Null)))
+}
+
+fn menu_for<R: Runtime>(app: &tauri::AppHandle<R>, label: Option<&str>) -> Result<Option<tauri::menu::Menu<R>>> {
+  Ok(match label {
+    Some(label) => find_window(app, label)?.menu(),
+    None => app.menu(),
+  })
+}
+
+/// Default delay before quitting/restarting so the IPC response reaches the caller first
+const LIFECYCLE_DELAY_MS: u64 = 100;
+
+/// Exit the app with `exit_code` (default 0) after `delay_ms`
+#[command]
+pub(crate) async fn quit_app<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  exit_code: Option<i32>,
+  delay_ms: Option<u64>,
+) -> Result<()> {
+  let delay = std::time::Duration::from_millis(delay_ms.unwrap_or(LIFECYCLE_DELAY_MS));
+  tauri::async_runtime::spawn(async move {
+    tokio::time::sleep(delay).await;
+    app.exit(exit_code.unwrap_or(0));
+  });
+  Ok(())
+}
+
+/// Restart the app after `delay_ms`
+#[command]
+pub(crate) async fn restart_app<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  delay_ms: Option<u64>,
+) -> Result<()> {
+  let delay = std::time::Duration::from_millis(delay_ms.unwrap_or(LIFECYCLE_DELAY_MS));
+  tauri::async_runtime::spawn(async move {
+    tokio::time::sleep(delay).await;
+    app.restart();
+  });
+  Ok(())
+}
+
+/// List all windows in the application, sorted by label
 #[command]
 pub(crate) async fn list_windows<R: Runtime>(
   app: tauri::AppHandle<R>,
-) -> Result<Vec<String>> {
-  Ok(app.webview_windows().keys().cloned().collect())
+) -> Result<Vec<WindowInfo>> {
+  let config = &app.state::<WdioState>().config;
+  command_timeout::with_retries(config, "list_windows", || {
+    let app = app.clone();
+    // Window geometry is read on the main thread, which may be busy
+    async move { Ok(tauri::async_runtime::spawn_blocking(move || window_infos(&app)).await?) }
+  })
+  .await
+}
+
+/// Details of every open window, sorted by label
+pub(crate) fn window_infos<R: Runtime>(app: &tauri::AppHandle<R>) -> Vec<WindowInfo> {
+  let mut windows: Vec<WindowInfo> = app
+    .webview_windows()
+    .iter()
+    .map(|(label, window)| window_info(label, window))
+    .collect();
+  windows.sort_by(|a, b| a.label.cmp(&b.label));
+  windows
+}
+
+/// Get details of the window with the given label
+#[command]
+pub(crate) async fn get_window_info<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+) -> Result<WindowInfo> {
+  let window = find_window(&app, &label)?;
+  Ok(window_info(&label, &window))
+}
+
+/// Show, unminimize, and focus the window with the given label
+#[command]
+pub(crate) async fn focus_window<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+) -> Result<()> {
+  let window = find_window(&app, &label)?;
+  if window.is_minimized().unwrap_or(false) {
+    window.unminimize()?;
+  }
+  window.show()?;
+  window.set_focus()?;
+  Ok(())
+}
+
+/// Move and resize a window; `unit` selects physical (default) or logical coordinates
+#[command]
+pub(crate) async fn set_window_bounds<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+  bounds: SetWindowBounds,
+) -> Result<()> {
+  let window = find_window(&app, &label)?;
+  let (position, size): (tauri::Position, tauri::Size) = match bounds.unit {
+    CoordinateUnit::Physical => (
+      tauri::PhysicalPosition::new(bounds.x.round() as i32, bounds.y.round() as i32).into(),
+      tauri::PhysicalSize::new(bounds.width.round() as u32, bounds.height.round() as u32).into(),
+    ),
+    CoordinateUnit::Logical => (
+      tauri::LogicalPosition::new(bounds.x, bounds.y).into(),
+      tauri::LogicalSize::new(bounds.width, bounds.height).into(),
+    ),
+  };
+  window.set_position(position)?;
+  window.set_size(size)?;
+  Ok(())
+}
+
+/// Maximize a window
+#[command]
+pub(crate) async fn maximize_window<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+) -> Result<()> {
+  Ok(find_window(&app, &label)?.maximize()?)
+}
+
+/// Minimize a window
+#[command]
+pub(crate) async fn minimize_window<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+) -> Result<()> {
+  Ok(find_window(&app, &label)?.minimize()?)
+}
+
+/// Restore a window from the minimized, maximized, or fullscreen state
+#[command]
+pub(crate) async fn restore_window<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+) -> Result<()> {
+  let window = find_window(&app, &label)?;
+  if window.is_fullscreen().unwrap_or(false) {
+    window.set_fullscreen(false)?;
+  }
+  if window.is_minimized().unwrap_or(false) {
+    window.unminimize()?;
+  }
+  if window.is_maximized().unwrap_or(false) {
+    window.unmaximize()?;
+  }
+  Ok(())
+}
+
+/// Enter or leave fullscreen
+#[command]
+pub(crate) async fn set_fullscreen<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+  fullscreen: bool,
+) -> Result<()> {
+  Ok(find_window(&app, &label)?.set_fullscreen(fullscreen)?)
+}
+
+/// Keep a window above all others, or stop doing so
+#[command]
+pub(crate) async fn set_always_on_top<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+  always_on_top: bool,
+) -> Result<()> {
+  Ok(find_window(&app, &label)?.set_always_on_top(always_on_top)?)
+}
+
+/// Open the devtools for a window (debug builds or the `devtools` feature only)
+#[command]
+pub(crate) async fn open_devtools<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+) -> Result<()> {
+  let window = find_window(&app, &label)?;
+  #[cfg(any(debug_assertions, feature = "devtools"))]
+  {
+    window.open_devtools();
+    Ok(())
+  }
+  #[cfg(not(any(debug_assertions, feature = "devtools")))]
+  {
+    let _ = window;
+    Err(devtools_unsupported())
+  }
+}
+
+/// Close the devtools for a window (debug builds or the `devtools` feature only)
+#[command]
+pub(crate) async fn close_devtools<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+) -> Result<()> {
+  let window = find_window(&app, &label)?;
+  #[cfg(any(debug_assertions, feature = "devtools"))]
+  {
+    window.close_devtools();
+    Ok(())
+  }
+  #[cfg(not(any(debug_assertions, feature = "devtools")))]
+  {
+    let _ = window;
+    Err(devtools_unsupported())
+  }
+}
+
+/// Whether the devtools are open for a window (debug builds or the `devtools` feature only)
+#[command]
+pub(crate) async fn is_devtools_open<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+) -> Result<bool> {
+  let window = find_window(&app, &label)?;
+  #[cfg(any(debug_assertions, feature = "devtools"))]
+  {
+    Ok(window.is_devtools_open())
+  }
+  #[cfg(not(any(debug_assertions, feature = "devtools")))]
+  {
+    let _ = window;
+    Err(devtools_unsupported())
+  }
+}
+
+#[cfg(not(any(debug_assertions, feature = "devtools")))]
+fn devtools_unsupported() -> crate::Error {
+  crate::Error::UnsupportedInBuild(
+    "devtools are only available in debug builds or with the tauri-plugin-wdio `devtools` feature".to_string(),
+  )
+}
+
+/// Navigate a window to `url` (absolute, or relative to the current page).
+/// Returns the page load count before navigating, to pass to wait_for_load.
+#[command]
+pub(crate) async fn navigate<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  state: State<'_, WdioState>,
+  label: String,
+  url: String,
+) -> Result<u64> {
+  let window = find_window(&app, &label)?;
+  let current = window.url()?;
+  let target = navigation::resolve_navigation(
+    &current,
+    &url,
+    app.config().build.dev_url.as_ref(),
+    &state.config.navigation_allowlist,
+  )?;
+  let before = state.page_loads.count(&label);
+  window.navigate(target)?;
+  Ok(before)
+}
+
+/// Reload a window's page. Returns the page load count before reloading, to pass to wait_for_load.
+#[command]
+pub(crate) async fn reload<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  state: State<'_, WdioState>,
+  label: String,
+) -> Result<u64> {
+  let window = find_window(&app, &label)?;
+  let before = state.page_loads.count(&label);
+  window.eval("location.reload()")?;
+  Ok(before)
+}
+
+/// Wait for a window to finish loading a page. With `after` (the value returned by
+/// navigate or reload) this also succeeds if that load already finished; without it,
+/// waits for the next load. Returns the new page load count.
+#[command]
+pub(crate) async fn wait_for_load<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  state: State<'_, WdioState>,
+  label: String,
+  timeout_ms: Option<u64>,
+  after: Option<u64>,
+) -> Result<u64> {
+  find_window(&app, &label)?;
+  let after = after.unwrap_or_else(|| state.page_loads.count(&label));
+  state.page_loads
+    .wait_for(&label, after, std::time::Duration::from_millis(timeout_ms.unwrap_or(10_000)))
+    .await
+}
+
+/// Wait for a window whose label matches `label` to be created and return
+/// its state. `label` may be a glob, where `*` matches any run of characters
+/// and `?` any one, for apps that generate labels. A matching window that is
+/// already open is returned right away.
+#[command]
+pub(crate) async fn wait_for_window<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  state: State<'_, WdioState>,
+  label: String,
+  timeout_ms: Option<u64>,
+) -> Result<WindowInfo> {
+  let timeout = std::time::Duration::from_millis(timeout_ms.unwrap_or(10_000));
+  let found = state.window_changes
+    .wait_until(timeout, || {
+      let mut windows: Vec<_> = app
+        .webview_windows()
+        .into_iter()
+        .filter(|(window_label, _)| window_watch::label_matches(&label, window_label))
+        .collect();
+      windows.sort_by(|(a, _), (b, _)| a.cmp(b));
+      windows.into_iter().next()
+    })
+    .await;
+  match found {
+    Some((window_label, window)) => Ok(window_info(&window_label, &window)),
+    None => Err(crate::Error::WindowError(format!(
+      "timed out after {}ms waiting for a window matching '{}'",
+      timeout.as_millis(),
+      label
+    ))),
+  }
+}
+
+/// Wait for window `label` to be destroyed. Succeeds right away if no such window is open.
+#[command]
+pub(crate) async fn wait_for_window_close<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  state: State<'_, WdioState>,
+  label: String,
+  timeout_ms: Option<u64>,
+) -> Result<()> {
+  let timeout = std::time::Duration::from_millis(timeout_ms.unwrap_or(10_000));
+  state.window_changes
+    .wait_until(timeout, || app.get_webview_window(&label).is_none().then_some(()))
+    .await
+    .ok_or_else(|| {
+      crate::Error::WindowError(format!(
+        "timed out after {}ms waiting for window '{}' to close",
+        timeout.as_millis(),
+        label
+      ))
+    })
+}
+
+/// How long request_window_close waits for the close request to reach the app
+const CLOSE_REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How long after the app's close handlers ran request_window_close waits for
+/// the window to be destroyed before reporting the close as prevented
+const CLOSE_GRACE: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Ask window `label` to close, as its close button does, and report whether
+/// the app prevented it. Handlers run synchronously when the request arrives,
+/// so once the plugin records the close request the outcome is settled: the
+/// close counts as prevented if the window is still open `CLOSE_GRACE` later.
+/// Fails if the request isn't recorded within `CLOSE_REQUEST_TIMEOUT`.
+#[command]
+pub(crate) async fn request_window_close<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  state: State<'_, WdioState>,
+  label: String,
+) -> Result<CloseRequestOutcome> {
+  let window = find_window(&app, &label)?;
+  let since = crate::log_buffer::now_ms();
+  window.close()?;
+  if !state
+    .window_events
+    .wait_for(&label, WindowEventKind::CloseRequested, since, CLOSE_REQUEST_TIMEOUT)
+    .await
+  {
+    return Err(crate::Error::WindowError(format!(
+      "window '{}' did not receive the close request within {}ms",
+      label,
+      CLOSE_REQUEST_TIMEOUT.as_millis()
+    )));
+  }
+  let closed = state
+    .window_changes
+    .wait_until(CLOSE_GRACE, || app.get_webview_window(&label).is_none().then_some(()))
+    .await
+    .is_some();
+  Ok(CloseRequestOutcome { prevented: !closed })
+}
+
+/// Destroy window `label` without a close request, so the app can't prevent it
+#[command]
+pub(crate) async fn force_close_window<R: Runtime>(app: tauri::AppHandle<R>, label: String) -> Result<()> {
+  find_window(&app, &label)?.destroy()?;
+  Ok(())
+}
+
+/// Snapshot the native accessibility tree of window `label`: its title bar,
+/// menus, and other native widgets, with the web content as a single node.
+/// Walks `max_depth` levels (default 10). Needs the `a11y` feature; only
+/// Linux (AT-SPI) is supported so far.
+#[command]
+pub(crate) async fn get_native_accessibility_tree<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+  max_depth: Option<u32>,
+) -> Result<AccessibilityNode> {
+  let title = find_window(&app, &label)?.title()?;
+  let max_depth = max_depth.unwrap_or(crate::a11y::DEFAULT_MAX_DEPTH);
+  // The app answers accessibility queries on its main thread, so don't block an async worker waiting for them
+  tauri::async_runtime::spawn_blocking(move || crate::a11y::snapshot(&title, max_depth))
+    .await
+    .map_err(|e| crate::Error::AccessibilityError(e.to_string()))?
+}
+
+/// Type `keys` into the window with the given label as OS-level key events,
+/// which reach shortcuts handled by Rust or native UI rather than only the
+/// page. The window is focused first. Needs the `native-input` feature.
+#[command]
+pub(crate) async fn send_native_keys<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+  keys: Vec<KeyChord>,
+) -> Result<()> {
+  native_input::ensure_supported()?;
+  let chords = native_input::parse_chords(&keys)?;
+  let window = find_window(&app, &label)?;
+  focus_for_input(&label, &window).await?;
+  tauri::async_runtime::spawn_blocking(move || native_input::send_keys(&chords))
+    .await
+    .map_err(|e| crate::Error::NativeInputError(e.to_string()))?
+}
+
+/// Click `button` (default left) as an OS-level mouse event at `x`, `y`
+/// logical pixels from the outer top-left corner of the window with the given
+/// label. The window is focused first. Needs the `native-input` feature.
+#[command]
+pub(crate) async fn send_native_click<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+  x: f64,
+  y: f64,
+  button: Option<MouseButton>,
+) -> Result<()> {
+  native_input::ensure_supported()?;
+  let window = find_window(&app, &label)?;
+  focus_for_input(&label, &window).await?;
+  let point = native_input::screen_point(window.outer_position()?, window.scale_factor()?, x, y);
+  let button = button.unwrap_or_default();
+  tauri::async_runtime::spawn_blocking(move || native_input::click(point, button))
+    .await
+    .map_err(|e| crate::Error::NativeInputError(e.to_string()))?
+}
+
+/// Show and focus `window`, then wait for it to report focus, since OS input
+/// goes to whichever window has it
+async fn focus_for_input<R: Runtime>(label: &str, window: &WebviewWindow<R>) -> Result<()> {
+  if window.is_minimized().unwrap_or(false) {
+    window.unminimize()?;
+  }
+  window.show()?;
+  window.set_focus()?;
+  let deadline = std::time::Instant::now() + native_input::FOCUS_TIMEOUT;
+  while !window.is_focused().unwrap_or(false) {
+    if std::time::Instant::now() >= deadline {
+      return Err(crate::Error::WindowNotFocused(label.to_string()));
+    }
+    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+  }
+  Ok(())
+}
+
+/// List all connected monitors
+#[command]
+pub(crate) async fn get_monitors<R: Runtime>(
+  app: tauri::AppHandle<R>,
+) -> Result<Vec<MonitorInfo>> {
+  let primary = app.primary_monitor()?;
+  let monitors = app.available_monitors()?;
+  Ok(monitors
+    .iter()
+    .map(|monitor| monitor_info(monitor, primary.as_ref()))
+    .collect())
+}
+
+/// Get the monitor a window is on, or None if it cannot be determined (e.g. off-screen)
+#[command]
+pub(crate) async fn get_current_monitor<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+) -> Result<Option<MonitorInfo>> {
+  let window = find_window(&app, &label)?;
+  let primary = window.primary_monitor()?;
+  let current = window.current_monitor()?;
+  Ok(current.map(|monitor| monitor_info(&monitor, primary.as_ref())))
+}
+
+fn monitor_info(monitor: &tauri::Monitor, primary: Option<&tauri::Monitor>) -> MonitorInfo {
+  // Monitors have no stable id; name plus position identifies one on the virtual desktop
+  let is_primary = primary.is_some_and(|p| {
+    p.name() == monitor.name() && p.position() == monitor.position()
+  });
+  MonitorInfo {
+    name: monitor.name().cloned(),
+    position: Point {
+      x: monitor.position().x,
+      y: monitor.position().y,
+    },
+    size: Dimensions {
+      width: monitor.size().width,
+      height: monitor.size().height,
+    },
+    scale_factor: monitor.scale_factor(),
+    is_primary,
+  }
+}
+
+/// Capture a window as PNG or JPEG; returns a data URL, or the file path when `options.path` is set
+#[command]
+pub(crate) async fn capture_window<R: Runtime>(
+  app: tauri::AppHandle<R>,
+  label: String,
+  options: Option<ScreenshotOptions>,
+) -> Result<String> {
+  let window = find_window(&app, &label)?;
+  let options = options.unwrap_or_default();
+  let config = &app.state::<WdioState>().config;
+  command_timeout::with_timeout(config, "capture_window", crate::desktop::capture_window(&window, &options)).await
+}
+
+fn find_window<R: Runtime>(app: &tauri::AppHandle<R>, label: &str) -> Result<WebviewWindow<R>> {
+  app
+    .get_webview_window(label)
+    .ok_or_else(|| crate::Error::WindowNotFound(label.to_string()))
+}
+
+fn window_info<R: Runtime>(label: &str, window: &WebviewWindow<R>) -> WindowInfo {
+  let bounds = match (window.outer_position(), window.outer_size()) {
+    (Ok(position), Ok(size)) => Some(WindowBounds {
+      x: position.x,
+      y: position.y,
+      width: size.width,
+      height: size.height,
+    }),
+    _ => None,
+  };
+  WindowInfo {
+    label: label.to_string(),
+    title: window.title().unwrap_or_default(),
+    focused: window.is_focused().unwrap_or(false),
+    visible: window.is_visible().unwrap_or(false),
+    maximized: window.is_maximized().unwrap_or(false),
+    minimized: window.is_minimized().unwrap_or(false),
+    fullscreen: window.is_fullscreen().unwrap_or(false),
+    bounds,
+  }
 }
 
 /// Get detailed state of all windows (for generic window management like Electron)
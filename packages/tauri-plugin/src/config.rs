@@ -0,0 +1,186 @@
+use serde_json::{Map, Value as JsonValue};
+
+use crate::models::WdioConfig;
+use crate::{Error, Result};
+
+/// Prefix of the environment variables that override config fields, e.g.
+/// `WDIO_TAURI_LOG_FILE` for `log_file`
+pub(crate) const ENV_PREFIX: &str = "WDIO_TAURI_";
+
+/// Layer the `plugins.wdio` section of `tauri.conf.json`, then `WDIO_TAURI_*`
+/// environment variables, over the config passed to `init_with_config`.
+/// Invalid file values are an error; invalid environment values are skipped
+/// with a warning, as the variable may be meant for a different app.
+pub(crate) fn resolve(
+    base: &WdioConfig,
+    file: &JsonValue,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<(WdioConfig, Vec<String>)> {
+    let mut warnings = Vec::new();
+    let mut fields = match serde_json::to_value(base) {
+        Ok(JsonValue::Object(fields)) => fields,
+        Ok(_) => unreachable!("WdioConfig serializes to an object"),
+        Err(e) => return Err(Error::ConfigError(e.to_string())),
+    };
+
+    match file {
+        JsonValue::Null => {}
+        JsonValue::Object(overrides) => {
+            for (key, value) in overrides {
+                if fields.contains_key(key) {
+                    fields.insert(key.clone(), value.clone());
+                } else {
+                    warnings.push(format!("Ignoring unknown plugins.wdio option `{}`", key));
+                }
+            }
+            parse(&fields).map_err(|e| Error::ConfigError(format!("plugins.wdio: {}", e)))?;
+        }
+        _ => return Err(Error::ConfigError("plugins.wdio must be an object".to_string())),
+    }
+
+    let keys: Vec<String> = fields.keys().cloned().collect();
+    for key in keys {
+        let name = env_name(&key);
+        let Some(raw) = env(&name).filter(|raw| !raw.trim().is_empty()) else {
+            continue;
+        };
+        let value = env_candidates(raw.trim(), &fields[&key]).into_iter().find(|value| {
+            let mut candidate = fields.clone();
+            candidate.insert(key.clone(), value.clone());
+            parse(&candidate).is_ok()
+        });
+        match value {
+            Some(value) => {
+                fields.insert(key, value);
+            }
+            None => warnings.push(format!("Ignoring invalid {}: {:?}", name, raw)),
+        }
+    }
+
+    let config = parse(&fields).map_err(|e| Error::ConfigError(e.to_string()))?;
+    Ok((config, warnings))
+}
+
+fn parse(fields: &Map<String, JsonValue>) -> serde_json::Result<WdioConfig> {
+    serde_json::from_value(JsonValue::Object(fields.clone()))
+}
+
+/// `logFileMaxBytes` -> `WDIO_TAURI_LOG_FILE_MAX_BYTES`
+fn env_name(key: &str) -> String {
+    let mut name = ENV_PREFIX.to_string();
+    for ch in key.chars() {
+        if ch.is_ascii_uppercase() {
+            name.push('_');
+        }
+        name.push(ch.to_ascii_uppercase());
+    }
+    name
+}
+
+/// Values to try for a raw environment string, in order: JSON (numbers,
+/// booleans, arrays), a comma-separated list for list fields, then the
+/// string itself
+fn env_candidates(raw: &str, current: &JsonValue) -> Vec<JsonValue> {
+    let mut candidates = Vec::new();
+    if let Ok(value) = serde_json::from_str(raw) {
+        candidates.push(value);
+    }
+    if current.is_array() {
+        candidates.push(
+            raw.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| JsonValue::String(item.to_string()))
+                .collect(),
+        );
+    }
+    candidates.push(JsonValue::String(raw.to_string()));
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::LogFormat;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_env_overrides_file_overrides_code() {
+        let base = WdioConfig {
+            log_buffer_capacity: 10,
+            event_recording_capacity: 20,
+            capture_panics: false,
+            ..Default::default()
+        };
+        let file = json!({ "logBufferCapacity": 30, "eventRecordingCapacity": 40 });
+        let (config, warnings) = resolve(&base, &file, env(&[("WDIO_TAURI_EVENT_RECORDING_CAPACITY", "50")])).unwrap();
+
+        assert!(!config.capture_panics);
+        assert_eq!(config.log_buffer_capacity, 30);
+        assert_eq!(config.event_recording_capacity, 50);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_parses_env_values_by_field_type() {
+        let (config, warnings) = resolve(
+            &WdioConfig::default(),
+            &JsonValue::Null,
+            env(&[
+                ("WDIO_TAURI_CAPTURE_NOTIFICATIONS", "true"),
+                ("WDIO_TAURI_MIN_LEVEL", "debug"),
+                ("WDIO_TAURI_LOG_FORMAT", "json"),
+                ("WDIO_TAURI_LOG_FILE", "/tmp/123"),
+                ("WDIO_TAURI_CONTROL_PORT", " 4445 "),
+                ("WDIO_TAURI_ENV_ALLOWLIST", "HOME, PATH"),
+                ("WDIO_TAURI_NAVIGATION_ALLOWLIST", r#"["https://example.com"]"#),
+                ("WDIO_TAURI_LOG_FILE_KEEP", ""),
+            ]),
+        )
+        .unwrap();
+
+        assert!(config.capture_notifications);
+        assert_eq!(config.min_level, log::LevelFilter::Debug);
+        assert_eq!(config.log_format, LogFormat::Json);
+        assert_eq!(config.log_file, Some(PathBuf::from("/tmp/123")));
+        assert_eq!(config.control_port, Some(4445));
+        assert_eq!(config.env_allowlist, vec!["HOME", "PATH"]);
+        assert_eq!(config.navigation_allowlist, vec!["https://example.com"]);
+        assert_eq!(config.log_file_keep, WdioConfig::default().log_file_keep);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_skips_invalid_env_values_with_warning() {
+        let (config, warnings) = resolve(
+            &WdioConfig::default(),
+            &JsonValue::Null,
+            env(&[("WDIO_TAURI_CONTROL_PORT", "99999"), ("WDIO_TAURI_CAPTURE_PANICS", "maybe")]),
+        )
+        .unwrap();
+
+        assert_eq!(config.control_port, None);
+        assert!(config.capture_panics);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.iter().any(|w| w.contains("WDIO_TAURI_CONTROL_PORT")));
+    }
+
+    #[test]
+    fn test_rejects_invalid_file_config_and_warns_on_unknown_keys() {
+        let error = resolve(&WdioConfig::default(), &json!({ "logBufferCapacity": "lots" }), env(&[])).unwrap_err();
+        assert!(error.to_string().contains("plugins.wdio"));
+        assert!(resolve(&WdioConfig::default(), &json!(true), env(&[])).is_err());
+
+        let (_, warnings) = resolve(&WdioConfig::default(), &json!({ "logBuferCapacity": 1 }), env(&[])).unwrap();
+        assert_eq!(warnings, vec!["Ignoring unknown plugins.wdio option `logBuferCapacity`"]);
+    }
+}
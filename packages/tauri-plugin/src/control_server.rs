@@ -0,0 +1,628 @@
+use tauri::{AppHandle, Runtime};
+
+#[cfg(feature = "control-server")]
+pub(crate) use server::start;
+
+#[cfg(not(feature = "control-server"))]
+pub(crate) use unsupported::start;
+
+#[cfg(feature = "control-server")]
+mod server {
+    use std::collections::{HashMap, VecDeque};
+    use std::net::SocketAddr;
+    use std::path::{Path, PathBuf};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+    use axum::extract::{Query, Request, State};
+    use axum::http::header::{AUTHORIZATION, ORIGIN};
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::middleware::{self, Next};
+    use axum::response::{IntoResponse, Response};
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value as JsonValue;
+    use tauri::{Manager, WebviewWindow};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::commands;
+    use crate::models::{ExecuteRequest, LogFilter, SetWindowBounds, StreamFrame, StreamSubscription};
+    use crate::state::WdioState;
+    use crate::stream::StreamHub;
+    use crate::{Error, Result};
+
+    /// Window `execute` runs in when the request doesn't name one
+    const DEFAULT_WINDOW: &str = "main";
+
+    /// Failed token checks allowed within [`AUTH_FAILURE_WINDOW`] before
+    /// further requests are turned away
+    const MAX_AUTH_FAILURES: usize = 10;
+
+    const AUTH_FAILURE_WINDOW: Duration = Duration::from_secs(10);
+
+    struct ControlState<R: Runtime> {
+        app: AppHandle<R>,
+    }
+
+    /// Gate in front of every route. Browsers send an `Origin` header with
+    /// every cross-origin request and WebSocket handshake and native clients
+    /// don't, so refusing it keeps web pages out even if they learn the token.
+    struct Auth {
+        token: String,
+        failures: Mutex<VecDeque<Instant>>,
+    }
+
+    impl Auth {
+        fn new(token: String) -> Self {
+            Self {
+                token,
+                failures: Mutex::default(),
+            }
+        }
+
+        fn check(&self, headers: &HeaderMap, query_token: Option<&str>) -> std::result::Result<(), StatusCode> {
+            if headers.contains_key(ORIGIN) {
+                return Err(StatusCode::FORBIDDEN);
+            }
+            let mut failures = self.failures.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            while failures.front().is_some_and(|at| now.duration_since(*at) >= AUTH_FAILURE_WINDOW) {
+                failures.pop_front();
+            }
+            // Turn requests away without comparing the token, so guessing stays slow
+            if failures.len() >= MAX_AUTH_FAILURES {
+                return Err(StatusCode::TOO_MANY_REQUESTS);
+            }
+            if authorized(&self.token, headers, query_token) {
+                Ok(())
+            } else {
+                failures.push_back(now);
+                Err(StatusCode::UNAUTHORIZED)
+            }
+        }
+    }
+
+    /// Envelope of a control request; `payload` holds the same arguments the
+    /// IPC command takes
+    #[derive(Deserialize)]
+    struct ControlRequest {
+        #[serde(default)]
+        id: JsonValue,
+        command: String,
+        #[serde(default)]
+        payload: JsonValue,
+    }
+
+    #[derive(Serialize, Debug)]
+    #[serde(rename_all = "lowercase")]
+    enum Outcome {
+        Result(JsonValue),
+        Error(Error),
+    }
+
+    #[derive(Serialize, Debug)]
+    struct ControlResponse {
+        id: JsonValue,
+        #[serde(flatten)]
+        outcome: Outcome,
+    }
+
+    /// Commands reachable over the control channel, named like their IPC
+    /// counterparts. Mocks live in the frontend, so they go through `execute`.
+    #[derive(Deserialize, Debug)]
+    #[serde(
+        tag = "command",
+        content = "payload",
+        rename_all = "snake_case",
+        rename_all_fields = "camelCase"
+    )]
+    enum ControlCall {
+        Execute { request: ExecuteRequest },
+        GetLogs { filter: Option<LogFilter> },
+        ClearLogs {},
+        SetLogContext { context: String },
+        ClearLogContext {},
+        GetLogFilePath {},
+        GetAppInfo {},
+        GetPluginCapabilities {},
+        GetCommandSchemas {},
+        ListWindows {},
+        GetWindowInfo { label: String },
+        FocusWindow { label: String },
+        SetWindowBounds { label: String, bounds: SetWindowBounds },
+        MaximizeWindow { label: String },
+        MinimizeWindow { label: String },
+        RestoreWindow { label: String },
+        SetFullscreen { label: String, fullscreen: bool },
+        SetAlwaysOnTop { label: String, always_on_top: bool },
+    }
+
+    impl ControlCall {
+        fn parse(command: &str, payload: JsonValue) -> Result<Self> {
+            let payload = match payload {
+                JsonValue::Null => JsonValue::Object(Default::default()),
+                payload => payload,
+            };
+            serde_json::from_value(serde_json::json!({ "command": command, "payload": payload }))
+                .map_err(|e| Error::ControlError(format!("{}: {}", command, e)))
+        }
+    }
+
+    /// Bind the control server to 127.0.0.1:`port` on Tauri's async runtime
+    /// and print the session token clients must present to stderr, also
+    /// writing it to `token_file` when set
+    pub(crate) fn start<R: Runtime>(app: AppHandle<R>, port: u16, token_file: Option<PathBuf>) {
+        let auth = Arc::new(Auth::new(Uuid::new_v4().simple().to_string()));
+        let state = Arc::new(ControlState { app });
+
+        tauri::async_runtime::spawn(async move {
+            let addr = SocketAddr::from(([127, 0, 0, 1], port));
+            let listener = match tokio::net::TcpListener::bind(addr).await {
+                Ok(listener) => listener,
+                Err(e) => {
+                    log::error!("Failed to bind wdio control server to {}: {}", addr, e);
+                    return;
+                }
+            };
+            let port = listener.local_addr().map(|a| a.port()).unwrap_or(port);
+            eprintln!("[WDIO:Control] port={} token={}", port, auth.token);
+            if let Some(path) = token_file {
+                if let Err(e) = write_token_file(&path, &auth.token) {
+                    log::error!("Failed to write the control token to {}: {}", path.display(), e);
+                }
+            }
+
+            let router = Router::new()
+                .route("/rpc", post(rpc::<R>))
+                .route("/ws", get(ws::<R>))
+                .route("/stream", get(stream::<R>))
+                .with_state(state);
+            if let Err(e) = axum::serve(listener, protect(router, auth)).await {
+                log::error!("wdio control server error: {}", e);
+            }
+        });
+    }
+
+    /// Write the token to a file only the current user can read
+    fn write_token_file(path: &Path, token: &str) -> std::io::Result<()> {
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        std::io::Write::write_all(&mut options.open(path)?, token.as_bytes())
+    }
+
+    /// Run every request through `auth` before it reaches `router`
+    fn protect(router: Router, auth: Arc<Auth>) -> Router {
+        router.layer(middleware::from_fn_with_state(auth, require_auth))
+    }
+
+    async fn require_auth(
+        State(auth): State<Arc<Auth>>,
+        Query(query): Query<HashMap<String, String>>,
+        request: Request,
+        next: Next,
+    ) -> Response {
+        let query_token = query.get("token").map(String::as_str);
+        match auth.check(request.headers(), query_token) {
+            Ok(()) => next.run(request).await,
+            Err(status) => status.into_response(),
+        }
+    }
+
+    async fn rpc<R: Runtime>(State(state): State<Arc<ControlState<R>>>, body: String) -> Response {
+        Json(handle(&state.app, &body).await).into_response()
+    }
+
+    async fn ws<R: Runtime>(State(state): State<Arc<ControlState<R>>>, upgrade: WebSocketUpgrade) -> Response {
+        upgrade.on_upgrade(move |socket| serve_socket(state, socket))
+    }
+
+    /// Answer each text message on its own task so a slow `execute` doesn't
+    /// hold up the requests behind it; responses carry the request id
+    async fn serve_socket<R: Runtime>(state: Arc<ControlState<R>>, mut socket: WebSocket) {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+        loop {
+            tokio::select! {
+                message = socket.recv() => match message {
+                    Some(Ok(Message::Text(text))) => {
+                        let state = state.clone();
+                        let tx = tx.clone();
+                        tauri::async_runtime::spawn(async move {
+                            let response = handle(&state.app, text.as_str()).await;
+                            let _ = tx.send(serde_json::to_string(&response).unwrap_or_default());
+                        });
+                    }
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                },
+                Some(response) = rx.recv() => {
+                    if socket.send(Message::Text(response.into())).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn stream<R: Runtime>(State(state): State<Arc<ControlState<R>>>, upgrade: WebSocketUpgrade) -> Response {
+        let hub = state.app.state::<WdioState>().logs.stream().clone();
+        upgrade.on_upgrade(move |socket| serve_stream(hub, socket))
+    }
+
+    /// Push the frames the client subscribed to until it disconnects; each
+    /// text message replaces the subscription. A client that stops reading
+    /// only loses its oldest frames, it never holds up the app.
+    async fn serve_stream(hub: StreamHub, mut socket: WebSocket) {
+        let client = hub.connect();
+        loop {
+            tokio::select! {
+                message = socket.recv() => match message {
+                    Some(Ok(Message::Text(text))) => {
+                        let reply = match serde_json::from_str::<StreamSubscription>(text.as_str()) {
+                            Ok(subscription) => {
+                                client.subscribe(subscription);
+                                StreamFrame::Subscribed {}
+                            }
+                            Err(e) => StreamFrame::Error {
+                                message: format!("invalid subscription: {}", e),
+                            },
+                        };
+                        if send_frame(&mut socket, &reply).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                },
+                frames = client.next_frames() => {
+                    for frame in frames {
+                        if send_frame(&mut socket, &frame).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn send_frame(socket: &mut WebSocket, frame: &StreamFrame) -> std::result::Result<(), axum::Error> {
+        let text = serde_json::to_string(frame).unwrap_or_default();
+        socket.send(Message::Text(text.into())).await
+    }
+
+    fn authorized(expected: &str, headers: &HeaderMap, query_token: Option<&str>) -> bool {
+        let header_token = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        header_token
+            .or(query_token)
+            .is_some_and(|token| tokens_match(expected, token))
+    }
+
+    /// Compare without returning early so response timing doesn't reveal the token
+    fn tokens_match(expected: &str, given: &str) -> bool {
+        expected.len() == given.len()
+            && expected
+                .bytes()
+                .zip(given.bytes())
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+
+    async fn handle<R: Runtime>(app: &AppHandle<R>, body: &str) -> ControlResponse {
+        let request: ControlRequest = match serde_json::from_str(body) {
+            Ok(request) => request,
+            Err(e) => {
+                return ControlResponse {
+                    id: JsonValue::Null,
+                    outcome: Outcome::Error(Error::ControlError(format!("invalid request: {}", e))),
+                }
+            }
+        };
+        let result = match ControlCall::parse(&request.command, request.payload) {
+            Ok(call) => dispatch(app, call).await,
+            Err(e) => Err(e),
+        };
+        ControlResponse {
+            id: request.id,
+            outcome: match result {
+                Ok(value) => Outcome::Result(value),
+                Err(e) => Outcome::Error(e),
+            },
+        }
+    }
+
+    async fn dispatch<R: Runtime>(app: &AppHandle<R>, call: ControlCall) -> Result<JsonValue> {
+        let app = app.clone();
+        match call {
+            ControlCall::Execute { request } => {
+                let window = default_window(&app)?;
+                to_json(commands::execute(app.clone(), window, request).await)
+            }
+            ControlCall::GetLogs { filter } => to_json(commands::get_logs(app.state(), filter).await),
+            ControlCall::ClearLogs {} => to_json(commands::clear_logs(app.state()).await),
+            ControlCall::SetLogContext { context } => to_json(commands::set_log_context(app.state(), context).await),
+            ControlCall::ClearLogContext {} => to_json(commands::clear_log_context(app.state()).await),
+            ControlCall::GetLogFilePath {} => to_json(commands::get_log_file_path(app.state()).await),
+            ControlCall::GetAppInfo {} => to_json(commands::get_app_info(app).await),
+            ControlCall::GetPluginCapabilities {} => to_json(commands::get_plugin_capabilities().await),
+            ControlCall::GetCommandSchemas {} => to_json(commands::get_command_schemas().await),
+            ControlCall::ListWindows {} => to_json(commands::list_windows(app).await),
+            ControlCall::GetWindowInfo { label } => to_json(commands::get_window_info(app, label).await),
+            ControlCall::FocusWindow { label } => to_json(commands::focus_window(app, label).await),
+            ControlCall::SetWindowBounds { label, bounds } => {
+                to_json(commands::set_window_bounds(app, label, bounds).await)
+            }
+            ControlCall::MaximizeWindow { label } => to_json(commands::maximize_window(app, label).await),
+            ControlCall::MinimizeWindow { label } => to_json(commands::minimize_window(app, label).await),
+            ControlCall::RestoreWindow { label } => to_json(commands::restore_window(app, label).await),
+            ControlCall::SetFullscreen { label, fullscreen } => {
+                to_json(commands::set_fullscreen(app, label, fullscreen).await)
+            }
+            ControlCall::SetAlwaysOnTop { label, always_on_top } => {
+                to_json(commands::set_always_on_top(app, label, always_on_top).await)
+            }
+        }
+    }
+
+    /// The `main` window, or the first by label; `execute` still honours
+    /// `request.window_label` over it
+    fn default_window<R: Runtime>(app: &AppHandle<R>) -> Result<WebviewWindow<R>> {
+        app.get_webview_window(DEFAULT_WINDOW)
+            .or_else(|| {
+                app.webview_windows()
+                    .into_iter()
+                    .min_by(|a, b| a.0.cmp(&b.0))
+                    .map(|(_, window)| window)
+            })
+            .ok_or_else(|| Error::WindowNotFound("no webview windows are open".to_string()))
+    }
+
+    fn to_json<T: Serialize>(result: Result<T>) -> Result<JsonValue> {
+        Ok(serde_json::to_value(result?)?)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use axum::http::HeaderValue;
+
+        use super::*;
+
+        #[test]
+        fn test_parses_calls_with_ipc_argument_names() {
+            let call = ControlCall::parse(
+                "set_always_on_top",
+                serde_json::json!({ "label": "main", "alwaysOnTop": true }),
+            )
+            .unwrap();
+            assert!(matches!(
+                call,
+                ControlCall::SetAlwaysOnTop { ref label, always_on_top: true } if label == "main"
+            ));
+
+            assert!(matches!(
+                ControlCall::parse("list_windows", JsonValue::Null).unwrap(),
+                ControlCall::ListWindows {}
+            ));
+            assert!(matches!(
+                ControlCall::parse("get_logs", JsonValue::Null).unwrap(),
+                ControlCall::GetLogs { filter: None }
+            ));
+
+            let error = ControlCall::parse("format_disk", JsonValue::Null).unwrap_err();
+            assert!(error.to_string().contains("unknown variant `format_disk`"));
+        }
+
+        #[test]
+        fn test_requires_matching_token() {
+            let mut headers = HeaderMap::new();
+            assert!(!authorized("secret", &headers, None));
+            assert!(authorized("secret", &headers, Some("secret")));
+            assert!(!authorized("secret", &headers, Some("secreT")));
+            assert!(!authorized("secret", &headers, Some("secret2")));
+
+            headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer secret"));
+            assert!(authorized("secret", &headers, None));
+            headers.insert(AUTHORIZATION, HeaderValue::from_static("secret"));
+            assert!(!authorized("secret", &headers, None));
+        }
+
+        /// A `/rpc` route that answers "ok", behind the auth gate
+        fn protected(token: &str) -> Router {
+            let router = Router::new().route("/rpc", post(|| async { "ok" }));
+            protect(router, Arc::new(Auth::new(token.to_string())))
+        }
+
+        fn send(router: &Router, uri: &str, headers: &[(&str, &str)]) -> StatusCode {
+            use tower::ServiceExt;
+
+            let mut request = axum::http::Request::post(uri);
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            let request = request.body(axum::body::Body::empty()).unwrap();
+            tauri::async_runtime::block_on(router.clone().oneshot(request)).unwrap().status()
+        }
+
+        #[test]
+        fn test_rejects_requests_without_the_token_or_with_an_origin() {
+            let router = protected("secret");
+            let bearer = ("authorization", "Bearer secret");
+
+            assert_eq!(send(&router, "/rpc", &[bearer]), StatusCode::OK);
+            assert_eq!(send(&router, "/rpc?token=secret", &[]), StatusCode::OK);
+            assert_eq!(send(&router, "/rpc", &[]), StatusCode::UNAUTHORIZED);
+            assert_eq!(
+                send(&router, "/rpc", &[bearer, ("origin", "http://localhost:1420")]),
+                StatusCode::FORBIDDEN
+            );
+            assert_eq!(send(&router, "/rpc?token=secret", &[("origin", "null")]), StatusCode::FORBIDDEN);
+        }
+
+        #[test]
+        fn test_turns_requests_away_after_repeated_auth_failures() {
+            let router = protected("secret");
+            for _ in 0..MAX_AUTH_FAILURES {
+                assert_eq!(
+                    send(&router, "/rpc", &[("authorization", "Bearer guess")]),
+                    StatusCode::UNAUTHORIZED
+                );
+            }
+
+            assert_eq!(
+                send(&router, "/rpc", &[("authorization", "Bearer secret")]),
+                StatusCode::TOO_MANY_REQUESTS
+            );
+        }
+
+        #[test]
+        fn test_writes_the_token_file_for_the_current_user_only() {
+            let path = std::env::temp_dir().join(format!("wdio-token-{}", Uuid::new_v4()));
+            write_token_file(&path, "first").unwrap();
+            write_token_file(&path, "secret").unwrap();
+
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), "secret");
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+            }
+            std::fs::remove_file(&path).unwrap();
+        }
+
+        #[test]
+        fn test_stream_pushes_subscribed_frames_over_websocket() {
+            use futures_util::{SinkExt, StreamExt};
+            use tokio_tungstenite::tungstenite::Message as ClientMessage;
+
+            use crate::models::{InvokeOutcome, InvokeTraceEntry, LogLevel};
+            use crate::stream::tests::{log_entry, FakeEvents};
+
+            let hub = StreamHub::new(16);
+            let events = FakeEvents::default();
+            hub.set_event_source(Box::new(events.clone()));
+
+            tauri::async_runtime::block_on(async {
+                let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+                let addr = listener.local_addr().unwrap();
+                let server_hub = hub.clone();
+                let router = Router::new().route(
+                    "/stream",
+                    get(move |upgrade: WebSocketUpgrade| {
+                        let hub = server_hub.clone();
+                        async move { upgrade.on_upgrade(move |socket| serve_stream(hub, socket)) }
+                    }),
+                );
+                tauri::async_runtime::spawn(async move { axum::serve(listener, router).await });
+
+                let (mut socket, _) = tokio_tungstenite::connect_async(format!("ws://{}/stream", addr))
+                    .await
+                    .unwrap();
+                macro_rules! next_frame {
+                    () => {
+                        match socket.next().await.unwrap().unwrap() {
+                            ClientMessage::Text(text) => serde_json::from_str::<JsonValue>(text.as_str()).unwrap(),
+                            other => panic!("unexpected message {:?}", other),
+                        }
+                    };
+                }
+                let subscription = serde_json::json!({
+                    "logs": { "level": "warn" },
+                    "event_names": ["sync"],
+                    "invoke_trace": true,
+                });
+                socket.send(ClientMessage::Text(subscription.to_string().into())).await.unwrap();
+                assert_eq!(next_frame!(), serde_json::json!({ "type": "subscribed" }));
+
+                hub.publish_log(&log_entry("skipped", LogLevel::Info));
+                hub.publish_log(&log_entry("disk almost full", LogLevel::Warn));
+                events.emit("sync", serde_json::json!({ "synced": 3 }));
+                hub.publish_invoke(&InvokeTraceEntry {
+                    command: "save".to_string(),
+                    args_summary: "{}".to_string(),
+                    args_truncated: false,
+                    started_at_ms: 0,
+                    duration_ms: 1.0,
+                    outcome: InvokeOutcome::Ok,
+                    window_label: "main".to_string(),
+                    session_id: None,
+                });
+
+                let log = next_frame!();
+                assert_eq!((&log["type"], &log["seq"]), (&"log".into(), &1.into()));
+                assert_eq!(log["entry"]["message"], "disk almost full");
+                let event = next_frame!();
+                assert_eq!((&event["type"], &event["seq"]), (&"event".into(), &2.into()));
+                assert_eq!(event["event"]["payload"]["synced"], 3);
+                let invoke = next_frame!();
+                assert_eq!((&invoke["type"], &invoke["seq"]), (&"invoke_trace".into(), &3.into()));
+                assert_eq!(invoke["entry"]["command"], "save");
+
+                socket.send(ClientMessage::Text(r#"{"logs":5}"#.into())).await.unwrap();
+                assert_eq!(next_frame!()["type"], "error");
+
+                // Publish far more than the queue holds without reading; every
+                // frame is either delivered in order or counted as dropped
+                let published = 2_000;
+                for i in 0..published {
+                    hub.publish_log(&log_entry(&format!("burst {}", i), LogLevel::Error));
+                }
+                let mut next_seq = 4;
+                while next_seq <= 3 + published {
+                    let frame = next_frame!();
+                    match frame["type"].as_str().unwrap() {
+                        "dropped" => next_seq += frame["count"].as_u64().unwrap(),
+                        "log" => {
+                            assert_eq!(frame["seq"], next_seq);
+                            next_seq += 1;
+                        }
+                        other => panic!("unexpected frame {}", other),
+                    }
+                }
+                assert_eq!(next_seq, 4 + published);
+            });
+        }
+
+        #[test]
+        fn test_response_carries_result_or_error() {
+            let ok = ControlResponse {
+                id: serde_json::json!(7),
+                outcome: Outcome::Result(JsonValue::Null),
+            };
+            assert_eq!(
+                serde_json::to_value(&ok).unwrap(),
+                serde_json::json!({ "id": 7, "result": null })
+            );
+
+            let err = ControlResponse {
+                id: serde_json::json!("a"),
+                outcome: Outcome::Error(Error::ControlError("boom".to_string())),
+            };
+            assert_eq!(
+                serde_json::to_value(&err).unwrap(),
+                serde_json::json!({
+                    "id": "a",
+                    "error": { "code": "CONTROL_ERROR", "message": "Control request error: boom", "details": null },
+                })
+            );
+        }
+    }
+}
+
+#[cfg(not(feature = "control-server"))]
+mod unsupported {
+    use super::*;
+
+    /// Stand-in used when the `control-server` feature is disabled
+    pub(crate) fn start<R: Runtime>(_app: AppHandle<R>, port: u16, _token_file: Option<std::path::PathBuf>) {
+        log::warn!(
+            "Control port {} is set but the control server requires the tauri-plugin-wdio `control-server` feature",
+            port
+        );
+    }
+}
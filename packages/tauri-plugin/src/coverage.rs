@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use serde_json::Value as JsonValue;
+use tauri::{AppHandle, ExitRequestApi, Manager, Runtime};
+
+use crate::state::WdioState;
+use crate::{Error, Result};
+
+/// Async function that sends a page's `window.__coverage__` through coverage_chunk
+const COLLECTOR_SCRIPT: &str = include_str!("js/coverage.js");
+
+/// Characters of serialized coverage per coverage_chunk call, well below
+/// what an IPC message can carry on every platform
+pub(crate) const CHUNK_CHARS: usize = 256 * 1024;
+
+/// Serialized coverage arriving in chunks from the collector scripts, by transfer id
+#[derive(Default)]
+pub(crate) struct CoverageTransfers {
+    transfers: Mutex<HashMap<String, Transfer>>,
+    /// Set once coverage has been dumped for exit, so the exit it then
+    /// requests isn't held up again
+    dumped_for_exit: AtomicBool,
+}
+
+struct Transfer {
+    window_label: String,
+    data: String,
+    chunks: usize,
+}
+
+impl CoverageTransfers {
+    /// Expect chunks for `id` from the page of window `window_label`
+    pub(crate) fn begin(&self, id: &str, window_label: &str) {
+        let mut transfers = self.transfers.lock().unwrap_or_else(|e| e.into_inner());
+        transfers.insert(
+            id.to_string(),
+            Transfer {
+                window_label: window_label.to_string(),
+                data: String::new(),
+                chunks: 0,
+            },
+        );
+    }
+
+    /// Append chunk `index` of transfer `id`. Chunks must arrive in order and
+    /// from the window the transfer was started for.
+    pub(crate) fn append(&self, id: &str, from_window: &str, index: usize, data: &str) -> Result<()> {
+        let mut transfers = self.transfers.lock().unwrap_or_else(|e| e.into_inner());
+        let transfer = transfers
+            .get_mut(id)
+            .filter(|transfer| transfer.window_label == from_window)
+            .ok_or_else(|| Error::CoverageError(format!("no coverage transfer '{}' for this window", id)))?;
+        if index != transfer.chunks {
+            return Err(Error::CoverageError(format!(
+                "expected chunk {} of transfer '{}', got {}",
+                transfer.chunks, id, index
+            )));
+        }
+        transfer.data.push_str(data);
+        transfer.chunks += 1;
+        Ok(())
+    }
+
+    /// End transfer `id`, returning the serialized coverage received
+    pub(crate) fn finish(&self, id: &str) -> Option<String> {
+        let mut transfers = self.transfers.lock().unwrap_or_else(|e| e.into_inner());
+        transfers.remove(id).map(|transfer| transfer.data)
+    }
+}
+
+/// Script that sends the page's coverage as transfer `id`. The arguments are
+/// embedded as JSON literals.
+pub(crate) fn collector_script(id: &str, chunk_chars: usize) -> String {
+    format!(
+        "(async () => ({})({}, {}))()",
+        COLLECTOR_SCRIPT.trim_end(),
+        JsonValue::from(id),
+        chunk_chars
+    )
+}
+
+/// File in `dir` that the coverage of window `label` is dumped to on exit.
+/// Characters that can't appear in file names on every platform become `_`.
+pub(crate) fn exit_dump_path(dir: &Path, label: &str) -> PathBuf {
+    let name: String = label
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    dir.join(format!("{}.json", name))
+}
+
+/// Hold up an exit while the coverage of every open window is dumped to
+/// `dir`, then exit with the requested code
+pub(crate) fn dump_before_exit<R: Runtime>(app: &AppHandle<R>, dir: &Path, code: Option<i32>, api: &ExitRequestApi) {
+    let Some(state) = app.try_state::<WdioState>() else {
+        return;
+    };
+    if app.webview_windows().is_empty() || state.coverage.dumped_for_exit.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    api.prevent_exit();
+
+    let app = app.clone();
+    let dir = dir.to_path_buf();
+    tauri::async_runtime::spawn(async move {
+        for window in app.webview_windows().into_values() {
+            let label = window.label().to_string();
+            let path = exit_dump_path(&dir, &label);
+            match crate::commands::collect_window_coverage(&app, window, Some(path)).await {
+                Ok(result) => log::info!("Dumped {} bytes of coverage from window '{}'", result.bytes, label),
+                Err(e) => log::warn!("Failed to dump coverage from window '{}': {}", label, e),
+            }
+        }
+        app.exit(code.unwrap_or(0));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assembles_chunks_in_order_from_the_transfer_window() {
+        let coverage = CoverageTransfers::default();
+        coverage.begin("t1", "main");
+
+        coverage.append("t1", "main", 0, r#"{"/src/app.js":"#).unwrap();
+        coverage.append("t1", "main", 1, r#"{"s":{"0":3}}}"#).unwrap();
+
+        assert!(coverage.append("t1", "other", 2, "x").is_err());
+        assert!(coverage.append("t1", "main", 1, "x").is_err());
+        assert!(coverage.append("t2", "main", 0, "x").is_err());
+        assert_eq!(coverage.finish("t1").unwrap(), r#"{"/src/app.js":{"s":{"0":3}}}"#);
+        assert_eq!(coverage.finish("t1"), None);
+    }
+
+    #[test]
+    fn test_collector_script_embeds_its_arguments() {
+        let script = collector_script("wdio-coverage-1", 1024);
+
+        // Passed through as-is by execute, which only wraps non-function scripts
+        assert!(script.starts_with("(async () => (// Evaluated by tauri-plugin-wdio"));
+        assert!(script.ends_with(r#")("wdio-coverage-1", 1024))()"#));
+    }
+
+    #[test]
+    fn test_exit_dump_path_keeps_labels_file_safe() {
+        let dir = Path::new("/tmp/coverage");
+
+        assert_eq!(exit_dump_path(dir, "main"), dir.join("main.json"));
+        assert_eq!(exit_dump_path(dir, "settings/about:1"), dir.join("settings_about_1.json"));
+    }
+}
@@ -0,0 +1,109 @@
+use std::backtrace::Backtrace;
+use std::sync::{Arc, Mutex, Once};
+
+use crate::log_buffer::now_ms;
+use crate::logging::LogPipeline;
+use crate::models::{CrashReport, LogEntry, LogLevel, LogSource};
+
+/// Prefix for panic reports written to stderr
+const PANIC_PREFIX: &str = "[WDIO:Panic]";
+
+static HOOK_INSTALLED: Once = Once::new();
+
+/// Holds the most recent panic captured by the plugin's panic hook
+#[derive(Clone, Default)]
+pub(crate) struct CrashStore {
+    last: Arc<Mutex<Option<CrashReport>>>,
+}
+
+impl CrashStore {
+    pub(crate) fn last(&self) -> Option<CrashReport> {
+        self.last.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn store(&self, report: CrashReport) {
+        // try_lock: the hook may run while this thread already holds the lock
+        if let Ok(mut last) = self.last.try_lock() {
+            *last = Some(report);
+        }
+    }
+}
+
+/// Install a process-wide panic hook that reports panics to stderr, the log
+/// buffer, and `store`, then chains to the previously installed hook.
+/// Only the first call per process installs the hook.
+pub(crate) fn install_panic_hook(store: CrashStore, logs: LogPipeline) {
+    HOOK_INSTALLED.call_once(move || {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let report = CrashReport {
+                message: panic_message(info.payload()),
+                location: info
+                    .location()
+                    .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+                backtrace: Backtrace::force_capture().to_string(),
+                thread: std::thread::current()
+                    .name()
+                    .unwrap_or("<unnamed>")
+                    .to_string(),
+                timestamp_ms: now_ms(),
+            };
+
+            eprintln!(
+                "{} thread '{}' panicked: {}{}",
+                PANIC_PREFIX,
+                report.thread,
+                report.message,
+                report
+                    .location
+                    .as_deref()
+                    .map(|l| format!(" at {}", l))
+                    .unwrap_or_default()
+            );
+            for line in report.backtrace.lines() {
+                eprintln!("{} {}", PANIC_PREFIX, line);
+            }
+
+            logs.buffer().try_push(LogEntry {
+                source: LogSource::Backend,
+                level: LogLevel::Error,
+                message: format!("thread '{}' panicked: {}", report.thread, report.message),
+                target: Some("panic".to_string()),
+                timestamp_ms: report.timestamp_ms,
+                context: logs.context(),
+                session_id: logs.session(),
+            });
+            store.store(report);
+
+            previous(info);
+        }));
+    });
+}
+
+/// Extract the message from a panic payload (`&str` or `String`)
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panic_message_from_str_and_string_payloads() {
+        let str_payload: Box<dyn std::any::Any + Send> = Box::new("static message");
+        assert_eq!(panic_message(str_payload.as_ref()), "static message");
+
+        let string_payload: Box<dyn std::any::Any + Send> = Box::new(format!("formatted {}", 42));
+        assert_eq!(panic_message(string_payload.as_ref()), "formatted 42");
+
+        let other_payload: Box<dyn std::any::Any + Send> = Box::new(7u32);
+        assert_eq!(panic_message(other_payload.as_ref()), "Box<dyn Any>");
+    }
+}
@@ -0,0 +1,48 @@
+use tauri::{AppHandle, Runtime, Url};
+
+use crate::{Error, Result};
+
+/// Event tauri-plugin-deep-link emits for each opened URL; `on_open_url` and
+/// the JavaScript `onOpenUrl` both listen to it
+#[cfg(feature = "deep-link")]
+const NEW_URL_EVENT: &str = "deep-link://new-url";
+
+/// Parse a deep link, which must be an absolute URL with a scheme
+pub(crate) fn parse(url: &str) -> Result<Url> {
+    Url::parse(url).map_err(|e| Error::InvalidDeepLink(format!("{}: {}", url, e)))
+}
+
+/// Deliver `url` to the app's deep link handlers as if the OS opened it
+#[cfg(feature = "deep-link")]
+pub(crate) fn simulate<R: Runtime>(app: &AppHandle<R>, url: Url) -> Result<()> {
+    use tauri::{Emitter, Manager};
+
+    if app.try_state::<tauri_plugin_deep_link::DeepLink<R>>().is_none() {
+        return Err(Error::DeepLinkNotConfigured);
+    }
+    app.emit(NEW_URL_EVENT, vec![url])
+        .map_err(|e| Error::InvalidDeepLink(e.to_string()))
+}
+
+#[cfg(not(feature = "deep-link"))]
+pub(crate) fn simulate<R: Runtime>(_app: &AppHandle<R>, _url: Url) -> Result<()> {
+    Err(Error::UnsupportedInBuild(
+        "deep link simulation requires the tauri-plugin-wdio `deep-link` feature".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accepts_custom_schemes_and_rejects_malformed_urls() {
+        let url = parse("myapp://invite/123?ref=email").unwrap();
+        assert_eq!(url.scheme(), "myapp");
+        assert_eq!(url.host_str(), Some("invite"));
+        assert_eq!(url.path(), "/123");
+
+        assert!(matches!(parse("not a url"), Err(Error::InvalidDeepLink(_))));
+        assert!(matches!(parse("://missing-scheme"), Err(Error::InvalidDeepLink(_))));
+    }
+}
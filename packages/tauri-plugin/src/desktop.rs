@@ -1,25 +1,751 @@
+use std::sync::{Arc, Mutex};
+
+use base64::Engine;
 use serde::de::DeserializeOwned;
-use tauri::{plugin::PluginApi, AppHandle, Runtime};
+use tauri::menu::{MenuEvent, MenuId};
+use serde_json::Value as JsonValue;
+use tauri::{plugin::PluginApi, AppHandle, DragDropEvent, Manager, Runtime, WebviewWindow, Window};
+
+use crate::clock::{Clock, TestClock};
+use crate::execute_hooks::ExecuteHooks;
+use crate::rust_handlers::RustHandlers;
+use crate::shortcuts::Shortcuts;
+use crate::state::WdioState;
+use crate::state_providers::StateProviders;
+use crate::tray::Trays;
+
+use crate::models::{ExecuteRequest, ImageFormat, LogEntry, LogFilter, MockConfig, ScreenshotOptions, WindowInfo};
+use crate::Error;
 
 pub fn init<R: Runtime, C: DeserializeOwned>(
-    _app: &AppHandle<R>,
+    app: &AppHandle<R>,
     _api: PluginApi<R, C>,
 ) -> crate::Result<Wdio<R>> {
     Ok(Wdio {
-        _phantom: std::marker::PhantomData,
+        app: app.clone(),
+        menu_handlers: Arc::default(),
+        drag_drop_handlers: Mutex::default(),
+        trays: Trays::new(app),
+        shortcuts: Shortcuts::new(app),
+        state_providers: StateProviders::new(),
+        rust_handlers: RustHandlers::new(),
+        execute_hooks: ExecuteHooks::default(),
+        clock: Arc::default(),
     })
 }
 
+type MenuHandler<R> = Arc<dyn Fn(&AppHandle<R>, MenuEvent) + Send + Sync>;
+type DragDropHandler<R> = Box<dyn Fn(&Window<R>, &DragDropEvent) + Send + Sync>;
+
 /// Access to the wdio APIs.
 pub struct Wdio<R: Runtime> {
-    _phantom: std::marker::PhantomData<R>,
+    app: AppHandle<R>,
+    menu_handlers: Arc<Mutex<Vec<MenuHandler<R>>>>,
+    drag_drop_handlers: Mutex<Vec<DragDropHandler<R>>>,
+    trays: Trays<R>,
+    shortcuts: Shortcuts<R>,
+    state_providers: StateProviders<R>,
+    rust_handlers: RustHandlers<R>,
+    execute_hooks: ExecuteHooks,
+    clock: Arc<TestClock>,
 }
 
-// Wdio is Send + Sync regardless of R because it only contains PhantomData
-unsafe impl<R: Runtime> Send for Wdio<R> {}
-unsafe impl<R: Runtime> Sync for Wdio<R> {}
-
 impl<R: Runtime> Wdio<R> {
-    // Add desktop-specific methods here
+    /// Register a menu event handler, like [`AppHandle::on_menu_event`], that
+    /// also runs for items activated with the trigger_menu_item command.
+    ///
+    /// Tauri has no public API to feed synthetic events to handlers registered
+    /// directly on the app, so handlers that tests trigger must be registered
+    /// here.
+    pub fn on_menu_event<F>(&self, handler: F)
+    where
+        F: Fn(&AppHandle<R>, MenuEvent) + Send + Sync + 'static,
+    {
+        let handler: MenuHandler<R> = Arc::new(handler);
+        let native = handler.clone();
+        self.app.on_menu_event(move |app, event| native(app, event));
+        self.menu_handlers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(handler);
+    }
+
+    /// Run the handlers registered with [`Wdio::on_menu_event`] on the main
+    /// thread, as for a native click
+    pub(crate) fn dispatch_menu_event(&self, id: MenuId) -> crate::Result<()> {
+        let handlers = self
+            .menu_handlers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        let app = self.app.clone();
+        self.app
+            .run_on_main_thread(move || {
+                for handler in &handlers {
+                    handler(&app, MenuEvent { id: id.clone() });
+                }
+            })
+            .map_err(|e| Error::MenuError(e.to_string()))
+    }
+
+    /// Register a handler for file drag and drop events on any window. It runs
+    /// for native drops and for drops simulated with the simulate_file_drop
+    /// command, which can't reach `on_window_event` handlers.
+    pub fn on_drag_drop_event<F>(&self, handler: F)
+    where
+        F: Fn(&Window<R>, &DragDropEvent) + Send + Sync + 'static,
+    {
+        self.drag_drop_handlers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(Box::new(handler));
+    }
+
+    pub(crate) fn dispatch_drag_drop_event(&self, window: &Window<R>, event: &DragDropEvent) {
+        let handlers = self.drag_drop_handlers.lock().unwrap_or_else(|e| e.into_inner());
+        for handler in handlers.iter() {
+            handler(window, event);
+        }
+    }
+
+    /// Make a tray icon and its menu visible to the tray commands. Tauri
+    /// doesn't expose an app's trays, so each one tests use must be registered.
+    #[cfg(feature = "tray")]
+    pub fn register_tray(&self, tray: &tauri::tray::TrayIcon<R>, menu: Option<&tauri::menu::Menu<R>>) {
+        self.trays.register(tray, menu);
+    }
+
+    /// Register a tray icon event handler, like [`AppHandle::on_tray_icon_event`],
+    /// that also runs for clicks synthesized by the simulate_tray_click command.
+    /// Handlers set with `TrayIconBuilder::on_tray_icon_event` can't be reached.
+    #[cfg(feature = "tray")]
+    pub fn on_tray_icon_event<F>(&self, handler: F)
+    where
+        F: Fn(&AppHandle<R>, tauri::tray::TrayIconEvent) + Send + Sync + 'static,
+    {
+        self.trays.on_tray_icon_event(handler);
+    }
+
+    pub(crate) fn trays(&self) -> &Trays<R> {
+        &self.trays
+    }
+
+    /// Register a global shortcut handler, like `on_shortcut` of
+    /// tauri-plugin-global-shortcut (which must be initialized first), that the
+    /// trigger_global_shortcut command can also invoke. `accelerator` uses the
+    /// global-shortcut syntax, e.g. `CommandOrControl+Shift+K`.
+    #[cfg(feature = "global-shortcut")]
+    pub fn on_global_shortcut<F>(&self, accelerator: &str, handler: F) -> crate::Result<()>
+    where
+        F: Fn(&AppHandle<R>, &tauri_plugin_global_shortcut::Shortcut, tauri_plugin_global_shortcut::ShortcutEvent)
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.shortcuts.register(accelerator, handler)
+    }
+
+    pub(crate) fn shortcuts(&self) -> &Shortcuts<R> {
+        &self.shortcuts
+    }
+
+    /// Expose a JSON snapshot of app state to the get_app_state command under
+    /// `name`, replacing any provider previously registered with that name.
+    /// The provider runs on each call and should only hold its locks while
+    /// building the snapshot.
+    ///
+    /// ```ignore
+    /// app.wdio().register_state_provider("todos", |app| {
+    ///     let todos = app.state::<Mutex<Todos>>();
+    ///     serde_json::to_value(&*todos.lock().unwrap()).unwrap_or_default()
+    /// });
+    /// ```
+    pub fn register_state_provider<F>(&self, name: impl Into<String>, provider: F)
+    where
+        F: Fn(&AppHandle<R>) -> serde_json::Value + Send + Sync + 'static,
+    {
+        self.state_providers.register(name.into(), Arc::new(provider));
+    }
+
+    pub(crate) fn state_providers(&self) -> &StateProviders<R> {
+        &self.state_providers
+    }
+
+    /// Expose a backend test hook to the execute_rust command under `name`,
+    /// replacing any handler previously registered with that name. The
+    /// handler gets the command's JSON args (`null` when none are passed) and
+    /// its result is returned to the test; a panic fails the call with
+    /// `RUST_HANDLER_PANICKED` instead of crashing the app.
+    ///
+    /// ```ignore
+    /// app.wdio().register_rust_handler("flush_cache", |app, _args| {
+    ///     let evicted = app.state::<Cache>().flush();
+    ///     Ok(serde_json::json!({ "evicted": evicted }))
+    /// });
+    /// ```
+    pub fn register_rust_handler<F>(&self, name: impl Into<String>, handler: F)
+    where
+        F: Fn(&AppHandle<R>, serde_json::Value) -> crate::Result<serde_json::Value> + Send + Sync + 'static,
+    {
+        self.rust_handlers.register(name.into(), Arc::new(handler));
+    }
+
+    pub(crate) fn rust_handlers(&self) -> &RustHandlers<R> {
+        &self.rust_handlers
+    }
+
+    /// Run `hook` before each script the execute command evaluates, e.g. to
+    /// set up a `window.__TEST__` helper with [`tauri::WebviewWindow::eval`].
+    /// Hooks run on the command's async task, in registration order; a
+    /// panicking hook is logged and skipped.
+    pub fn on_before_execute<F>(&self, hook: F)
+    where
+        F: Fn(&ExecuteRequest) + Send + Sync + 'static,
+    {
+        self.execute_hooks.on_before(Arc::new(hook));
+    }
+
+    /// Run `hook` after each execute call with its outcome, including failed
+    /// and timed-out calls
+    pub fn on_after_execute<F>(&self, hook: F)
+    where
+        F: Fn(&ExecuteRequest, &crate::Result<serde_json::Value>) + Send + Sync + 'static,
+    {
+        self.execute_hooks.on_after(Arc::new(hook));
+    }
+
+    pub(crate) fn execute_hooks(&self) -> &ExecuteHooks {
+        &self.execute_hooks
+    }
+
+    /// Time source that tests can freeze and advance with the clock_freeze,
+    /// clock_advance, and clock_reset commands. It follows real time until
+    /// they do, so app code can read time through it unconditionally; code
+    /// that uses `SystemTime` or `tokio::time` directly isn't affected.
+    ///
+    /// ```ignore
+    /// let clock = app.wdio().clock();
+    /// tauri::async_runtime::spawn(async move {
+    ///     clock.sleep(Duration::from_secs(30)).await;
+    ///     sync_now().await;
+    /// });
+    /// ```
+    pub fn clock(&self) -> Arc<dyn Clock> {
+        self.clock.clone()
+    }
+
+    pub(crate) fn test_clock(&self) -> &TestClock {
+        &self.clock
+    }
+
+    /// Run `script`, a JavaScript function expression, in the window
+    /// `window_label` with `args` and return its awaited result, as the
+    /// execute command does for tests. `undefined` comes back as `null`.
+    /// The execute hooks run around it, and it fails the same ways, e.g. with
+    /// [`Error::ExecuteTimeout`] after 30 seconds.
+    ///
+    /// ```no_run
+    /// # use tauri_plugin_wdio::WdioExt;
+    /// # async fn example<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> tauri_plugin_wdio::Result<()> {
+    /// let count = app
+    ///     .wdio()
+    ///     .execute("main", "(selector) => document.querySelectorAll(selector).length", vec!["li".into()])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute(&self, window_label: &str, script: &str, args: Vec<JsonValue>) -> crate::Result<JsonValue> {
+        let window = self.window(window_label)?;
+        let request = ExecuteRequest {
+            script: call_script(script, &args),
+            args: Vec::new(),
+            window_label: None,
+            detailed: false,
+        };
+        let result = crate::commands::run_execute(&self.app, window, &request).await?;
+        Ok(if result == serde_json::json!({ "__wdio_undefined__": true }) {
+            JsonValue::Null
+        } else {
+            result
+        })
+    }
+
+    /// Mock the frontend command `config.command` in the page of window
+    /// `window_label`, as the test service's mocks do: invokes of it from the
+    /// page get `config.return_value`, or run `config.implementation` (a
+    /// JavaScript function expression) when set. Replaces any mock of the
+    /// command in that page, and lasts until it navigates.
+    ///
+    /// ```no_run
+    /// # use tauri_plugin_wdio::{MockConfig, WdioExt};
+    /// # async fn example<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> tauri_plugin_wdio::Result<()> {
+    /// app.wdio()
+    ///     .set_mock(
+    ///         "main",
+    ///         &MockConfig {
+    ///             command: "fetch_user".to_string(),
+    ///             return_value: Some(serde_json::json!({ "name": "Ada" })),
+    ///             implementation: None,
+    ///         },
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_mock(&self, window_label: &str, config: &MockConfig) -> crate::Result<()> {
+        let args = vec![
+            JsonValue::from(config.command.as_str()),
+            config.return_value.clone().unwrap_or(JsonValue::Null),
+        ];
+        self.execute(window_label, &set_mock_script(config), args).await?;
+        Ok(())
+    }
+
+    /// Remove the mock of the frontend command `command` from the page of
+    /// window `window_label`; returns false if there was none
+    pub async fn clear_mock(&self, window_label: &str, command: &str) -> crate::Result<bool> {
+        let cleared = self.execute(window_label, CLEAR_MOCK_SCRIPT, vec![command.into()]).await?;
+        Ok(cleared.as_bool().unwrap_or(false))
+    }
+
+    /// Buffered log entries matching `filter`, as the get_logs command
+    /// returns them
+    ///
+    /// ```no_run
+    /// # use tauri_plugin_wdio::{LogFilter, LogLevel, WdioExt};
+    /// # fn example<R: tauri::Runtime>(app: tauri::AppHandle<R>) {
+    /// let errors = app.wdio().get_logs(&LogFilter { level: Some(LogLevel::Error), ..Default::default() });
+    /// # }
+    /// ```
+    pub fn get_logs(&self, filter: &LogFilter) -> Vec<LogEntry> {
+        self.app.state::<WdioState>().logs.buffer().query(filter)
+    }
+
+    /// Clear the log buffer, returning the number of entries removed
+    pub fn clear_logs(&self) -> usize {
+        self.app.state::<WdioState>().logs.buffer().clear()
+    }
+
+    /// Details of every open window, sorted by label
+    pub fn list_windows(&self) -> Vec<WindowInfo> {
+        crate::commands::window_infos(&self.app)
+    }
+
+    fn window(&self, label: &str) -> crate::Result<WebviewWindow<R>> {
+        self.app
+            .get_webview_window(label)
+            .ok_or_else(|| Error::WindowNotFound(label.to_string()))
+    }
+}
+
+/// Script that calls the function expression `script` with `args` and
+/// evaluates to its promise, in the pre-wrapped form the execute command
+/// passes through as-is
+fn call_script(script: &str, args: &[JsonValue]) -> String {
+    format!("(async () => ({})(...{}))()", script.trim(), JsonValue::from(args))
+}
+
+/// Function expression, called with the command and return value, that
+/// installs the mock described by `config` in `window.__wdio_mocks__`
+fn set_mock_script(config: &MockConfig) -> String {
+    let implementation = config
+        .implementation
+        .as_deref()
+        .unwrap_or("async () => returnValue");
+    format!(
+        "(command, returnValue) => {{ (window.__wdio_mocks__ ??= {{}})[command] = ({}); }}",
+        implementation.trim()
+    )
 }
 
+const CLEAR_MOCK_SCRIPT: &str =
+    "(command) => !!window.__wdio_mocks__ && Object.hasOwn(window.__wdio_mocks__, command) && delete window.__wdio_mocks__[command]";
+
+/// Capture the visible contents of a window's webview.
+///
+/// Returns the written path when `options.path` is set, otherwise a base64
+/// data URL. Minimized or hidden windows fail with [`Error::ScreenshotFailed`]
+/// because there is nothing rendered to capture, as does a JPEG quality
+/// above 100.
+pub async fn capture_window<R: Runtime>(
+    window: &WebviewWindow<R>,
+    options: &ScreenshotOptions,
+) -> crate::Result<String> {
+    validate_options(options)?;
+    if window.is_minimized().unwrap_or(false) {
+        return Err(Error::ScreenshotFailed(format!(
+            "window '{}' is minimized",
+            window.label()
+        )));
+    }
+    if !window.is_visible().unwrap_or(true) {
+        return Err(Error::ScreenshotFailed(format!(
+            "window '{}' is not visible",
+            window.label()
+        )));
+    }
+
+    let bytes = snapshot(window, options).await?;
+
+    match &options.path {
+        Some(path) => {
+            std::fs::write(path, &bytes).map_err(|e| {
+                Error::ScreenshotFailed(format!("failed to write '{}': {}", path.display(), e))
+            })?;
+            Ok(path.to_string_lossy().into_owned())
+        }
+        None => Ok(format!(
+            "data:{};base64,{}",
+            mime_type(options.format),
+            base64::engine::general_purpose::STANDARD.encode(bytes)
+        )),
+    }
+}
+
+fn validate_options(options: &ScreenshotOptions) -> crate::Result<()> {
+    match options.quality {
+        Some(quality) if quality > 100 => Err(Error::ScreenshotFailed(format!(
+            "invalid JPEG quality {}: must be between 0 and 100",
+            quality
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn mime_type(format: ImageFormat) -> &'static str {
+    match format {
+        ImageFormat::Png => "image/png",
+        ImageFormat::Jpeg => "image/jpeg",
+    }
+}
+
+/// Snapshot the webview with WebKitGTK and encode it with gdk-pixbuf
+#[cfg(all(target_os = "linux", feature = "screenshot"))]
+async fn snapshot<R: Runtime>(
+    window: &WebviewWindow<R>,
+    options: &ScreenshotOptions,
+) -> crate::Result<Vec<u8>> {
+    use webkit2gtk::{SnapshotOptions, SnapshotRegion, WebViewExt};
+
+    let (tx, rx) = tokio::sync::oneshot::channel::<Result<Vec<u8>, String>>();
+    let format = options.format;
+    let quality = options.quality.unwrap_or(90).to_string();
+
+    window
+        .with_webview(move |webview| {
+            webview.inner().snapshot(
+                SnapshotRegion::Visible,
+                SnapshotOptions::NONE,
+                None::<&webkit2gtk::gio::Cancellable>,
+                move |result| {
+                    let encoded = result.map_err(|e| e.to_string()).and_then(|surface| {
+                        let image = gdk::cairo::ImageSurface::try_from(surface)
+                            .map_err(|_| "snapshot is not an image surface".to_string())?;
+                        let pixbuf = gdk::pixbuf_get_from_surface(
+                            &image,
+                            0,
+                            0,
+                            image.width(),
+                            image.height(),
+                        )
+                        .ok_or_else(|| "failed to read snapshot pixels".to_string())?;
+                        match format {
+                            ImageFormat::Png => pixbuf.save_to_bufferv("png", &[]),
+                            ImageFormat::Jpeg => {
+                                pixbuf.save_to_bufferv("jpeg", &[("quality", quality.as_str())])
+                            }
+                        }
+                        .map_err(|e| e.to_string())
+                    });
+                    let _ = tx.send(encoded);
+                },
+            );
+        })
+        .map_err(|e| Error::ScreenshotFailed(e.to_string()))?;
+
+    rx.await
+        .map_err(|_| Error::ScreenshotFailed("snapshot was cancelled".to_string()))?
+        .map_err(Error::ScreenshotFailed)
+}
+
+/// Snapshot the webview with WKWebView and encode it with NSBitmapImageRep
+#[cfg(all(target_os = "macos", feature = "screenshot"))]
+async fn snapshot<R: Runtime>(
+    window: &WebviewWindow<R>,
+    options: &ScreenshotOptions,
+) -> crate::Result<Vec<u8>> {
+    use objc2_app_kit::NSImage;
+    use objc2_foundation::NSError;
+    use objc2_web_kit::WKWebView;
+
+    let (tx, rx) = tokio::sync::oneshot::channel::<Result<Vec<u8>, String>>();
+    let format = options.format;
+    let quality = options.quality.unwrap_or(90);
+
+    window
+        .with_webview(move |webview| {
+            // SAFETY: on macOS the platform webview is a WKWebView, and this
+            // closure runs on the main thread that owns it
+            let webview: &WKWebView = unsafe { &*webview.inner().cast() };
+            // The block is `Fn`, but WebKit calls it once
+            let tx = std::cell::Cell::new(Some(tx));
+            let handler = block2::RcBlock::new(move |image: *mut NSImage, error: *mut NSError| {
+                // SAFETY: WebKit passes either a valid image or a valid error
+                let encoded = match unsafe { image.as_ref() } {
+                    Some(image) => encode_ns_image(image, format, quality),
+                    None => Err(unsafe { error.as_ref() }.map_or_else(
+                        || "snapshot returned no image".to_string(),
+                        |error| error.localizedDescription().to_string(),
+                    )),
+                };
+                if let Some(tx) = tx.take() {
+                    let _ = tx.send(encoded);
+                }
+            });
+            unsafe { webview.takeSnapshotWithConfiguration_completionHandler(None, &handler) };
+        })
+        .map_err(|e| Error::ScreenshotFailed(e.to_string()))?;
+
+    rx.await
+        .map_err(|_| Error::ScreenshotFailed("snapshot was cancelled".to_string()))?
+        .map_err(Error::ScreenshotFailed)
+}
+
+#[cfg(all(target_os = "macos", feature = "screenshot"))]
+fn encode_ns_image(
+    image: &objc2_app_kit::NSImage,
+    format: ImageFormat,
+    quality: u8,
+) -> Result<Vec<u8>, String> {
+    use objc2::runtime::AnyObject;
+    use objc2_app_kit::{NSBitmapImageFileType, NSBitmapImageRep, NSImageCompressionFactor};
+    use objc2_foundation::{NSDictionary, NSNumber};
+
+    let bitmap = image
+        .TIFFRepresentation()
+        .and_then(|tiff| NSBitmapImageRep::imageRepWithData(&tiff))
+        .ok_or_else(|| "failed to read snapshot pixels".to_string())?;
+    let (file_type, properties) = match format {
+        ImageFormat::Png => (NSBitmapImageFileType::PNG, NSDictionary::new()),
+        ImageFormat::Jpeg => {
+            let factor = NSNumber::numberWithFloat(f32::from(quality) / 100.0);
+            let factor: &AnyObject = &factor;
+            // SAFETY: a constant NSString provided by AppKit
+            let key = unsafe { NSImageCompressionFactor };
+            (NSBitmapImageFileType::JPEG, NSDictionary::from_slices(&[key], &[factor]))
+        }
+    };
+    // SAFETY: the compression factor is an NSNumber, as AppKit expects
+    unsafe { bitmap.representationUsingType_properties(file_type, &properties) }
+        .map(|data| data.to_vec())
+        .ok_or_else(|| "failed to encode snapshot".to_string())
+}
+
+/// Snapshot the webview with WebView2's CapturePreview. WebView2 has no JPEG
+/// quality setting, so JPEG is re-encoded from its PNG with `image`.
+#[cfg(all(windows, feature = "screenshot"))]
+async fn snapshot<R: Runtime>(
+    window: &WebviewWindow<R>,
+    options: &ScreenshotOptions,
+) -> crate::Result<Vec<u8>> {
+    let (tx, rx) = tokio::sync::oneshot::channel::<Result<Vec<u8>, String>>();
+    // Shared so a capture that fails to start can report it too
+    let tx = Arc::new(Mutex::new(Some(tx)));
+    let send = move |result: Result<Vec<u8>, String>| {
+        if let Some(tx) = tx.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            let _ = tx.send(result);
+        }
+    };
+
+    window
+        .with_webview(move |webview| {
+            if let Err(error) = capture_preview(&webview.controller(), Box::new(send.clone())) {
+                send(Err(error));
+            }
+        })
+        .map_err(|e| Error::ScreenshotFailed(e.to_string()))?;
+
+    let png = rx
+        .await
+        .map_err(|_| Error::ScreenshotFailed("snapshot was cancelled".to_string()))?
+        .map_err(Error::ScreenshotFailed)?;
+    match options.format {
+        ImageFormat::Png => Ok(png),
+        ImageFormat::Jpeg => {
+            png_to_jpeg(&png, options.quality.unwrap_or(90)).map_err(Error::ScreenshotFailed)
+        }
+    }
+}
+
+/// Start capturing the webview as PNG, calling `done` with the image once
+/// WebView2 has written it
+#[cfg(all(windows, feature = "screenshot"))]
+fn capture_preview(
+    controller: &webview2_com::Microsoft::Web::WebView2::Win32::ICoreWebView2Controller,
+    done: Box<dyn FnOnce(Result<Vec<u8>, String>)>,
+) -> Result<(), String> {
+    use webview2_com::CapturePreviewCompletedHandler;
+    use webview2_com::Microsoft::Web::WebView2::Win32::COREWEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_PNG;
+    use windows::Win32::UI::Shell::SHCreateMemStream;
+
+    // SAFETY: COM calls on the main thread that owns the controller
+    let core = unsafe { controller.CoreWebView2() }.map_err(|e| e.to_string())?;
+    let stream = unsafe { SHCreateMemStream(None) }
+        .ok_or_else(|| "failed to create a memory stream".to_string())?;
+    let handler = CapturePreviewCompletedHandler::create(Box::new({
+        let stream = stream.clone();
+        move |result| {
+            done(result.map_err(|e| e.to_string()).and_then(|()| read_stream(&stream)));
+            Ok(())
+        }
+    }));
+    unsafe { core.CapturePreview(COREWEBVIEW2_CAPTURE_PREVIEW_IMAGE_FORMAT_PNG, &stream, &handler) }
+        .map_err(|e| e.to_string())
+}
+
+/// The whole contents of a COM memory stream
+#[cfg(all(windows, feature = "screenshot"))]
+fn read_stream(stream: &windows::Win32::System::Com::IStream) -> Result<Vec<u8>, String> {
+    use windows::Win32::System::Com::{STATFLAG_NONAME, STATSTG, STREAM_SEEK_SET};
+
+    let mut stat = STATSTG::default();
+    // SAFETY: `stat` and `bytes` outlive the calls that write to them
+    unsafe {
+        stream.Stat(&mut stat, STATFLAG_NONAME).map_err(|e| e.to_string())?;
+        stream.Seek(0, STREAM_SEEK_SET, None).map_err(|e| e.to_string())?;
+        let mut bytes = vec![0u8; stat.cbSize as usize];
+        let mut read = 0u32;
+        stream
+            .Read(bytes.as_mut_ptr().cast(), bytes.len() as u32, Some(&mut read))
+            .ok()
+            .map_err(|e| e.to_string())?;
+        bytes.truncate(read as usize);
+        Ok(bytes)
+    }
+}
+
+#[cfg(all(windows, feature = "screenshot"))]
+fn png_to_jpeg(png: &[u8], quality: u8) -> Result<Vec<u8>, String> {
+    let image = image::load_from_memory_with_format(png, image::ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+    let mut jpeg = Vec::new();
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut jpeg, quality)
+        .encode_image(&image.to_rgb8())
+        .map_err(|e| e.to_string())?;
+    Ok(jpeg)
+}
+
+#[cfg(all(
+    not(any(target_os = "linux", target_os = "macos", windows)),
+    feature = "screenshot"
+))]
+async fn snapshot<R: Runtime>(
+    _window: &WebviewWindow<R>,
+    _options: &ScreenshotOptions,
+) -> crate::Result<Vec<u8>> {
+    Err(Error::ScreenshotFailed(format!(
+        "window capture is not supported on {}",
+        std::env::consts::OS
+    )))
+}
+
+#[cfg(not(feature = "screenshot"))]
+async fn snapshot<R: Runtime>(
+    _window: &WebviewWindow<R>,
+    _options: &ScreenshotOptions,
+) -> crate::Result<Vec<u8>> {
+    Err(Error::UnsupportedInBuild(
+        "window capture requires the tauri-plugin-wdio `screenshot` feature".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_call_script_spreads_json_args_into_the_function() {
+        let script = call_script(" (a, b) => a + b\n", &[JsonValue::from("x"), serde_json::json!({ "y": [1] })]);
+
+        assert_eq!(script, r#"(async () => ((a, b) => a + b)(...["x",{"y":[1]}]))()"#);
+        assert_eq!(call_script("() => 1", &[]), "(async () => (() => 1)(...[]))()");
+    }
+
+    #[test]
+    fn test_set_mock_script_uses_the_implementation_or_return_value() {
+        let config = |implementation: Option<&str>| MockConfig {
+            command: "fetch_user".to_string(),
+            return_value: None,
+            implementation: implementation.map(str::to_string),
+        };
+
+        assert_eq!(
+            set_mock_script(&config(None)),
+            "(command, returnValue) => { (window.__wdio_mocks__ ??= {})[command] = (async () => returnValue); }"
+        );
+        assert!(set_mock_script(&config(Some("(args) => args.id * 2"))).contains("[command] = ((args) => args.id * 2);"));
+    }
+
+    #[test]
+    fn test_rejects_quality_above_100() {
+        let options = |quality| ScreenshotOptions {
+            format: ImageFormat::Jpeg,
+            quality: Some(quality),
+            path: None,
+        };
+        assert!(validate_options(&options(100)).is_ok());
+        assert!(validate_options(&ScreenshotOptions::default()).is_ok());
+        assert!(matches!(
+            validate_options(&options(101)),
+            Err(Error::ScreenshotFailed(message)) if message.contains("between 0 and 100")
+        ));
+    }
+
+    // Wdio is Send + Sync through its fields alone, with no unsafe impls, so
+    // a field that isn't fails the build here rather than at app.manage()
+    const _: fn() = || {
+        fn assert_send_sync<T: Send + Sync + 'static>() {}
+        assert_send_sync::<Wdio<tauri::test::MockRuntime>>();
+    };
+
+    fn mock_app() -> tauri::App<tauri::test::MockRuntime> {
+        tauri::test::mock_builder()
+            .plugin(crate::init())
+            .build(tauri::test::mock_context(tauri::test::noop_assets()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_wdio_lists_windows_and_reads_logs() {
+        use crate::models::{LogLevel, LogSource};
+        use crate::WdioExt;
+
+        let app = mock_app();
+        for label in ["settings", "main"] {
+            tauri::WebviewWindowBuilder::new(&app, label, Default::default()).build().unwrap();
+        }
+        let wdio = app.wdio();
+
+        let labels: Vec<String> = wdio.list_windows().into_iter().map(|window| window.label).collect();
+        assert_eq!(labels, ["main", "settings"]);
+
+        wdio.clear_logs();
+        let logs = &app.state::<WdioState>().logs;
+        logs.emit(LogSource::Backend, LogLevel::Info, "ready".to_string(), None);
+        logs.emit(LogSource::Backend, LogLevel::Error, "failed".to_string(), None);
+        let errors = wdio.get_logs(&LogFilter { level: Some(LogLevel::Error), ..Default::default() });
+        assert_eq!(errors.iter().map(|entry| entry.message.as_str()).collect::<Vec<_>>(), ["failed"]);
+        assert_eq!(wdio.clear_logs(), 2);
+    }
+
+    #[test]
+    fn test_wdio_execute_fails_for_an_unknown_window() {
+        use crate::WdioExt;
+
+        let app = mock_app();
+
+        let result = tauri::async_runtime::block_on(app.wdio().execute("missing", "() => 1", Vec::new()));
+
+        assert!(matches!(result, Err(Error::WindowNotFound(label)) if label == "missing"));
+    }
+}
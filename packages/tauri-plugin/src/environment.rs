@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+
+use crate::models::WdioConfig;
+use crate::{Error, Result};
+
+/// Select environment variables for get_env according to the plugin config.
+///
+/// Without `names` the whole environment is returned, which requires
+/// `allow_full_env_dump`. With `names`, every name must be in `env_allowlist`
+/// unless `allow_full_env_dump` is set. Unset variables are omitted.
+pub(crate) fn filter_env(
+    vars: impl IntoIterator<Item = (String, String)>,
+    names: Option<&[String]>,
+    config: &WdioConfig,
+) -> Result<BTreeMap<String, String>> {
+    let Some(names) = names else {
+        if !config.allow_full_env_dump {
+            return Err(Error::EnvAccessDenied(
+                "reading the full environment requires WdioConfig::allow_full_env_dump".to_string(),
+            ));
+        }
+        return Ok(vars.into_iter().collect());
+    };
+
+    if !config.allow_full_env_dump {
+        let denied: Vec<&str> = names
+            .iter()
+            .filter(|name| !config.env_allowlist.contains(name))
+            .map(String::as_str)
+            .collect();
+        if !denied.is_empty() {
+            return Err(Error::EnvAccessDenied(format!(
+                "not in WdioConfig::env_allowlist: {}",
+                denied.join(", ")
+            )));
+        }
+    }
+
+    Ok(vars
+        .into_iter()
+        .filter(|(key, _)| names.contains(key))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> Vec<(String, String)> {
+        vec![
+            ("HOME".to_string(), "/home/test".to_string()),
+            ("SECRET_TOKEN".to_string(), "hunter2".to_string()),
+            ("WDIO_SENTINEL".to_string(), "42".to_string()),
+        ]
+    }
+
+    #[test]
+    fn test_allowlisted_names_are_returned() {
+        let config = WdioConfig {
+            env_allowlist: vec!["WDIO_SENTINEL".to_string(), "MISSING".to_string()],
+            ..Default::default()
+        };
+        let names = vec!["WDIO_SENTINEL".to_string(), "MISSING".to_string()];
+
+        let env = filter_env(vars(), Some(&names), &config).unwrap();
+        assert_eq!(env.len(), 1);
+        assert_eq!(env["WDIO_SENTINEL"], "42");
+    }
+
+    #[test]
+    fn test_names_outside_allowlist_and_full_dump_are_denied_by_default() {
+        let config = WdioConfig {
+            env_allowlist: vec!["WDIO_SENTINEL".to_string()],
+            ..Default::default()
+        };
+        let names = vec!["WDIO_SENTINEL".to_string(), "SECRET_TOKEN".to_string()];
+
+        assert!(matches!(
+            filter_env(vars(), Some(&names), &config),
+            Err(Error::EnvAccessDenied(msg)) if msg.ends_with("SECRET_TOKEN")
+        ));
+        assert!(matches!(
+            filter_env(vars(), None, &config),
+            Err(Error::EnvAccessDenied(_))
+        ));
+    }
+
+    #[test]
+    fn test_full_dump_flag_allows_everything() {
+        let config = WdioConfig {
+            allow_full_env_dump: true,
+            ..Default::default()
+        };
+
+        assert_eq!(filter_env(vars(), None, &config).unwrap().len(), 3);
+        let names = vec!["SECRET_TOKEN".to_string()];
+        assert_eq!(filter_env(vars(), Some(&names), &config).unwrap().len(), 1);
+    }
+}
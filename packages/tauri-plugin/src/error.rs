@@ -1,4 +1,8 @@
-use serde::{ser::Serializer, Serialize};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::models::ScriptPosition;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -10,9 +14,33 @@ pub enum Error {
     #[error(transparent)]
     PluginInvoke(#[from] tauri::plugin::mobile::PluginInvokeError),
 
+    #[error("Tauri error: {0}")]
+    Tauri(#[from] tauri::Error),
+
     #[error("Execute error: {0}")]
     ExecuteError(String),
 
+    #[error("Script error: {message}{}", at(position))]
+    ScriptError {
+        message: String,
+        position: Option<ScriptPosition>,
+    },
+
+    #[error("Payload too large: {actual} bytes, over the {limit}-byte max_payload_bytes limit")]
+    PayloadTooLarge { actual: usize, limit: usize },
+
+    #[error("Script execution timed out: {0}")]
+    ExecuteTimeout(String),
+
+    #[error("Command {command} timed out after {limit_ms}ms")]
+    CommandTimeout { command: String, limit_ms: u64 },
+
+    #[error("Execute bridge unavailable in window {0}: its page couldn't reach the plugin over Tauri IPC to report results; grant the window wdio:allow-resolve-execute, with its URL listed as a remote in the capability if it isn't local")]
+    BridgeUnavailable(String),
+
+    #[error("Execute preamble error: {0}")]
+    PreambleError(String),
+
     #[error("Mock error: {0}")]
     MockError(String),
 
@@ -21,13 +49,332 @@ pub enum Error {
 
     #[error("Window error: {0}")]
     WindowError(String),
+
+    #[error("Window not found: {0}")]
+    WindowNotFound(String),
+
+    #[error("Window could not be focused: {0}")]
+    WindowNotFocused(String),
+
+    #[error("Window navigated before the script finished: {0}")]
+    WindowNavigated(String),
+
+    #[error("Screenshot failed: {0}")]
+    ScreenshotFailed(String),
+
+    #[error("Unsupported in this build: {0}")]
+    UnsupportedInBuild(String),
+
+    #[error("Unsupported on this platform: {0}")]
+    UnsupportedPlatform(String),
+
+    #[error("Navigation denied: {0}")]
+    NavigationDenied(String),
+
+    #[error("Environment access denied: {0}")]
+    EnvAccessDenied(String),
+
+    #[error("Clipboard error: {0}")]
+    ClipboardError(String),
+
+    #[error("Clipboard format mismatch: {0}")]
+    ClipboardFormatMismatch(String),
+
+    #[error("Menu item not found: {0}")]
+    MenuItemNotFound(String),
+
+    #[error("Menu error: {0}")]
+    MenuError(String),
+
+    #[error("No tray icon is registered with the wdio plugin")]
+    TrayNotConfigured,
+
+    #[error("Tray icon not found: {0}")]
+    TrayNotFound(String),
+
+    #[error("Tray error: {0}")]
+    TrayError(String),
+
+    #[error("Global shortcut not registered: {0}")]
+    ShortcutNotRegistered(String),
+
+    #[error("Global shortcut error: {0}")]
+    ShortcutError(String),
+
+    #[error("File drop error: {0}")]
+    FileDropError(String),
+
+    #[error("tauri-plugin-deep-link is not initialized")]
+    DeepLinkNotConfigured,
+
+    #[error("Invalid deep link: {0}")]
+    InvalidDeepLink(String),
+
+    #[error("State provider not found: {0}")]
+    StateProviderNotFound(String),
+
+    #[error("Rust handler not found: {0}")]
+    RustHandlerNotFound(String),
+
+    #[error("Rust handler {0} panicked: {1}")]
+    RustHandlerPanicked(String, String),
+
+    #[error("Rust handler failed: {0}")]
+    RustHandlerError(String),
+
+    #[error("Invalid invoke conditions: {0}")]
+    InvalidInvokeConditions(String),
+
+    #[error("Invoke replay error: {0}")]
+    InvokeReplayError(String),
+
+    #[error("No recorded result for {0}")]
+    UnrecordedInvoke(String),
+
+    #[error("Web storage error: {0}")]
+    WebStorageError(String),
+
+    #[error("Coverage error: {0}")]
+    CoverageError(String),
+
+    #[error("No '{0}' event within {1}ms")]
+    WaitTimeout(String, u64),
+
+    #[error("Accessibility error: {0}")]
+    AccessibilityError(String),
+
+    #[error("Native input error: {0}")]
+    NativeInputError(String),
+
+    #[error("Event recording error: {0}")]
+    EventRecordingError(String),
+
+    #[error("Failed to emit event: {0}")]
+    EmitError(String),
+
+    #[error("Control request error: {0}")]
+    ControlError(String),
+
+    #[error("Another session is active: {0}")]
+    SessionConflict(String),
+
+    #[error("Session is not active: {0}")]
+    SessionNotActive(String),
+
+    #[error("Session error: {0}")]
+    SessionError(String),
+
+    #[error("Invalid wdio plugin config: {0}")]
+    ConfigError(String),
+
+    #[error("The wdio plugin is disabled in release builds; set WdioConfig::enabled_in_release or WDIO_ENABLE=1 to enable it")]
+    PluginDisabled,
+}
+
+/// Where a script error was raised, for its message
+fn at(position: &Option<ScriptPosition>) -> String {
+    match position {
+        Some(position) => format!(" (line {}, column {})", position.line, position.column),
+        None => String::new(),
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::SerializationError(e.to_string())
+    }
+}
+
+impl Error {
+    /// Stable identifier for the error kind, for clients to match on instead
+    /// of the message
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::Io(_) => "IO_ERROR",
+            #[cfg(mobile)]
+            Error::PluginInvoke(_) => "PLUGIN_INVOKE_ERROR",
+            Error::Tauri(_) => "TAURI_ERROR",
+            Error::ExecuteError(_) => "EXECUTE_ERROR",
+            Error::ScriptError { .. } => "SCRIPT_ERROR",
+            Error::PayloadTooLarge { .. } => "PAYLOAD_TOO_LARGE",
+            Error::ExecuteTimeout(_) => "EXECUTE_TIMEOUT",
+            Error::CommandTimeout { .. } => "COMMAND_TIMEOUT",
+            Error::BridgeUnavailable(_) => "BRIDGE_UNAVAILABLE",
+            Error::PreambleError(_) => "PREAMBLE_ERROR",
+            Error::MockError(_) => "MOCK_ERROR",
+            Error::SerializationError(_) => "SERIALIZATION_ERROR",
+            Error::WindowError(_) => "WINDOW_ERROR",
+            Error::WindowNotFound(_) => "WINDOW_NOT_FOUND",
+            Error::WindowNotFocused(_) => "WINDOW_NOT_FOCUSED",
+            Error::WindowNavigated(_) => "WINDOW_NAVIGATED",
+            Error::ScreenshotFailed(_) => "SCREENSHOT_FAILED",
+            Error::UnsupportedInBuild(_) => "UNSUPPORTED_IN_BUILD",
+            Error::UnsupportedPlatform(_) => "UNSUPPORTED_PLATFORM",
+            Error::NavigationDenied(_) => "NAVIGATION_DENIED",
+            Error::EnvAccessDenied(_) => "ENV_ACCESS_DENIED",
+            Error::ClipboardError(_) => "CLIPBOARD_ERROR",
+            Error::ClipboardFormatMismatch(_) => "CLIPBOARD_FORMAT_MISMATCH",
+            Error::MenuItemNotFound(_) => "MENU_ITEM_NOT_FOUND",
+            Error::MenuError(_) => "MENU_ERROR",
+            Error::TrayNotConfigured => "TRAY_NOT_CONFIGURED",
+            Error::TrayNotFound(_) => "TRAY_NOT_FOUND",
+            Error::TrayError(_) => "TRAY_ERROR",
+            Error::ShortcutNotRegistered(_) => "SHORTCUT_NOT_REGISTERED",
+            Error::ShortcutError(_) => "SHORTCUT_ERROR",
+            Error::FileDropError(_) => "FILE_DROP_ERROR",
+            Error::DeepLinkNotConfigured => "DEEP_LINK_NOT_CONFIGURED",
+            Error::InvalidDeepLink(_) => "INVALID_DEEP_LINK",
+            Error::StateProviderNotFound(_) => "STATE_PROVIDER_NOT_FOUND",
+            Error::RustHandlerNotFound(_) => "RUST_HANDLER_NOT_FOUND",
+            Error::RustHandlerPanicked(..) => "RUST_HANDLER_PANICKED",
+            Error::RustHandlerError(_) => "RUST_HANDLER_ERROR",
+            Error::InvalidInvokeConditions(_) => "INVALID_INVOKE_CONDITIONS",
+            Error::InvokeReplayError(_) => "INVOKE_REPLAY_ERROR",
+            Error::UnrecordedInvoke(_) => "UNRECORDED_INVOKE",
+            Error::WebStorageError(_) => "WEB_STORAGE_ERROR",
+            Error::CoverageError(_) => "COVERAGE_ERROR",
+            Error::WaitTimeout(..) => "WAIT_TIMEOUT",
+            Error::AccessibilityError(_) => "ACCESSIBILITY_ERROR",
+            Error::NativeInputError(_) => "NATIVE_INPUT_ERROR",
+            Error::EventRecordingError(_) => "EVENT_RECORDING_ERROR",
+            Error::EmitError(_) => "EMIT_ERROR",
+            Error::ControlError(_) => "CONTROL_ERROR",
+            Error::SessionConflict(_) => "SESSION_CONFLICT",
+            Error::SessionNotActive(_) => "SESSION_NOT_ACTIVE",
+            Error::SessionError(_) => "SESSION_ERROR",
+            Error::ConfigError(_) => "CONFIG_ERROR",
+            Error::PluginDisabled => "PLUGIN_DISABLED",
+        }
+    }
+
+    /// Machine-readable context for errors that name the thing they failed on
+    fn details(&self) -> Option<JsonValue> {
+        match self {
+            Error::Io(e) => Some(serde_json::json!({ "kind": e.kind().to_string() })),
+            Error::WindowNotFound(label)
+            | Error::WindowNotFocused(label)
+            | Error::WindowNavigated(label)
+            | Error::BridgeUnavailable(label) => {
+                Some(serde_json::json!({ "label": label }))
+            }
+            Error::MenuItemNotFound(id) | Error::TrayNotFound(id) => Some(serde_json::json!({ "id": id })),
+            Error::ShortcutNotRegistered(accelerator) => Some(serde_json::json!({ "accelerator": accelerator })),
+            Error::StateProviderNotFound(name)
+            | Error::RustHandlerNotFound(name)
+            | Error::RustHandlerPanicked(name, _)
+            | Error::WaitTimeout(name, _) => Some(serde_json::json!({ "name": name })),
+            Error::SessionConflict(session_id) | Error::SessionNotActive(session_id) => {
+                Some(serde_json::json!({ "session_id": session_id }))
+            }
+            Error::UnsupportedPlatform(platform) => Some(serde_json::json!({ "platform": platform })),
+            Error::UnrecordedInvoke(command) => Some(serde_json::json!({ "command": command })),
+            Error::ScriptError { position: Some(position), .. } => serde_json::to_value(position).ok(),
+            Error::PayloadTooLarge { actual, limit } => Some(serde_json::json!({ "actual": actual, "limit": limit })),
+            Error::CommandTimeout { command, limit_ms } => {
+                Some(serde_json::json!({ "command": command, "limit_ms": limit_ms }))
+            }
+            _ => None,
+        }
+    }
 }
 
+/// Errors reach the frontend (and the control server) as
+/// `{ code, message, details }`, where `message` is the display text
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(self.to_string().as_ref())
+        let mut error = serializer.serialize_struct("Error", 3)?;
+        error.serialize_field("code", self.code())?;
+        error.serialize_field("message", &self.to_string())?;
+        error.serialize_field("details", &self.details())?;
+        error.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_serializes_code_message_and_details() {
+        assert_eq!(
+            serde_json::to_value(Error::WindowNotFound("other".to_string())).unwrap(),
+            json!({
+                "code": "WINDOW_NOT_FOUND",
+                "message": "Window not found: other",
+                "details": { "label": "other" },
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(Error::PluginDisabled).unwrap()["details"],
+            JsonValue::Null
+        );
+    }
+
+    #[test]
+    fn test_payload_too_large_carries_the_sizes() {
+        let error = Error::PayloadTooLarge { actual: 2048, limit: 1024 };
+
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "code": "PAYLOAD_TOO_LARGE",
+                "message": "Payload too large: 2048 bytes, over the 1024-byte max_payload_bytes limit",
+                "details": { "actual": 2048, "limit": 1024 },
+            })
+        );
+    }
+
+    #[test]
+    fn test_command_timeout_names_the_command_and_limit() {
+        let error = Error::CommandTimeout {
+            command: "capture_window".to_string(),
+            limit_ms: 30_000,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "code": "COMMAND_TIMEOUT",
+                "message": "Command capture_window timed out after 30000ms",
+                "details": { "command": "capture_window", "limit_ms": 30_000 },
+            })
+        );
+    }
+
+    #[test]
+    fn test_script_errors_carry_their_position() {
+        let error = Error::ScriptError {
+            message: "y is not defined".to_string(),
+            position: Some(ScriptPosition { line: 2, column: 11, snippet: "const x = y;\n          ^".to_string() }),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "code": "SCRIPT_ERROR",
+                "message": "Script error: y is not defined (line 2, column 11)",
+                "details": { "line": 2, "column": 11, "snippet": "const x = y;\n          ^" },
+            })
+        );
+
+        let unplaced = Error::ScriptError { message: "boom".to_string(), position: None };
+        assert_eq!(unplaced.to_string(), "Script error: boom");
+        assert_eq!(serde_json::to_value(&unplaced).unwrap()["details"], JsonValue::Null);
+    }
+
+    #[test]
+    fn test_converts_serde_and_io_errors() {
+        let serde_error: Error = serde_json::from_str::<u32>("nope").unwrap_err().into();
+        assert_eq!(serde_error.code(), "SERIALIZATION_ERROR");
+
+        let io_error: Error = std::io::Error::from(std::io::ErrorKind::NotFound).into();
+        let value = serde_json::to_value(&io_error).unwrap();
+        assert_eq!(value["code"], "IO_ERROR");
+        assert_eq!(value["details"], json!({ "kind": "entity not found" }));
     }
 }
@@ -0,0 +1,179 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value as JsonValue;
+use tauri::{AppHandle, EventId, Listener, Runtime};
+
+use crate::log_buffer::now_ms;
+use crate::models::{EventRecord, EventRecordFilter, RecordedEvents};
+use crate::{Error, Result};
+
+/// Records events emitted through Tauri's event system. Listeners live in
+/// Rust, so recording carries on across webview reloads.
+pub(crate) struct EventRecorder {
+    records: Arc<Mutex<Records>>,
+    listeners: Mutex<Vec<EventId>>,
+}
+
+/// Bounded record buffer; the oldest records are dropped and counted once
+/// capacity is reached so high-frequency events can't grow it unbounded
+pub(crate) struct Records {
+    entries: VecDeque<EventRecord>,
+    capacity: usize,
+    dropped: u64,
+}
+
+impl Records {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity.min(1024)),
+            capacity,
+            dropped: 0,
+        }
+    }
+
+    pub(crate) fn push(&mut self, record: EventRecord) {
+        if self.capacity == 0 {
+            self.dropped += 1;
+            return;
+        }
+        while self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+            self.dropped += 1;
+        }
+        self.entries.push_back(record);
+    }
+
+    pub(crate) fn query(&self, filter: &EventRecordFilter) -> RecordedEvents {
+        RecordedEvents {
+            events: self.entries.iter().filter(|r| filter.matches(r)).cloned().collect(),
+            dropped: self.dropped,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.dropped = 0;
+    }
+}
+
+impl EventRecorder {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            records: Arc::new(Mutex::new(Records::new(capacity))),
+            listeners: Mutex::default(),
+        }
+    }
+
+    /// Start recording `event_names` emitted to any target, discarding earlier
+    /// records and replacing any recording in progress
+    pub(crate) fn start<R: Runtime>(&self, app: &AppHandle<R>, event_names: Vec<String>) -> Result<()> {
+        if event_names.is_empty() {
+            return Err(Error::EventRecordingError(
+                "at least one event name is required; Tauri can't listen to all events".to_string(),
+            ));
+        }
+        let mut listeners = self.listeners.lock().unwrap_or_else(|e| e.into_inner());
+        for id in listeners.drain(..) {
+            app.unlisten(id);
+        }
+        self.records.lock().unwrap_or_else(|e| e.into_inner()).clear();
+
+        for name in event_names {
+            let records = self.records.clone();
+            let event_name = name.clone();
+            let id = app.listen_any(name, move |event| {
+                let payload = event_payload(event.payload());
+                records
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .push(EventRecord {
+                        name: event_name.clone(),
+                        payload,
+                        timestamp_ms: now_ms(),
+                    });
+            });
+            listeners.push(id);
+        }
+        Ok(())
+    }
+
+    /// Stop listening, keeping the records for get_recorded_events. Returns
+    /// the number of events recorded.
+    pub(crate) fn stop<R: Runtime>(&self, app: &AppHandle<R>) -> usize {
+        for id in self
+            .listeners
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .drain(..)
+        {
+            app.unlisten(id);
+        }
+        self.records.lock().unwrap_or_else(|e| e.into_inner()).entries.len()
+    }
+
+    /// Stop listening and discard the records; returns the number discarded
+    pub(crate) fn reset<R: Runtime>(&self, app: &AppHandle<R>) -> usize {
+        let count = self.stop(app);
+        self.records.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        count
+    }
+
+    pub(crate) fn query(&self, filter: &EventRecordFilter) -> RecordedEvents {
+        self.records.lock().unwrap_or_else(|e| e.into_inner()).query(filter)
+    }
+}
+
+/// Payloads arrive serialized; keep non-JSON payloads as strings
+pub(crate) fn event_payload(raw: &str) -> JsonValue {
+    serde_json::from_str(raw).unwrap_or_else(|_| JsonValue::String(raw.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, timestamp_ms: u64) -> EventRecord {
+        EventRecord {
+            name: name.to_string(),
+            payload: JsonValue::Null,
+            timestamp_ms,
+        }
+    }
+
+    #[test]
+    fn test_caps_records_and_counts_dropped() {
+        let mut records = Records::new(2);
+        for i in 0..5 {
+            records.push(record("tick", i));
+        }
+
+        let recorded = records.query(&EventRecordFilter::default());
+        assert_eq!(recorded.dropped, 3);
+        assert_eq!(
+            recorded.events.iter().map(|r| r.timestamp_ms).collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+    }
+
+    #[test]
+    fn test_filter_by_name_and_time() {
+        let mut records = Records::new(10);
+        records.push(record("sync-start", 10));
+        records.push(record("sync-complete", 20));
+        records.push(record("sync-start", 30));
+
+        let by_name = records.query(&EventRecordFilter {
+            name: Some("sync-start".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(by_name.events.len(), 2);
+
+        let since = records.query(&EventRecordFilter {
+            since_timestamp: Some(20),
+            ..Default::default()
+        });
+        assert_eq!(since.events.len(), 2);
+        assert_eq!(since.events[0].name, "sync-complete");
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::Value as JsonValue;
+use tokio::sync::oneshot;
+
+use crate::stream::EventSource;
+use crate::{Error, Result};
+
+/// Wait up to `timeout` for a `name` event whose payload contains `filter`,
+/// resolving with the payload. `recorded` returns the payloads recorded since
+/// the wait was requested; it's consulted once the listener is in place, so
+/// an event emitted before that is still seen.
+pub(crate) async fn wait_for_event(
+    events: &dyn EventSource,
+    name: &str,
+    filter: Option<&JsonValue>,
+    recorded: impl FnOnce() -> Vec<JsonValue>,
+    timeout: Duration,
+) -> Result<JsonValue> {
+    let (tx, rx) = oneshot::channel();
+    let tx = Arc::new(Mutex::new(Some(tx)));
+    let handler_filter = filter.cloned();
+    let listener = events.listen(
+        name.to_string(),
+        Box::new(move |payload| {
+            if handler_filter.as_ref().map_or(true, |filter| payload_contains(&payload, filter)) {
+                if let Some(tx) = tx.lock().unwrap_or_else(|e| e.into_inner()).take() {
+                    let _ = tx.send(payload);
+                }
+            }
+        }),
+    );
+
+    let earlier = recorded()
+        .into_iter()
+        .find(|payload| filter.map_or(true, |filter| payload_contains(payload, filter)));
+    let result = match earlier {
+        Some(payload) => Ok(payload),
+        None => match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(payload)) => Ok(payload),
+            _ => Err(Error::WaitTimeout(name.to_string(), timeout.as_millis() as u64)),
+        },
+    };
+    events.unlisten(listener);
+    result
+}
+
+/// Whether `payload` contains `filter` as a structural subset: every key of a
+/// filter object is in the payload with a matching value, every element of a
+/// filter array matches some payload element, and anything else is equal
+pub(crate) fn payload_contains(payload: &JsonValue, filter: &JsonValue) -> bool {
+    match (payload, filter) {
+        (JsonValue::Object(payload), JsonValue::Object(filter)) => filter
+            .iter()
+            .all(|(key, expected)| payload.get(key).is_some_and(|value| payload_contains(value, expected))),
+        (JsonValue::Array(payload), JsonValue::Array(filter)) => filter
+            .iter()
+            .all(|expected| payload.iter().any(|value| payload_contains(value, expected))),
+        _ => payload == filter,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::stream::tests::FakeEvents;
+
+    fn wait(
+        events: &FakeEvents,
+        filter: Option<JsonValue>,
+        recorded: Vec<JsonValue>,
+        timeout_ms: u64,
+    ) -> Result<JsonValue> {
+        tauri::async_runtime::block_on(wait_for_event(
+            events,
+            "sync-complete",
+            filter.as_ref(),
+            move || recorded,
+            Duration::from_millis(timeout_ms),
+        ))
+    }
+
+    #[test]
+    fn test_payload_contains_is_a_structural_subset() {
+        let payload = json!({ "id": 7, "status": "done", "tags": ["a", "b"], "meta": { "retries": 0, "host": "x" } });
+
+        assert!(payload_contains(&payload, &json!({})));
+        assert!(payload_contains(&payload, &json!({ "status": "done", "meta": { "retries": 0 } })));
+        assert!(payload_contains(&payload, &json!({ "tags": ["b"] })));
+        assert!(!payload_contains(&payload, &json!({ "status": "failed" })));
+        assert!(!payload_contains(&payload, &json!({ "missing": null })));
+        assert!(!payload_contains(&payload, &json!({ "tags": ["c"] })));
+        assert!(!payload_contains(&json!(1), &json!("1")));
+    }
+
+    #[test]
+    fn test_matches_an_event_recorded_before_the_listener() {
+        let events = FakeEvents::default();
+        let recorded = vec![json!({ "id": 1 }), json!({ "id": 2 })];
+
+        let payload = wait(&events, Some(json!({ "id": 2 })), recorded, 1000).unwrap();
+
+        assert_eq!(payload, json!({ "id": 2 }));
+        assert_eq!(events.listener_count(), 0);
+    }
+
+    #[test]
+    fn test_matches_a_future_event_and_skips_mismatches() {
+        let events = FakeEvents::default();
+        let emitter = events.clone();
+        let emitting = std::thread::spawn(move || {
+            while emitter.listener_count() == 0 {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            emitter.emit("other", json!({ "id": 2 }));
+            emitter.emit("sync-complete", json!({ "id": 1 }));
+            emitter.emit("sync-complete", json!({ "id": 2, "ok": true }));
+        });
+
+        let payload = wait(&events, Some(json!({ "id": 2 })), Vec::new(), 5000).unwrap();
+        emitting.join().unwrap();
+
+        assert_eq!(payload, json!({ "id": 2, "ok": true }));
+        assert_eq!(events.listener_count(), 0);
+    }
+
+    #[test]
+    fn test_times_out_when_no_event_matches() {
+        let events = FakeEvents::default();
+
+        let error = wait(&events, Some(json!({ "id": 3 })), vec![json!({ "id": 1 })], 20).unwrap_err();
+
+        assert!(matches!(error, Error::WaitTimeout(ref name, 20) if name == "sync-complete"));
+        assert_eq!(events.listener_count(), 0);
+    }
+}
@@ -0,0 +1,99 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value as JsonValue;
+
+use crate::crash::panic_message;
+use crate::models::ExecuteRequest;
+use crate::Result;
+
+pub(crate) type BeforeExecuteHook = Arc<dyn Fn(&ExecuteRequest) + Send + Sync>;
+pub(crate) type AfterExecuteHook = Arc<dyn Fn(&ExecuteRequest, &Result<JsonValue>) + Send + Sync>;
+
+/// Callbacks the app runs around every execute call
+#[derive(Default)]
+pub(crate) struct ExecuteHooks {
+    before: Mutex<Vec<BeforeExecuteHook>>,
+    after: Mutex<Vec<AfterExecuteHook>>,
+}
+
+impl ExecuteHooks {
+    pub(crate) fn on_before(&self, hook: BeforeExecuteHook) {
+        self.before.lock().unwrap_or_else(|e| e.into_inner()).push(hook);
+    }
+
+    pub(crate) fn on_after(&self, hook: AfterExecuteHook) {
+        self.after.lock().unwrap_or_else(|e| e.into_inner()).push(hook);
+    }
+
+    /// Run the before hooks in registration order. The hook list is copied out
+    /// first, so a hook can register further hooks or take its own locks.
+    pub(crate) fn run_before(&self, request: &ExecuteRequest) {
+        let hooks = self.before.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        for hook in hooks {
+            guarded("before", || hook(request));
+        }
+    }
+
+    pub(crate) fn run_after(&self, request: &ExecuteRequest, result: &Result<JsonValue>) {
+        let hooks = self.after.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        for hook in hooks {
+            guarded("after", || hook(request, result));
+        }
+    }
+}
+
+/// A panicking hook is logged and skipped rather than failing the execute call
+fn guarded(stage: &str, hook: impl FnOnce()) {
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(hook)) {
+        log::error!("execute {} hook panicked: {}", stage, panic_message(payload.as_ref()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn request() -> ExecuteRequest {
+        ExecuteRequest {
+            script: "1 + 1".to_string(),
+            args: Vec::new(),
+            window_label: None,
+            detailed: false,
+        }
+    }
+
+    #[test]
+    fn test_panicking_hook_does_not_stop_the_others() {
+        let hooks = ExecuteHooks::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        hooks.on_before(Arc::new(|_| panic!("hook failed")));
+        let counter = calls.clone();
+        hooks.on_before(Arc::new(move |request| {
+            assert_eq!(request.script, "1 + 1");
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+        let counter = calls.clone();
+        hooks.on_after(Arc::new(move |_, result| {
+            assert!(matches!(result, Ok(value) if value == 2));
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        hooks.run_before(&request());
+        hooks.run_after(&request(), &Ok(JsonValue::from(2)));
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_hook_can_register_another_hook() {
+        let hooks = Arc::new(ExecuteHooks::default());
+        let inner = hooks.clone();
+        hooks.on_before(Arc::new(move |_| inner.on_before(Arc::new(|_| {}))));
+
+        hooks.run_before(&request());
+        assert_eq!(hooks.before.lock().unwrap().len(), 2);
+    }
+}
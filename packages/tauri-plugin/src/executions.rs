@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::Value as JsonValue;
+use tauri::{AppHandle, EventId, Listener, Runtime};
+use tokio::sync::oneshot;
+
+use crate::log_buffer::now_ms;
+use crate::models::{ExecuteResponse, PendingExecution, ScriptPosition};
+use crate::script_map::FUNCTION_HEADER_LINES;
+use crate::{Error, Result};
+
+struct Pending {
+    window_label: String,
+    /// Result event listener, when results arrive as events rather than
+    /// through resolve_execute
+    listener: Option<EventId>,
+    created_at_ms: u64,
+    tx: oneshot::Sender<Result<JsonValue>>,
+}
+
+/// Execute calls waiting for their script's result. The injected script
+/// reports back through resolve_execute (or a result event), and a page can
+/// go away before it does (navigation, reload, closed window), so the
+/// plugin's page-load and window hooks fail those calls here instead of
+/// leaving them to time out. Whichever comes first removes the entry.
+#[derive(Clone, Default)]
+pub(crate) struct PendingExecutions {
+    entries: Arc<Mutex<HashMap<String, Pending>>>,
+}
+
+impl PendingExecutions {
+    /// Track an execution until the returned registration is dropped, which
+    /// also removes its result listener
+    pub(crate) fn register<'a, R: Runtime>(
+        &self,
+        app: &'a AppHandle<R>,
+        id: &str,
+        window_label: &str,
+        tx: oneshot::Sender<Result<JsonValue>>,
+    ) -> Registration<'a, R> {
+        self.insert(id, window_label, tx);
+        Registration {
+            app,
+            pending: self.clone(),
+            id: id.to_string(),
+        }
+    }
+
+    fn insert(&self, id: &str, window_label: &str, tx: oneshot::Sender<Result<JsonValue>>) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.insert(
+            id.to_string(),
+            Pending {
+                window_label: window_label.to_string(),
+                listener: None,
+                created_at_ms: now_ms(),
+                tx,
+            },
+        );
+    }
+
+    /// Record the result event listener for `id` so it's removed with the entry
+    pub(crate) fn set_listener(&self, id: &str, listener: EventId) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(pending) = entries.get_mut(id) {
+            pending.listener = Some(listener);
+        }
+    }
+
+    fn remove(&self, id: &str) -> Option<Pending> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.remove(id)
+    }
+
+    /// Deliver the result for `id`. With `from_window`, only a script running
+    /// in the execution's own window may resolve it.
+    pub(crate) fn resolve(&self, id: &str, from_window: Option<&str>, result: Result<JsonValue>) -> Result<()> {
+        let pending = {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            match entries.get(id) {
+                Some(pending) if from_window.map_or(true, |label| label == pending.window_label) => entries.remove(id),
+                _ => None,
+            }
+        };
+        match pending {
+            Some(pending) => {
+                let _ = pending.tx.send(result);
+                Ok(())
+            }
+            None => Err(Error::ExecuteError(format!("no pending execute call '{}' for this window", id))),
+        }
+    }
+
+    /// Fail every execution waiting on `window_label` with `error`, returning
+    /// the listeners to remove
+    pub(crate) fn cancel_window(&self, window_label: &str, error: impl Fn() -> Error) -> Vec<EventId> {
+        self.cancel(|label| label == window_label, error).1
+    }
+
+    /// Fail every execution not waiting on `except_window` with `error`,
+    /// returning how many were failed and the listeners to remove
+    pub(crate) fn cancel_all(&self, except_window: Option<&str>, error: impl Fn() -> Error) -> (usize, Vec<EventId>) {
+        self.cancel(|label| Some(label) != except_window, error)
+    }
+
+    fn cancel(&self, waits_on: impl Fn(&str) -> bool, error: impl Fn() -> Error) -> (usize, Vec<EventId>) {
+        let cancelled: Vec<Pending> = {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            let ids: Vec<String> = entries
+                .iter()
+                .filter(|(_, pending)| waits_on(&pending.window_label))
+                .map(|(id, _)| id.clone())
+                .collect();
+            ids.iter().filter_map(|id| entries.remove(id)).collect()
+        };
+
+        let count = cancelled.len();
+        let listeners = cancelled
+            .into_iter()
+            .filter_map(|pending| {
+                let _ = pending.tx.send(Err(error()));
+                pending.listener
+            })
+            .collect();
+        (count, listeners)
+    }
+
+    /// Pending executions, oldest first
+    pub(crate) fn list(&self) -> Vec<PendingExecution> {
+        let now = now_ms();
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let mut list: Vec<PendingExecution> = entries
+            .iter()
+            .map(|(id, pending)| PendingExecution {
+                id: id.clone(),
+                window_label: pending.window_label.clone(),
+                created_at_ms: pending.created_at_ms,
+                age_ms: now.saturating_sub(pending.created_at_ms),
+            })
+            .collect();
+        list.sort_by(|a, b| a.created_at_ms.cmp(&b.created_at_ms).then_with(|| a.id.cmp(&b.id)));
+        list
+    }
+}
+
+/// Keeps an execution registered; dropping it on any return path (result,
+/// error, timeout, or the command future being dropped) cleans it up
+pub(crate) struct Registration<'a, R: Runtime> {
+    app: &'a AppHandle<R>,
+    pending: PendingExecutions,
+    id: String,
+}
+
+impl<R: Runtime> Drop for Registration<'_, R> {
+    fn drop(&mut self) {
+        if let Some(listener) = self.pending.remove(&self.id).and_then(|pending| pending.listener) {
+            self.app.unlisten(listener);
+        }
+    }
+}
+
+/// Turn the injected script's `{ success, result | __wdio_undefined__ | error
+/// | preamble_error }` report into the execute result; `None` if it isn't one.
+/// The result is the script's value as-is, decoded once along with the report.
+/// A script error's `line` and `column` are where the engine placed it in the
+/// wrapper, or in the wrapper compiled by the syntax check when `syntax` is
+/// set; the execute command maps them onto the script.
+///
+/// Deprecated report forms from older wrappers are still accepted for now:
+/// the value under `value` instead of `result`, and the whole report
+/// `JSON.stringify`d into a string.
+pub(crate) fn result_from_report(report: &JsonValue) -> Option<Result<JsonValue>> {
+    if let JsonValue::String(encoded) = report {
+        log::warn!("Execute result reported as a JSON string; this form is deprecated");
+        let decoded = serde_json::from_str::<JsonValue>(encoded).ok().filter(JsonValue::is_object)?;
+        return result_from_report(&decoded);
+    }
+    let success = report.get("success")?.as_bool()?;
+    if let Some(error) = report.get("preamble_error").filter(|_| !success) {
+        let error = error.as_str().unwrap_or("Unknown error");
+        return Some(Err(Error::PreambleError(error.to_string())));
+    }
+    if !success {
+        let error = report.get("error").and_then(|e| e.as_str()).unwrap_or("Unknown error");
+        return Some(Err(Error::ScriptError {
+            message: error.to_string(),
+            position: reported_position(report),
+        }));
+    }
+    if report.get("__wdio_undefined__").and_then(|v| v.as_bool()).unwrap_or(false) {
+        return Some(Ok(serde_json::json!({ "__wdio_undefined__": true })));
+    }
+    let result = report.get("result").or_else(|| report.get("value"));
+    Some(Ok(result.cloned().unwrap_or(JsonValue::Null)))
+}
+
+/// Build the response to a `detailed` execute from the wrapper's
+/// `{ value, eval_started_ms, eval_duration_ms }` result and the plugin's
+/// `total` for the whole call
+pub(crate) fn detailed_response(result: JsonValue, total: Duration, window_label: &str) -> Result<ExecuteResponse> {
+    let timing = |key: &str| result.get(key).and_then(JsonValue::as_f64);
+    let (Some(eval_started_ms), Some(eval_duration_ms)) = (timing("eval_started_ms"), timing("eval_duration_ms")) else {
+        return Err(Error::ExecuteError(format!("malformed detailed execute result: {}", result)));
+    };
+    Ok(ExecuteResponse {
+        value: result.get("value").cloned().unwrap_or(JsonValue::Null),
+        eval_started_ms,
+        eval_duration_ms,
+        total_duration_ms: total.as_secs_f64() * 1_000.0,
+        window_label: window_label.to_string(),
+    })
+}
+
+/// The position a failure report gives, in the wrapper's lines
+fn reported_position(report: &JsonValue) -> Option<ScriptPosition> {
+    let number = |key: &str| report.get(key)?.as_u64().and_then(|n| u32::try_from(n).ok());
+    let header = if report.get("syntax").and_then(|s| s.as_bool()).unwrap_or(false) {
+        FUNCTION_HEADER_LINES
+    } else {
+        0
+    };
+    Some(ScriptPosition {
+        line: number("line")?.checked_sub(header)?,
+        column: number("column")?,
+        snippet: String::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_cancel_window_fails_only_that_windows_executions() {
+        let pending = PendingExecutions::default();
+        let (main_tx, mut main_rx) = oneshot::channel();
+        let (other_tx, mut other_rx) = oneshot::channel();
+        pending.insert("a", "main", main_tx);
+        pending.set_listener("a", 1);
+        pending.insert("b", "other", other_tx);
+
+        let listeners = pending.cancel_window("main", || Error::WindowNavigated("main".to_string()));
+
+        assert_eq!(listeners, vec![1]);
+        assert!(matches!(main_rx.try_recv(), Ok(Err(Error::WindowNavigated(label))) if label == "main"));
+        assert!(other_rx.try_recv().is_err());
+        let remaining: Vec<String> = pending.list().into_iter().map(|p| p.id).collect();
+        assert_eq!(remaining, vec!["b"]);
+    }
+
+    #[test]
+    fn test_cancel_all_spares_only_the_excepted_window() {
+        let pending = PendingExecutions::default();
+        let (main_tx, mut main_rx) = oneshot::channel();
+        let (other_tx, mut other_rx) = oneshot::channel();
+        let (third_tx, _third_rx) = oneshot::channel();
+        pending.insert("a", "main", main_tx);
+        pending.insert("b", "other", other_tx);
+        pending.set_listener("b", 2);
+        pending.insert("c", "third", third_tx);
+
+        let (count, listeners) = pending.cancel_all(Some("main"), || Error::ExecuteError("reset".to_string()));
+
+        assert_eq!((count, listeners), (2, vec![2]));
+        assert!(main_rx.try_recv().is_err());
+        assert!(matches!(other_rx.try_recv(), Ok(Err(Error::ExecuteError(_)))));
+        assert_eq!(pending.cancel_all(None, || Error::ExecuteError("reset".to_string())).0, 1);
+        assert!(pending.list().is_empty());
+    }
+
+    #[test]
+    fn test_resolve_only_from_the_executions_window() {
+        let pending = PendingExecutions::default();
+        let (tx, mut rx) = oneshot::channel();
+        pending.insert("a", "main", tx);
+
+        assert!(pending.resolve("a", Some("other"), Ok(json!(1))).is_err());
+        assert!(pending.resolve("missing", Some("main"), Ok(json!(1))).is_err());
+        pending.resolve("a", Some("main"), Ok(json!(42))).unwrap();
+
+        assert!(matches!(rx.try_recv(), Ok(Ok(value)) if value == 42));
+        assert!(pending.resolve("a", Some("main"), Ok(json!(43))).is_err());
+        assert!(pending.list().is_empty());
+    }
+
+    #[test]
+    fn test_event_results_resolve_without_window_check() {
+        let pending = PendingExecutions::default();
+        let (tx, mut rx) = oneshot::channel();
+        pending.insert("a", "main", tx);
+
+        pending.resolve("a", None, Ok(json!("via event"))).unwrap();
+        assert!(matches!(rx.try_recv(), Ok(Ok(value)) if value == "via event"));
+    }
+
+    #[test]
+    fn test_result_from_report() {
+        assert!(matches!(
+            result_from_report(&json!({ "success": true, "result": [1, 2] })),
+            Some(Ok(value)) if value == json!([1, 2])
+        ));
+        assert!(matches!(
+            result_from_report(&json!({ "success": true, "__wdio_undefined__": true })),
+            Some(Ok(value)) if value == json!({ "__wdio_undefined__": true })
+        ));
+        assert!(matches!(
+            result_from_report(&json!({ "success": false, "error": "boom" })),
+            Some(Err(Error::ScriptError { message, position: None })) if message == "boom"
+        ));
+        assert!(matches!(
+            result_from_report(&json!({ "success": false, "preamble_error": "helper is not defined" })),
+            Some(Err(Error::PreambleError(message))) if message == "helper is not defined"
+        ));
+        assert!(result_from_report(&json!({ "value": 1 })).is_none());
+    }
+
+    #[test]
+    fn test_detailed_response_pairs_page_timings_with_the_total() {
+        let report = json!({ "success": true, "result": {
+            "value": { "__wdio_undefined__": true },
+            "eval_started_ms": 1_700_000_000_000.25,
+            "eval_duration_ms": 301.5,
+        } });
+        let result = result_from_report(&report).unwrap().unwrap();
+
+        let response = detailed_response(result, Duration::from_micros(312_250), "main").unwrap();
+
+        assert_eq!(response.value, json!({ "__wdio_undefined__": true }));
+        assert_eq!(response.eval_started_ms, 1_700_000_000_000.25);
+        assert_eq!(response.eval_duration_ms, 301.5);
+        assert_eq!(response.total_duration_ms, 312.25);
+        assert!(response.eval_duration_ms <= response.total_duration_ms);
+        assert_eq!(response.window_label, "main");
+    }
+
+    #[test]
+    fn test_detailed_response_needs_the_page_timings() {
+        let result = json!({ "value": 1, "eval_duration_ms": 2.0 });
+
+        let error = detailed_response(result, Duration::from_millis(5), "main").unwrap_err();
+
+        assert!(matches!(error, Error::ExecuteError(ref m) if m.starts_with("malformed detailed execute result")));
+    }
+
+    // Decode a report the way the result event listener and resolve_execute
+    // receive it: serialized once by Tauri, parsed once here
+    fn round_trip(report: JsonValue) -> String {
+        let payload = serde_json::to_string(&report).unwrap();
+        let report: JsonValue = serde_json::from_str(&payload).unwrap();
+        serde_json::to_string(&result_from_report(&report).unwrap().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_results_round_trip_byte_exact() {
+        let results = [
+            r#""{\"a\":1}""#,
+            r#""[1, 2]""#,
+            r#""""#,
+            "0",
+            "-12.5",
+            "9007199254740993",
+            "1e+300",
+            "null",
+            "true",
+            r#"{"a":{"b":[1,{"c":null}],"d":"x"},"e":[]}"#,
+            r#""héllo wörld ✓ 日本語 🚀""#,
+            r#""line\nbreak \"quoted\" back\\slash""#,
+        ];
+        for expected in results {
+            let result: JsonValue = serde_json::from_str(expected).unwrap();
+            assert_eq!(round_trip(json!({ "success": true, "result": result })), expected);
+        }
+    }
+
+    #[test]
+    fn test_json_looking_string_results_stay_strings() {
+        let report = json!({ "success": true, "result": "{\"a\":1}" });
+
+        assert!(matches!(result_from_report(&report), Some(Ok(JsonValue::String(s))) if s == r#"{"a":1}"#));
+    }
+
+    #[test]
+    fn test_result_from_deprecated_report_forms() {
+        assert!(matches!(
+            result_from_report(&json!({ "success": true, "value": "legacy" })),
+            Some(Ok(value)) if value == "legacy"
+        ));
+        let encoded = json!(r#"{"success":true,"value":{"a":1}}"#);
+        assert!(matches!(result_from_report(&encoded), Some(Ok(value)) if value == json!({ "a": 1 })));
+        let encoded_error = json!(r#"{"success":false,"error":"boom"}"#);
+        assert!(matches!(
+            result_from_report(&encoded_error),
+            Some(Err(Error::ScriptError { message, .. })) if message == "boom"
+        ));
+        for not_a_report in [json!("not json"), json!(r#""{\"success\":true}""#), json!("[1]")] {
+            assert!(result_from_report(&not_a_report).is_none(), "{not_a_report}");
+        }
+    }
+}
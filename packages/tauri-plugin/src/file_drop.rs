@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+use tauri::{DragDropEvent, Emitter, EventTarget, Manager, PhysicalPosition, Runtime, WebviewWindow};
+
+use crate::models::Point;
+use crate::{Error, Result, WdioExt};
+
+/// Payload of the `tauri://drag-*` events, as emitted by Tauri for native drops
+#[derive(Serialize, Clone)]
+struct DragDropPayload<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paths: Option<&'a Vec<PathBuf>>,
+    position: &'a PhysicalPosition<f64>,
+}
+
+/// Check that every path exists unless `allow_missing` is set
+pub(crate) fn validate_paths(paths: &[String], allow_missing: bool) -> Result<Vec<PathBuf>> {
+    if paths.is_empty() {
+        return Err(Error::FileDropError("at least one path is required".to_string()));
+    }
+    paths
+        .iter()
+        .map(PathBuf::from)
+        .map(|path| {
+            if allow_missing || path.exists() {
+                Ok(path)
+            } else {
+                Err(Error::FileDropError(format!("path does not exist: {}", path.display())))
+            }
+        })
+        .collect()
+}
+
+/// Play the enter, over, and drop events of a file drag onto `window`: the
+/// frontend receives the `tauri://drag-*` events and handlers registered with
+/// `Wdio::on_drag_drop_event` run on the main thread. `position` defaults to
+/// the center of the window.
+pub(crate) fn simulate<R: Runtime>(
+    window: &WebviewWindow<R>,
+    paths: Vec<PathBuf>,
+    position: Option<Point>,
+) -> Result<()> {
+    let position = match position {
+        Some(point) => PhysicalPosition::new(point.x as f64, point.y as f64),
+        None => {
+            let size = window.inner_size().map_err(|e| Error::FileDropError(e.to_string()))?;
+            PhysicalPosition::new(size.width as f64 / 2.0, size.height as f64 / 2.0)
+        }
+    };
+    let events = [
+        DragDropEvent::Enter {
+            paths: paths.clone(),
+            position,
+        },
+        DragDropEvent::Over { position },
+        DragDropEvent::Drop { paths, position },
+    ];
+
+    let target = EventTarget::labeled(window.label());
+    for event in &events {
+        let (name, paths) = match event {
+            DragDropEvent::Enter { paths, .. } => ("tauri://drag-enter", Some(paths)),
+            DragDropEvent::Over { .. } => ("tauri://drag-over", None),
+            DragDropEvent::Drop { paths, .. } => ("tauri://drag-drop", Some(paths)),
+            _ => continue,
+        };
+        window
+            .emit_to(target.clone(), name, DragDropPayload { paths, position: &position })
+            .map_err(|e| Error::FileDropError(e.to_string()))?;
+    }
+
+    let handle = window.clone();
+    window
+        .run_on_main_thread(move || {
+            let window = handle.as_ref().window();
+            for event in &events {
+                handle.app_handle().wdio().dispatch_drag_drop_event(&window, event);
+            }
+        })
+        .map_err(|e| Error::FileDropError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_paths_rejects_missing_unless_allowed() {
+        let existing = std::env::temp_dir().to_string_lossy().into_owned();
+        let missing = "/definitely/not/a/real/path.txt".to_string();
+        let both = vec![existing.clone(), missing.clone()];
+
+        assert_eq!(validate_paths(&both[..1], false).unwrap(), vec![PathBuf::from(&existing)]);
+        assert!(matches!(
+            validate_paths(&both, false),
+            Err(Error::FileDropError(msg)) if msg.contains("path.txt")
+        ));
+        assert_eq!(validate_paths(&both[1..], true).unwrap(), vec![PathBuf::from(missing)]);
+        assert!(validate_paths(&[], true).is_err());
+    }
+}
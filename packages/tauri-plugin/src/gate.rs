@@ -0,0 +1,97 @@
+use tauri::ipc::Invoke;
+use tauri::{Manager, Runtime};
+
+use crate::models::WdioConfig;
+use crate::self_test::PROBE_HEADER;
+use crate::state::WdioState;
+use crate::{Error, Result};
+
+/// Env var that enables the plugin in release builds, like [`WdioConfig::enabled_in_release`]
+pub(crate) const ENABLE_ENV: &str = "WDIO_ENABLE";
+
+/// Commands that answer even while the plugin is disabled, so clients can probe it
+const ALWAYS_ALLOWED: &[&str] = &["is_enabled"];
+
+/// Decides whether plugin commands run. Release builds reject them with
+/// [`Error::PluginDisabled`] unless explicitly enabled, so an app can't ship
+/// the execute/mock commands to production by accident.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PluginGate {
+    enabled: bool,
+}
+
+impl PluginGate {
+    pub(crate) fn new(debug_build: bool, config: &WdioConfig, enable_env: Option<&str>) -> Self {
+        Self {
+            enabled: debug_build || config.enabled_in_release || enable_env.is_some_and(is_truthy),
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Error for a command invoked while the plugin is disabled
+    pub(crate) fn check(&self, command: &str) -> Result<()> {
+        if self.enabled || ALWAYS_ALLOWED.contains(&command) {
+            Ok(())
+        } else {
+            Err(Error::PluginDisabled)
+        }
+    }
+}
+
+/// Wrap the plugin's invoke handler so commands are rejected up front while
+/// the plugin is disabled, giving the same error on every Tauri version, and
+/// so self_test probes are answered without running the command
+pub(crate) fn guard<R: Runtime>(
+    handler: impl Fn(Invoke<R>) -> bool + Send + Sync + 'static,
+) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
+    move |invoke| {
+        let gate = invoke.message.webview_ref().try_state::<WdioState>().map(|state| state.gate);
+        if let Some(Err(e)) = gate.map(|gate| gate.check(invoke.message.command())) {
+            invoke.resolver.reject(e);
+            return true;
+        }
+        // Tauri checks the ACL before the handler runs, so a probe that gets here is permitted
+        if invoke.message.headers().contains_key(PROBE_HEADER) {
+            invoke.resolver.resolve(());
+            return true;
+        }
+        handler(invoke)
+    }
+}
+
+fn is_truthy(value: &str) -> bool {
+    matches!(value.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release_build_rejects_commands_unless_enabled() {
+        let gate = PluginGate::new(false, &WdioConfig::default(), None);
+        assert!(!gate.is_enabled());
+        assert!(matches!(gate.check("execute"), Err(Error::PluginDisabled)));
+        assert!(gate.check("is_enabled").is_ok());
+
+        let config = WdioConfig {
+            enabled_in_release: true,
+            ..Default::default()
+        };
+        assert!(PluginGate::new(false, &config, None).check("execute").is_ok());
+        assert!(PluginGate::new(true, &WdioConfig::default(), None).check("execute").is_ok());
+    }
+
+    #[test]
+    fn test_env_var_force_enables() {
+        for value in ["1", "true", " YES ", "on"] {
+            assert!(PluginGate::new(false, &WdioConfig::default(), Some(value)).is_enabled(), "{}", value);
+        }
+        for value in ["", "0", "false", "no"] {
+            assert!(!PluginGate::new(false, &WdioConfig::default(), Some(value)).is_enabled(), "{}", value);
+        }
+    }
+}
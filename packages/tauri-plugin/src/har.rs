@@ -0,0 +1,163 @@
+use std::io::Write;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::models::{InvokeOutcome, InvokeTraceEntry};
+
+/// An invoke trace as a HAR 1.2 document, each call an entry whose request
+/// is the command and its arguments and whose response is the outcome.
+/// Serializes straight from the trace, so writing it to a file doesn't
+/// build the document in memory first.
+pub(crate) struct Har<'a> {
+    /// App name and version, recorded as the log's creator
+    pub(crate) creator_name: &'a str,
+    pub(crate) creator_version: &'a str,
+    pub(crate) entries: &'a [InvokeTraceEntry],
+}
+
+impl Har<'_> {
+    pub(crate) fn write_to(&self, writer: impl Write) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Serialize for Har<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut har = serializer.serialize_map(Some(1))?;
+        har.serialize_entry("log", &HarLog(self))?;
+        har.end()
+    }
+}
+
+struct HarLog<'a>(&'a Har<'a>);
+
+impl Serialize for HarLog<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut log = serializer.serialize_map(Some(3))?;
+        log.serialize_entry("version", "1.2")?;
+        log.serialize_entry(
+            "creator",
+            &serde_json::json!({ "name": self.0.creator_name, "version": self.0.creator_version }),
+        )?;
+        log.serialize_entry("entries", &HarEntries(self.0.entries))?;
+        log.end()
+    }
+}
+
+struct HarEntries<'a>(&'a [InvokeTraceEntry]);
+
+impl Serialize for HarEntries<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(har_entry))
+    }
+}
+
+fn har_entry(entry: &InvokeTraceEntry) -> serde_json::Value {
+    let (status, status_text) = match entry.outcome {
+        InvokeOutcome::Ok => (200, "ok"),
+        InvokeOutcome::Mocked => (200, "mocked"),
+        InvokeOutcome::Error => (500, "error"),
+    };
+    serde_json::json!({
+        "startedDateTime": iso8601(entry.started_at_ms),
+        "time": entry.duration_ms,
+        "request": {
+            "method": "INVOKE",
+            "url": format!("ipc://localhost/{}", entry.command),
+            "httpVersion": "IPC",
+            "cookies": [],
+            "headers": [],
+            "queryString": [],
+            "postData": { "mimeType": "application/json", "text": entry.args_summary },
+            "headersSize": -1,
+            "bodySize": entry.args_summary.len(),
+        },
+        "response": {
+            "status": status,
+            "statusText": status_text,
+            "httpVersion": "IPC",
+            "cookies": [],
+            "headers": [],
+            "content": { "size": 0, "mimeType": "application/json" },
+            "redirectURL": "",
+            "headersSize": -1,
+            "bodySize": -1,
+        },
+        "cache": {},
+        "timings": { "send": 0, "wait": entry.duration_ms, "receive": 0 },
+        "_command": entry.command,
+        "_argsTruncated": entry.args_truncated,
+        "_windowLabel": entry.window_label,
+    })
+}
+
+/// `1700000000123` -> `2023-11-14T22:13:20.123Z`
+fn iso8601(timestamp_ms: u64) -> String {
+    let seconds = timestamp_ms / 1000;
+    let time_of_day = seconds % 86_400;
+    // Days since the epoch to a proleptic Gregorian date (Howard Hinnant's civil_from_days)
+    let z = (seconds / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        time_of_day / 3600,
+        time_of_day % 3600 / 60,
+        time_of_day % 60,
+        timestamp_ms % 1000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso8601() {
+        assert_eq!(iso8601(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(iso8601(1_700_000_000_123), "2023-11-14T22:13:20.123Z");
+        assert_eq!(iso8601(951_782_400_000), "2000-02-29T00:00:00.000Z");
+    }
+
+    #[test]
+    fn test_har_document() {
+        let entries = vec![InvokeTraceEntry {
+            command: "get_user".to_string(),
+            args_summary: "{\"id\":1}".to_string(),
+            args_truncated: false,
+            started_at_ms: 1_700_000_000_123,
+            duration_ms: 12.5,
+            outcome: InvokeOutcome::Error,
+            window_label: "main".to_string(),
+            session_id: None,
+        }];
+        let har = Har {
+            creator_name: "my-app",
+            creator_version: "1.2.3",
+            entries: &entries,
+        };
+
+        let mut written = Vec::new();
+        har.write_to(&mut written).unwrap();
+        let document: serde_json::Value = serde_json::from_slice(&written).unwrap();
+
+        assert_eq!(document["log"]["version"], "1.2");
+        assert_eq!(document["log"]["creator"], serde_json::json!({ "name": "my-app", "version": "1.2.3" }));
+        let entry = &document["log"]["entries"][0];
+        assert_eq!(entry["startedDateTime"], "2023-11-14T22:13:20.123Z");
+        assert_eq!(entry["time"], 12.5);
+        assert_eq!(entry["request"]["url"], "ipc://localhost/get_user");
+        assert_eq!(entry["request"]["postData"]["text"], "{\"id\":1}");
+        assert_eq!(entry["response"]["status"], 500);
+        assert_eq!(entry["response"]["statusText"], "error");
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+
+use crate::models::HealthStatus;
+
+/// Emitted to every listener once, when the first window finishes loading,
+/// with payload `{ label, uptime_ms }`
+pub(crate) const READY_EVENT: &str = "wdio:ready";
+
+/// Readiness as reported by health_check. The app counts as ready once a
+/// window has finished loading a page, so test startup can wait for that
+/// instead of sleeping.
+pub(crate) struct Health {
+    started: Instant,
+    ready_announced: AtomicBool,
+}
+
+impl Health {
+    pub(crate) fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            ready_announced: AtomicBool::new(false),
+        }
+    }
+
+    /// Record a finished page load; true only for the first one, which
+    /// should announce readiness
+    pub(crate) fn page_loaded(&self) -> bool {
+        !self.ready_announced.swap(true, Ordering::SeqCst)
+    }
+
+    /// Milliseconds since the plugin was set up
+    pub(crate) fn uptime_ms(&self) -> u64 {
+        self.started.elapsed().as_millis() as u64
+    }
+
+    /// Status for `windows_loaded`, the open windows that have finished a page load
+    pub(crate) fn status(&self, mut windows_loaded: Vec<String>, pending_executions: usize) -> HealthStatus {
+        windows_loaded.sort();
+        HealthStatus {
+            ready: !windows_loaded.is_empty(),
+            windows_loaded,
+            pending_executions,
+            uptime_ms: self.uptime_ms(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_becomes_ready_once_a_window_has_loaded() {
+        let health = Health::new();
+        let starting = health.status(Vec::new(), 0);
+        assert!(!starting.ready);
+        assert!(starting.windows_loaded.is_empty());
+
+        assert!(health.page_loaded());
+        let loaded = health.status(vec!["settings".to_string(), "main".to_string()], 2);
+        assert!(loaded.ready);
+        assert_eq!(loaded.windows_loaded, vec!["main", "settings"]);
+        assert_eq!(loaded.pending_executions, 2);
+        assert!(loaded.uptime_ms >= starting.uptime_ms);
+    }
+
+    #[test]
+    fn test_announces_ready_only_for_the_first_load() {
+        let health = Health::new();
+        assert!(health.page_loaded());
+        assert!(!health.page_loaded());
+        assert!(!health.page_loaded());
+    }
+}
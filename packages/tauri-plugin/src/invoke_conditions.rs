@@ -0,0 +1,92 @@
+use std::sync::Mutex;
+
+use crate::models::InvokeConditions;
+use crate::{Error, Result};
+
+/// Global the guest-js invoke interceptor reads to delay or fail matching
+/// invokes before dispatching them
+const CONDITIONS_GLOBAL: &str = "window.__wdio_invoke_conditions__";
+
+/// Conditions set with set_invoke_conditions, kept so pages loaded later
+/// get them too
+#[derive(Default)]
+pub(crate) struct ActiveInvokeConditions {
+    current: Mutex<Option<InvokeConditions>>,
+}
+
+impl ActiveInvokeConditions {
+    /// Replace the active conditions
+    pub(crate) fn set(&self, conditions: InvokeConditions) -> Result<()> {
+        if !(0.0..=1.0).contains(&conditions.failure_rate) {
+            return Err(Error::InvalidInvokeConditions(format!(
+                "failure_rate must be between 0 and 1, got {}",
+                conditions.failure_rate
+            )));
+        }
+        *self.current.lock().unwrap_or_else(|e| e.into_inner()) = Some(conditions);
+        Ok(())
+    }
+
+    /// Stop applying conditions; returns whether any were active
+    pub(crate) fn clear(&self) -> bool {
+        self.current.lock().unwrap_or_else(|e| e.into_inner()).take().is_some()
+    }
+
+    pub(crate) fn current(&self) -> Option<InvokeConditions> {
+        self.current.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+/// Script that hands `conditions` to a page's interceptor, or removes them.
+/// Setting them restarts a seeded page's draws from the seed.
+pub(crate) fn conditions_script(conditions: Option<&InvokeConditions>) -> String {
+    let value = conditions.map_or(serde_json::Value::Null, |c| serde_json::json!(c));
+    format!("{} = {};", CONDITIONS_GLOBAL, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_out_of_range_failure_rates() {
+        let active = ActiveInvokeConditions::default();
+        for failure_rate in [-0.1, 1.5, f32::NAN] {
+            let conditions = InvokeConditions {
+                failure_rate,
+                ..InvokeConditions::default()
+            };
+            assert!(matches!(active.set(conditions), Err(Error::InvalidInvokeConditions(_))));
+        }
+        assert_eq!(active.current(), None);
+
+        let conditions = InvokeConditions {
+            failure_rate: 1.0,
+            command_filter: Some(vec!["save".to_string()]),
+            ..InvokeConditions::default()
+        };
+        active.set(conditions.clone()).unwrap();
+        assert_eq!(active.current(), Some(conditions));
+        assert!(active.clear());
+        assert!(!active.clear());
+    }
+
+    #[test]
+    fn test_script_embeds_the_conditions_as_json() {
+        let conditions = InvokeConditions {
+            latency_ms: 200,
+            failure_error: serde_json::json!({ "code": "OFFLINE" }),
+            seed: Some(7),
+            ..InvokeConditions::default()
+        };
+        let script = conditions_script(Some(&conditions));
+        let json = script
+            .strip_prefix("window.__wdio_invoke_conditions__ = ")
+            .and_then(|rest| rest.strip_suffix(';'))
+            .unwrap();
+
+        let parsed: InvokeConditions = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed, conditions);
+        assert_eq!(conditions_script(None), "window.__wdio_invoke_conditions__ = null;");
+    }
+}
@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde_json::Value as JsonValue;
+
+use crate::models::{InvocationRecording, InvokeOutcome, RecordedInvocation};
+use crate::{Error, Result};
+
+/// Global the guest-js invoke interceptor reads to decide whether to report
+/// non-mocked invokes (`"record"`) or ask for their recorded result (`"replay"`)
+const REPLAY_GLOBAL: &str = "window.__wdio_invoke_replay__";
+
+/// Version of the recording file format
+const RECORDING_VERSION: u32 = 1;
+
+/// Records real command results reported by the guest-js invoke interceptor,
+/// or serves them back in place of the backend
+#[derive(Default)]
+pub(crate) struct InvokeReplay {
+    mode: Mutex<Mode>,
+}
+
+#[derive(Default)]
+enum Mode {
+    #[default]
+    Off,
+    Recording {
+        path: PathBuf,
+        invocations: Vec<RecordedInvocation>,
+    },
+    Replaying {
+        strict: bool,
+        results: HashMap<(String, String), Sequence>,
+    },
+}
+
+/// Results recorded for one command and args hash, served in the order they
+/// were recorded; the last one repeats once they run out
+struct Sequence {
+    invocations: Vec<RecordedInvocation>,
+    next: usize,
+}
+
+impl InvokeReplay {
+    /// Start recording to `path`, replacing any recording or replay. The file
+    /// is written straight away so a bad path fails here rather than at the end.
+    pub(crate) fn start_recording(&self, path: PathBuf) -> Result<()> {
+        write_recording(&path, &[])?;
+        *self.mode.lock().unwrap_or_else(|e| e.into_inner()) = Mode::Recording {
+            path,
+            invocations: Vec::new(),
+        };
+        Ok(())
+    }
+
+    /// Write the recording to its file and stop; returns the number of invocations
+    pub(crate) fn stop_recording(&self) -> Result<usize> {
+        let mut mode = self.mode.lock().unwrap_or_else(|e| e.into_inner());
+        let Mode::Recording { path, invocations } = &*mode else {
+            return Err(Error::InvokeReplayError("no recording is in progress".to_string()));
+        };
+        write_recording(path, invocations)?;
+        let count = invocations.len();
+        *mode = Mode::Off;
+        Ok(count)
+    }
+
+    /// Add a settled invoke to the recording; returns false when not recording
+    pub(crate) fn record(&self, command: String, args: &JsonValue, outcome: InvokeOutcome, result: JsonValue) -> bool {
+        let mut mode = self.mode.lock().unwrap_or_else(|e| e.into_inner());
+        let Mode::Recording { invocations, .. } = &mut *mode else {
+            return false;
+        };
+        invocations.push(RecordedInvocation {
+            command,
+            args_hash: args_hash(args),
+            outcome,
+            result,
+        });
+        true
+    }
+
+    /// Serve the recording at `path`, replacing any recording or replay;
+    /// returns the number of invocations loaded
+    pub(crate) fn enable_replay(&self, path: &Path, strict: bool) -> Result<usize> {
+        let file = std::io::BufReader::new(std::fs::File::open(path)?);
+        let recording: InvocationRecording = serde_json::from_reader(file)
+            .map_err(|e| Error::InvokeReplayError(format!("{}: {}", path.display(), e)))?;
+        if recording.version != RECORDING_VERSION {
+            return Err(Error::InvokeReplayError(format!(
+                "{}: unsupported recording version {}",
+                path.display(),
+                recording.version
+            )));
+        }
+
+        let count = recording.invocations.len();
+        let mut results: HashMap<(String, String), Sequence> = HashMap::new();
+        for invocation in recording.invocations {
+            let key = (invocation.command.clone(), invocation.args_hash.clone());
+            results
+                .entry(key)
+                .or_insert_with(|| Sequence {
+                    invocations: Vec::new(),
+                    next: 0,
+                })
+                .invocations
+                .push(invocation);
+        }
+        *self.mode.lock().unwrap_or_else(|e| e.into_inner()) = Mode::Replaying { strict, results };
+        Ok(count)
+    }
+
+    /// Stop replaying; returns whether replay was on
+    pub(crate) fn disable_replay(&self) -> bool {
+        let mut mode = self.mode.lock().unwrap_or_else(|e| e.into_inner());
+        let replaying = matches!(*mode, Mode::Replaying { .. });
+        if replaying {
+            *mode = Mode::Off;
+        }
+        replaying
+    }
+
+    /// Stop recording, without writing the recording, or replaying; returns
+    /// whether either was on
+    pub(crate) fn reset(&self) -> bool {
+        let mut mode = self.mode.lock().unwrap_or_else(|e| e.into_inner());
+        !matches!(std::mem::take(&mut *mode), Mode::Off)
+    }
+
+    /// Recorded result for the next call of `command` with `args`. `None`
+    /// lets the call through: replay is off, or nothing was recorded for it
+    /// and replay isn't strict.
+    pub(crate) fn replay(&self, command: &str, args: &JsonValue) -> Result<Option<RecordedInvocation>> {
+        let mut mode = self.mode.lock().unwrap_or_else(|e| e.into_inner());
+        let Mode::Replaying { strict, results } = &mut *mode else {
+            return Ok(None);
+        };
+        let Some(sequence) = results.get_mut(&(command.to_string(), args_hash(args))) else {
+            return match strict {
+                true => Err(Error::UnrecordedInvoke(command.to_string())),
+                false => Ok(None),
+            };
+        };
+        let index = sequence.next.min(sequence.invocations.len() - 1);
+        sequence.next += 1;
+        Ok(Some(sequence.invocations[index].clone()))
+    }
+
+    /// What pages' interceptors should do with non-mocked invokes
+    pub(crate) fn page_mode(&self) -> Option<&'static str> {
+        match *self.mode.lock().unwrap_or_else(|e| e.into_inner()) {
+            Mode::Off => None,
+            Mode::Recording { .. } => Some("record"),
+            Mode::Replaying { .. } => Some("replay"),
+        }
+    }
+}
+
+/// Script that switches a page's interceptor to `mode`
+pub(crate) fn mode_script(mode: Option<&str>) -> String {
+    format!("{} = {};", REPLAY_GLOBAL, JsonValue::from(mode))
+}
+
+/// Hash of `args` that ignores object key order and stays the same across
+/// runs and builds, so a recording keeps matching
+pub(crate) fn args_hash(args: &JsonValue) -> String {
+    let mut canonical = String::new();
+    write_canonical(args, &mut canonical);
+    // FNV-1a; std's hashers aren't guaranteed to be stable between releases
+    let hash = canonical
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3));
+    format!("{:016x}", hash)
+}
+
+/// JSON with object keys sorted
+fn write_canonical(value: &JsonValue, out: &mut String) {
+    match value {
+        JsonValue::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        JsonValue::Object(fields) => {
+            let mut keys: Vec<&String> = fields.keys().collect();
+            keys.sort();
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&JsonValue::from(key.as_str()).to_string());
+                out.push(':');
+                write_canonical(&fields[key], out);
+            }
+            out.push('}');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+fn write_recording(path: &Path, invocations: &[RecordedInvocation]) -> Result<()> {
+    let recording = InvocationRecording {
+        version: RECORDING_VERSION,
+        invocations: invocations.to_vec(),
+    };
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, &recording)?;
+    std::io::Write::flush(&mut writer)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn recording_path() -> PathBuf {
+        std::env::temp_dir().join(format!("wdio-replay-{}.json", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn test_args_hash_ignores_key_order() {
+        let a = json!({ "path": "/tmp/a", "options": { "encoding": "utf8", "create": true } });
+        let b = json!({ "options": { "create": true, "encoding": "utf8" }, "path": "/tmp/a" });
+
+        assert_eq!(args_hash(&a), args_hash(&b));
+        assert_ne!(args_hash(&a), args_hash(&json!({ "path": "/tmp/b" })));
+        assert_ne!(args_hash(&json!([1, 2])), args_hash(&json!([2, 1])));
+        assert_eq!(args_hash(&json!({})).len(), 16);
+    }
+
+    #[test]
+    fn test_round_trips_a_recording_and_replays_repeats_in_order() {
+        let path = recording_path();
+        let replay = InvokeReplay::default();
+        let args = json!({ "path": "/tmp/note.txt" });
+
+        replay.start_recording(path.clone()).unwrap();
+        assert_eq!(replay.page_mode(), Some("record"));
+        assert!(replay.record("read_file".into(), &args, InvokeOutcome::Ok, json!("first")));
+        assert!(replay.record("read_file".into(), &args, InvokeOutcome::Ok, json!("second")));
+        assert!(replay.record("delete_file".into(), &args, InvokeOutcome::Error, json!("not found")));
+        assert_eq!(replay.stop_recording().unwrap(), 3);
+        assert!(!replay.record("read_file".into(), &args, InvokeOutcome::Ok, json!("late")));
+
+        assert_eq!(replay.enable_replay(&path, true).unwrap(), 3);
+        assert_eq!(replay.page_mode(), Some("replay"));
+        let next = || replay.replay("read_file", &args).unwrap().unwrap().result;
+        assert_eq!(next(), "first");
+        assert_eq!(next(), "second");
+        assert_eq!(next(), "second");
+        let failed = replay.replay("delete_file", &args).unwrap().unwrap();
+        assert_eq!((failed.outcome, failed.result), (InvokeOutcome::Error, json!("not found")));
+
+        assert!(replay.disable_replay());
+        assert!(!replay.disable_replay());
+        assert_eq!(replay.replay("read_file", &args).unwrap(), None);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_strict_replay_rejects_unrecorded_invokes() {
+        let path = recording_path();
+        let replay = InvokeReplay::default();
+        replay.start_recording(path.clone()).unwrap();
+        replay.record("read_file".into(), &json!({ "path": "a" }), InvokeOutcome::Ok, json!("a"));
+        replay.stop_recording().unwrap();
+
+        replay.enable_replay(&path, true).unwrap();
+        let error = replay.replay("read_file", &json!({ "path": "b" })).unwrap_err();
+        assert!(matches!(error, Error::UnrecordedInvoke(ref command) if command == "read_file"));
+
+        replay.enable_replay(&path, false).unwrap();
+        assert_eq!(replay.replay("read_file", &json!({ "path": "b" })).unwrap(), None);
+        assert!(replay.replay("read_file", &json!({ "path": "a" })).unwrap().is_some());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_rejects_missing_or_invalid_recordings() {
+        let replay = InvokeReplay::default();
+        let path = recording_path();
+        assert!(matches!(replay.enable_replay(&path, true), Err(Error::Io(_))));
+        assert!(matches!(replay.stop_recording(), Err(Error::InvokeReplayError(_))));
+
+        std::fs::write(&path, r#"{ "version": 99, "invocations": [] }"#).unwrap();
+        let error = replay.enable_replay(&path, true).unwrap_err();
+        assert!(error.to_string().contains("unsupported recording version 99"));
+        assert_eq!(replay.page_mode(), None);
+        assert_eq!(mode_script(None), "window.__wdio_invoke_replay__ = null;");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
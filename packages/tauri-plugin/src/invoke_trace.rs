@@ -0,0 +1,229 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::models::{InvokeTrace, InvokeTraceEntry};
+use crate::payload::truncate_at_char_boundary;
+
+/// Global the guest-js invoke interceptor reads to decide whether, and with
+/// what args budget, to report each invoke to record_invoke
+const TRACING_GLOBAL: &str = "window.__wdio_invoke_tracing__";
+
+/// IPC command calls reported by the guest-js invoke interceptor while
+/// tracing is on. Calls made before the interceptor is installed, or through
+/// the plugin's own snapshot of the original invoke, aren't seen.
+pub(crate) struct InvokeTracer {
+    state: Mutex<TraceState>,
+}
+
+struct TraceState {
+    /// Args budget in bytes while tracing is on
+    args_max_bytes: Option<usize>,
+    default_args_max_bytes: usize,
+    entries: VecDeque<InvokeTraceEntry>,
+    capacity: usize,
+    dropped: u64,
+    /// Test session new entries are tagged with
+    session_id: Option<String>,
+}
+
+impl InvokeTracer {
+    pub(crate) fn new(capacity: usize, default_args_max_bytes: usize) -> Self {
+        Self {
+            state: Mutex::new(TraceState {
+                args_max_bytes: None,
+                default_args_max_bytes,
+                entries: VecDeque::with_capacity(capacity.min(1024)),
+                capacity,
+                dropped: 0,
+                session_id: None,
+            }),
+        }
+    }
+
+    /// Start tracing, discarding any earlier trace; returns the args budget in use
+    pub(crate) fn start(&self, args_max_bytes: Option<usize>) -> usize {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let budget = args_max_bytes.unwrap_or(state.default_args_max_bytes);
+        state.args_max_bytes = Some(budget);
+        state.entries.clear();
+        state.dropped = 0;
+        budget
+    }
+
+    /// Stop tracing, keeping the trace; returns the number of entries
+    pub(crate) fn stop(&self) -> usize {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.args_max_bytes = None;
+        state.entries.len()
+    }
+
+    /// Stop tracing and discard the trace; returns the number of entries discarded
+    pub(crate) fn reset(&self) -> usize {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.args_max_bytes = None;
+        state.dropped = 0;
+        state.session_id = None;
+        let count = state.entries.len();
+        state.entries.clear();
+        count
+    }
+
+    /// Args budget while tracing is on
+    pub(crate) fn active(&self) -> Option<usize> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).args_max_bytes
+    }
+
+    /// Add an entry if tracing is on, enforcing the args budget; the oldest
+    /// entries are dropped and counted once the trace is full. Returns the
+    /// entry as recorded, or `None` when tracing is off.
+    pub(crate) fn record(&self, mut entry: InvokeTraceEntry) -> Option<InvokeTraceEntry> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let budget = state.args_max_bytes?;
+        entry.session_id = state.session_id.clone();
+        if entry.args_summary.len() > budget {
+            truncate_at_char_boundary(&mut entry.args_summary, budget);
+            entry.args_truncated = true;
+        }
+        if state.capacity == 0 {
+            state.dropped += 1;
+            return Some(entry);
+        }
+        while state.entries.len() >= state.capacity {
+            state.entries.pop_front();
+            state.dropped += 1;
+        }
+        state.entries.push_back(entry.clone());
+        Some(entry)
+    }
+
+    /// Tag entries recorded from now on with a test session, or stop with `None`
+    pub(crate) fn set_session(&self, session_id: Option<&str>) {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).session_id = session_id.map(str::to_string);
+    }
+
+    /// Remove the entries tagged with `session_id`, returning how many were dropped
+    pub(crate) fn clear_session(&self, session_id: &str) -> usize {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let count = state.entries.len();
+        state.entries.retain(|entry| entry.session_id.as_deref() != Some(session_id));
+        count - state.entries.len()
+    }
+
+    /// The trace ordered by start time. Entries are reported as calls
+    /// finish, so a slow call is recorded after faster ones it preceded.
+    pub(crate) fn trace(&self) -> InvokeTrace {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let mut entries: Vec<InvokeTraceEntry> = state.entries.iter().cloned().collect();
+        entries.sort_by_key(|entry| entry.started_at_ms);
+        InvokeTrace {
+            entries,
+            dropped: state.dropped,
+        }
+    }
+}
+
+/// Script that switches a page's interceptor to the tracer's current state
+pub(crate) fn tracing_script(args_max_bytes: Option<usize>) -> String {
+    match args_max_bytes {
+        Some(budget) => format!("{} = {{ argsMaxBytes: {} }};", TRACING_GLOBAL, budget),
+        None => format!("{} = null;", TRACING_GLOBAL),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::InvokeOutcome;
+
+    fn entry(command: &str, started_at_ms: u64, args_summary: &str) -> InvokeTraceEntry {
+        InvokeTraceEntry {
+            command: command.to_string(),
+            args_summary: args_summary.to_string(),
+            args_truncated: false,
+            started_at_ms,
+            duration_ms: 1.0,
+            outcome: InvokeOutcome::Ok,
+            window_label: "main".to_string(),
+            session_id: None,
+        }
+    }
+
+    #[test]
+    fn test_records_only_while_tracing_and_orders_by_start() {
+        let tracer = InvokeTracer::new(10, 64);
+        assert!(tracer.record(entry("early", 1, "{}")).is_none());
+
+        tracer.start(None);
+        assert!(tracer.record(entry("slow", 10, "{}")).is_some());
+        assert!(tracer.record(entry("fast", 20, "{}")).is_some());
+        assert_eq!(tracer.stop(), 2);
+        assert!(tracer.record(entry("late", 30, "{}")).is_none());
+
+        let commands: Vec<String> = tracer.trace().entries.into_iter().map(|e| e.command).collect();
+        assert_eq!(commands, vec!["slow", "fast"]);
+    }
+
+    #[test]
+    fn test_truncates_args_to_budget_on_char_boundary() {
+        let tracer = InvokeTracer::new(10, 64);
+        assert_eq!(tracer.start(Some(5)), 5);
+        tracer.record(entry("a", 1, "{\"k\":\"é\"}"));
+        tracer.record(entry("b", 2, "{}"));
+
+        let trace = tracer.trace();
+        assert_eq!(trace.entries[0].args_summary, "{\"k\":");
+        assert!(trace.entries[0].args_truncated);
+        assert_eq!(trace.entries[1].args_summary, "{}");
+        assert!(!trace.entries[1].args_truncated);
+    }
+
+    #[test]
+    fn test_full_trace_drops_oldest() {
+        let tracer = InvokeTracer::new(2, 64);
+        tracer.start(None);
+        for (i, command) in ["a", "b", "c"].iter().enumerate() {
+            tracer.record(entry(command, i as u64, ""));
+        }
+
+        let trace = tracer.trace();
+        assert_eq!(trace.dropped, 1);
+        assert_eq!(trace.entries[0].command, "b");
+        assert_eq!(tracing_script(Some(8)), "window.__wdio_invoke_tracing__ = { argsMaxBytes: 8 };");
+    }
+
+    #[test]
+    fn test_tags_entries_with_the_session_and_clears_them() {
+        let tracer = InvokeTracer::new(10, 64);
+        tracer.start(None);
+        tracer.record(entry("before", 1, ""));
+        tracer.set_session(Some("a"));
+        tracer.record(entry("during", 2, ""));
+        tracer.set_session(None);
+        tracer.record(entry("after", 3, ""));
+
+        let sessions: Vec<Option<String>> = tracer.trace().entries.into_iter().map(|e| e.session_id).collect();
+        assert_eq!(sessions, vec![None, Some("a".to_string()), None]);
+        assert_eq!(tracer.clear_session("a"), 1);
+        let commands: Vec<String> = tracer.trace().entries.into_iter().map(|e| e.command).collect();
+        assert_eq!(commands, vec!["before", "after"]);
+    }
+
+    #[test]
+    fn test_entry_json_shape_is_stable() {
+        let mut mocked = entry("get_user", 5, "{\"id\":1}");
+        mocked.outcome = InvokeOutcome::Mocked;
+        assert_eq!(
+            serde_json::to_value(&mocked).unwrap(),
+            serde_json::json!({
+                "command": "get_user",
+                "args_summary": "{\"id\":1}",
+                "args_truncated": false,
+                "started_at_ms": 5,
+                "duration_ms": 1.0,
+                "outcome": "mocked",
+                "window_label": "main",
+                "session_id": null,
+            })
+        );
+    }
+}
@@ -0,0 +1,23 @@
+// Injected by tauri-plugin-wdio into every page before app scripts run.
+// Defines window.__WDIO_EMIT__, which scripts run by execute report their
+// results through. It uses Tauri's internal IPC, which pages get whether or
+// not withGlobalTauri exposes window.__TAURI__, and checks in with the plugin
+// so execute knows results from this window can reach it.
+(function () {
+  var internals = window.__TAURI_INTERNALS__;
+  if (!internals || typeof internals.invoke !== 'function' || window.__WDIO_EMIT__) {
+    return;
+  }
+  var invoke = internals.invoke.bind(internals);
+
+  function emit(id, report, viaEvent) {
+    return viaEvent
+      ? invoke('plugin:event|emit', { event: id, payload: report })
+      : invoke('plugin:wdio|resolve_execute', { id: id, report: report });
+  }
+
+  Object.defineProperty(window, '__WDIO_EMIT__', { value: Object.freeze(emit) });
+  emit('__WDIO_BRIDGE_ID__', null, false).catch(function () {
+    // Not permitted to report results; execute says so instead of timing out
+  });
+})();
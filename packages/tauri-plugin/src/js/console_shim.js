@@ -0,0 +1,88 @@
+// Injected by tauri-plugin-wdio into every page before app scripts run.
+// Forwards console output to the plugin's log_frontend command so standalone
+// runs capture frontend logs on stderr. Original console behavior is preserved.
+(function () {
+  var WRAPPED_KEY = Symbol.for('wdio:console-wrapped');
+  if (console[WRAPPED_KEY]) {
+    return;
+  }
+  console[WRAPPED_KEY] = true;
+
+  var LEVELS = {
+    log: 'trace',
+    trace: 'trace',
+    debug: 'debug',
+    info: 'info',
+    warn: 'warn',
+    error: 'error',
+  };
+
+  function stringify(value) {
+    if (typeof value === 'string') {
+      return value;
+    }
+    if (value instanceof Error) {
+      return value.stack || String(value);
+    }
+    if (value === undefined || typeof value === 'function' || typeof value === 'symbol') {
+      return String(value);
+    }
+
+    // Track the current ancestor chain so only real cycles are replaced,
+    // not objects that are merely referenced twice.
+    var ancestors = [];
+    try {
+      var json = JSON.stringify(value, function (_key, v) {
+        if (typeof v === 'bigint') {
+          return v.toString();
+        }
+        if (typeof v !== 'object' || v === null) {
+          return v;
+        }
+        while (ancestors.length > 0 && ancestors[ancestors.length - 1] !== this) {
+          ancestors.pop();
+        }
+        if (ancestors.indexOf(v) !== -1) {
+          return '[Circular]';
+        }
+        ancestors.push(v);
+        return v;
+      });
+      return json === undefined ? String(value) : json;
+    } catch (_e) {
+      return String(value);
+    }
+  }
+
+  function forward(level, args) {
+    var internals = window.__TAURI_INTERNALS__;
+    if (!internals || typeof internals.invoke !== 'function') {
+      return;
+    }
+    var message = Array.prototype.map.call(args, stringify).join(' ');
+    try {
+      internals.invoke('plugin:wdio|log_frontend', { message: message, level: level }).catch(function () {});
+    } catch (_e) {
+      // Never let log forwarding break the page
+    }
+  }
+
+  Object.keys(LEVELS).forEach(function (method) {
+    var original = console[method];
+    if (typeof original !== 'function') {
+      return;
+    }
+    try {
+      Object.defineProperty(console, method, {
+        value: function () {
+          original.apply(console, arguments);
+          forward(LEVELS[method], arguments);
+        },
+        writable: true,
+        configurable: true,
+      });
+    } catch (_e) {
+      // Console methods may be non-configurable on some webviews
+    }
+  });
+})();
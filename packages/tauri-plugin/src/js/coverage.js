@@ -0,0 +1,23 @@
+// Evaluated by tauri-plugin-wdio's collect_coverage command through execute.
+// Serializes window.__coverage__ (null when the bundle isn't instrumented)
+// and sends it to the backend in order, in chunks of at most chunkChars
+// characters, through coverage_chunk; resolves to the number of chunks sent.
+async function (id, chunkChars) {
+  const json = JSON.stringify(window.__coverage__ ?? null);
+  const core = window.__wdio_original_core__;
+  let index = 0;
+  let start = 0;
+  do {
+    let end = Math.min(start + chunkChars, json.length);
+    // Never split a surrogate pair, which would reach the backend as two
+    // replacement characters
+    const last = json.charCodeAt(end - 1);
+    if (end < json.length && last >= 0xd800 && last <= 0xdbff) {
+      end--;
+    }
+    await core.invoke('plugin:wdio|coverage_chunk', { id: id, index: index, data: json.slice(start, end) });
+    index++;
+    start = end;
+  } while (start < json.length);
+  return index;
+}
@@ -0,0 +1,16 @@
+// Evaluated by tauri-plugin-wdio's self_test. Called with the plugin's command
+// names, a nonce, and the probe header; invokes every command as a probe, which
+// the plugin answers without running the command, so only Tauri's ACL can
+// reject it. Reports whether each probe got through to self_test_report.
+async function (commands, nonce, header) {
+  const { invoke } = window.__TAURI_INTERNALS__;
+  const permitted = await Promise.all(
+    commands.map((command) =>
+      invoke(`plugin:wdio|${command}`, {}, { headers: { [header]: '1' } }).then(
+        () => true,
+        () => false,
+      ),
+    ),
+  );
+  await invoke('plugin:wdio|self_test_report', { nonce, permitted });
+}
@@ -0,0 +1,412 @@
+// Evaluated by tauri-plugin-wdio's web storage commands through execute.
+// Called with an operation ('snapshot', 'clear', or 'restore') and its JSON
+// input; resolves to the JSON result the command returns.
+async function (operation, input) {
+  const KINDS = ['localStorage', 'sessionStorage', 'indexedDb', 'cookies'];
+  const FIELDS = {
+    localStorage: 'local_storage',
+    sessionStorage: 'session_storage',
+    indexedDb: 'indexed_db',
+    cookies: 'cookies',
+  };
+  const TYPED_ARRAYS = [
+    'Int8Array',
+    'Uint8Array',
+    'Uint8ClampedArray',
+    'Int16Array',
+    'Uint16Array',
+    'Int32Array',
+    'Uint32Array',
+    'Float32Array',
+    'Float64Array',
+    'BigInt64Array',
+    'BigUint64Array',
+  ];
+
+  function message(error) {
+    return (error && error.message) || String(error);
+  }
+
+  // IndexedDB keys and values are structured clones rather than JSON; the
+  // common non-JSON types are tagged so they survive the round trip
+  function encode(value) {
+    if (value === undefined) {
+      return { $wdio: 'undefined' };
+    }
+    if (typeof value === 'bigint') {
+      return { $wdio: 'BigInt', value: String(value) };
+    }
+    if (typeof value === 'number' && !Number.isFinite(value)) {
+      return { $wdio: 'Number', value: String(value) };
+    }
+    if (value === null || typeof value !== 'object') {
+      return value;
+    }
+    if (value instanceof Date) {
+      return { $wdio: 'Date', value: value.getTime() };
+    }
+    if (value instanceof ArrayBuffer) {
+      return { $wdio: 'ArrayBuffer', value: Array.from(new Uint8Array(value)) };
+    }
+    if (ArrayBuffer.isView(value) && TYPED_ARRAYS.indexOf(value.constructor.name) !== -1) {
+      return { $wdio: value.constructor.name, value: Array.from(value, encode) };
+    }
+    if (value instanceof Map) {
+      return { $wdio: 'Map', value: Array.from(value, (entry) => [encode(entry[0]), encode(entry[1])]) };
+    }
+    if (value instanceof Set) {
+      return { $wdio: 'Set', value: Array.from(value, encode) };
+    }
+    if (Array.isArray(value)) {
+      return value.map(encode);
+    }
+    const object = {};
+    for (const key of Object.keys(value)) {
+      object[key] = encode(value[key]);
+    }
+    // An object with its own $wdio key would otherwise be read back as a tag
+    return '$wdio' in value ? { $wdio: 'Object', value: object } : object;
+  }
+
+  function decodeObject(value) {
+    const object = {};
+    for (const key of Object.keys(value)) {
+      object[key] = decode(value[key]);
+    }
+    return object;
+  }
+
+  function decode(value) {
+    if (value === null || typeof value !== 'object') {
+      return value;
+    }
+    if (Array.isArray(value)) {
+      return value.map(decode);
+    }
+    switch (value.$wdio) {
+      case undefined:
+        return decodeObject(value);
+      case 'undefined':
+        return undefined;
+      case 'BigInt':
+        return BigInt(value.value);
+      case 'Number':
+        return Number(value.value);
+      case 'Date':
+        return new Date(value.value);
+      case 'ArrayBuffer':
+        return new Uint8Array(value.value).buffer;
+      case 'Map':
+        return new Map(value.value.map((entry) => [decode(entry[0]), decode(entry[1])]));
+      case 'Set':
+        return new Set(value.value.map(decode));
+      case 'Object':
+        return decodeObject(value.value);
+      default:
+        if (TYPED_ARRAYS.indexOf(value.$wdio) !== -1) {
+          return window[value.$wdio].from(value.value.map(decode));
+        }
+        throw new Error('unknown tagged value ' + JSON.stringify(value.$wdio));
+    }
+  }
+
+  function completed(tx) {
+    return new Promise((resolve, reject) => {
+      tx.oncomplete = () => resolve();
+      tx.onerror = () => reject(tx.error);
+      tx.onabort = () => reject(tx.error || new Error('transaction aborted'));
+    });
+  }
+
+  // An app holding the database open blocks deleting or upgrading it until
+  // it closes its connection; fail instead of waiting forever
+  function blocking(req, name) {
+    return new Promise((resolve, reject) => {
+      req.onsuccess = () => resolve(req.result);
+      req.onerror = () => reject(req.error);
+      req.onblocked = () => reject(new Error("database '" + name + "' is open in the page and can't be replaced"));
+    });
+  }
+
+  function open(name, version, upgrade) {
+    const req = version === undefined ? indexedDB.open(name) : indexedDB.open(name, version);
+    if (upgrade) {
+      req.onupgradeneeded = () => upgrade(req.result);
+    }
+    return blocking(req, name);
+  }
+
+  async function databaseNames() {
+    if (typeof indexedDB === 'undefined' || typeof indexedDB.databases !== 'function') {
+      throw new Error('indexedDB.databases() is not supported by this webview');
+    }
+    return (await indexedDB.databases()).map((info) => info.name).filter(Boolean);
+  }
+
+  function storeNames(db) {
+    return Array.from(db.objectStoreNames);
+  }
+
+  function records(store) {
+    return new Promise((resolve, reject) => {
+      const entries = [];
+      const req = store.openCursor();
+      req.onsuccess = () => {
+        const cursor = req.result;
+        if (!cursor) {
+          resolve(entries);
+          return;
+        }
+        entries.push({ key: encode(cursor.primaryKey), value: encode(cursor.value) });
+        cursor.continue();
+      };
+      req.onerror = () => reject(req.error);
+    });
+  }
+
+  function describeStore(store) {
+    const indexes = Array.from(store.indexNames, (name) => {
+      const index = store.index(name);
+      return { name: name, key_path: index.keyPath, unique: index.unique, multi_entry: index.multiEntry };
+    });
+    return records(store).then((entries) => ({
+      name: store.name,
+      key_path: store.keyPath,
+      auto_increment: store.autoIncrement,
+      indexes: indexes,
+      records: entries,
+    }));
+  }
+
+  async function snapshotDatabase(name) {
+    const db = await open(name);
+    try {
+      const names = storeNames(db);
+      let stores = [];
+      if (names.length > 0) {
+        const tx = db.transaction(names, 'readonly');
+        stores = await Promise.all(names.map((storeName) => describeStore(tx.objectStore(storeName))));
+      }
+      return { name: name, version: db.version, stores: stores };
+    } finally {
+      db.close();
+    }
+  }
+
+  // Empty every store, keeping the schema, so an app holding the database
+  // open isn't blocked
+  async function clearDatabase(name) {
+    const db = await open(name);
+    try {
+      const names = storeNames(db);
+      if (names.length > 0) {
+        const tx = db.transaction(names, 'readwrite');
+        names.forEach((storeName) => tx.objectStore(storeName).clear());
+        await completed(tx);
+      }
+    } finally {
+      db.close();
+    }
+  }
+
+  function sameSchema(db, database) {
+    const names = storeNames(db).sort();
+    const wanted = database.stores.map((store) => store.name).sort();
+    return db.version === database.version && JSON.stringify(names) === JSON.stringify(wanted);
+  }
+
+  function createStores(db, database) {
+    for (const store of database.stores) {
+      const created = db.createObjectStore(store.name, {
+        keyPath: store.key_path,
+        autoIncrement: store.auto_increment,
+      });
+      for (const index of store.indexes) {
+        created.createIndex(index.name, index.key_path, { unique: index.unique, multiEntry: index.multi_entry });
+      }
+    }
+  }
+
+  // Databases the snapshot doesn't contain are left alone. One with the
+  // snapshot's version and stores is emptied and refilled in place; any
+  // other is deleted and recreated from the snapshot.
+  async function restoreDatabase(database, existing) {
+    let db = null;
+    if (existing.indexOf(database.name) !== -1) {
+      db = await open(database.name);
+      if (!sameSchema(db, database)) {
+        db.close();
+        db = null;
+        await blocking(indexedDB.deleteDatabase(database.name), database.name);
+      }
+    }
+    if (!db) {
+      db = await open(database.name, database.version, (created) => createStores(created, database));
+    }
+    try {
+      const names = database.stores.map((store) => store.name);
+      if (names.length === 0) {
+        return 0;
+      }
+      let count = 0;
+      const tx = db.transaction(names, 'readwrite');
+      for (const store of database.stores) {
+        const target = tx.objectStore(store.name);
+        target.clear();
+        for (const record of store.records) {
+          if (store.key_path === null) {
+            target.put(decode(record.value), decode(record.key));
+          } else {
+            target.put(decode(record.value));
+          }
+          count++;
+        }
+      }
+      await completed(tx);
+      return count;
+    } finally {
+      db.close();
+    }
+  }
+
+  function readCookies() {
+    const cookies = {};
+    for (const part of document.cookie.split(';')) {
+      const separator = part.indexOf('=');
+      const name = (separator === -1 ? '' : part.slice(0, separator)).trim();
+      if (name) {
+        cookies[name] = part.slice(separator + 1).trim();
+      }
+    }
+    return cookies;
+  }
+
+  // Cookies only expire when the path matches the one they were set with, so
+  // try every prefix of the current path
+  function expireCookie(name) {
+    const paths = ['/'];
+    const segments = location.pathname.split('/').filter(Boolean);
+    for (let i = 1; i <= segments.length; i++) {
+      paths.push('/' + segments.slice(0, i).join('/'));
+    }
+    for (const path of paths) {
+      document.cookie = name + '=; expires=Thu, 01 Jan 1970 00:00:00 GMT; path=' + path;
+    }
+  }
+
+  function clearCookies() {
+    const names = Object.keys(readCookies());
+    names.forEach(expireCookie);
+    const remaining = readCookies();
+    return names.filter((name) => !(name in remaining)).length;
+  }
+
+  function webStorage(area) {
+    return {
+      snapshot: () => {
+        const storage = area();
+        const items = {};
+        for (let i = 0; i < storage.length; i++) {
+          const key = storage.key(i);
+          items[key] = storage.getItem(key);
+        }
+        return items;
+      },
+      clear: () => {
+        const storage = area();
+        const count = storage.length;
+        storage.clear();
+        return count;
+      },
+      restore: (items) => {
+        const storage = area();
+        storage.clear();
+        for (const key of Object.keys(items)) {
+          storage.setItem(key, items[key]);
+        }
+        return Object.keys(items).length;
+      },
+    };
+  }
+
+  const handlers = {
+    // Reading the storage properties can throw, e.g. when storage is disabled
+    localStorage: webStorage(() => window.localStorage),
+    sessionStorage: webStorage(() => window.sessionStorage),
+    indexedDb: {
+      snapshot: async () => {
+        const databases = [];
+        for (const name of await databaseNames()) {
+          databases.push(await snapshotDatabase(name));
+        }
+        return databases;
+      },
+      clear: async () => {
+        const names = await databaseNames();
+        for (const name of names) {
+          await clearDatabase(name);
+        }
+        return names.length;
+      },
+      restore: async (databases) => {
+        const existing = await databaseNames();
+        let count = 0;
+        for (const database of databases) {
+          count += await restoreDatabase(database, existing);
+        }
+        return count;
+      },
+    },
+    cookies: {
+      snapshot: readCookies,
+      clear: clearCookies,
+      restore: (cookies) => {
+        clearCookies();
+        for (const name of Object.keys(cookies)) {
+          document.cookie = name + '=' + cookies[name] + '; path=/';
+        }
+        return Object.keys(cookies).length;
+      },
+    },
+  };
+
+  // One kind failing doesn't stop the others; each reports its own outcome
+  async function each(kinds, run) {
+    const results = [];
+    for (const kind of kinds) {
+      try {
+        results.push({ kind: kind, count: await run(kind), error: null });
+      } catch (error) {
+        results.push({ kind: kind, count: 0, error: message(error) });
+      }
+    }
+    return results;
+  }
+
+  switch (operation) {
+    case 'snapshot': {
+      const snapshot = { origin: location.origin, errors: {} };
+      for (const kind of KINDS) {
+        try {
+          snapshot[FIELDS[kind]] = await handlers[kind].snapshot();
+        } catch (error) {
+          snapshot[FIELDS[kind]] = null;
+          snapshot.errors[kind] = message(error);
+        }
+      }
+      return snapshot;
+    }
+    case 'clear':
+      return each(input.kinds, (kind) => handlers[kind].clear());
+    case 'restore':
+      if (input.origin !== location.origin) {
+        throw new Error('the snapshot was taken at ' + input.origin + ', but the page is at ' + location.origin);
+      }
+      return each(
+        KINDS.filter((kind) => input[FIELDS[kind]] !== null && input[FIELDS[kind]] !== undefined),
+        (kind) => handlers[kind].restore(input[FIELDS[kind]]),
+      );
+    default:
+      throw new Error('unknown web storage operation ' + operation);
+  }
+}
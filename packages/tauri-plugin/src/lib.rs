@@ -1,66 +1,265 @@
-use std::sync::Mutex;
 use tauri::{
     plugin::{self, TauriPlugin},
-    Manager, Runtime,
+    webview::PageLoadEvent,
+    Emitter, Listener, Manager, RunEvent, Runtime, WindowEvent,
 };
 
 pub use models::*;
 
+// Defines wdio_commands!, so it must come before the modules that use it
+#[macro_use]
+mod command_list;
+
+mod a11y;
+mod appearance;
+mod bidi_log;
+mod bridge;
+mod command_timeout;
+mod deep_link;
 mod desktop;
+mod clipboard;
+mod clock;
 mod commands;
+mod config;
+mod control_server;
+mod coverage;
+mod crash;
+mod environment;
 mod error;
+mod event_recorder;
+mod event_wait;
+mod execute_hooks;
+mod executions;
+mod file_drop;
+mod gate;
+mod har;
+mod health;
+mod invoke_conditions;
+mod invoke_replay;
+mod invoke_trace;
+mod lifecycle;
+mod log_buffer;
+mod log_file;
+mod log_subscriptions;
+mod logging;
+mod menu;
+mod metrics;
 mod models;
+mod native_input;
+mod navigation;
+mod payload;
+mod notifications;
+mod preamble;
+mod rust_handlers;
+mod schema;
+mod script_map;
+mod self_test;
+mod sessions;
+mod shortcuts;
+mod state;
+mod state_providers;
+mod stream;
+mod tray;
+mod web_storage;
+mod window_events;
+mod window_watch;
 
+pub use clock::{Clock, Sleep, TestClock};
 pub use error::{Error, Result};
+pub use logging::wdio_log_target;
 
+pub use desktop::capture_window;
 use desktop::Wdio;
 
-struct WdioUnifiedLogger;
-
-static LOGGER_INIT: Mutex<bool> = Mutex::new(false);
+/// Version of the command schema shared with the JS service. Bump it in the
+/// same change that alters a command's arguments or result.
+pub const PROTOCOL_VERSION: u32 = 2;
 
-impl log::Log for WdioUnifiedLogger {
-    fn enabled(&self, _metadata: &log::Metadata) -> bool {
-        true
-    }
+/// Optional capabilities and whether this build includes them
+const OPTIONAL_FEATURES: &[(&str, bool)] = &[
+    ("screenshot", cfg!(feature = "screenshot")),
+    ("metrics", cfg!(feature = "metrics")),
+    ("clipboard", cfg!(feature = "clipboard")),
+    ("tray", cfg!(feature = "tray")),
+    ("global-shortcut", cfg!(feature = "global-shortcut")),
+    ("deep-link", cfg!(feature = "deep-link")),
+    ("control-server", cfg!(feature = "control-server")),
+    ("a11y", cfg!(feature = "a11y")),
+    ("native-input", cfg!(feature = "native-input")),
+    ("json-schema", cfg!(feature = "json-schema")),
+    ("devtools", cfg!(any(debug_assertions, feature = "devtools"))),
+];
 
-    fn log(&self, record: &log::Record) {
-        eprintln!("[Tauri:Backend] {}: {}", record.level(), record.args());
-    }
+/// Names of the optional capabilities compiled into this build
+pub(crate) fn compiled_features() -> Vec<String> {
+    OPTIONAL_FEATURES
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
 
-    fn flush(&self) {}
+/// Registers every command in src/command_list.rs with the invoke handler
+macro_rules! invoke_handler {
+    ($($name:ident),* $(,)?) => {
+        tauri::generate_handler![$(commands::$name),*]
+    };
 }
 
 /// Creates the Wdio plugin with default options.
-pub fn init<R: Runtime>() -> TauriPlugin<R> {
-    plugin::Builder::new("wdio")
-        .invoke_handler(tauri::generate_handler![
-            commands::execute,
-            commands::log_frontend,
-            commands::debug_plugin,
-            commands::get_active_window_label,
-            commands::list_windows,
-            commands::get_window_states
-        ])
-        .setup(|app_handle, _api| {
-            // Only set up our global logger if no logger is already configured
-            // This prevents conflicts with tauri_plugin_log or other loggers
-            let mut initialized = LOGGER_INIT.lock().unwrap();
-            if !*initialized {
-                let logger = Box::new(WdioUnifiedLogger);
-                if let Err(e) = log::set_boxed_logger(logger) {
-                    eprintln!("[WDIO] Failed to set global logger (may be already set by another plugin): {}", e);
-                } else {
-                    *initialized = true;
+pub fn init<R: Runtime>() -> TauriPlugin<R, serde_json::Value> {
+    init_with_config(WdioConfig::default())
+}
+
+/// Creates the Wdio plugin with the given configuration.
+pub fn init_with_config<R: Runtime>(config: WdioConfig) -> TauriPlugin<R, serde_json::Value> {
+    // The builder takes a single init script, so the console shim rides along
+    // with the bridge
+    let mut init_script = bridge::script();
+    if config.capture_frontend_console {
+        init_script.push('\n');
+        init_script.push_str(logging::CONSOLE_SHIM_SCRIPT);
+    }
+
+    plugin::Builder::<R, serde_json::Value>::new("wdio")
+        .invoke_handler(gate::guard(wdio_commands!(invoke_handler)))
+        .js_init_script(init_script)
+        .on_webview_ready(|webview| {
+            if let Some(state) = webview.try_state::<state::WdioState>() {
+                state.window_changes.changed();
+            }
+        })
+        .on_page_load(|webview, payload| {
+            let Some(state) = webview.try_state::<state::WdioState>() else {
+                return;
+            };
+            match payload.event() {
+                PageLoadEvent::Started => {
+                    // The new page can't emit results for scripts evaluated in the old one
+                    let label = webview.label();
+                    for listener in state
+                        .executions
+                        .cancel_window(label, || Error::WindowNavigated(label.to_string()))
+                    {
+                        webview.unlisten(listener);
+                    }
+                }
+                PageLoadEvent::Finished => {
+                    state.page_loads.finished(webview.label());
+                    if state.health.page_loaded() {
+                        let label = webview.label();
+                        log::info!("{}: window '{}' finished loading", health::READY_EVENT, label);
+                        let payload = serde_json::json!({ "label": label, "uptime_ms": state.health.uptime_ms() });
+                        let _ = webview.app_handle().emit(health::READY_EVENT, payload);
+                    }
+                    // A new page starts untraced; carry an active trace over to it
+                    if let Some(budget) = state.invoke_trace.active() {
+                        let _ = webview.eval(invoke_trace::tracing_script(Some(budget)));
+                    }
+                    if let Some(mode) = state.invoke_replay.page_mode() {
+                        let _ = webview.eval(invoke_replay::mode_script(Some(mode)));
+                    }
+                    if let Some(conditions) = state.invoke_conditions.current() {
+                        let _ = webview.eval(invoke_conditions::conditions_script(Some(&conditions)));
+                    }
+                }
+            }
+        })
+        .on_event(|app, event| {
+            if let RunEvent::WindowEvent { label, event, .. } = event {
+                if let Some(state) = app.try_state::<state::WdioState>() {
+                    state.window_events.record(label, event);
+                }
+            }
+            // Forward native drops to handlers registered with Wdio::on_drag_drop_event
+            if let RunEvent::WindowEvent {
+                label,
+                event: WindowEvent::DragDrop(drag_drop),
+                ..
+            } = event
+            {
+                if let (Some(wdio), Some(window)) = (app.try_state::<Wdio<R>>(), app.get_webview_window(label)) {
+                    wdio.dispatch_drag_drop_event(&window.as_ref().window(), drag_drop);
+                }
+            }
+            if let RunEvent::WindowEvent {
+                label,
+                event: WindowEvent::Destroyed,
+                ..
+            } = event
+            {
+                if let Some(state) = app.try_state::<state::WdioState>() {
+                    state.window_changes.changed();
+                    let closed = || Error::WindowError(format!("window '{}' was closed", label));
+                    for listener in state.executions.cancel_window(label, closed) {
+                        app.unlisten(listener);
+                    }
+                }
+            }
+            if let RunEvent::ExitRequested { code, api, .. } = event {
+                let coverage_dir = app
+                    .try_state::<state::WdioState>()
+                    .filter(|state| state.gate.is_enabled())
+                    .and_then(|state| state.config.coverage_dir.clone());
+                if let Some(dir) = coverage_dir {
+                    coverage::dump_before_exit(app, &dir, *code, api);
+                }
+            }
+        })
+        .setup(move |app_handle, _api| {
+            let (config, warnings) = config::resolve(&config, _api.config(), |name| std::env::var(name).ok())?;
+            let enable_env = std::env::var(gate::ENABLE_ENV).ok();
+            let plugin_gate = gate::PluginGate::new(cfg!(debug_assertions), &config, enable_env.as_deref());
+
+            let log_pipeline = logging::LogPipeline::new(&config);
+            logging::install(&config, &log_pipeline);
+            for warning in warnings {
+                log::warn!("{}", warning);
+            }
+
+            let event_handle = app_handle.clone();
+            log_pipeline
+                .subscriptions()
+                .set_sink(Box::new(move |label, event, payload| {
+                    let _ = match label {
+                        Some(label) => event_handle.emit_to(label, event, payload),
+                        None => event_handle.emit(event, payload),
+                    };
+                }));
+            let bidi_handle = app_handle.clone();
+            log_pipeline.bidi().set_sink(Box::new(move |_, event, payload| {
+                let _ = bidi_handle.emit(event, payload);
+            }));
+            log_pipeline.stream().set_event_source(Box::new(app_handle.clone()));
+            let subscriptions = log_pipeline.subscriptions().clone();
+            tauri::async_runtime::spawn(async move {
+                let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
+                loop {
+                    interval.tick().await;
+                    subscriptions.flush_dropped();
                 }
+            });
+
+            let crash_store = crash::CrashStore::default();
+            if config.capture_panics {
+                crash::install_panic_hook(crash_store.clone(), log_pipeline.clone());
             }
-            drop(initialized);
+            let control = config.control_port.map(|port| (port, config.token_file.clone()));
+            app_handle.manage(state::WdioState::new(config, plugin_gate, log_pipeline, crash_store));
 
             #[cfg(desktop)]
             let wdio = desktop::init(app_handle, _api)?;
 
             app_handle.manage(wdio);
 
+            match control {
+                Some((port, token_file)) if plugin_gate.is_enabled() => {
+                    control_server::start(app_handle.clone(), port, token_file)
+                }
+                Some(_) => log::warn!("Not starting the control server: {}", Error::PluginDisabled),
+                None => {}
+            }
+
             Ok(())
         })
         .build()
@@ -76,3 +275,76 @@ impl<R: Runtime, T: Manager<R>> WdioExt<R> for T {
         self.state::<Wdio<R>>().inner()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    macro_rules! command_names {
+        ($($name:ident),* $(,)?) => {
+            vec![$(stringify!($name)),*]
+        };
+    }
+
+    fn permissions_dir() -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/permissions/autogenerated"))
+    }
+
+    fn allow(command: &str) -> String {
+        format!("allow-{}", command.replace('_', "-"))
+    }
+
+    /// `allow-*` identifiers in a generated permission list
+    fn identifiers(permissions: &toml::Value) -> Vec<String> {
+        let permissions = permissions.as_array().unwrap().iter();
+        permissions.map(|permission| permission.as_str().unwrap().to_string()).collect()
+    }
+
+    #[test]
+    fn test_generated_permissions_cover_every_command() {
+        let commands: Vec<&str> = wdio_commands!(command_names);
+        let file: toml::Value =
+            toml::from_str(&std::fs::read_to_string(permissions_dir().join("default.toml")).unwrap()).unwrap();
+        let set = |identifier: &str| {
+            let sets = file["set"].as_array().unwrap();
+            let set = sets.iter().find(|set| set["identifier"].as_str() == Some(identifier)).unwrap();
+            identifiers(&set["permissions"])
+        };
+
+        // Exiting the app stays opt-in; everything else is in the default set
+        let expected: Vec<String> = commands
+            .iter()
+            .filter(|command| !["quit_app", "restart_app"].contains(command))
+            .map(|command| allow(command))
+            .collect();
+        assert_eq!(identifiers(&file["default"]["permissions"]), expected);
+        assert_eq!(set("full"), commands.iter().map(|command| allow(command)).collect::<Vec<_>>());
+
+        let readonly = set("readonly");
+        assert!(readonly.iter().all(|permission| expected.contains(permission)));
+        for mutating in [
+            "execute",
+            "resolve_execute",
+            "set_window_bounds",
+            "emit_event",
+            "export_invoke_trace",
+            "capture_window",
+            "snapshot_web_storage",
+        ] {
+            assert!(!readonly.contains(&allow(mutating)), "{} is in the readonly set", mutating);
+        }
+        assert!(readonly.contains(&allow("get_app_info")));
+
+        for command in &commands {
+            let file = permissions_dir().join("commands").join(format!("{}.toml", command));
+            let permissions: toml::Value = toml::from_str(&std::fs::read_to_string(&file).unwrap()).unwrap();
+            let identifiers: Vec<&str> = permissions["permission"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|permission| permission["identifier"].as_str().unwrap())
+                .collect();
+            assert!(identifiers.contains(&allow(command).as_str()), "no allow permission for {}", command);
+        }
+    }
+}
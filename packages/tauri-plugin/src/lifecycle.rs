@@ -0,0 +1,36 @@
+use tauri::{Emitter, Runtime, WebviewWindow};
+
+use crate::models::LifecycleEvent;
+use crate::{Error, Result};
+
+/// Event emitted to the window after a simulated transition, with payload
+/// `{ event }`, so listeners see the same signal on every platform
+pub(crate) const LIFECYCLE_EVENT: &str = "wdio://lifecycle";
+
+/// Put `window` through a lifecycle transition. Desktop has no app-level
+/// background state, so it maps to the window: background minimizes it (the
+/// webview blurs and usually reports itself hidden), and foreground restores
+/// and focuses it. Desktop has no low-memory signal to deliver.
+pub(crate) fn simulate<R: Runtime>(window: &WebviewWindow<R>, event: LifecycleEvent) -> Result<()> {
+    if cfg!(mobile) {
+        return Err(Error::UnsupportedPlatform(std::env::consts::OS.to_string()));
+    }
+    match event {
+        LifecycleEvent::Background => window.minimize()?,
+        LifecycleEvent::Foreground => {
+            if window.is_minimized().unwrap_or(false) {
+                window.unminimize()?;
+            }
+            window.show()?;
+            window.set_focus()?;
+        }
+        LifecycleEvent::LowMemory => {
+            return Err(Error::UnsupportedPlatform(format!(
+                "{} (low_memory is only delivered on mobile)",
+                std::env::consts::OS
+            )))
+        }
+    }
+    window.emit_to(window.label(), LIFECYCLE_EVENT, serde_json::json!({ "event": event }))?;
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::models::{LogEntry, LogFilter};
+
+/// Bounded ring buffer of captured log entries, shared between the global
+/// logger and the get_logs/clear_logs commands. Oldest entries are dropped
+/// once capacity is reached.
+#[derive(Clone)]
+pub(crate) struct LogBuffer {
+    entries: Arc<Mutex<VecDeque<LogEntry>>>,
+    capacity: usize,
+}
+
+impl LogBuffer {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(VecDeque::with_capacity(capacity.min(1024)))),
+            capacity,
+        }
+    }
+
+    /// Append an entry, evicting the oldest one when full
+    pub(crate) fn push(&self, entry: LogEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        while entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// Like [`LogBuffer::push`] but drops the entry instead of blocking when
+    /// the buffer is locked (used from the panic hook, which may run while the
+    /// panicking thread holds the lock)
+    pub(crate) fn try_push(&self, entry: LogEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.try_lock() {
+            while entries.len() >= self.capacity {
+                entries.pop_front();
+            }
+            entries.push_back(entry);
+        }
+    }
+
+    /// Entries matching the filter, oldest first
+    pub(crate) fn query(&self, filter: &LogFilter) -> Vec<LogEntry> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.iter().filter(|e| filter.matches(e)).cloned().collect()
+    }
+
+    /// Remove all entries, returning how many were dropped
+    pub(crate) fn clear(&self) -> usize {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let count = entries.len();
+        entries.clear();
+        count
+    }
+
+    /// Remove the entries tagged with `session_id`, returning how many were dropped
+    pub(crate) fn clear_session(&self, session_id: &str) -> usize {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let count = entries.len();
+        entries.retain(|entry| entry.session_id.as_deref() != Some(session_id));
+        count - entries.len()
+    }
+}
+
+/// Milliseconds since the Unix epoch
+pub(crate) fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{LogLevel, LogSource};
+
+    fn entry(message: &str, level: LogLevel, source: LogSource, timestamp_ms: u64) -> LogEntry {
+        LogEntry {
+            source,
+            level,
+            message: message.to_string(),
+            target: None,
+            timestamp_ms,
+            context: None,
+            session_id: None,
+        }
+    }
+
+    #[test]
+    fn test_wraps_around_at_capacity() {
+        let buffer = LogBuffer::new(3);
+        for i in 0..5 {
+            buffer.push(entry(&format!("msg {}", i), LogLevel::Info, LogSource::Backend, i));
+        }
+
+        let messages: Vec<String> = buffer
+            .query(&LogFilter::default())
+            .into_iter()
+            .map(|e| e.message)
+            .collect();
+        assert_eq!(messages, vec!["msg 2", "msg 3", "msg 4"]);
+    }
+
+    #[test]
+    fn test_filter_by_level_source_substring_and_time() {
+        let buffer = LogBuffer::new(10);
+        buffer.push(entry("backend debug", LogLevel::Debug, LogSource::Backend, 10));
+        buffer.push(entry("backend error", LogLevel::Error, LogSource::Backend, 20));
+        buffer.push(entry("frontend warn", LogLevel::Warn, LogSource::Frontend, 30));
+
+        let warn_and_up = buffer.query(&LogFilter {
+            level: Some(LogLevel::Warn),
+            ..Default::default()
+        });
+        assert_eq!(warn_and_up.len(), 2);
+
+        let frontend = buffer.query(&LogFilter {
+            source: Some(LogSource::Frontend),
+            ..Default::default()
+        });
+        assert_eq!(frontend.len(), 1);
+        assert_eq!(frontend[0].message, "frontend warn");
+
+        let contains = buffer.query(&LogFilter {
+            contains: Some("error".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(contains.len(), 1);
+
+        let since = buffer.query(&LogFilter {
+            since_timestamp: Some(20),
+            ..Default::default()
+        });
+        assert_eq!(since.len(), 2);
+    }
+
+    #[test]
+    fn test_clear_session_keeps_other_entries() {
+        let buffer = LogBuffer::new(10);
+        for (message, session_id) in [("before", None), ("a", Some("a")), ("b", Some("b")), ("a again", Some("a"))] {
+            let mut entry = entry(message, LogLevel::Info, LogSource::Backend, 1);
+            entry.session_id = session_id.map(str::to_string);
+            buffer.push(entry);
+        }
+
+        assert_eq!(buffer.clear_session("a"), 2);
+        let messages: Vec<String> = buffer.query(&LogFilter::default()).into_iter().map(|e| e.message).collect();
+        assert_eq!(messages, vec!["before", "b"]);
+    }
+
+    #[test]
+    fn test_clear_returns_dropped_count() {
+        let buffer = LogBuffer::new(10);
+        buffer.push(entry("a", LogLevel::Info, LogSource::Backend, 1));
+        buffer.push(entry("b", LogLevel::Info, LogSource::Backend, 2));
+
+        assert_eq!(buffer.clear(), 2);
+        assert!(buffer.query(&LogFilter::default()).is_empty());
+    }
+}
@@ -0,0 +1,138 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+struct OpenFile {
+    file: File,
+    size: u64,
+}
+
+/// Append-only log file with size-based rotation. Every line is written and
+/// flushed individually so a crashed app still leaves a complete file.
+pub(crate) struct RotatingLogFile {
+    path: PathBuf,
+    max_bytes: u64,
+    keep: usize,
+    file: Mutex<Option<OpenFile>>,
+}
+
+impl RotatingLogFile {
+    pub(crate) fn open(path: PathBuf, max_bytes: u64, keep: usize) -> io::Result<Self> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let file = open_append(&path)?;
+        Ok(Self {
+            path,
+            max_bytes,
+            keep,
+            file: Mutex::new(Some(file)),
+        })
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append `line` plus a newline, rotating first if it would exceed the size limit
+    pub(crate) fn write_line(&self, line: &str) {
+        let mut guard = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        let len = line.len() as u64 + 1;
+
+        let needs_rotation = guard
+            .as_ref()
+            .is_some_and(|f| f.size > 0 && f.size + len > self.max_bytes);
+        if needs_rotation || guard.is_none() {
+            // Close the current handle before renaming (required on Windows)
+            *guard = None;
+            if needs_rotation {
+                if let Err(e) = self.rotate() {
+                    eprintln!("[WDIO] Failed to rotate log file {}: {}", self.path.display(), e);
+                }
+            }
+            match open_append(&self.path) {
+                Ok(file) => *guard = Some(file),
+                Err(e) => {
+                    eprintln!("[WDIO] Failed to open log file {}: {}", self.path.display(), e);
+                    return;
+                }
+            }
+        }
+
+        if let Some(open) = guard.as_mut() {
+            let written = writeln!(open.file, "{}", line).and_then(|_| open.file.flush());
+            if written.is_ok() {
+                open.size += len;
+            }
+        }
+    }
+
+    /// Shift `<path>.N` to `<path>.N+1`, dropping the oldest, then move `<path>` to `<path>.1`
+    fn rotate(&self) -> io::Result<()> {
+        if self.keep == 0 {
+            return fs::remove_file(&self.path);
+        }
+        let _ = fs::remove_file(self.rotated(self.keep));
+        for n in (1..self.keep).rev() {
+            let from = self.rotated(n);
+            if from.exists() {
+                fs::rename(&from, self.rotated(n + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.rotated(1))
+    }
+
+    fn rotated(&self, n: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{}", n));
+        PathBuf::from(name)
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<OpenFile> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let size = file.metadata()?.len();
+    Ok(OpenFile { file, size })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("wdio-log-file-{}-{}", name, uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_rotates_and_keeps_configured_number_of_files() {
+        let dir = temp_dir("rotate");
+        let path = dir.join("app.log");
+        // Each line is 10 bytes including the newline; two fit per file
+        let log_file = RotatingLogFile::open(path.clone(), 20, 2).unwrap();
+        for i in 0..7 {
+            log_file.write_line(&format!("line {:04}", i));
+        }
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "line 0006\n");
+        assert_eq!(fs::read_to_string(dir.join("app.log.1")).unwrap(), "line 0004\nline 0005\n");
+        assert_eq!(fs::read_to_string(dir.join("app.log.2")).unwrap(), "line 0002\nline 0003\n");
+        assert!(!dir.join("app.log.3").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_appends_to_existing_file_and_creates_parent_dirs() {
+        let dir = temp_dir("append");
+        let path = dir.join("nested").join("app.log");
+        RotatingLogFile::open(path.clone(), 1024, 3).unwrap().write_line("first");
+        RotatingLogFile::open(path.clone(), 1024, 3).unwrap().write_line("second");
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first\nsecond\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,286 @@
+use std::cell::Cell;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread::LocalKey;
+use std::time::{Duration, Instant};
+
+use crate::models::{LogDropSummary, LogEntry, LogEvent, LogSubscriptionFilter};
+
+/// Event carrying a single matching [`LogEntry`]
+pub(crate) const LOG_EVENT: &str = "wdio:log";
+
+/// Event reporting entries dropped by the rate limiter
+pub(crate) const LOG_DROPPED_EVENT: &str = "wdio:log-dropped";
+
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Delivers an event to a window label, or to every webview when `None`.
+/// Set once the app handle is available so the pipeline stays runtime-agnostic.
+pub(crate) type EventSink = Box<dyn Fn(Option<&str>, &str, serde_json::Value) + Send + Sync>;
+
+thread_local! {
+    // Emitting an event can itself log; never dispatch recursively from the same thread
+    static DISPATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Marks a thread-local flag as set while alive, so a panicking sink can't
+/// leave it set and silence the thread for good
+pub(crate) struct ReentryGuard(&'static LocalKey<Cell<bool>>);
+
+impl ReentryGuard {
+    /// Set `flag`, or `None` if this thread already holds it
+    pub(crate) fn enter(flag: &'static LocalKey<Cell<bool>>) -> Option<Self> {
+        if flag.with(|f| f.replace(true)) {
+            return None;
+        }
+        Some(Self(flag))
+    }
+}
+
+impl Drop for ReentryGuard {
+    fn drop(&mut self) {
+        self.0.with(|f| f.set(false));
+    }
+}
+
+struct Subscription {
+    id: String,
+    filter: LogSubscriptionFilter,
+    seq: u64,
+    window_start: Instant,
+    sent_in_window: u32,
+    dropped: u64,
+}
+
+/// Active log subscriptions registered via subscribe_logs
+#[derive(Clone)]
+pub(crate) struct LogSubscriptions {
+    subscriptions: Arc<Mutex<Vec<Subscription>>>,
+    sink: Arc<OnceLock<EventSink>>,
+    max_per_second: u32,
+}
+
+impl LogSubscriptions {
+    pub(crate) fn new(max_per_second: u32) -> Self {
+        Self {
+            subscriptions: Arc::new(Mutex::new(Vec::new())),
+            sink: Arc::new(OnceLock::new()),
+            max_per_second,
+        }
+    }
+
+    pub(crate) fn set_sink(&self, sink: EventSink) {
+        let _ = self.sink.set(sink);
+    }
+
+    /// Register a filter, returning the subscription id
+    pub(crate) fn subscribe(&self, filter: LogSubscriptionFilter) -> String {
+        let id = uuid::Uuid::new_v4().to_string();
+        let mut subscriptions = self.subscriptions.lock().unwrap_or_else(|e| e.into_inner());
+        subscriptions.push(Subscription {
+            id: id.clone(),
+            filter,
+            seq: 0,
+            window_start: Instant::now(),
+            sent_in_window: 0,
+            dropped: 0,
+        });
+        id
+    }
+
+    /// Remove a subscription, returning whether it existed
+    pub(crate) fn unsubscribe(&self, id: &str) -> bool {
+        let mut subscriptions = self.subscriptions.lock().unwrap_or_else(|e| e.into_inner());
+        let before = subscriptions.len();
+        subscriptions.retain(|s| s.id != id);
+        subscriptions.len() != before
+    }
+
+    /// Emit `entry` to every subscription whose filter matches it
+    pub(crate) fn dispatch(&self, entry: &LogEntry) {
+        let Some(sink) = self.sink.get() else {
+            return;
+        };
+        let Some(_guard) = ReentryGuard::enter(&DISPATCHING) else {
+            return;
+        };
+
+        let events = {
+            let mut subscriptions = self.subscriptions.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let mut events = Vec::new();
+            for sub in subscriptions.iter_mut() {
+                if !sub.filter.filter.matches(entry) {
+                    continue;
+                }
+                if now.duration_since(sub.window_start) >= RATE_WINDOW {
+                    sub.window_start = now;
+                    sub.sent_in_window = 0;
+                }
+                if sub.sent_in_window >= self.max_per_second {
+                    sub.dropped += 1;
+                    continue;
+                }
+                sub.sent_in_window += 1;
+                sub.seq += 1;
+                events.push((
+                    sub.filter.window_label.clone(),
+                    LogEvent {
+                        subscription_id: sub.id.clone(),
+                        seq: sub.seq,
+                        entry: entry.clone(),
+                    },
+                ));
+            }
+            events
+        };
+
+        for (label, event) in events {
+            if let Ok(payload) = serde_json::to_value(event) {
+                sink(label.as_deref(), LOG_EVENT, payload);
+            }
+        }
+    }
+
+    /// Emit a drop summary for each subscription that dropped entries since the last call
+    pub(crate) fn flush_dropped(&self) {
+        let Some(sink) = self.sink.get() else {
+            return;
+        };
+
+        let summaries: Vec<(Option<String>, LogDropSummary)> = {
+            let mut subscriptions = self.subscriptions.lock().unwrap_or_else(|e| e.into_inner());
+            subscriptions
+                .iter_mut()
+                .filter(|s| s.dropped > 0)
+                .map(|s| {
+                    let dropped = std::mem::take(&mut s.dropped);
+                    (
+                        s.filter.window_label.clone(),
+                        LogDropSummary {
+                            subscription_id: s.id.clone(),
+                            dropped,
+                        },
+                    )
+                })
+                .collect()
+        };
+
+        for (label, summary) in summaries {
+            if let Ok(payload) = serde_json::to_value(summary) {
+                sink(label.as_deref(), LOG_DROPPED_EVENT, payload);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{LogFilter, LogLevel, LogSource};
+
+    type Sent = Arc<Mutex<Vec<(Option<String>, String, serde_json::Value)>>>;
+
+    fn recording(max_per_second: u32) -> (LogSubscriptions, Sent) {
+        let subscriptions = LogSubscriptions::new(max_per_second);
+        let sent: Sent = Arc::default();
+        let sink_sent = sent.clone();
+        subscriptions.set_sink(Box::new(move |label, event, payload| {
+            sink_sent
+                .lock()
+                .unwrap()
+                .push((label.map(str::to_string), event.to_string(), payload));
+        }));
+        (subscriptions, sent)
+    }
+
+    fn entry(message: &str, level: LogLevel) -> LogEntry {
+        LogEntry {
+            source: LogSource::Backend,
+            level,
+            message: message.to_string(),
+            target: None,
+            timestamp_ms: 0,
+            context: None,
+            session_id: None,
+        }
+    }
+
+    #[test]
+    fn test_delivers_matching_entries_in_order() {
+        let (subscriptions, sent) = recording(100);
+        let id = subscriptions.subscribe(LogSubscriptionFilter {
+            filter: LogFilter {
+                level: Some(LogLevel::Warn),
+                ..Default::default()
+            },
+            window_label: Some("main".to_string()),
+        });
+
+        subscriptions.dispatch(&entry("first", LogLevel::Warn));
+        subscriptions.dispatch(&entry("skipped", LogLevel::Info));
+        subscriptions.dispatch(&entry("second", LogLevel::Error));
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 2);
+        for (i, (label, event, payload)) in sent.iter().enumerate() {
+            assert_eq!(label.as_deref(), Some("main"));
+            assert_eq!(event, LOG_EVENT);
+            assert_eq!(payload["subscription_id"], id.as_str());
+            assert_eq!(payload["seq"], i as u64 + 1);
+        }
+        assert_eq!(sent[0].2["entry"]["message"], "first");
+        assert_eq!(sent[1].2["entry"]["message"], "second");
+    }
+
+    #[test]
+    fn test_drops_over_rate_limit_and_reports_summary() {
+        let (subscriptions, sent) = recording(2);
+        let id = subscriptions.subscribe(LogSubscriptionFilter::default());
+
+        for i in 0..5 {
+            subscriptions.dispatch(&entry(&format!("msg {}", i), LogLevel::Info));
+        }
+        subscriptions.flush_dropped();
+        subscriptions.flush_dropped();
+
+        let sent = sent.lock().unwrap();
+        let events: Vec<&str> = sent.iter().map(|(_, e, _)| e.as_str()).collect();
+        assert_eq!(events, vec![LOG_EVENT, LOG_EVENT, LOG_DROPPED_EVENT]);
+        assert_eq!(sent[2].2["subscription_id"], id.as_str());
+        assert_eq!(sent[2].2["dropped"], 3);
+    }
+
+    #[test]
+    fn test_unsubscribe_stops_delivery() {
+        let (subscriptions, sent) = recording(100);
+        let id = subscriptions.subscribe(LogSubscriptionFilter::default());
+
+        assert!(subscriptions.unsubscribe(&id));
+        assert!(!subscriptions.unsubscribe(&id));
+        subscriptions.dispatch(&entry("after", LogLevel::Info));
+
+        assert!(sent.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_keeps_delivering_after_a_panicking_sink() {
+        let subscriptions = LogSubscriptions::new(100);
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let sink_sent = sent.clone();
+        subscriptions.set_sink(Box::new(move |_, _, payload| {
+            if payload["entry"]["message"] == "boom" {
+                panic!("sink failed");
+            }
+            sink_sent.lock().unwrap().push(payload["entry"]["message"].clone());
+        }));
+        subscriptions.subscribe(LogSubscriptionFilter::default());
+
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            subscriptions.dispatch(&entry("boom", LogLevel::Info))
+        }));
+        subscriptions.dispatch(&entry("after", LogLevel::Info));
+
+        assert!(panicked.is_err());
+        assert_eq!(*sent.lock().unwrap(), vec![serde_json::json!("after")]);
+    }
+}
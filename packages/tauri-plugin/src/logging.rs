@@ -0,0 +1,437 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+use crate::bidi_log::BidiLogAdapter;
+use crate::log_buffer::{now_ms, LogBuffer};
+use crate::log_file::RotatingLogFile;
+use crate::payload;
+use crate::log_subscriptions::LogSubscriptions;
+use crate::models::{LogEntry, LogFormat, LogLevel, LogSource, WdioConfig};
+use crate::stream::StreamHub;
+
+/// Prefix recognised by @wdio/tauri-service's log parser for backend records
+const BACKEND_PREFIX: &str = "[Tauri:Backend]";
+
+/// Marker recognised by @wdio/tauri-service's log parser for frontend entries
+const FRONTEND_MARKER: &str = "[WDIO-FRONTEND]";
+
+/// Console forwarding shim injected into every page when frontend capture is enabled
+pub(crate) const CONSOLE_SHIM_SCRIPT: &str = include_str!("js/console_shim.js");
+
+static LOGGER_INIT: Mutex<bool> = Mutex::new(false);
+
+/// Set once [`wdio_log_target`] has handed out a forwarding logger
+static EXTERNAL_TARGET: AtomicBool = AtomicBool::new(false);
+
+/// Pipeline that [`wdio_log_target`] forwards to, available once the plugin's setup has run
+static SHARED_PIPELINE: OnceLock<LogPipeline> = OnceLock::new();
+
+/// Shared destination for captured log entries: writes each entry to stderr
+/// (and the log file, if configured) in the configured format, forwards it to
+/// log subscribers and control server streams, and appends it to the
+/// in-memory buffer.
+#[derive(Clone)]
+pub(crate) struct LogPipeline {
+    buffer: LogBuffer,
+    subscriptions: LogSubscriptions,
+    bidi: BidiLogAdapter,
+    stream: StreamHub,
+    file: Option<Arc<RotatingLogFile>>,
+    // RwLock so concurrent loggers only contend with set/clear, never with each other;
+    // the Arc is cloned out and the lock released before formatting
+    context: Arc<RwLock<Option<Arc<str>>>>,
+    session: Arc<RwLock<Option<Arc<str>>>>,
+    format: LogFormat,
+    /// Longer messages are cut short and marked
+    max_message_bytes: usize,
+}
+
+impl LogPipeline {
+    pub(crate) fn new(config: &WdioConfig) -> Self {
+        let file = config.log_file.clone().and_then(|path| {
+            RotatingLogFile::open(path.clone(), config.log_file_max_bytes, config.log_file_keep)
+                .map_err(|e| eprintln!("[WDIO] Failed to open log file {}: {}", path.display(), e))
+                .ok()
+                .map(Arc::new)
+        });
+        Self {
+            buffer: LogBuffer::new(config.log_buffer_capacity),
+            subscriptions: LogSubscriptions::new(config.log_subscription_rate_limit),
+            bidi: BidiLogAdapter::new(config.bidi_log_events),
+            stream: StreamHub::new(config.stream_queue_capacity),
+            file,
+            context: Arc::new(RwLock::new(None)),
+            session: Arc::new(RwLock::new(None)),
+            format: config.log_format,
+            max_message_bytes: config.max_payload_bytes,
+        }
+    }
+
+    pub(crate) fn buffer(&self) -> &LogBuffer {
+        &self.buffer
+    }
+
+    pub(crate) fn subscriptions(&self) -> &LogSubscriptions {
+        &self.subscriptions
+    }
+
+    pub(crate) fn bidi(&self) -> &BidiLogAdapter {
+        &self.bidi
+    }
+
+    /// Control server stream clients, which also receive invoke trace entries and events
+    pub(crate) fn stream(&self) -> &StreamHub {
+        &self.stream
+    }
+
+    /// Path of the log file, if file output is enabled
+    pub(crate) fn log_file_path(&self) -> Option<&std::path::Path> {
+        self.file.as_deref().map(RotatingLogFile::path)
+    }
+
+    /// Set the context attached to every subsequent entry, or clear it with `None`
+    pub(crate) fn set_context(&self, context: Option<String>) {
+        *self.context.write().unwrap_or_else(|e| e.into_inner()) = context.map(Arc::from);
+    }
+
+    pub(crate) fn context(&self) -> Option<String> {
+        let context = self.context.read().unwrap_or_else(|e| e.into_inner()).clone();
+        context.map(|c| c.to_string())
+    }
+
+    /// Tag every subsequent entry with a test session, or stop with `None`
+    pub(crate) fn set_session(&self, session_id: Option<&str>) {
+        *self.session.write().unwrap_or_else(|e| e.into_inner()) = session_id.map(Arc::from);
+    }
+
+    pub(crate) fn session(&self) -> Option<String> {
+        let session = self.session.read().unwrap_or_else(|e| e.into_inner()).clone();
+        session.map(|s| s.to_string())
+    }
+
+    /// Print an entry to stderr and the log file, dispatch it to subscribers and streams, and buffer it
+    pub(crate) fn emit(
+        &self,
+        source: LogSource,
+        level: LogLevel,
+        mut message: String,
+        target: Option<String>,
+    ) {
+        payload::truncate_marked(&mut message, self.max_message_bytes);
+        let entry = LogEntry {
+            source,
+            level,
+            message,
+            target,
+            timestamp_ms: now_ms(),
+            context: self.context(),
+            session_id: self.session(),
+        };
+        let line = format_line(&entry, self.format);
+        eprintln!("{}", line);
+        if let Some(file) = &self.file {
+            file.write_line(&line);
+        }
+        self.subscriptions.dispatch(&entry);
+        self.bidi.dispatch(&entry);
+        self.stream.publish_log(&entry);
+        self.buffer.push(entry);
+    }
+}
+
+/// Global `log::Log` implementation that forwards backend records to stderr
+/// so tauri-driver and the standalone launcher can capture them without the
+/// app configuring its own logger.
+struct WdioUnifiedLogger {
+    min_level: log::LevelFilter,
+    pipeline: LogPipeline,
+}
+
+impl log::Log for WdioUnifiedLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= self.min_level
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        self.pipeline.emit(
+            LogSource::Backend,
+            LogLevel::from(record.level()),
+            record.args().to_string(),
+            Some(record.target().to_string()),
+        );
+    }
+
+    fn flush(&self) {}
+}
+
+/// Logger handed to an app's own log plugin by [`wdio_log_target`]. Records
+/// logged before the wdio plugin's setup runs are dropped.
+struct WdioTargetLogger {
+    active: bool,
+}
+
+impl log::Log for WdioTargetLogger {
+    // Level filtering is left to the app's log plugin
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        self.active
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.active {
+            return;
+        }
+        if let Some(pipeline) = SHARED_PIPELINE.get() {
+            pipeline.emit(
+                LogSource::Backend,
+                LogLevel::from(record.level()),
+                record.args().to_string(),
+                Some(record.target().to_string()),
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// A logger for apps that already use `tauri_plugin_log` (or any fern-based
+/// logger), which owns the global logger slot. Add it as a dispatch target so
+/// records reach the wdio stderr format, buffer, and subscribers:
+///
+/// ```ignore
+/// use tauri_plugin_log::{Target, TargetKind};
+///
+/// tauri_plugin_log::Builder::new()
+///     .target(Target::new(TargetKind::Dispatch(
+///         fern::Dispatch::new().chain(tauri_plugin_wdio::wdio_log_target()),
+///     )))
+///     .build()
+/// ```
+///
+/// Once called, the wdio plugin no longer tries to install its own global
+/// logger. Only the first returned logger forwards records; later calls print
+/// a warning and return a logger that ignores everything, so registering the
+/// target twice does not duplicate lines.
+pub fn wdio_log_target() -> Box<dyn log::Log> {
+    let active = register_external_target();
+    if !active {
+        eprintln!("[WDIO] wdio_log_target() was already registered; ignoring the duplicate target");
+    }
+    Box::new(WdioTargetLogger { active })
+}
+
+/// Returns true for the first registration only
+fn register_external_target() -> bool {
+    !EXTERNAL_TARGET.swap(true, Ordering::SeqCst)
+}
+
+/// Format an entry as a single stderr line.
+///
+/// Text mode keeps the prefixes the service's log parser understands:
+/// `[Tauri:Backend] LEVEL target: message` and `[WDIO-FRONTEND][LEVEL] message`,
+/// with `[ctx:...] ` inserted after the prefix when a context is set.
+/// Json mode writes one object per line; serde_json escapes embedded newlines
+/// so a record never spans more than one line.
+fn format_line(entry: &LogEntry, format: LogFormat) -> String {
+    let ctx = entry
+        .context
+        .as_deref()
+        .map(|c| format!("[ctx:{}] ", c))
+        .unwrap_or_default();
+    match format {
+        LogFormat::Text => match entry.source {
+            LogSource::Backend => format!(
+                "{} {}{} {}: {}",
+                BACKEND_PREFIX,
+                ctx,
+                level_name(entry.level),
+                entry.target.as_deref().unwrap_or_default(),
+                entry.message
+            ),
+            LogSource::Frontend => format!(
+                "{}[{}] {}{}",
+                FRONTEND_MARKER,
+                level_name(entry.level),
+                ctx,
+                entry.message
+            ),
+        },
+        LogFormat::Json => serde_json::json!({
+            "src": entry.source,
+            "lvl": entry.level,
+            "ts": entry.timestamp_ms,
+            "msg": entry.message,
+            "target": entry.target,
+            "ctx": entry.context,
+        })
+        .to_string(),
+    }
+}
+
+fn level_name(level: LogLevel) -> &'static str {
+    match level {
+        LogLevel::Trace => "TRACE",
+        LogLevel::Debug => "DEBUG",
+        LogLevel::Info => "INFO",
+        LogLevel::Warn => "WARN",
+        LogLevel::Error => "ERROR",
+    }
+}
+
+/// Install the unified logger if backend log capture is enabled.
+///
+/// Only one global logger can exist per process, so if the app already set one
+/// (e.g. via tauri_plugin_log) we keep it and print a warning instead of failing setup.
+/// In that case backend records only reach `pipeline` through [`wdio_log_target`].
+pub(crate) fn install(config: &WdioConfig, pipeline: &LogPipeline) {
+    if !config.capture_backend_logs {
+        return;
+    }
+
+    let _ = SHARED_PIPELINE.set(pipeline.clone());
+    if EXTERNAL_TARGET.load(Ordering::SeqCst) {
+        // Records arrive via the app's log plugin; a second logger would print them twice
+        return;
+    }
+
+    let mut initialized = LOGGER_INIT.lock().unwrap_or_else(|e| e.into_inner());
+    if *initialized {
+        return;
+    }
+
+    let logger = Box::new(WdioUnifiedLogger {
+        min_level: config.min_level,
+        pipeline: pipeline.clone(),
+    });
+    match log::set_boxed_logger(logger) {
+        Ok(()) => {
+            log::set_max_level(config.min_level);
+            *initialized = true;
+        }
+        Err(e) => {
+            eprintln!(
+                "[WDIO] Backend log capture skipped, a global logger is already set (likely by another plugin): {}. \
+                 Add tauri_plugin_wdio::wdio_log_target() to that logger to capture backend logs.",
+                e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(source: LogSource, message: &str) -> LogEntry {
+        LogEntry {
+            source,
+            level: LogLevel::Error,
+            message: message.to_string(),
+            target: match source {
+                LogSource::Backend => Some("my_app::commands".to_string()),
+                LogSource::Frontend => None,
+            },
+            timestamp_ms: 1_700_000_000_000,
+            context: None,
+            session_id: None,
+        }
+    }
+
+    #[test]
+    fn test_text_format_keeps_parser_prefixes() {
+        assert_eq!(
+            format_line(&entry(LogSource::Backend, "boom"), LogFormat::Text),
+            "[Tauri:Backend] ERROR my_app::commands: boom"
+        );
+        assert_eq!(
+            format_line(&entry(LogSource::Frontend, "boom"), LogFormat::Text),
+            "[WDIO-FRONTEND][ERROR] boom"
+        );
+    }
+
+    #[test]
+    fn test_json_format_is_one_parseable_line_for_multiline_messages() {
+        let message = "failed to open config\n\nCaused by:\n    0: No such file or directory (os error 2)\r\n\tat \"main.rs\"";
+        let line = format_line(&entry(LogSource::Backend, message), LogFormat::Json);
+
+        assert_eq!(line.lines().count(), 1);
+        let parsed: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed["src"], "backend");
+        assert_eq!(parsed["lvl"], "error");
+        assert_eq!(parsed["ts"], 1_700_000_000_000u64);
+        assert_eq!(parsed["msg"], message);
+        assert_eq!(parsed["target"], "my_app::commands");
+    }
+
+    #[test]
+    fn test_json_format_frontend_has_null_target() {
+        let line = format_line(&entry(LogSource::Frontend, "a\nb"), LogFormat::Json);
+
+        assert_eq!(line.lines().count(), 1);
+        let parsed: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed["src"], "frontend");
+        assert!(parsed["target"].is_null());
+    }
+
+    #[test]
+    fn test_context_is_included_in_text_and_json() {
+        let mut backend = entry(LogSource::Backend, "boom");
+        backend.context = Some("login > rejects bad password".to_string());
+        let mut frontend = entry(LogSource::Frontend, "boom");
+        frontend.context = backend.context.clone();
+
+        assert_eq!(
+            format_line(&backend, LogFormat::Text),
+            "[Tauri:Backend] [ctx:login > rejects bad password] ERROR my_app::commands: boom"
+        );
+        assert_eq!(
+            format_line(&frontend, LogFormat::Text),
+            "[WDIO-FRONTEND][ERROR] [ctx:login > rejects bad password] boom"
+        );
+        let parsed: serde_json::Value =
+            serde_json::from_str(&format_line(&backend, LogFormat::Json)).unwrap();
+        assert_eq!(parsed["ctx"], "login > rejects bad password");
+    }
+
+    #[test]
+    fn test_pipeline_applies_context_until_cleared() {
+        let pipeline = LogPipeline::new(&WdioConfig::default());
+        pipeline.set_context(Some("suite a".to_string()));
+        pipeline.emit(LogSource::Backend, LogLevel::Info, "with".to_string(), None);
+        pipeline.set_context(None);
+        pipeline.emit(LogSource::Frontend, LogLevel::Info, "without".to_string(), None);
+
+        let entries = pipeline.buffer().query(&crate::models::LogFilter::default());
+        assert_eq!(entries[0].context.as_deref(), Some("suite a"));
+        assert_eq!(entries[1].context, None);
+    }
+
+    #[test]
+    fn test_pipeline_marks_messages_cut_to_the_payload_limit() {
+        let pipeline = LogPipeline::new(&WdioConfig {
+            max_payload_bytes: 8,
+            ..WdioConfig::default()
+        });
+        pipeline.emit(LogSource::Frontend, LogLevel::Info, "0123456789abcdef".to_string(), None);
+        pipeline.emit(LogSource::Frontend, LogLevel::Info, "short".to_string(), None);
+
+        let messages: Vec<String> = pipeline
+            .buffer()
+            .query(&crate::models::LogFilter::default())
+            .into_iter()
+            .map(|entry| entry.message)
+            .collect();
+        assert_eq!(messages, vec!["01234567… [truncated 8 bytes]", "short"]);
+    }
+
+    #[test]
+    fn test_only_first_external_target_registration_is_active() {
+        let first = register_external_target();
+        let second = register_external_target();
+
+        assert!(first);
+        assert!(!second);
+    }
+}
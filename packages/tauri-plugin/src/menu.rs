@@ -0,0 +1,199 @@
+use tauri::menu::{MenuItemKind, MenuId};
+use tauri::Runtime;
+
+use crate::models::{MenuItemInfo, MenuItemType};
+use crate::{Error, Result};
+
+fn menu_error(e: tauri::Error) -> Error {
+    Error::MenuError(e.to_string())
+}
+
+/// Serialize a menu's items, recursing into submenus
+pub(crate) fn describe<R: Runtime>(items: &[MenuItemKind<R>]) -> Result<Vec<MenuItemInfo>> {
+    items.iter().map(describe_item).collect()
+}
+
+pub(crate) fn describe_item<R: Runtime>(item: &MenuItemKind<R>) -> Result<MenuItemInfo> {
+    let id = item.id().0.clone();
+    let info = match item {
+        MenuItemKind::MenuItem(i) => MenuItemInfo {
+            id,
+            label: i.text().map_err(menu_error)?,
+            kind: MenuItemType::Normal,
+            enabled: i.is_enabled().map_err(menu_error)?,
+            checked: None,
+            children: Vec::new(),
+        },
+        MenuItemKind::Check(i) => MenuItemInfo {
+            id,
+            label: i.text().map_err(menu_error)?,
+            kind: MenuItemType::Check,
+            enabled: i.is_enabled().map_err(menu_error)?,
+            checked: Some(i.is_checked().map_err(menu_error)?),
+            children: Vec::new(),
+        },
+        MenuItemKind::Icon(i) => MenuItemInfo {
+            id,
+            label: i.text().map_err(menu_error)?,
+            kind: MenuItemType::Icon,
+            enabled: i.is_enabled().map_err(menu_error)?,
+            checked: None,
+            children: Vec::new(),
+        },
+        MenuItemKind::Predefined(i) => MenuItemInfo {
+            id,
+            label: i.text().map_err(menu_error)?,
+            kind: MenuItemType::Predefined,
+            enabled: true,
+            checked: None,
+            children: Vec::new(),
+        },
+        MenuItemKind::Submenu(i) => MenuItemInfo {
+            id,
+            label: i.text().map_err(menu_error)?,
+            kind: MenuItemType::Submenu,
+            enabled: i.is_enabled().map_err(menu_error)?,
+            checked: None,
+            children: describe(&i.items().map_err(menu_error)?)?,
+        },
+    };
+    Ok(info)
+}
+
+/// Find an item by id, or by the labels leading to it (`["File", "Save"]`).
+/// Labels match as written or with their `&` mnemonic markers removed.
+pub(crate) fn find<'a>(
+    items: &'a [MenuItemInfo],
+    id: Option<&str>,
+    path: Option<&[String]>,
+) -> Result<&'a MenuItemInfo> {
+    let found = match (id, path) {
+        (Some(id), _) => find_by_id(items, id),
+        (None, Some(path)) if !path.is_empty() => find_by_path(items, path),
+        _ => {
+            return Err(Error::MenuError(
+                "either an item id or a non-empty label path is required".to_string(),
+            ))
+        }
+    };
+    found.ok_or_else(|| {
+        Error::MenuItemNotFound(match (id, path) {
+            (Some(id), _) => id.to_string(),
+            (None, Some(path)) => path.join(" > "),
+            (None, None) => String::new(),
+        })
+    })
+}
+
+fn find_by_id<'a>(items: &'a [MenuItemInfo], id: &str) -> Option<&'a MenuItemInfo> {
+    items.iter().find_map(|item| {
+        if item.id == id {
+            Some(item)
+        } else {
+            find_by_id(&item.children, id)
+        }
+    })
+}
+
+fn find_by_path<'a>(items: &'a [MenuItemInfo], path: &[String]) -> Option<&'a MenuItemInfo> {
+    let (first, rest) = path.split_first()?;
+    let item = items
+        .iter()
+        .find(|item| item.label == *first || strip_mnemonic(&item.label) == *first)?;
+    if rest.is_empty() {
+        Some(item)
+    } else {
+        find_by_path(&item.children, rest)
+    }
+}
+
+/// Remove `&` mnemonic markers, keeping escaped `&&` as a literal `&`
+fn strip_mnemonic(label: &str) -> String {
+    label.replace("&&", "\u{0}").replace('&', "").replace('\u{0}', "&")
+}
+
+/// The live menu item with the given id, recursing into submenus
+pub(crate) fn find_kind<R: Runtime>(items: Vec<MenuItemKind<R>>, id: &MenuId) -> Option<MenuItemKind<R>> {
+    for item in items {
+        if item.id() == id {
+            return Some(item);
+        }
+        if let MenuItemKind::Submenu(submenu) = &item {
+            if let Some(found) = submenu.items().ok().and_then(|children| find_kind(children, id)) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+/// Apply the state change a native click makes before the menu event fires:
+/// check items flip their checked state. Disabled items and submenus can't be
+/// activated.
+pub(crate) fn activate<R: Runtime>(item: &MenuItemKind<R>) -> Result<()> {
+    let info = describe_item(item)?;
+    if !info.enabled {
+        return Err(Error::MenuError(format!("menu item '{}' is disabled", info.label)));
+    }
+    match item {
+        MenuItemKind::Submenu(_) => Err(Error::MenuError(format!(
+            "'{}' is a submenu and has no click event",
+            info.label
+        ))),
+        MenuItemKind::Check(check) => check
+            .set_checked(!info.checked.unwrap_or(false))
+            .map_err(menu_error),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, label: &str, children: Vec<MenuItemInfo>) -> MenuItemInfo {
+        MenuItemInfo {
+            id: id.to_string(),
+            label: label.to_string(),
+            kind: if children.is_empty() {
+                MenuItemType::Normal
+            } else {
+                MenuItemType::Submenu
+            },
+            enabled: true,
+            checked: None,
+            children,
+        }
+    }
+
+    fn tree() -> Vec<MenuItemInfo> {
+        vec![
+            item("file", "&File", vec![item("save", "&Save", vec![]), item("auto-1", "Save && Quit", vec![])]),
+            item("view", "View", vec![item("sidebar", "Sidebar", vec![])]),
+        ]
+    }
+
+    #[test]
+    fn test_find_by_id_searches_submenus() {
+        let items = tree();
+        assert_eq!(find(&items, Some("sidebar"), None).unwrap().label, "Sidebar");
+        assert!(matches!(
+            find(&items, Some("missing"), None),
+            Err(Error::MenuItemNotFound(id)) if id == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_find_by_label_path_ignores_mnemonics() {
+        let items = tree();
+        let path = |labels: &[&str]| labels.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(find(&items, None, Some(&path(&["File", "Save"]))).unwrap().id, "save");
+        assert_eq!(find(&items, None, Some(&path(&["File", "Save & Quit"]))).unwrap().id, "auto-1");
+        assert!(matches!(
+            find(&items, None, Some(&path(&["View", "Save"]))),
+            Err(Error::MenuItemNotFound(p)) if p == "View > Save"
+        ));
+        assert!(matches!(find(&items, None, Some(&[])), Err(Error::MenuError(_))));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::models::ProcessMetrics;
+use crate::Result;
+
+#[cfg(feature = "metrics")]
+pub(crate) use sampler::Metrics;
+
+#[cfg(not(feature = "metrics"))]
+pub(crate) use unsupported::Metrics;
+
+#[cfg(feature = "metrics")]
+mod sampler {
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use sysinfo::{Pid, System};
+    use tauri::async_runtime::JoinHandle;
+
+    use super::*;
+    use crate::log_buffer::now_ms;
+    use crate::Error;
+
+    /// Samples kept before the oldest are dropped (about 2.7 hours at 1s intervals)
+    const MAX_SAMPLES: usize = 10_000;
+
+    /// Measures the app's own process with sysinfo and records time series samples
+    #[derive(Clone)]
+    pub(crate) struct Metrics {
+        pid: Pid,
+        // Reused between measurements so cpu_usage reflects the time since the previous one
+        system: Arc<Mutex<System>>,
+        samples: Arc<Mutex<VecDeque<ProcessMetrics>>>,
+        sampling: Arc<Mutex<Option<JoinHandle<()>>>>,
+    }
+
+    impl Metrics {
+        pub(crate) fn new() -> Self {
+            Self {
+                pid: Pid::from_u32(std::process::id()),
+                system: Arc::new(Mutex::new(System::new())),
+                samples: Arc::default(),
+                sampling: Arc::default(),
+            }
+        }
+
+        pub(crate) fn snapshot(&self) -> Result<ProcessMetrics> {
+            let mut system = self.system.lock().unwrap_or_else(|e| e.into_inner());
+            system.refresh_process(self.pid);
+            let process = system
+                .process(self.pid)
+                .ok_or_else(|| Error::ExecuteError("current process not found".to_string()))?;
+            let timestamp_ms = now_ms();
+            Ok(ProcessMetrics {
+                rss_bytes: process.memory(),
+                virtual_bytes: process.virtual_memory(),
+                cpu_percent: process.cpu_usage(),
+                num_threads: process.tasks().map(|tasks| tasks.len()),
+                uptime_ms: timestamp_ms.saturating_sub(process.start_time() * 1000),
+                timestamp_ms,
+            })
+        }
+
+        /// Start recording a sample every `interval`, replacing any running sampler
+        /// and discarding previous samples
+        pub(crate) fn start_sampling(&self, interval: Duration) -> Result<()> {
+            let mut sampling = self.sampling.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(previous) = sampling.take() {
+                previous.abort();
+            }
+            self.samples.lock().unwrap_or_else(|e| e.into_inner()).clear();
+
+            let metrics = self.clone();
+            *sampling = Some(tauri::async_runtime::spawn(async move {
+                let mut ticker = tokio::time::interval(interval);
+                loop {
+                    ticker.tick().await;
+                    if let Ok(sample) = metrics.snapshot() {
+                        let mut samples = metrics.samples.lock().unwrap_or_else(|e| e.into_inner());
+                        if samples.len() >= MAX_SAMPLES {
+                            samples.pop_front();
+                        }
+                        samples.push_back(sample);
+                    }
+                }
+            }));
+            Ok(())
+        }
+
+        /// Stop the sampler, returning the number of samples collected
+        pub(crate) fn stop_sampling(&self) -> Result<usize> {
+            if let Some(handle) = self.sampling.lock().unwrap_or_else(|e| e.into_inner()).take() {
+                handle.abort();
+            }
+            Ok(self.samples.lock().unwrap_or_else(|e| e.into_inner()).len())
+        }
+
+        pub(crate) fn samples(&self) -> Result<Vec<ProcessMetrics>> {
+            Ok(self
+                .samples
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .iter()
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_snapshot_reports_current_process() {
+            let metrics = Metrics::new();
+            let sample = metrics.snapshot().unwrap();
+
+            assert!(sample.rss_bytes > 0);
+            assert!(sample.virtual_bytes >= sample.rss_bytes);
+            assert!(sample.timestamp_ms > 0);
+        }
+    }
+}
+
+#[cfg(not(feature = "metrics"))]
+mod unsupported {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Error;
+
+    /// Stand-in used when the `metrics` feature is disabled
+    #[derive(Clone)]
+    pub(crate) struct Metrics;
+
+    fn unsupported<T>() -> Result<T> {
+        Err(Error::UnsupportedInBuild(
+            "process metrics require the tauri-plugin-wdio `metrics` feature".to_string(),
+        ))
+    }
+
+    impl Metrics {
+        pub(crate) fn new() -> Self {
+            Self
+        }
+
+        pub(crate) fn snapshot(&self) -> Result<ProcessMetrics> {
+            unsupported()
+        }
+
+        pub(crate) fn start_sampling(&self, _interval: Duration) -> Result<()> {
+            unsupported()
+        }
+
+        pub(crate) fn stop_sampling(&self) -> Result<usize> {
+            unsupported()
+        }
+
+        pub(crate) fn samples(&self) -> Result<Vec<ProcessMetrics>> {
+            unsupported()
+        }
+    }
+}
@@ -1,20 +1,50 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
 pub use serde_json::Value as JsonValue;
 
 /// Execute command request
 #[derive(serde::Deserialize, Debug)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export, optional_fields))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ExecuteRequest {
     /// JavaScript code to execute
     pub script: String,
     /// Arguments to pass to the script
     #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<_>", optional))]
     pub args: Vec<JsonValue>,
     /// Window label to execute in (optional, uses current window if not specified)
     #[serde(default)]
     pub window_label: Option<String>,
+    /// Return an [`ExecuteResponse`] with timings instead of the bare value
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<_>", optional))]
+    pub detailed: bool,
+}
+
+/// Result of a `detailed` execute: the script's value and where the time went.
+/// `total_duration_ms` less `eval_duration_ms` is the plugin and IPC overhead.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ExecuteResponse {
+    /// The script's value, `{ "__wdio_undefined__": true }` when it returned undefined
+    pub value: JsonValue,
+    /// When the script started running in the page, in Unix epoch milliseconds
+    pub eval_started_ms: f64,
+    /// How long the page spent running the script, awaiting its promise, as
+    /// measured there with `performance.now()`
+    pub eval_duration_ms: f64,
+    /// How long the whole execute took, as measured by the plugin
+    pub total_duration_ms: f64,
+    pub window_label: String,
 }
 
 /// Mock configuration
 #[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct MockConfig {
     /// Command name to mock
     pub command: String,
@@ -23,3 +53,1208 @@ pub struct MockConfig {
     /// Mock implementation (for mockImplementation - serialized function string)
     pub implementation: Option<String>,
 }
+
+/// Plugin configuration passed to [`crate::init_with_config`], or set with
+/// camelCase keys under `plugins.wdio` in `tauri.conf.json`. Any field can be
+/// overridden at startup by a `WDIO_TAURI_<FIELD>` environment variable.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WdioConfig {
+    /// Install a global logger that forwards backend `log` records to stderr
+    pub capture_backend_logs: bool,
+    /// Most verbose level forwarded by the backend logger
+    pub min_level: log::LevelFilter,
+    /// Inject a console shim into every page that forwards `console.*` output to stderr
+    pub capture_frontend_console: bool,
+    /// Maximum number of entries kept in the in-memory log buffer
+    pub log_buffer_capacity: usize,
+    /// Format of captured log lines written to stderr
+    pub log_format: LogFormat,
+    /// Install a panic hook that reports backend panics to stderr and get_last_crash
+    pub capture_panics: bool,
+    /// Maximum `wdio:log` events per second per subscription; excess entries are dropped and counted
+    pub log_subscription_rate_limit: u32,
+    /// Append captured log lines to this file; overridden by `WDIO_TAURI_LOG_FILE`
+    pub log_file: Option<PathBuf>,
+    /// Rotate the log file once it would grow past this many bytes
+    pub log_file_max_bytes: u64,
+    /// Number of rotated files (`<log_file>.1` .. `<log_file>.N`) to keep
+    pub log_file_keep: usize,
+    /// Extra origins (e.g. `https://example.com`) the navigate command may load,
+    /// in addition to the app's own origins and dev server
+    pub navigation_allowlist: Vec<String>,
+    /// Environment variable names get_env may read
+    pub env_allowlist: Vec<String>,
+    /// Let get_env read any variable, including the whole environment when called without names
+    pub allow_full_env_dump: bool,
+    /// Start with notification capture on, recording notifications instead of showing them
+    pub capture_notifications: bool,
+    /// Maximum number of events kept by start_event_recording; older ones are dropped and counted
+    pub event_recording_capacity: usize,
+    /// Serve the token-authenticated control server on 127.0.0.1 at this port;
+    /// overridden by `WDIO_TAURI_CONTROL_PORT`. Needs the `control-server` feature.
+    pub control_port: Option<u16>,
+    /// Also write the control server's session token to this file, readable
+    /// only by the current user; overridden by `WDIO_TAURI_TOKEN_FILE`
+    pub token_file: Option<PathBuf>,
+    /// Frames queued for each control server `/stream` client that isn't
+    /// keeping up; older ones are dropped and reported
+    pub stream_queue_capacity: usize,
+    /// Run plugin commands in release builds too; otherwise they fail with
+    /// `Error::PluginDisabled`. `WDIO_ENABLE=1` has the same effect.
+    pub enabled_in_release: bool,
+    /// Report execute results through global Tauri events instead of the
+    /// resolve_execute command, for setups where the command can't be reached
+    pub execute_results_via_events: bool,
+    /// JavaScript run ahead of every executed script, in the same scope; the
+    /// set_execute_preamble command replaces it at runtime
+    pub execute_preamble: Option<String>,
+    /// Before the app exits, dump the JavaScript coverage (`window.__coverage__`)
+    /// of every open window to `<label>.json` in this directory
+    pub coverage_dir: Option<PathBuf>,
+    /// Maximum number of calls kept by start_invoke_tracing; older ones are dropped and counted
+    pub invoke_trace_capacity: usize,
+    /// Bytes of each traced call's JSON arguments to keep, unless
+    /// start_invoke_tracing is given a budget
+    pub invoke_trace_args_max_bytes: usize,
+    /// Maximum number of window events kept for get_window_events; older ones are dropped and counted
+    pub window_event_capacity: usize,
+    /// Largest execute result, invoke recording entry, log message, or traced
+    /// call's args the plugin buffers, in bytes. Larger execute results and
+    /// recordings fail with `Error::PayloadTooLarge`; longer messages and
+    /// args are cut short and marked.
+    pub max_payload_bytes: usize,
+    /// Also emit every captured log entry as a `wdio:bidi:log.entryAdded`
+    /// event shaped like a WebDriver BiDi log entry
+    pub bidi_log_events: bool,
+    /// Milliseconds each named command may run before failing with
+    /// `Error::CommandTimeout`; 0 waits without a limit. Entries override
+    /// the defaults for capture_window (30s) and collect_coverage (120s)
+    /// one command at a time.
+    pub command_timeouts: HashMap<String, u64>,
+    /// Times get_logs and list_windows are run again after timing out
+    pub read_retries: u32,
+}
+
+impl Default for WdioConfig {
+    fn default() -> Self {
+        Self {
+            capture_backend_logs: true,
+            min_level: log::LevelFilter::Info,
+            capture_frontend_console: true,
+            log_buffer_capacity: 5_000,
+            log_format: LogFormat::Text,
+            capture_panics: true,
+            log_subscription_rate_limit: 500,
+            log_file: None,
+            log_file_max_bytes: 10 * 1024 * 1024,
+            log_file_keep: 3,
+            navigation_allowlist: Vec::new(),
+            env_allowlist: Vec::new(),
+            allow_full_env_dump: false,
+            capture_notifications: false,
+            event_recording_capacity: 10_000,
+            control_port: None,
+            token_file: None,
+            stream_queue_capacity: 1_000,
+            enabled_in_release: false,
+            execute_results_via_events: false,
+            execute_preamble: None,
+            coverage_dir: None,
+            invoke_trace_capacity: 10_000,
+            invoke_trace_args_max_bytes: 1024,
+            window_event_capacity: 1_000,
+            max_payload_bytes: 16 * 1024 * 1024,
+            bidi_log_events: false,
+            command_timeouts: HashMap::new(),
+            read_retries: 0,
+        }
+    }
+}
+
+/// Output format for captured log lines
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Prefixed lines understood by @wdio/tauri-service's log parser
+    #[default]
+    Text,
+    /// One JSON object per line: `{"src","lvl","ts","msg","target","ctx"}`
+    Json,
+}
+
+/// Origin of a captured log entry
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum LogSource {
+    Backend,
+    Frontend,
+}
+
+/// Log level of a captured entry, ordered from least to most severe
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Parse a level name as sent by the frontend, defaulting to `Info`
+    pub fn parse(level: &str) -> Self {
+        match level.to_ascii_lowercase().as_str() {
+            "trace" | "log" => LogLevel::Trace,
+            "debug" => LogLevel::Debug,
+            "warn" | "warning" => LogLevel::Warn,
+            "error" => LogLevel::Error,
+            _ => LogLevel::Info,
+        }
+    }
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Trace => LogLevel::Trace,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Error => LogLevel::Error,
+        }
+    }
+}
+
+/// A backend log record or forwarded frontend console entry
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct LogEntry {
+    pub source: LogSource,
+    pub level: LogLevel,
+    pub message: String,
+    /// Module path of the backend record (`None` for frontend entries)
+    pub target: Option<String>,
+    /// Milliseconds since the Unix epoch
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub timestamp_ms: u64,
+    /// Log context set via set_log_context when the entry was captured
+    #[serde(default)]
+    pub context: Option<String>,
+    /// Test session active when the entry was captured, see begin_session
+    #[serde(default)]
+    pub session_id: Option<String>,
+}
+
+/// Level of a [`BidiLogEntry`], as WebDriver BiDi names them
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum BidiLogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+/// Realm a [`BidiLogEntry`] came from
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct BidiSource {
+    pub realm: String,
+    /// Browsing context, left unset for the service to fill in from its session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "export-bindings", ts(optional))]
+    pub context: Option<String>,
+}
+
+/// A captured [`LogEntry`] in the shape of a WebDriver BiDi `log.entryAdded`
+/// entry, emitted as `wdio:bidi:log.entryAdded` while `bidi_log_events` is on
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct BidiLogEntry {
+    /// `console` for frontend entries, `generic` for backend ones
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    pub level: BidiLogLevel,
+    pub source: BidiSource,
+    pub text: Option<String>,
+    /// Milliseconds since the Unix epoch
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub timestamp: u64,
+    /// Console method of a console entry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "export-bindings", ts(optional))]
+    pub method: Option<String>,
+    /// Arguments of a console entry, as BiDi remote values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "export-bindings", ts(optional))]
+    pub args: Option<Vec<serde_json::Value>>,
+}
+
+/// Filter for the get_logs command; all set fields must match
+#[derive(serde::Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export, optional_fields))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct LogFilter {
+    /// Minimum level to include
+    #[serde(default)]
+    pub level: Option<LogLevel>,
+    #[serde(default)]
+    pub source: Option<LogSource>,
+    /// Substring the message must contain
+    #[serde(default)]
+    pub contains: Option<String>,
+    /// Only entries at or after this timestamp (ms since epoch)
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<f64>"))]
+    pub since_timestamp: Option<u64>,
+}
+
+impl LogFilter {
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        self.level.map_or(true, |level| entry.level >= level)
+            && self.source.map_or(true, |source| entry.source == source)
+            && self
+                .contains
+                .as_deref()
+                .map_or(true, |needle| entry.message.contains(needle))
+            && self
+                .since_timestamp
+                .map_or(true, |since| entry.timestamp_ms >= since)
+    }
+}
+
+/// Who receives an event sent with emit_event, mirroring [`tauri::EventTarget`]
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", content = "label", rename_all = "snake_case")]
+pub enum EventTargetSpec {
+    /// Every listener, like `app.emit`
+    #[default]
+    All,
+    /// Any window, webview, or webview window with this label
+    Label(String),
+    Window(String),
+    Webview(String),
+    WebviewWindow(String),
+}
+
+impl From<EventTargetSpec> for tauri::EventTarget {
+    fn from(spec: EventTargetSpec) -> Self {
+        match spec {
+            EventTargetSpec::All => tauri::EventTarget::Any,
+            EventTargetSpec::Label(label) => tauri::EventTarget::AnyLabel { label },
+            EventTargetSpec::Window(label) => tauri::EventTarget::Window { label },
+            EventTargetSpec::Webview(label) => tauri::EventTarget::Webview { label },
+            EventTargetSpec::WebviewWindow(label) => tauri::EventTarget::WebviewWindow { label },
+        }
+    }
+}
+
+/// An event captured by start_event_recording
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct EventRecord {
+    pub name: String,
+    /// Event payload; payloads that aren't JSON are kept as strings
+    pub payload: serde_json::Value,
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub timestamp_ms: u64,
+}
+
+/// Filter for the get_recorded_events command; all set fields must match
+#[derive(serde::Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export, optional_fields))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct EventRecordFilter {
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Only events at or after this timestamp (ms since epoch)
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<f64>"))]
+    pub since_timestamp: Option<u64>,
+}
+
+impl EventRecordFilter {
+    pub fn matches(&self, record: &EventRecord) -> bool {
+        self.name.as_deref().map_or(true, |name| record.name == name)
+            && self
+                .since_timestamp
+                .map_or(true, |since| record.timestamp_ms >= since)
+    }
+}
+
+/// How a traced invoke ended
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum InvokeOutcome {
+    Ok,
+    Error,
+    /// Answered by a mock without reaching the backend
+    Mocked,
+}
+
+/// An IPC command call recorded by start_invoke_tracing
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct InvokeTraceEntry {
+    pub command: String,
+    /// JSON of the call's arguments, cut to the trace's args budget
+    pub args_summary: String,
+    pub args_truncated: bool,
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub started_at_ms: u64,
+    pub duration_ms: f64,
+    pub outcome: InvokeOutcome,
+    /// Window the call was made from; set by the backend
+    #[serde(default)]
+    pub window_label: String,
+    /// Test session active when the call was recorded; set by the backend
+    #[serde(default)]
+    pub session_id: Option<String>,
+}
+
+/// Result of get_invoke_trace
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct InvokeTrace {
+    /// Recorded calls, ordered by start time
+    pub entries: Vec<InvokeTraceEntry>,
+    /// Calls dropped because the trace was full
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub dropped: u64,
+}
+
+/// Document format for export_invoke_trace
+#[derive(serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TraceExportFormat {
+    /// The get_invoke_trace result
+    #[default]
+    Json,
+    /// HTTP Archive 1.2, for report viewers
+    Har,
+}
+
+/// Result of export_invoke_trace
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct InvokeTraceExport {
+    /// File the document was written to, when a path was given
+    pub path: Option<String>,
+    /// The document itself, when no path was given
+    pub document: Option<serde_json::Value>,
+    pub entry_count: usize,
+}
+
+/// Latency and failures that set_invoke_conditions adds to frontend invokes
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export, optional_fields))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct InvokeConditions {
+    /// Delay before each matching invoke is dispatched
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<f64>", optional))]
+    pub latency_ms: u64,
+    /// Random extra delay of up to this many milliseconds
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<f64>", optional))]
+    pub jitter_ms: u64,
+    /// Share of matching invokes, from 0 to 1, that reject without being dispatched
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<_>", optional))]
+    pub failure_rate: f32,
+    /// Value failed invokes reject with; a message naming the command when null
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<_>", optional))]
+    pub failure_error: JsonValue,
+    /// Commands the conditions apply to; every command when unset
+    #[serde(default)]
+    pub command_filter: Option<Vec<String>>,
+    /// Seed for the jitter and failure draws, so a run can be reproduced
+    #[serde(default)]
+    pub seed: Option<u32>,
+}
+
+/// Entries end_session removed along with the session
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct EndedSession {
+    pub session_id: String,
+    pub log_entries: usize,
+    pub invoke_trace_entries: usize,
+}
+
+/// What reset_all cleared
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ResetSummary {
+    pub logs_cleared: usize,
+    pub window_events_cleared: usize,
+    pub invoke_trace_cleared: usize,
+    /// Session that was active, ended without removing what it tagged
+    pub session_ended: Option<String>,
+    /// Whether a preamble set with set_execute_preamble was dropped
+    pub preamble_cleared: bool,
+    /// Executions failed, other than those waiting on the calling window
+    pub executions_cancelled: usize,
+    pub notifications_cleared: usize,
+    pub recorded_events_cleared: usize,
+    pub invoke_conditions_cleared: bool,
+    /// Whether an invoke recording, discarded unwritten, or replay was on
+    pub invoke_replay_stopped: bool,
+    /// Pages sent the reset of their mocks, mock calls, and interceptor settings
+    pub pages_reset: usize,
+}
+
+/// Result of get_recorded_events
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct RecordedEvents {
+    /// Matching events, oldest first
+    pub events: Vec<EventRecord>,
+    /// Events dropped because the recording was full
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub dropped: u64,
+}
+
+/// Kind of window event recorded for get_window_events
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WindowEventKind {
+    Moved,
+    Resized,
+    Focused,
+    ScaleFactorChanged,
+    CloseRequested,
+}
+
+/// A window event recorded by the plugin
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct WindowEventRecord {
+    pub label: String,
+    pub kind: WindowEventKind,
+    /// `{ x, y }` when moved, `{ width, height }` when resized, `{ focused }`,
+    /// `{ scale_factor, width, height }` when the scale factor changed, and
+    /// null when a close was requested; positions and sizes are physical
+    pub detail: serde_json::Value,
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub timestamp_ms: u64,
+}
+
+/// Result of get_window_events
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct WindowEvents {
+    /// Matching events, oldest first
+    pub events: Vec<WindowEventRecord>,
+    /// Events dropped because the buffer was full
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub dropped: u64,
+}
+
+/// Result of request_window_close
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct CloseRequestOutcome {
+    /// Whether the window was still open once the close grace period ran
+    /// out, as it is when the app's handler called `api.prevent_close()`
+    pub prevented: bool,
+}
+
+/// Filter for the subscribe_logs command
+#[derive(serde::Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export, optional_fields))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct LogSubscriptionFilter {
+    #[serde(flatten)]
+    pub filter: LogFilter,
+    /// Deliver events only to this window; all webviews when unset
+    #[serde(default)]
+    pub window_label: Option<String>,
+}
+
+/// Payload of a `wdio:log` event
+#[derive(serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct LogEvent {
+    pub subscription_id: String,
+    /// Per-subscription sequence number, starting at 1
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub seq: u64,
+    pub entry: LogEntry,
+}
+
+/// Payload of a `wdio:log-dropped` event, emitted at most once per second
+/// for each subscription that exceeded its rate limit
+#[derive(serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct LogDropSummary {
+    pub subscription_id: String,
+    /// Entries dropped since the previous summary
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub dropped: u64,
+}
+
+/// Outer position and size of a window in physical pixels
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct WindowBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Coordinate space for [`SetWindowBounds`]
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum CoordinateUnit {
+    /// Device pixels, matching [`WindowBounds`] as reported by list_windows
+    #[default]
+    Physical,
+    /// DPI-independent pixels (physical pixels divided by the scale factor)
+    Logical,
+}
+
+/// Bounds for the set_window_bounds command
+#[derive(serde::Deserialize, Debug, Clone, Copy)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SetWindowBounds {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<_>", optional))]
+    pub unit: CoordinateUnit,
+}
+
+/// Window details returned by list_windows and get_window_info
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct WindowInfo {
+    pub label: String,
+    pub title: String,
+    pub focused: bool,
+    pub visible: bool,
+    pub maximized: bool,
+    pub minimized: bool,
+    pub fullscreen: bool,
+    /// `None` if the platform cannot report the window geometry
+    pub bounds: Option<WindowBounds>,
+}
+
+/// Position in physical pixels
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Size in physical pixels
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct Dimensions {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Display details returned by get_monitors and get_current_monitor
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct MonitorInfo {
+    pub name: Option<String>,
+    /// Top-left corner on the virtual desktop
+    pub position: Point,
+    pub size: Dimensions,
+    /// This monitor's own scale factor, which can differ between monitors
+    pub scale_factor: f64,
+    pub is_primary: bool,
+}
+
+/// Image encoding for [`ScreenshotOptions`]
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    #[default]
+    Png,
+    Jpeg,
+}
+
+/// Options for the capture_window command
+#[derive(serde::Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export, optional_fields))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ScreenshotOptions {
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<_>", optional))]
+    pub format: ImageFormat,
+    /// JPEG quality from 0 to 100 (default 90); ignored for PNG
+    #[serde(default)]
+    pub quality: Option<u8>,
+    /// Write the image to this file and return the path instead of a data URL
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+}
+
+/// Application details returned by the get_app_info command
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AppInfo {
+    /// Package name from the Tauri config (`productName`)
+    pub name: String,
+    pub version: String,
+    pub tauri_version: String,
+    /// Whether the binary was built with debug assertions
+    pub debug: bool,
+    pub pid: u32,
+}
+
+/// An execute call waiting for its script's result, see get_pending_executions
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PendingExecution {
+    /// Event the script emits its result on
+    pub id: String,
+    pub window_label: String,
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub created_at_ms: u64,
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub age_ms: u64,
+}
+
+/// Where an executed script raised an error, relative to the script the
+/// execute command received. Lines and columns are 1-based; columns count
+/// UTF-16 code units, as JavaScript engines do.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ScriptPosition {
+    pub line: u32,
+    pub column: u32,
+    /// The offending line with a caret under the column
+    pub snippet: String,
+}
+
+/// Readiness returned by the health_check command
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct HealthStatus {
+    /// Whether any window has finished loading a page
+    pub ready: bool,
+    /// Open windows that have finished loading a page, by label
+    pub windows_loaded: Vec<String>,
+    /// Execute calls still waiting for their script's result
+    pub pending_executions: usize,
+    /// Milliseconds since the plugin was set up
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub uptime_ms: u64,
+}
+
+/// Time on the backend test clock, returned by the clock_* commands
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ClockStatus {
+    /// What `Wdio::clock` reads now, in milliseconds since the Unix epoch
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub now_ms: u64,
+    /// Whether the clock is stopped until a test advances it
+    pub frozen: bool,
+}
+
+/// Plugin build details returned by the get_plugin_capabilities command
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PluginCapabilities {
+    pub plugin_version: String,
+    /// See [`crate::PROTOCOL_VERSION`]
+    pub protocol_version: u32,
+    /// Optional capabilities compiled into this build, named after their cargo features
+    pub features: Vec<String>,
+}
+
+/// Kind of entry in a menu, see [`MenuItemInfo`]
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MenuItemType {
+    Normal,
+    Check,
+    Icon,
+    /// Built-in items such as separators, copy, or quit
+    Predefined,
+    Submenu,
+}
+
+/// A menu entry returned by get_menu_structure and trigger_menu_item
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct MenuItemInfo {
+    /// Tauri menu id; generated by Tauri for items created without one
+    pub id: String,
+    pub label: String,
+    pub kind: MenuItemType,
+    pub enabled: bool,
+    /// Checked state, only set for check items
+    pub checked: Option<bool>,
+    /// Items of a submenu
+    pub children: Vec<MenuItemInfo>,
+}
+
+/// A tray icon registered with the plugin, returned by get_tray_items
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct TrayInfo {
+    pub id: String,
+    /// Items of the tray's menu, empty when it has none
+    pub menu: Vec<MenuItemInfo>,
+}
+
+/// Mouse button for simulate_tray_click
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TrayMouseButton {
+    #[default]
+    Left,
+    Right,
+    Middle,
+}
+
+/// A global shortcut registered with the plugin, returned by list_global_shortcuts
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ShortcutInfo {
+    /// Normalized accelerator, e.g. `shift+control+KeyK`
+    pub accelerator: String,
+    /// Whether the OS registration is still active
+    pub registered: bool,
+}
+
+/// Window appearance for set_app_theme and get_system_appearance
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum AppTheme {
+    Light,
+    Dark,
+}
+
+/// App lifecycle transition for simulate_lifecycle
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum LifecycleEvent {
+    Background,
+    Foreground,
+    LowMemory,
+}
+
+/// Theme, locale, and timezone returned by get_system_appearance. `locale` is
+/// a BCP 47 tag read from `LC_ALL`/`LC_MESSAGES`/`LANG` and `timezone` an IANA
+/// name; either is `None` when the platform doesn't expose it.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SystemAppearance {
+    pub theme: AppTheme,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+}
+
+/// Tauri app directories returned by the get_app_paths command. A field is
+/// `None` when the path cannot be resolved on the current platform.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AppPaths {
+    pub app_data_dir: Option<PathBuf>,
+    pub app_config_dir: Option<PathBuf>,
+    pub app_cache_dir: Option<PathBuf>,
+    pub app_log_dir: Option<PathBuf>,
+    pub temp_dir: Option<PathBuf>,
+    pub resource_dir: Option<PathBuf>,
+    pub executable_path: Option<PathBuf>,
+}
+
+/// The fields of a `plugin:notification|notify` request that capture mode records
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export, optional_fields))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct NotifyOptions {
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<_>", optional))]
+    pub title: String,
+    pub body: Option<String>,
+}
+
+/// A notification recorded instead of shown, returned by get_captured_notifications
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct NotificationRecord {
+    pub title: String,
+    pub body: Option<String>,
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub timestamp_ms: u64,
+}
+
+/// Resource usage of the app process, returned by get_process_metrics and get_metrics_samples
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ProcessMetrics {
+    /// Resident set size
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub rss_bytes: u64,
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub virtual_bytes: u64,
+    /// CPU usage since the previous measurement; 100 = one full core
+    pub cpu_percent: f32,
+    /// Thread count (`None` where the platform does not report it)
+    pub num_threads: Option<usize>,
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub uptime_ms: u64,
+    /// Milliseconds since the Unix epoch when the measurement was taken
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub timestamp_ms: u64,
+}
+
+/// Details of the most recent backend panic
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct CrashReport {
+    /// Panic payload message
+    pub message: String,
+    /// Source location as `file:line:column`, if known
+    pub location: Option<String>,
+    pub backtrace: String,
+    /// Name of the panicking thread (`<unnamed>` if it has none)
+    pub thread: String,
+    /// Milliseconds since the Unix epoch
+    #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+    pub timestamp_ms: u64,
+}
+
+/// Kind of frontend storage for the web storage commands
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum WebStorageKind {
+    LocalStorage,
+    SessionStorage,
+    IndexedDb,
+    Cookies,
+}
+
+/// Outcome of clear_web_storage or restore_web_storage for one kind of storage
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct WebStorageResult {
+    pub kind: WebStorageKind,
+    /// Items, databases, or cookies cleared, or items, records, or cookies restored
+    pub count: usize,
+    /// Why this kind failed; the other kinds are still processed
+    pub error: Option<String>,
+}
+
+/// A page's frontend storage, taken by snapshot_web_storage for
+/// restore_web_storage. A kind is `None` when it couldn't be read, with the
+/// reason in `errors`.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct WebStorageSnapshot {
+    /// Origin of the page. Storage belongs to an origin, so the snapshot can
+    /// only be restored into a page at the same one.
+    pub origin: String,
+    pub local_storage: Option<BTreeMap<String, String>>,
+    pub session_storage: Option<BTreeMap<String, String>>,
+    /// Databases with their version, object stores, indexes, and records.
+    /// Keys and values that aren't JSON, such as dates, are tagged with `$wdio`.
+    pub indexed_db: Option<Vec<JsonValue>>,
+    /// Cookies readable by scripts, by name
+    pub cookies: Option<BTreeMap<String, String>>,
+    #[serde(default)]
+    pub errors: BTreeMap<WebStorageKind, String>,
+}
+
+/// Subscription a control server `/stream` client sends as a text message.
+/// Each kind of frame is only sent when asked for.
+#[derive(serde::Deserialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export, optional_fields))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct StreamSubscription {
+    /// Log entries matching this filter, as for subscribe_logs
+    #[serde(default)]
+    pub logs: Option<LogFilter>,
+    /// Tauri events with these names, as for start_event_recording
+    #[serde(default)]
+    pub event_names: Option<Vec<String>>,
+    /// Calls recorded while invoke tracing is on
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<_>", optional))]
+    pub invoke_trace: bool,
+}
+
+/// Frame pushed to a control server `/stream` client. Log, event, and invoke
+/// frames share one sequence per connection, so a gap means frames were dropped.
+#[derive(serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StreamFrame {
+    Log {
+        #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+        seq: u64,
+        entry: LogEntry,
+    },
+    Event {
+        #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+        seq: u64,
+        event: EventRecord,
+    },
+    InvokeTrace {
+        #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+        seq: u64,
+        entry: InvokeTraceEntry,
+    },
+    /// The client fell behind and the `count` frames before the next one were dropped
+    Dropped {
+        #[cfg_attr(feature = "export-bindings", ts(type = "number"))]
+        count: u64,
+    },
+    /// The subscription the client sent is in effect
+    Subscribed {},
+    /// The client's message wasn't a valid subscription; the previous one stays
+    Error { message: String },
+}
+
+/// A real command result captured by start_recording
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct RecordedInvocation {
+    pub command: String,
+    /// Hash of the invoke arguments that ignores key order
+    pub args_hash: String,
+    /// `ok`, or `error` when the command rejected with `result`
+    pub outcome: InvokeOutcome,
+    pub result: JsonValue,
+}
+
+/// File written by stop_recording and read by enable_replay
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct InvocationRecording {
+    pub version: u32,
+    /// Invocations in the order they settled
+    pub invocations: Vec<RecordedInvocation>,
+}
+
+/// JavaScript coverage collected from one window by collect_coverage
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct CoverageResult {
+    pub label: String,
+    /// Size of the serialized `window.__coverage__`; 0 when the page has none
+    pub bytes: usize,
+    /// File the coverage was written to, when an output path was given
+    pub path: Option<PathBuf>,
+    /// The coverage itself, when no output path was given
+    pub coverage: Option<JsonValue>,
+}
+
+/// A node of a native window's accessibility tree, from get_native_accessibility_tree
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct AccessibilityNode {
+    /// Platform role, e.g. `frame`, `menu bar`, or `push button` on Linux
+    pub role: String,
+    pub name: Option<String>,
+    /// Platform states that are set, e.g. `focused`, `enabled`
+    pub states: Vec<String>,
+    /// Children the platform reports; more than `children` holds when the
+    /// walk was pruned here
+    pub child_count: usize,
+    pub children: Vec<AccessibilityNode>,
+}
+
+/// Modifier held down for a [`KeyChord`]
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum KeyModifier {
+    Shift,
+    Control,
+    Alt,
+    /// Command on macOS, the Windows/Super key elsewhere
+    Meta,
+}
+
+/// One key press for send_native_keys, with the modifiers held around it
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct KeyChord {
+    /// A single character, or a `KeyboardEvent.key` name such as `Enter`,
+    /// `Escape`, `ArrowUp`, or `F5`
+    pub key: String,
+    #[serde(default)]
+    #[cfg_attr(feature = "export-bindings", ts(as = "Option<_>", optional))]
+    pub modifiers: Vec<KeyModifier>,
+}
+
+/// Mouse button for send_native_click
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum MouseButton {
+    #[default]
+    Left,
+    Right,
+    Middle,
+}
+
+/// Whether the calling webview may invoke one plugin command, from self_test
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct CommandPermission {
+    pub command: String,
+    pub permitted: bool,
+}
+
+/// Result of self_test
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[cfg_attr(feature = "export-bindings", derive(ts_rs::TS), ts(export))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct SelfTestReport {
+    /// Every plugin command and whether the webview's capabilities allow it;
+    /// empty when the round trip failed
+    pub commands: Vec<CommandPermission>,
+    /// Whether a script evaluated in the webview reported back to the plugin
+    pub ipc_event_roundtrip_ok: bool,
+}
+
+#[cfg(all(test, feature = "export-bindings"))]
+mod tests {
+    use ts_rs::TS;
+
+    use super::*;
+
+    /// Generated TypeScript with runs of whitespace collapsed, so the
+    /// snapshot doesn't depend on ts-rs's line breaks
+    fn bindings<T: TS + 'static>() -> String {
+        let bindings = T::export_to_string().unwrap();
+        bindings.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    #[test]
+    fn test_mock_config_bindings() {
+        let expected = r#"
+            // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+            import type { JsonValue } from "./serde_json/JsonValue";
+
+            /**
+             * Mock configuration
+             */
+            export type MockConfig = {
+            /**
+             * Command name to mock
+             */
+            command: string,
+            /**
+             * Mock return value (for mockReturnValue)
+             */
+            return_value: JsonValue | null,
+            /**
+             * Mock implementation (for mockImplementation - serialized function string)
+             */
+            implementation: string | null, };
+        "#;
+        assert_eq!(bindings::<MockConfig>(), expected.split_whitespace().collect::<Vec<_>>().join(" "));
+    }
+
+    #[test]
+    fn test_request_bindings_follow_serde_defaults() {
+        let execute = bindings::<ExecuteRequest>();
+        assert!(execute.contains("script: string,"));
+        assert!(execute.contains("args?: Array<JsonValue>,"));
+        assert!(execute.contains("window_label?: string,"));
+        assert!(execute.contains("detailed?: boolean,"));
+
+        // Flattened filter fields sit beside the subscription's own
+        let subscription = bindings::<LogSubscriptionFilter>();
+        assert!(subscription.contains("window_label?: string,"));
+        assert!(subscription.contains("level?: LogLevel,"));
+        assert!(subscription.contains("since_timestamp?: number,"));
+
+        assert!(bindings::<EventTargetSpec>().contains(r#"{ "kind": "all" } | { "kind": "label", "label": string }"#));
+        assert!(bindings::<LifecycleEvent>().contains(r#""background" | "foreground" | "low_memory""#));
+        assert!(bindings::<ProcessMetrics>().contains("timestamp_ms: number,"));
+    }
+}
+
@@ -0,0 +1,259 @@
+use std::time::Duration;
+
+use tauri::PhysicalPosition;
+
+use crate::models::{KeyChord, KeyModifier, MouseButton};
+use crate::{Error, Result};
+
+/// How long the target window gets to report focus before input is refused
+pub(crate) const FOCUS_TIMEOUT: Duration = Duration::from_millis(1_000);
+
+/// A key to press, independent of the input backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Key {
+    Char(char),
+    Enter,
+    Tab,
+    Escape,
+    Backspace,
+    Delete,
+    Space,
+    ArrowUp,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    F(u8),
+}
+
+/// Parse a [`KeyChord`] key: a single character, or a `KeyboardEvent.key` name
+pub(crate) fn parse_key(key: &str) -> Result<Key> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(Key::Char(c));
+    }
+    let named = match key {
+        "Enter" => Key::Enter,
+        "Tab" => Key::Tab,
+        "Escape" => Key::Escape,
+        "Backspace" => Key::Backspace,
+        "Delete" => Key::Delete,
+        "Space" => Key::Space,
+        "ArrowUp" => Key::ArrowUp,
+        "ArrowDown" => Key::ArrowDown,
+        "ArrowLeft" => Key::ArrowLeft,
+        "ArrowRight" => Key::ArrowRight,
+        "Home" => Key::Home,
+        "End" => Key::End,
+        "PageUp" => Key::PageUp,
+        "PageDown" => Key::PageDown,
+        _ => match key.strip_prefix('F').and_then(|n| n.parse::<u8>().ok()) {
+            Some(n @ 1..=12) => Key::F(n),
+            _ => return Err(Error::NativeInputError(format!("unknown key '{}'", key))),
+        },
+    };
+    Ok(named)
+}
+
+/// Screen position of the point `x`, `y` logical pixels from the top-left
+/// corner of a window whose outer position is `outer`, in the units the input
+/// backend moves the pointer in: points on macOS, physical pixels elsewhere
+pub(crate) fn screen_point(outer: PhysicalPosition<i32>, scale_factor: f64, x: f64, y: f64) -> (i32, i32) {
+    let physical = (outer.x as f64 + x * scale_factor, outer.y as f64 + y * scale_factor);
+    let (x, y) = if cfg!(target_os = "macos") {
+        (physical.0 / scale_factor, physical.1 / scale_factor)
+    } else {
+        physical
+    };
+    (x.round() as i32, y.round() as i32)
+}
+
+/// Fail unless this build can synthesize input
+pub(crate) fn ensure_supported() -> Result<()> {
+    if cfg!(feature = "native-input") {
+        Ok(())
+    } else {
+        Err(Error::UnsupportedInBuild(
+            "native input requires the tauri-plugin-wdio `native-input` feature".to_string(),
+        ))
+    }
+}
+
+/// Parse the keys of `chords`, so a typo is reported before anything is typed
+pub(crate) fn parse_chords(chords: &[KeyChord]) -> Result<Vec<(Vec<KeyModifier>, Key)>> {
+    chords
+        .iter()
+        .map(|chord| Ok((chord.modifiers.clone(), parse_key(&chord.key)?)))
+        .collect()
+}
+
+/// Press each chord in turn, holding its modifiers around the key
+pub(crate) fn send_keys(chords: &[(Vec<KeyModifier>, Key)]) -> Result<()> {
+    backend::send_keys(chords)
+}
+
+/// Move the pointer to `point` (from [`screen_point`]) and click `button`
+pub(crate) fn click(point: (i32, i32), button: MouseButton) -> Result<()> {
+    backend::click(point, button)
+}
+
+#[cfg(feature = "native-input")]
+mod backend {
+    use enigo::{Button, Coordinate, Direction, Enigo, Keyboard, Mouse, Settings};
+
+    use super::Key;
+    use crate::models::{KeyModifier, MouseButton};
+    use crate::{Error, Result};
+
+    fn enigo() -> Result<Enigo> {
+        Enigo::new(&Settings::default()).map_err(input_error)
+    }
+
+    fn input_error(e: impl std::fmt::Display) -> Error {
+        Error::NativeInputError(e.to_string())
+    }
+
+    fn enigo_key(key: Key) -> enigo::Key {
+        match key {
+            Key::Char(c) => enigo::Key::Unicode(c),
+            Key::Enter => enigo::Key::Return,
+            Key::Tab => enigo::Key::Tab,
+            Key::Escape => enigo::Key::Escape,
+            Key::Backspace => enigo::Key::Backspace,
+            Key::Delete => enigo::Key::Delete,
+            Key::Space => enigo::Key::Space,
+            Key::ArrowUp => enigo::Key::UpArrow,
+            Key::ArrowDown => enigo::Key::DownArrow,
+            Key::ArrowLeft => enigo::Key::LeftArrow,
+            Key::ArrowRight => enigo::Key::RightArrow,
+            Key::Home => enigo::Key::Home,
+            Key::End => enigo::Key::End,
+            Key::PageUp => enigo::Key::PageUp,
+            Key::PageDown => enigo::Key::PageDown,
+            Key::F(n) => [
+                enigo::Key::F1,
+                enigo::Key::F2,
+                enigo::Key::F3,
+                enigo::Key::F4,
+                enigo::Key::F5,
+                enigo::Key::F6,
+                enigo::Key::F7,
+                enigo::Key::F8,
+                enigo::Key::F9,
+                enigo::Key::F10,
+                enigo::Key::F11,
+                enigo::Key::F12,
+            ][usize::from(n - 1)],
+        }
+    }
+
+    fn modifier_key(modifier: KeyModifier) -> enigo::Key {
+        match modifier {
+            KeyModifier::Shift => enigo::Key::Shift,
+            KeyModifier::Control => enigo::Key::Control,
+            KeyModifier::Alt => enigo::Key::Alt,
+            KeyModifier::Meta => enigo::Key::Meta,
+        }
+    }
+
+    pub(super) fn send_keys(chords: &[(Vec<KeyModifier>, Key)]) -> Result<()> {
+        let mut enigo = enigo()?;
+        for (modifiers, key) in chords {
+            let mut held = Vec::new();
+            let mut result = Ok(());
+            for modifier in modifiers.iter().copied().map(modifier_key) {
+                result = enigo.key(modifier, Direction::Press);
+                if result.is_err() {
+                    break;
+                }
+                held.push(modifier);
+            }
+            if result.is_ok() {
+                result = enigo.key(enigo_key(*key), Direction::Click);
+            }
+            // Released even after a failure, so no modifier stays stuck down
+            for modifier in held.into_iter().rev() {
+                let released = enigo.key(modifier, Direction::Release);
+                result = result.and(released);
+            }
+            result.map_err(input_error)?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn click((x, y): (i32, i32), button: MouseButton) -> Result<()> {
+        let button = match button {
+            MouseButton::Left => Button::Left,
+            MouseButton::Right => Button::Right,
+            MouseButton::Middle => Button::Middle,
+        };
+        let mut enigo = enigo()?;
+        enigo.move_mouse(x, y, Coordinate::Abs).map_err(input_error)?;
+        enigo.button(button, Direction::Click).map_err(input_error)
+    }
+}
+
+#[cfg(not(feature = "native-input"))]
+mod backend {
+    use super::{ensure_supported, Key};
+    use crate::models::{KeyModifier, MouseButton};
+    use crate::Result;
+
+    pub(super) fn send_keys(_chords: &[(Vec<KeyModifier>, Key)]) -> Result<()> {
+        ensure_supported()
+    }
+
+    pub(super) fn click(_point: (i32, i32), _button: MouseButton) -> Result<()> {
+        ensure_supported()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_key_accepts_characters_and_key_names() {
+        assert_eq!(parse_key("s").unwrap(), Key::Char('s'));
+        assert_eq!(parse_key("ü").unwrap(), Key::Char('ü'));
+        assert_eq!(parse_key("Enter").unwrap(), Key::Enter);
+        assert_eq!(parse_key("ArrowLeft").unwrap(), Key::ArrowLeft);
+        assert_eq!(parse_key("F").unwrap(), Key::Char('F'));
+        assert_eq!(parse_key("F12").unwrap(), Key::F(12));
+
+        for unknown in ["", "F0", "F13", "enter", "Hyper"] {
+            assert!(
+                matches!(parse_key(unknown), Err(Error::NativeInputError(ref m)) if m.contains("unknown key")),
+                "{unknown}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_chords_rejects_any_unknown_key() {
+        let chords = [
+            KeyChord { key: "a".to_string(), modifiers: vec![] },
+            KeyChord { key: "Hyper".to_string(), modifiers: vec![KeyModifier::Control] },
+        ];
+
+        assert!(matches!(parse_chords(&chords), Err(Error::NativeInputError(ref m)) if m == "unknown key 'Hyper'"));
+        assert_eq!(parse_chords(&chords[..1]).unwrap(), vec![(vec![], Key::Char('a'))]);
+    }
+
+    #[test]
+    fn test_screen_point_scales_from_the_outer_position() {
+        let outer = PhysicalPosition::new(100, -40);
+
+        let point = screen_point(outer, 2.0, 10.25, 20.0);
+
+        if cfg!(target_os = "macos") {
+            assert_eq!(point, (60, 0));
+        } else {
+            assert_eq!(point, (121, 0));
+        }
+        assert_eq!(screen_point(outer, 1.0, 0.0, 0.0), (100, -40));
+    }
+}
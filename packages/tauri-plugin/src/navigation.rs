@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tauri::Url;
+use tokio::sync::watch;
+
+use crate::{Error, Result};
+
+/// Origins the bundled frontend is served from, depending on platform and protocol settings
+const APP_ORIGINS: &[&str] = &["tauri://localhost", "http://tauri.localhost", "https://tauri.localhost"];
+
+/// Per-window count of finished page loads, updated from the plugin's `on_page_load` hook
+#[derive(Clone, Default)]
+pub(crate) struct PageLoads {
+    windows: Arc<Mutex<HashMap<String, watch::Sender<u64>>>>,
+}
+
+impl PageLoads {
+    fn sender(&self, label: &str) -> watch::Sender<u64> {
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        windows
+            .entry(label.to_string())
+            .or_insert_with(|| watch::channel(0).0)
+            .clone()
+    }
+
+    /// Record a finished page load for `label`
+    pub(crate) fn finished(&self, label: &str) {
+        self.sender(label).send_modify(|count| *count += 1);
+    }
+
+    /// Number of page loads finished so far for `label`
+    pub(crate) fn count(&self, label: &str) -> u64 {
+        *self.sender(label).borrow()
+    }
+
+    /// Labels of the windows that have finished at least one page load
+    pub(crate) fn loaded(&self) -> Vec<String> {
+        let windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        windows
+            .iter()
+            .filter(|(_, loads)| *loads.borrow() > 0)
+            .map(|(label, _)| label.clone())
+            .collect()
+    }
+
+    /// Wait until more than `after` loads have finished for `label`
+    pub(crate) async fn wait_for(&self, label: &str, after: u64, timeout: Duration) -> Result<u64> {
+        let mut rx = self.sender(label).subscribe();
+        let wait = async { rx.wait_for(|count| *count > after).await.map(|count| *count) };
+        match tokio::time::timeout(timeout, wait).await {
+            Ok(Ok(count)) => Ok(count),
+            Ok(Err(_)) => Err(Error::WindowError(format!("window '{}' was closed", label))),
+            Err(_) => Err(Error::WindowError(format!(
+                "timed out after {}ms waiting for window '{}' to load",
+                timeout.as_millis(),
+                label
+            ))),
+        }
+    }
+}
+
+/// Resolve `target` against `current` (so routes like `/error` work) and check
+/// that it stays within the app: the current origin, the bundled app origins,
+/// the dev server, or an origin listed in `allowed`.
+pub(crate) fn resolve_navigation(
+    current: &Url,
+    target: &str,
+    dev_url: Option<&Url>,
+    allowed: &[String],
+) -> Result<Url> {
+    let url = Url::parse(target)
+        .or_else(|_| current.join(target))
+        .map_err(|e| Error::NavigationDenied(format!("invalid URL '{}': {}", target, e)))?;
+
+    let origin = origin_of(&url);
+    let permitted = origin.is_some()
+        && (origin == origin_of(current)
+            || dev_url.is_some_and(|dev| origin == origin_of(dev))
+            || APP_ORIGINS
+                .iter()
+                .copied()
+                .chain(allowed.iter().map(String::as_str))
+                .filter_map(|o| Url::parse(o).ok())
+                .any(|o| origin == origin_of(&o)));
+
+    if permitted {
+        Ok(url)
+    } else {
+        Err(Error::NavigationDenied(format!(
+            "'{}' is outside the app's origins; add it to WdioConfig::navigation_allowlist to allow it",
+            url
+        )))
+    }
+}
+
+/// (scheme, host, port) for URLs with a host; `None` for data:, javascript:, about: etc.
+fn origin_of(url: &Url) -> Option<(String, String, Option<u16>)> {
+    url.host_str().map(|host| {
+        (
+            url.scheme().to_string(),
+            host.to_string(),
+            url.port_or_known_default(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(s: &str) -> Url {
+        Url::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_relative_and_same_origin_targets_are_allowed() {
+        let current = url("tauri://localhost/index.html");
+
+        assert_eq!(
+            resolve_navigation(&current, "/error", None, &[]).unwrap().as_str(),
+            "tauri://localhost/error"
+        );
+        assert!(resolve_navigation(&current, "http://tauri.localhost/settings", None, &[]).is_ok());
+    }
+
+    #[test]
+    fn test_dev_url_and_allowlist_are_allowed() {
+        let current = url("http://localhost:1420/");
+        let dev = url("http://localhost:1420");
+        let allowed = vec!["https://example.com".to_string()];
+
+        assert!(resolve_navigation(&current, "http://localhost:1420/page", Some(&dev), &[]).is_ok());
+        assert!(resolve_navigation(&current, "https://example.com/a", None, &allowed).is_ok());
+    }
+
+    #[test]
+    fn test_foreign_and_opaque_origins_are_denied() {
+        let current = url("tauri://localhost/");
+
+        for target in ["https://evil.example/", "javascript:alert(1)", "data:text/html,hi", "http://localhost:9999/"] {
+            assert!(
+                matches!(
+                    resolve_navigation(&current, target, None, &[]),
+                    Err(Error::NavigationDenied(_))
+                ),
+                "{} should be denied",
+                target
+            );
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use crate::log_buffer::now_ms;
+use crate::models::{NotificationRecord, NotifyOptions};
+
+/// Notifications recorded while capture mode is on. The guest-js invoke
+/// interceptor asks [`NotificationCapture::capture`] before forwarding
+/// `plugin:notification|notify`, so when capture is off the invoke reaches
+/// the notification plugin untouched.
+#[derive(Default)]
+pub(crate) struct NotificationCapture {
+    enabled: AtomicBool,
+    records: Mutex<Vec<NotificationRecord>>,
+}
+
+impl NotificationCapture {
+    pub(crate) fn new(enabled: bool) -> Self {
+        Self {
+            enabled: AtomicBool::new(enabled),
+            records: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Record the notification if capture is on; returns whether it was
+    /// captured (and must therefore not be shown)
+    pub(crate) fn capture(&self, options: NotifyOptions) -> bool {
+        if !self.enabled.load(Ordering::SeqCst) {
+            return false;
+        }
+        self.records
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(NotificationRecord {
+                title: options.title,
+                body: options.body,
+                timestamp_ms: now_ms(),
+            });
+        true
+    }
+
+    /// Captured notifications, oldest first
+    pub(crate) fn records(&self) -> Vec<NotificationRecord> {
+        self.records.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Remove all captured notifications, returning how many were dropped
+    pub(crate) fn clear(&self) -> usize {
+        let mut records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        let count = records.len();
+        records.clear();
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notify(title: &str) -> NotifyOptions {
+        NotifyOptions {
+            title: title.to_string(),
+            body: Some("body".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_records_only_while_enabled() {
+        let capture = NotificationCapture::new(false);
+        assert!(!capture.capture(notify("ignored")));
+
+        capture.set_enabled(true);
+        assert!(capture.capture(notify("first")));
+        assert!(capture.capture(notify("second")));
+
+        let titles: Vec<String> = capture.records().into_iter().map(|r| r.title).collect();
+        assert_eq!(titles, vec!["first", "second"]);
+        assert_eq!(capture.clear(), 2);
+        assert!(capture.records().is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+use std::io::Write;
+
+use serde_json::Value as JsonValue;
+
+use crate::{Error, Result};
+
+/// Bytes `value` takes serialized as JSON, counted without building the text
+pub(crate) fn json_size(value: &JsonValue) -> usize {
+    let mut counter = ByteCounter(0);
+    // Writing to the counter can't fail, and a JsonValue always serializes
+    let _ = serde_json::to_writer(&mut counter, value);
+    counter.0
+}
+
+/// Fail with [`Error::PayloadTooLarge`] if `value` serializes to more than `limit` bytes
+pub(crate) fn check(value: &JsonValue, limit: usize) -> Result<()> {
+    check_size(json_size(value), limit)
+}
+
+/// Fail with [`Error::PayloadTooLarge`] if `actual` bytes is over `limit`
+pub(crate) fn check_size(actual: usize, limit: usize) -> Result<()> {
+    if actual > limit {
+        return Err(Error::PayloadTooLarge { actual, limit });
+    }
+    Ok(())
+}
+
+/// Cut `text` down to `limit` bytes, ending it with a marker naming how
+/// many bytes were cut; returns whether it was over the limit
+pub(crate) fn truncate_marked(text: &mut String, limit: usize) -> bool {
+    let length = text.len();
+    if length <= limit {
+        return false;
+    }
+    truncate_at_char_boundary(text, limit);
+    let cut = length - text.len();
+    text.push_str(&format!("… [truncated {} bytes]", cut));
+    true
+}
+
+pub(crate) fn truncate_at_char_boundary(text: &mut String, max_bytes: usize) {
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+}
+
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_counts_serialized_bytes() {
+        let value = json!({ "text": "é", "list": [1, null] });
+
+        assert_eq!(json_size(&value), serde_json::to_vec(&value).unwrap().len());
+    }
+
+    #[test]
+    fn test_check_carries_the_sizes() {
+        let value = json!("0123456789");
+
+        assert!(check(&value, 12).is_ok());
+        assert!(matches!(
+            check(&value, 11),
+            Err(Error::PayloadTooLarge { actual: 12, limit: 11 })
+        ));
+    }
+
+    #[test]
+    fn test_truncate_marked_cuts_on_a_char_boundary() {
+        let mut text = "aéb".to_string();
+        assert!(truncate_marked(&mut text, 2));
+        assert_eq!(text, "a… [truncated 3 bytes]");
+
+        let mut text = "aé".to_string();
+        truncate_at_char_boundary(&mut text, 2);
+        assert_eq!(text, "a");
+
+        let mut short = "ab".to_string();
+        assert!(!truncate_marked(&mut short, 2));
+        assert_eq!(short, "ab");
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::Mutex;
+
+/// JavaScript the execute command runs before every script, in the same
+/// scope, so helpers it declares are visible to the script
+pub(crate) struct ExecutePreamble {
+    script: Mutex<Option<String>>,
+}
+
+impl ExecutePreamble {
+    pub(crate) fn new(script: Option<String>) -> Self {
+        Self {
+            script: Mutex::new(script.filter(|s| !s.trim().is_empty())),
+        }
+    }
+
+    pub(crate) fn set(&self, script: String) {
+        *self.script.lock().unwrap_or_else(|e| e.into_inner()) = Some(script).filter(|s| !s.trim().is_empty());
+    }
+
+    pub(crate) fn clear(&self) {
+        *self.script.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// Go back to `script`, the configured preamble; returns whether the
+    /// preamble in use was a different one
+    pub(crate) fn reset(&self, script: Option<String>) -> bool {
+        let script = script.filter(|s| !s.trim().is_empty());
+        let mut current = self.script.lock().unwrap_or_else(|e| e.into_inner());
+        std::mem::replace(&mut *current, script.clone()) != script
+    }
+
+    pub(crate) fn get(&self) -> Option<String> {
+        self.script.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+/// Statements placed in the execute wrapper's try block ahead of the user
+/// script. `__wdio_in_preamble` lets the wrapper's catch tell a throwing
+/// preamble from a throwing script.
+pub(crate) fn preamble_block(preamble: Option<&str>) -> String {
+    match preamble {
+        Some(preamble) => format!("__wdio_in_preamble = true;\n{}\n;__wdio_in_preamble = false;", preamble),
+        None => String::new(),
+    }
+}
+
+/// A syntax error in the preamble stops the whole wrapper from parsing, so
+/// nothing would report back. This script, evaluated before the wrapper,
+/// sets `flag` only if the preamble parses on its own; the page's CSP may
+/// forbid checking it with `eval` or `new Function`.
+pub(crate) fn probe_script(flag: &str, preamble: &str) -> String {
+    format!("window['{}'] = true;\n(function () {{\n{}\n}});", flag, preamble)
+}
+
+/// Evaluated after the wrapper: fails the execution with a preamble error
+/// if the probe never set `flag`. `report` is the body of the wrapper's
+/// `__wdio_report(report)` helper.
+pub(crate) fn fallback_script(flag: &str, report: &str) -> String {
+    format!(
+        r#"
+        (async () => {{
+            if (window['{flag}']) {{
+                delete window['{flag}'];
+                return;
+            }}
+            async function __wdio_report(report) {{
+                {report}
+            }}
+            await __wdio_report({{ success: false, preamble_error: 'the preamble has a syntax error' }});
+        }})();
+        "#,
+        flag = flag,
+        report = report
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blank_preamble_counts_as_none() {
+        let preamble = ExecutePreamble::new(Some("  ".to_string()));
+        assert_eq!(preamble.get(), None);
+
+        preamble.set("function double(x) { return x * 2; }".to_string());
+        assert!(preamble.get().is_some());
+        preamble.set("\n".to_string());
+        assert_eq!(preamble.get(), None);
+
+        preamble.set("const a = 1;".to_string());
+        preamble.clear();
+        assert_eq!(preamble.get(), None);
+    }
+
+    #[test]
+    fn test_preamble_precedes_script_with_injected_args() {
+        // guest-js applies the args itself, so the preamble only has to come
+        // first in the same block for the script to see its helpers
+        let script = "(async function() { return double(arguments[0]); }).apply(null, [21])";
+        let block = preamble_block(Some("function double(x) { return x * 2; }"));
+        let body = format!("{}\nconst __wdio_script = ({});", block, script);
+
+        let helper = body.find("function double").unwrap();
+        let call = body.find(".apply(null, [21])").unwrap();
+        assert!(helper < call);
+        assert!(block.starts_with("__wdio_in_preamble = true;"));
+        assert!(block.ends_with("__wdio_in_preamble = false;"));
+        assert_eq!(preamble_block(None), "");
+    }
+
+    #[test]
+    fn test_probe_wraps_preamble_without_running_it() {
+        let probe = probe_script("wdio-result-1:preamble", "alert(1)");
+        assert!(probe.starts_with("window['wdio-result-1:preamble'] = true;"));
+        assert!(probe.contains("(function () {\nalert(1)\n});"));
+
+        let fallback = fallback_script("wdio-result-1:preamble", "await send(report);");
+        assert!(fallback.contains("if (window['wdio-result-1:preamble'])"));
+        assert!(fallback.contains("preamble_error"));
+    }
+}
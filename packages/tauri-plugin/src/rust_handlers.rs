@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value as JsonValue;
+use tauri::{AppHandle, Runtime};
+
+use crate::crash::panic_message;
+use crate::{Error, Result};
+
+pub(crate) type RustHandler<R> = Arc<dyn Fn(&AppHandle<R>, JsonValue) -> Result<JsonValue> + Send + Sync>;
+
+/// Named backend callbacks the app exposes to tests through execute_rust
+pub(crate) struct RustHandlers<R: Runtime> {
+    handlers: Mutex<BTreeMap<String, RustHandler<R>>>,
+}
+
+impl<R: Runtime> RustHandlers<R> {
+    pub(crate) fn new() -> Self {
+        Self {
+            handlers: Mutex::default(),
+        }
+    }
+
+    /// Add a handler, replacing any previous one with the same name
+    pub(crate) fn register(&self, name: String, handler: RustHandler<R>) {
+        self.handlers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(name, handler);
+    }
+
+    /// The handler for `name`. The registry lock is released before it
+    /// returns, so the handler can register others or take its own locks.
+    pub(crate) fn get(&self, name: &str) -> Result<RustHandler<R>> {
+        self.handlers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(name)
+            .cloned()
+            .ok_or_else(|| Error::RustHandlerNotFound(name.to_string()))
+    }
+}
+
+/// Run the handler registered as `name`, turning a panic into an error so a
+/// broken test hook fails the call instead of taking down the command task
+pub(crate) fn guarded(name: &str, handler: impl FnOnce() -> Result<JsonValue>) -> Result<JsonValue> {
+    catch_unwind(AssertUnwindSafe(handler)).unwrap_or_else(|payload| {
+        Err(Error::RustHandlerPanicked(
+            name.to_string(),
+            panic_message(payload.as_ref()),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_by_name() {
+        let handlers = RustHandlers::<tauri::Wry>::new();
+        handlers.register("echo".to_string(), Arc::new(|_, args| Ok(args)));
+
+        assert!(handlers.get("echo").is_ok());
+        assert!(matches!(
+            handlers.get("missing"),
+            Err(Error::RustHandlerNotFound(name)) if name == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_panic_becomes_an_error() {
+        assert_eq!(guarded("ok", || Ok(serde_json::json!(1))).unwrap(), 1);
+        assert!(matches!(
+            guarded("flush", || Err(Error::RustHandlerNotFound("inner".to_string()))),
+            Err(Error::RustHandlerNotFound(name)) if name == "inner"
+        ));
+        assert!(matches!(
+            guarded("flush", || panic!("queue poisoned")),
+            Err(Error::RustHandlerPanicked(name, message)) if name == "flush" && message == "queue poisoned"
+        ));
+    }
+}
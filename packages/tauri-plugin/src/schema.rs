@@ -0,0 +1,235 @@
+use serde_json::Value as JsonValue;
+
+use crate::Result;
+
+#[cfg(feature = "json-schema")]
+pub(crate) use generated::command_schemas;
+
+#[cfg(not(feature = "json-schema"))]
+pub(crate) use unsupported::command_schemas;
+
+#[cfg(feature = "json-schema")]
+mod generated {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    use schemars::gen::{SchemaGenerator, SchemaSettings};
+    use schemars::schema::Schema;
+    use schemars::JsonSchema;
+
+    use super::*;
+    use crate::commands::WindowState;
+    use crate::models::*;
+
+    /// Lists every command as `name(arg: Type, ...) -> Response;`, mirroring
+    /// its signature in commands.rs minus the arguments Tauri injects. Each
+    /// command's arguments become a struct, camelCased as Tauri names them
+    /// over IPC, so optional ones come out as not required.
+    macro_rules! command_table {
+        ($($name:ident($($arg:ident: $ty:ty),* $(,)?) -> $response:ty;)*) => {
+            fn schemas(generator: &mut SchemaGenerator) -> Vec<(&'static str, Schema, Schema)> {
+                vec![$({
+                    #[derive(JsonSchema)]
+                    #[schemars(rename_all = "camelCase")]
+                    #[allow(dead_code)]
+                    struct Request {
+                        $($arg: $ty),*
+                    }
+                    (stringify!($name), Request::json_schema(generator), generator.subschema_for::<$response>())
+                }),*]
+            }
+        };
+    }
+
+    command_table! {
+        execute(request: ExecuteRequest) -> JsonValue;
+        log_frontend(message: String, level: String) -> String;
+        debug_plugin() -> String;
+        get_active_window_label() -> String;
+        get_window_states() -> Vec<WindowState>;
+        list_windows() -> Vec<WindowInfo>;
+        get_window_info(label: String) -> WindowInfo;
+        focus_window(label: String) -> ();
+        set_window_bounds(label: String, bounds: SetWindowBounds) -> ();
+        maximize_window(label: String) -> ();
+        minimize_window(label: String) -> ();
+        restore_window(label: String) -> ();
+        set_fullscreen(label: String, fullscreen: bool) -> ();
+        set_always_on_top(label: String, always_on_top: bool) -> ();
+        capture_window(label: String, options: Option<ScreenshotOptions>) -> String;
+        get_monitors() -> Vec<MonitorInfo>;
+        get_current_monitor(label: String) -> Option<MonitorInfo>;
+        open_devtools(label: String) -> ();
+        close_devtools(label: String) -> ();
+        is_devtools_open(label: String) -> bool;
+        navigate(label: String, url: String) -> u64;
+        reload(label: String) -> u64;
+        wait_for_load(label: String, timeout_ms: Option<u64>, after: Option<u64>) -> u64;
+        get_logs(filter: Option<LogFilter>) -> Vec<LogEntry>;
+        clear_logs() -> usize;
+        get_last_crash() -> Option<CrashReport>;
+        subscribe_logs(filter: Option<LogSubscriptionFilter>) -> String;
+        unsubscribe_logs(subscription_id: String) -> bool;
+        get_log_file_path() -> Option<String>;
+        set_log_context(context: String) -> ();
+        clear_log_context() -> ();
+        get_app_info() -> AppInfo;
+        get_app_paths() -> AppPaths;
+        quit_app(exit_code: Option<i32>, delay_ms: Option<u64>) -> ();
+        restart_app(delay_ms: Option<u64>) -> ();
+        get_process_metrics() -> ProcessMetrics;
+        start_metrics_sampling(interval_ms: Option<u64>) -> ();
+        stop_metrics_sampling() -> usize;
+        get_metrics_samples() -> Vec<ProcessMetrics>;
+        get_env(names: Option<Vec<String>>) -> BTreeMap<String, String>;
+        get_cli_args() -> Vec<String>;
+        capture_notification(options: NotifyOptions) -> bool;
+        enable_notification_capture() -> ();
+        disable_notification_capture() -> ();
+        get_captured_notifications() -> Vec<NotificationRecord>;
+        clear_captured_notifications() -> usize;
+        get_clipboard_text() -> Option<String>;
+        set_clipboard_text(text: String) -> ();
+        get_clipboard_image() -> Option<String>;
+        set_clipboard_image(image: String) -> ();
+        get_menu_structure(label: Option<String>) -> Vec<MenuItemInfo>;
+        trigger_menu_item(id: Option<String>, path: Option<Vec<String>>, label: Option<String>) -> MenuItemInfo;
+        get_tray_items() -> Vec<TrayInfo>;
+        trigger_tray_menu_item(tray_id: Option<String>, id: Option<String>, path: Option<Vec<String>>) -> MenuItemInfo;
+        simulate_tray_click(tray_id: Option<String>, button: Option<TrayMouseButton>, position: Option<Point>) -> ();
+        list_global_shortcuts() -> Vec<ShortcutInfo>;
+        trigger_global_shortcut(accelerator: String) -> ();
+        simulate_file_drop(label: String, paths: Vec<String>, position: Option<Point>, allow_missing: Option<bool>) -> ();
+        simulate_deep_link(url: String) -> ();
+        get_app_state(name: String) -> JsonValue;
+        start_event_recording(event_names: Option<Vec<String>>) -> ();
+        stop_event_recording() -> usize;
+        get_recorded_events(filter: Option<EventRecordFilter>) -> RecordedEvents;
+        emit_event(name: String, payload: Option<JsonValue>, target: Option<EventTargetSpec>) -> ();
+        get_plugin_capabilities() -> PluginCapabilities;
+        is_enabled() -> bool;
+        get_pending_executions() -> Vec<PendingExecution>;
+        resolve_execute(id: String, report: JsonValue) -> ();
+        set_execute_preamble(script: String) -> ();
+        clear_execute_preamble() -> ();
+        start_invoke_tracing(args_max_bytes: Option<usize>) -> ();
+        stop_invoke_tracing() -> usize;
+        get_invoke_trace() -> InvokeTrace;
+        record_invoke(entry: InvokeTraceEntry) -> bool;
+        export_invoke_trace(format: Option<TraceExportFormat>, path: Option<PathBuf>) -> InvokeTraceExport;
+        get_system_appearance() -> SystemAppearance;
+        set_app_theme(theme: Option<AppTheme>) -> ();
+        simulate_lifecycle(event: LifecycleEvent, label: Option<String>) -> ();
+        begin_session(session_id: String) -> ();
+        end_session(session_id: String) -> EndedSession;
+        health_check() -> HealthStatus;
+        execute_rust(name: String, args: Option<JsonValue>) -> JsonValue;
+        clock_freeze(at_ms: Option<u64>) -> ClockStatus;
+        clock_advance(ms: u64) -> ClockStatus;
+        clock_reset() -> ClockStatus;
+        set_invoke_conditions(conditions: InvokeConditions) -> ();
+        clear_invoke_conditions() -> bool;
+        clear_web_storage(label: String, kinds: Option<Vec<WebStorageKind>>) -> Vec<WebStorageResult>;
+        snapshot_web_storage(label: String) -> WebStorageSnapshot;
+        restore_web_storage(label: String, snapshot: WebStorageSnapshot) -> Vec<WebStorageResult>;
+        start_recording(path: PathBuf) -> ();
+        stop_recording() -> usize;
+        record_invocation(command: String, args: JsonValue, outcome: InvokeOutcome, result: JsonValue) -> bool;
+        enable_replay(path: PathBuf, strict: bool) -> usize;
+        disable_replay() -> bool;
+        replay_invocation(command: String, args: JsonValue) -> Option<RecordedInvocation>;
+        collect_coverage(label: String, output_path: Option<PathBuf>) -> CoverageResult;
+        coverage_chunk(id: String, index: usize, data: String) -> ();
+        wait_for_event(name: String, timeout_ms: u64, payload_filter: Option<JsonValue>) -> JsonValue;
+        wait_for_window(label: String, timeout_ms: Option<u64>) -> WindowInfo;
+        wait_for_window_close(label: String, timeout_ms: Option<u64>) -> ();
+        get_native_accessibility_tree(label: String, max_depth: Option<u32>) -> AccessibilityNode;
+        send_native_keys(label: String, keys: Vec<KeyChord>) -> ();
+        send_native_click(label: String, x: f64, y: f64, button: Option<MouseButton>) -> ();
+        self_test() -> SelfTestReport;
+        self_test_report(nonce: String, permitted: Vec<bool>) -> ();
+        reset_all() -> ResetSummary;
+        get_window_events(label: Option<String>, since: Option<u64>) -> WindowEvents;
+        clear_window_events() -> usize;
+        request_window_close(label: String) -> CloseRequestOutcome;
+        force_close_window(label: String) -> ();
+        get_command_schemas() -> JsonValue;
+    }
+
+    /// JSON Schemas of every command: `{ protocol_version, commands, definitions }`,
+    /// where `commands` maps each command name to its `request_schema`,
+    /// `response_schema`, and `permission`, and `$ref`s point into the shared
+    /// draft-07 `definitions`
+    pub(crate) fn command_schemas() -> Result<JsonValue> {
+        let mut generator = SchemaSettings::draft07().into_generator();
+        let commands: serde_json::Map<String, JsonValue> = schemas(&mut generator)
+            .into_iter()
+            .map(|(name, request_schema, response_schema)| {
+                let entry = serde_json::json!({
+                    "request_schema": request_schema,
+                    "response_schema": response_schema,
+                    "permission": format!("wdio:allow-{}", name.replace('_', "-")),
+                });
+                (name.to_string(), entry)
+            })
+            .collect();
+        Ok(serde_json::json!({
+            "protocol_version": crate::PROTOCOL_VERSION,
+            "commands": commands,
+            "definitions": generator.take_definitions(),
+        }))
+    }
+}
+
+#[cfg(not(feature = "json-schema"))]
+mod unsupported {
+    use super::*;
+    use crate::Error;
+
+    pub(crate) fn command_schemas() -> Result<JsonValue> {
+        Err(Error::UnsupportedInBuild(
+            "command schemas require the tauri-plugin-wdio `json-schema` feature".to_string(),
+        ))
+    }
+}
+
+#[cfg(all(test, feature = "json-schema"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::self_test::COMMANDS;
+
+    #[test]
+    fn test_every_command_has_a_schema() {
+        let schemas = command_schemas().unwrap();
+        let commands = schemas["commands"].as_object().unwrap();
+
+        let missing: Vec<&&str> = COMMANDS.iter().filter(|name| !commands.contains_key(**name)).collect();
+        let unknown: Vec<&String> = commands.keys().filter(|name| !COMMANDS.contains(&name.as_str())).collect();
+        assert!(missing.is_empty(), "commands without a schema: {:?}", missing);
+        assert!(unknown.is_empty(), "schemas for unknown commands: {:?}", unknown);
+    }
+
+    #[test]
+    fn test_describes_arguments_response_and_permission() {
+        let schemas = command_schemas().unwrap();
+        let wait_for_load = &schemas["commands"]["wait_for_load"];
+
+        assert_eq!(schemas["protocol_version"], json!(crate::PROTOCOL_VERSION));
+        assert_eq!(wait_for_load["permission"], json!("wdio:allow-wait-for-load"));
+        assert_eq!(wait_for_load["request_schema"]["required"], json!(["label"]));
+        let properties = wait_for_load["request_schema"]["properties"].as_object().unwrap();
+        assert_eq!(properties.keys().collect::<Vec<_>>(), vec!["after", "label", "timeoutMs"]);
+        assert_eq!(wait_for_load["response_schema"]["type"], json!("integer"));
+    }
+
+    #[test]
+    fn test_refs_resolve_in_the_definitions() {
+        let schemas = command_schemas().unwrap();
+
+        let reference = &schemas["commands"]["get_window_info"]["response_schema"]["$ref"];
+        assert_eq!(reference, &json!("#/definitions/WindowInfo"));
+        assert!(schemas["definitions"]["WindowInfo"]["properties"]["label"].is_object());
+    }
+}
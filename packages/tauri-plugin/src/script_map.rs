@@ -0,0 +1,217 @@
+use crate::models::ScriptPosition;
+use crate::Error;
+
+/// Lines `new Function` puts ahead of the body it compiles
+/// (`function anonymous(\n) {\n`), which its syntax error positions count
+pub(crate) const FUNCTION_HEADER_LINES: u32 = 2;
+
+/// Where a script sits in the source generated around it, so positions the
+/// engine reports in the generated source can be given relative to the script
+pub(crate) struct ScriptMap<'a> {
+    script: &'a str,
+    /// 1-based line of the generated source the script starts on
+    line: u32,
+    /// 1-based column, in UTF-16 code units, the script starts at on that line
+    column: u32,
+}
+
+impl<'a> ScriptMap<'a> {
+    /// Map `script`, which follows `prefix` in the generated source
+    pub(crate) fn new(script: &'a str, prefix: &str) -> Self {
+        let last_line = prefix.rsplit('\n').next().unwrap_or_default();
+        Self {
+            script,
+            line: prefix.matches('\n').count() as u32 + 1,
+            column: last_line.encode_utf16().count() as u32 + 1,
+        }
+    }
+
+    /// Position in the script of `line`, `column` in the generated source, or
+    /// None outside the script. A column past the end of a line, as for a
+    /// script cut short, points just after the line's last character.
+    pub(crate) fn locate(&self, line: u32, column: u32) -> Option<ScriptPosition> {
+        let index = line.checked_sub(self.line)?;
+        let column = match index {
+            0 => column.checked_sub(self.column - 1).filter(|column| *column > 0)?,
+            _ => column,
+        };
+        let text = self.script.split('\n').nth(index as usize)?.trim_end_matches('\r');
+        let column = column.min(text.encode_utf16().count() as u32 + 1);
+        Some(ScriptPosition {
+            line: index + 1,
+            column,
+            snippet: snippet(text, column),
+        })
+    }
+
+    /// Give a script error's position relative to the script, dropping it
+    /// when it's outside; other errors are returned as they are
+    pub(crate) fn map_error(&self, error: Error) -> Error {
+        match error {
+            Error::ScriptError { message, position } => Error::ScriptError {
+                message,
+                position: position.and_then(|position| self.locate(position.line, position.column)),
+            },
+            error => error,
+        }
+    }
+}
+
+/// `text` with a caret under `column` on the line below, indented with the
+/// line's own tabs so it lines up
+fn snippet(text: &str, column: u32) -> String {
+    let mut units = 0;
+    let indent: String = text
+        .chars()
+        .take_while(|c| {
+            let start = units;
+            units += c.len_utf16() as u32;
+            start + 1 < column
+        })
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    format!("{}\n{}^", text, indent)
+}
+
+/// Evaluated after the execute wrapper. A wrapper that didn't parse never
+/// set `parsed_flag`, so this compiles `source`, the wrapper, without running
+/// it and reports the syntax error; the page's CSP may forbid that, leaving
+/// only the message. A preamble that didn't parse is left to the preamble's
+/// fallback. `report` is the body of the wrapper's `__wdio_report(report)`.
+pub(crate) fn syntax_check_script(parsed_flag: &str, preamble_flag: Option<&str>, source: &str, report: &str) -> String {
+    let preamble_failed = match preamble_flag {
+        Some(flag) => format!("!window['{}']", flag),
+        None => "false".to_string(),
+    };
+    format!(
+        r#"
+        (async () => {{
+            if (window['{parsed}']) {{
+                delete window['{parsed}'];
+                return;
+            }}
+            if ({preamble_failed}) {{
+                return;
+            }}
+            async function __wdio_report(report) {{
+                {report}
+            }}
+            let syntaxError = null;
+            try {{
+                new Function({source});
+            }} catch (e) {{
+                syntaxError = e instanceof SyntaxError ? e : null;
+            }}
+            await __wdio_report({{
+                success: false,
+                error: syntaxError?.message || 'the script has a syntax error',
+                syntax: true,
+                line: syntaxError?.line ?? null,
+                column: syntaxError?.column ?? null,
+            }});
+        }})();
+        "#,
+        parsed = parsed_flag,
+        preamble_failed = preamble_failed,
+        report = report,
+        source = serde_json::Value::from(source)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::executions::result_from_report;
+
+    const PREFIX: &str = "(async () => {\n    const __wdio_script = (";
+
+    fn located(map: &ScriptMap, line: u32, column: u32) -> Option<(u32, u32, String)> {
+        map.locate(line, column)
+            .map(|position| (position.line, position.column, position.snippet))
+    }
+
+    #[test]
+    fn test_locate_offsets_only_the_first_line_by_the_prefix() {
+        let map = ScriptMap::new("const a = 1;\nreturn a.b.c;", PREFIX);
+
+        assert_eq!(located(&map, 2, 34), Some((1, 7, "const a = 1;\n      ^".to_string())));
+        assert_eq!(located(&map, 3, 10), Some((2, 10, "return a.b.c;\n         ^".to_string())));
+        // Before the script, or past its last line
+        assert_eq!(located(&map, 1, 5), None);
+        assert_eq!(located(&map, 2, 26), None);
+        assert_eq!(located(&map, 4, 1), None);
+    }
+
+    #[test]
+    fn test_locate_clamps_to_the_end_of_the_line() {
+        let map = ScriptMap::new("if (x) {", "");
+
+        assert_eq!(located(&map, 1, 14), Some((1, 9, "if (x) {\n        ^".to_string())));
+    }
+
+    #[test]
+    fn test_snippet_counts_utf16_columns_and_keeps_tabs() {
+        // 🚀 takes two UTF-16 code units, so y is at column 13
+        let map = ScriptMap::new("\tx = '🚀' + y;\r\n", "");
+
+        assert_eq!(located(&map, 1, 13), Some((1, 13, "\tx = '🚀' + y;\n\t          ^".to_string())));
+    }
+
+    #[test]
+    fn test_maps_runtime_errors_reported_from_the_stack() {
+        let script = "const list = null;\nreturn list.length;";
+        let map = ScriptMap::new(script, "(async () => {\n  try {\n    const s = ((async () => { ");
+        // The wrapper's catch reads the position off the stack frame naming its sourceURL
+        let report = json!({ "success": false, "error": "list is null", "line": 4, "column": 12 });
+
+        let error = map.map_error(result_from_report(&report).unwrap().unwrap_err());
+
+        assert!(matches!(
+            error,
+            Error::ScriptError { ref message, position: Some(ref position) }
+                if message == "list is null"
+                    && (position.line, position.column) == (2, 12)
+                    && position.snippet == "return list.length;\n           ^"
+        ));
+    }
+
+    #[test]
+    fn test_maps_syntax_errors_reported_by_the_syntax_check() {
+        let script = "const a = ;";
+        let map = ScriptMap::new(script, "(async () => {\n    const s = ((async () => { ");
+        // Compiled with new Function, whose header adds two lines
+        let report = json!({ "success": false, "error": "Unexpected token ';'", "syntax": true, "line": 4, "column": 41 });
+
+        let error = map.map_error(result_from_report(&report).unwrap().unwrap_err());
+
+        assert!(matches!(
+            error,
+            Error::ScriptError { position: Some(ref position), .. }
+                if (position.line, position.column) == (1, 11) && position.snippet == "const a = ;\n          ^"
+        ));
+    }
+
+    #[test]
+    fn test_map_error_drops_positions_outside_the_script() {
+        let map = ScriptMap::new("1 + 1", PREFIX);
+        let report = json!({ "success": false, "error": "Tauri core.invoke not available after timeout", "line": 1, "column": 3 });
+
+        let error = map.map_error(result_from_report(&report).unwrap().unwrap_err());
+
+        assert!(matches!(error, Error::ScriptError { position: None, .. }));
+        assert!(matches!(map.map_error(Error::ExecuteTimeout("t".to_string())), Error::ExecuteTimeout(_)));
+    }
+
+    #[test]
+    fn test_syntax_check_compiles_the_wrapper_only_when_it_did_not_run() {
+        let check = syntax_check_script("wdio-result-1:parsed", Some("wdio-result-1:preamble"), "a 'b'\n", "send(report);");
+
+        assert!(check.contains("if (window['wdio-result-1:parsed'])"));
+        assert!(check.contains("if (!window['wdio-result-1:preamble'])"));
+        assert!(check.contains(r#"new Function("a 'b'\n");"#));
+        assert!(check.contains("syntax: true"));
+        assert!(syntax_check_script("f", None, "", "").contains("if (false)"));
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde_json::Value as JsonValue;
+use tokio::sync::oneshot;
+
+use crate::models::{CommandPermission, SelfTestReport};
+
+macro_rules! command_names {
+    ($($name:ident),* $(,)?) => {
+        &[$(stringify!($name)),*]
+    };
+}
+
+/// Every command the plugin registers, in src/command_list.rs order
+pub(crate) const COMMANDS: &[&str] = wdio_commands!(command_names);
+
+/// Header marking an invoke as a self_test probe: the plugin resolves it
+/// without running the command, once Tauri's ACL has let it through
+pub(crate) const PROBE_HEADER: &str = "x-wdio-probe";
+
+/// How long the probing script gets to report back
+pub(crate) const ROUNDTRIP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Self tests waiting for their probing script to report, by nonce
+#[derive(Default)]
+pub(crate) struct PendingSelfTests {
+    pending: Mutex<HashMap<String, oneshot::Sender<Vec<bool>>>>,
+}
+
+impl PendingSelfTests {
+    /// Start waiting for the report of the self test `nonce`
+    pub(crate) fn begin(&self, nonce: &str) -> oneshot::Receiver<Vec<bool>> {
+        let (tx, rx) = oneshot::channel();
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        pending.insert(nonce.to_string(), tx);
+        rx
+    }
+
+    /// Hand the probe outcomes to the self test `nonce`; false if none is waiting
+    pub(crate) fn finish(&self, nonce: &str, permitted: Vec<bool>) -> bool {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        pending.remove(nonce).is_some_and(|tx| tx.send(permitted).is_ok())
+    }
+
+    /// Stop waiting for the self test `nonce`, after it timed out
+    pub(crate) fn cancel(&self, nonce: &str) {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner()).remove(nonce);
+    }
+}
+
+/// Async function that probes every command from the page
+const PROBE_SCRIPT: &str = include_str!("js/self_test.js");
+
+/// Script that probes `COMMANDS` from the page it's evaluated in and reports
+/// the outcomes to self_test_report under `nonce`
+pub(crate) fn probe_script(nonce: &str) -> String {
+    format!(
+        "(async () => ({})({}, {}, {}))()",
+        PROBE_SCRIPT.trim_end(),
+        JsonValue::from(COMMANDS),
+        JsonValue::from(nonce),
+        JsonValue::from(PROBE_HEADER)
+    )
+}
+
+/// Build the report from the probe outcomes, one per command in `COMMANDS`
+/// order. Without them the round trip failed and nothing is known about the
+/// commands, so the report lists none.
+pub(crate) fn report(permitted: Option<Vec<bool>>) -> SelfTestReport {
+    match permitted.filter(|permitted| permitted.len() == COMMANDS.len()) {
+        Some(permitted) => SelfTestReport {
+            commands: COMMANDS
+                .iter()
+                .zip(permitted)
+                .map(|(command, permitted)| CommandPermission {
+                    command: command.to_string(),
+                    permitted,
+                })
+                .collect(),
+            ipc_event_roundtrip_ok: true,
+        },
+        None => SelfTestReport {
+            commands: Vec::new(),
+            ipc_event_roundtrip_ok: false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_script_embeds_the_commands_nonce_and_header() {
+        let script = probe_script("self-test-1");
+
+        // Evaluated as-is, so the arguments are JSON literals
+        assert!(script.starts_with("(async () => (// Evaluated by tauri-plugin-wdio"));
+        assert!(script.ends_with(r#", "self-test-1", "x-wdio-probe"))()"#));
+        assert!(script.contains(r#"["execute","log_frontend","#));
+        assert!(COMMANDS.contains(&"self_test") && COMMANDS.contains(&"self_test_report"));
+    }
+
+    #[test]
+    fn test_report_pairs_commands_with_probe_outcomes() {
+        let permitted: Vec<bool> = COMMANDS.iter().map(|command| *command != "quit_app").collect();
+
+        let report = report(Some(permitted));
+
+        assert!(report.ipc_event_roundtrip_ok);
+        assert_eq!(report.commands.len(), COMMANDS.len());
+        assert_eq!(report.commands[0], CommandPermission { command: "execute".to_string(), permitted: true });
+        let denied: Vec<&str> = report
+            .commands
+            .iter()
+            .filter(|entry| !entry.permitted)
+            .map(|entry| entry.command.as_str())
+            .collect();
+        assert_eq!(denied, ["quit_app"]);
+    }
+
+    #[test]
+    fn test_report_without_every_outcome_lists_no_commands() {
+        for permitted in [None, Some(vec![true])] {
+            let report = report(permitted);
+            assert!(!report.ipc_event_roundtrip_ok);
+            assert!(report.commands.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_pending_self_tests_deliver_only_to_a_waiting_nonce() {
+        let tests = PendingSelfTests::default();
+        let mut rx = tests.begin("n1");
+
+        assert!(!tests.finish("n2", vec![true]));
+        assert!(tests.finish("n1", vec![false, true]));
+        assert_eq!(rx.try_recv().unwrap(), vec![false, true]);
+        assert!(!tests.finish("n1", vec![true]));
+
+        let _rx = tests.begin("n3");
+        tests.cancel("n3");
+        assert!(!tests.finish("n3", vec![true]));
+    }
+}
@@ -0,0 +1,217 @@
+use std::sync::Mutex;
+
+use crate::invoke_trace::InvokeTracer;
+use crate::logging::LogPipeline;
+use crate::models::EndedSession;
+use crate::{Error, Result};
+
+/// Global holding a page's mocks as they were when the session began, so
+/// end_session can tell the session's mocks and calls from earlier ones
+const SESSION_GLOBAL: &str = "window.__wdio_session__";
+
+/// The test session plugin state is tagged with. Only one can be active, so
+/// state from one test can't be attributed to another; ending it removes the
+/// log entries, invoke trace entries, and mocks created while it was active.
+/// Everything recorded outside a session is left alone.
+#[derive(Default)]
+pub(crate) struct Sessions {
+    active: Mutex<Option<String>>,
+}
+
+impl Sessions {
+    /// Start tagging new state with `session_id`
+    pub(crate) fn begin(&self, session_id: &str, logs: &LogPipeline, tracer: &InvokeTracer) -> Result<()> {
+        if session_id.is_empty() {
+            return Err(Error::SessionError("the session id must not be empty".to_string()));
+        }
+        let mut active = self.active.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(current) = active.as_deref() {
+            return Err(Error::SessionConflict(current.to_string()));
+        }
+        *active = Some(session_id.to_string());
+        logs.set_session(Some(session_id));
+        tracer.set_session(Some(session_id));
+        Ok(())
+    }
+
+    /// Stop tagging and remove everything tagged with `session_id`. The lock
+    /// is held throughout, so a new session can't begin halfway through.
+    pub(crate) fn end(&self, session_id: &str, logs: &LogPipeline, tracer: &InvokeTracer) -> Result<EndedSession> {
+        let mut active = self.active.lock().unwrap_or_else(|e| e.into_inner());
+        if active.as_deref() != Some(session_id) {
+            return Err(Error::SessionNotActive(session_id.to_string()));
+        }
+        logs.set_session(None);
+        tracer.set_session(None);
+        let ended = EndedSession {
+            session_id: session_id.to_string(),
+            log_entries: logs.buffer().clear_session(session_id),
+            invoke_trace_entries: tracer.clear_session(session_id),
+        };
+        *active = None;
+        Ok(ended)
+    }
+
+    /// Stop tagging without removing anything, and run `reset` before a new
+    /// session can begin. Returns the session that was active, and what
+    /// `reset` returned.
+    pub(crate) fn reset<T>(
+        &self,
+        logs: &LogPipeline,
+        tracer: &InvokeTracer,
+        reset: impl FnOnce() -> T,
+    ) -> (Option<String>, T) {
+        let mut active = self.active.lock().unwrap_or_else(|e| e.into_inner());
+        logs.set_session(None);
+        tracer.set_session(None);
+        let ended = active.take();
+        (ended, reset())
+    }
+}
+
+/// Script that records a page's current mocks and their call counts as the
+/// baseline for the session
+pub(crate) fn begin_script(session_id: &str) -> String {
+    format!(
+        r#"(() => {{
+  const mocks = {{}};
+  const calls = {{}};
+  for (const [command, mock] of Object.entries(window.__wdio_mocks__ ?? {{}})) {{
+    mocks[command] = mock;
+    calls[command] = mock?.mock?.calls?.length ?? 0;
+  }}
+  {global} = {{ id: {id}, mocks, calls }};
+}})();"#,
+        global = SESSION_GLOBAL,
+        id = serde_json::Value::from(session_id),
+    )
+}
+
+/// Script that undoes a page's mocks from the session: mocks added during it
+/// are removed, replaced ones are put back, and calls made during it are
+/// dropped from the mocks that remain. A page loaded during the session has
+/// no baseline, so all its mocks belong to the session.
+pub(crate) fn end_script(session_id: &str) -> String {
+    format!(
+        r#"(() => {{
+  const session = {global}?.id === {id} ? {global} : {{ mocks: {{}}, calls: {{}} }};
+  const mocks = window.__wdio_mocks__;
+  if (mocks) {{
+    for (const command of Object.keys(mocks)) {{
+      if (!(command in session.mocks)) {{
+        delete mocks[command];
+      }} else if (mocks[command] !== session.mocks[command]) {{
+        mocks[command] = session.mocks[command];
+      }}
+    }}
+    for (const [command, count] of Object.entries(session.calls)) {{
+      const state = mocks[command]?.mock;
+      for (const records of [state?.calls, state?.contexts, state?.results, state?.invocationCallOrder]) {{
+        if (Array.isArray(records) && records.length > count) {{
+          records.length = count;
+        }}
+      }}
+    }}
+  }}
+  {global} = null;
+}})();"#,
+        global = SESSION_GLOBAL,
+        id = serde_json::Value::from(session_id),
+    )
+}
+
+/// Script that removes all of a page's mocks, their calls with them, and its
+/// session baseline
+pub(crate) fn reset_script() -> String {
+    format!(
+        r#"(() => {{
+  const mocks = window.__wdio_mocks__;
+  if (mocks) {{
+    for (const command of Object.keys(mocks)) {{
+      delete mocks[command];
+    }}
+  }}
+  {global} = null;
+}})();"#,
+        global = SESSION_GLOBAL,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{InvokeOutcome, InvokeTraceEntry, LogFilter, LogLevel, LogSource, WdioConfig};
+
+    fn trace_entry(command: &str) -> InvokeTraceEntry {
+        InvokeTraceEntry {
+            command: command.to_string(),
+            args_summary: String::new(),
+            args_truncated: false,
+            started_at_ms: 0,
+            duration_ms: 0.0,
+            outcome: InvokeOutcome::Ok,
+            window_label: "main".to_string(),
+            session_id: None,
+        }
+    }
+
+    fn state() -> (Sessions, LogPipeline, InvokeTracer) {
+        (Sessions::default(), LogPipeline::new(&WdioConfig::default()), InvokeTracer::new(10, 64))
+    }
+
+    fn messages(logs: &LogPipeline) -> Vec<String> {
+        logs.buffer().query(&LogFilter::default()).into_iter().map(|e| e.message).collect()
+    }
+
+    fn commands(tracer: &InvokeTracer) -> Vec<String> {
+        tracer.trace().entries.into_iter().map(|e| e.command).collect()
+    }
+
+    #[test]
+    fn test_overlapping_sessions_are_rejected() {
+        let (sessions, logs, tracer) = state();
+        sessions.begin("a", &logs, &tracer).unwrap();
+
+        assert!(matches!(sessions.begin("b", &logs, &tracer), Err(Error::SessionConflict(id)) if id == "a"));
+        assert!(matches!(sessions.begin("a", &logs, &tracer), Err(Error::SessionConflict(_))));
+        assert!(matches!(sessions.end("b", &logs, &tracer), Err(Error::SessionNotActive(id)) if id == "b"));
+        assert_eq!(logs.session().as_deref(), Some("a"));
+
+        sessions.end("a", &logs, &tracer).unwrap();
+        assert!(sessions.end("a", &logs, &tracer).is_err());
+        sessions.begin("b", &logs, &tracer).unwrap();
+        assert!(matches!(sessions.begin("", &logs, &tracer), Err(Error::SessionError(_))));
+    }
+
+    #[test]
+    fn test_end_removes_only_the_sessions_state() {
+        let (sessions, logs, tracer) = state();
+        tracer.start(None);
+        logs.emit(LogSource::Backend, LogLevel::Info, "before".to_string(), None);
+        tracer.record(trace_entry("before"));
+
+        sessions.begin("a", &logs, &tracer).unwrap();
+        logs.emit(LogSource::Backend, LogLevel::Info, "during".to_string(), None);
+        tracer.record(trace_entry("during"));
+        assert_eq!(
+            sessions.end("a", &logs, &tracer).unwrap(),
+            EndedSession {
+                session_id: "a".to_string(),
+                log_entries: 1,
+                invoke_trace_entries: 1,
+            }
+        );
+
+        logs.emit(LogSource::Frontend, LogLevel::Info, "after".to_string(), None);
+        tracer.record(trace_entry("after"));
+        assert_eq!(messages(&logs), vec!["before", "after"]);
+        assert_eq!(commands(&tracer), vec!["before", "after"]);
+        assert_eq!(logs.session(), None);
+    }
+
+    #[test]
+    fn test_scripts_quote_the_session_id() {
+        assert!(begin_script("it's \"a\"").contains(r#"id: "it's \"a\"""#));
+        assert!(end_script("a").contains(r#"window.__wdio_session__?.id === "a""#));
+    }
+}
@@ -0,0 +1,139 @@
+use crate::models::ShortcutInfo;
+use crate::Result;
+
+#[cfg(feature = "global-shortcut")]
+pub(crate) use registry::Shortcuts;
+
+#[cfg(not(feature = "global-shortcut"))]
+pub(crate) use unsupported::Shortcuts;
+
+#[cfg(feature = "global-shortcut")]
+mod registry {
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+
+    use tauri::{AppHandle, Manager, Runtime};
+    use tauri_plugin_global_shortcut::{GlobalShortcut, Shortcut, ShortcutEvent, ShortcutState};
+
+    use super::*;
+    use crate::Error;
+
+    type ShortcutHandler<R> = Arc<dyn Fn(&AppHandle<R>, &Shortcut, ShortcutEvent) + Send + Sync>;
+
+    /// Global shortcut handlers registered through the plugin, keyed by hotkey
+    /// id, so tests can invoke them. The global-shortcut plugin keeps its own
+    /// handlers private and OS key events can't be sent from WebDriver.
+    pub(crate) struct Shortcuts<R: Runtime> {
+        app: AppHandle<R>,
+        handlers: Mutex<BTreeMap<u32, (Shortcut, ShortcutHandler<R>)>>,
+    }
+
+    impl<R: Runtime> Shortcuts<R> {
+        pub(crate) fn new(app: &AppHandle<R>) -> Self {
+            Self {
+                app: app.clone(),
+                handlers: Mutex::default(),
+            }
+        }
+
+        pub(crate) fn register<F>(&self, accelerator: &str, handler: F) -> Result<()>
+        where
+            F: Fn(&AppHandle<R>, &Shortcut, ShortcutEvent) + Send + Sync + 'static,
+        {
+            let shortcut = parse(accelerator)?;
+            let global = self.app.try_state::<GlobalShortcut<R>>().ok_or_else(|| {
+                Error::ShortcutError("tauri-plugin-global-shortcut is not initialized".to_string())
+            })?;
+
+            let handler: ShortcutHandler<R> = Arc::new(handler);
+            let native = handler.clone();
+            global
+                .on_shortcut(shortcut, move |app, shortcut, event| native(app, shortcut, event))
+                .map_err(|e| Error::ShortcutError(e.to_string()))?;
+            self.handlers
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(shortcut.id(), (shortcut, handler));
+            Ok(())
+        }
+
+        pub(crate) fn list(&self) -> Result<Vec<ShortcutInfo>> {
+            let global = self.app.try_state::<GlobalShortcut<R>>();
+            let handlers = self.handlers.lock().unwrap_or_else(|e| e.into_inner());
+            Ok(handlers
+                .values()
+                .map(|(shortcut, _)| ShortcutInfo {
+                    accelerator: shortcut.into_string(),
+                    registered: global
+                        .as_ref()
+                        .map(|global| global.is_registered(*shortcut))
+                        .unwrap_or(false),
+                })
+                .collect())
+        }
+
+        /// Call the handler for `accelerator` with a press and then a release
+        /// event, on the main thread like the OS hook would
+        pub(crate) fn trigger(&self, accelerator: &str) -> Result<()> {
+            let shortcut = parse(accelerator)?;
+            let (shortcut, handler) = self
+                .handlers
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .get(&shortcut.id())
+                .cloned()
+                .ok_or_else(|| Error::ShortcutNotRegistered(accelerator.to_string()))?;
+
+            let app = self.app.clone();
+            self.app
+                .run_on_main_thread(move || {
+                    for state in [ShortcutState::Pressed, ShortcutState::Released] {
+                        handler(&app, &shortcut, ShortcutEvent { id: shortcut.id(), state });
+                    }
+                })
+                .map_err(|e| Error::ShortcutError(e.to_string()))
+        }
+    }
+
+    fn parse(accelerator: &str) -> Result<Shortcut> {
+        Shortcut::from_str(accelerator).map_err(|e| Error::ShortcutError(format!("{}: {}", accelerator, e)))
+    }
+}
+
+#[cfg(not(feature = "global-shortcut"))]
+mod unsupported {
+    use std::marker::PhantomData;
+
+    use tauri::{AppHandle, Runtime};
+
+    use super::*;
+    use crate::Error;
+
+    fn unsupported<T>() -> Result<T> {
+        Err(Error::UnsupportedInBuild(
+            "global shortcut commands require the tauri-plugin-wdio `global-shortcut` feature".to_string(),
+        ))
+    }
+
+    /// Stand-in used when the `global-shortcut` feature is disabled
+    pub(crate) struct Shortcuts<R: Runtime> {
+        _phantom: PhantomData<fn() -> R>,
+    }
+
+    impl<R: Runtime> Shortcuts<R> {
+        pub(crate) fn new(_app: &AppHandle<R>) -> Self {
+            Self {
+                _phantom: PhantomData,
+            }
+        }
+
+        pub(crate) fn list(&self) -> Result<Vec<ShortcutInfo>> {
+            unsupported()
+        }
+
+        pub(crate) fn trigger(&self, _accelerator: &str) -> Result<()> {
+            unsupported()
+        }
+    }
+}
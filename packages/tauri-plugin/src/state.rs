@@ -0,0 +1,243 @@
+use tauri::{AppHandle, Listener, Runtime};
+
+use crate::bridge::Bridges;
+use crate::clipboard::Clipboard;
+use crate::coverage::CoverageTransfers;
+use crate::crash::CrashStore;
+use crate::event_recorder::EventRecorder;
+use crate::executions::PendingExecutions;
+use crate::gate::PluginGate;
+use crate::health::Health;
+use crate::invoke_conditions::ActiveInvokeConditions;
+use crate::invoke_replay::InvokeReplay;
+use crate::invoke_trace::InvokeTracer;
+use crate::logging::LogPipeline;
+use crate::metrics::Metrics;
+use crate::models::{ResetSummary, WdioConfig};
+use crate::navigation::PageLoads;
+use crate::notifications::NotificationCapture;
+use crate::preamble::ExecutePreamble;
+use crate::self_test::PendingSelfTests;
+use crate::sessions::Sessions;
+use crate::window_events::WindowEventLog;
+use crate::window_watch::WindowChanges;
+use crate::{invoke_conditions, invoke_replay, invoke_trace, sessions, Error};
+
+/// Everything the plugin keeps between commands. Setup manages this once, so
+/// commands take a single `State<'_, WdioState>` and hooks a single
+/// `try_state`; each part does its own locking. [`crate::desktop::Wdio`]
+/// stays managed on its own, as it's the app's handle rather than plugin state.
+pub(crate) struct WdioState {
+    /// Config resolved against tauri.conf.json and the environment
+    pub(crate) config: WdioConfig,
+    pub(crate) gate: PluginGate,
+    pub(crate) logs: LogPipeline,
+    pub(crate) crashes: CrashStore,
+    pub(crate) health: Health,
+    pub(crate) page_loads: PageLoads,
+    pub(crate) window_changes: WindowChanges,
+    pub(crate) window_events: WindowEventLog,
+    pub(crate) executions: PendingExecutions,
+    pub(crate) bridges: Bridges,
+    pub(crate) coverage: CoverageTransfers,
+    pub(crate) self_tests: PendingSelfTests,
+    pub(crate) preamble: ExecutePreamble,
+    pub(crate) metrics: Metrics,
+    pub(crate) clipboard: Clipboard,
+    pub(crate) notifications: NotificationCapture,
+    pub(crate) events: EventRecorder,
+    pub(crate) invoke_trace: InvokeTracer,
+    pub(crate) invoke_conditions: ActiveInvokeConditions,
+    pub(crate) invoke_replay: InvokeReplay,
+    pub(crate) sessions: Sessions,
+}
+
+impl WdioState {
+    /// Fresh state for `config`, around the parts setup has already wired up
+    pub(crate) fn new(config: WdioConfig, gate: PluginGate, logs: LogPipeline, crashes: CrashStore) -> Self {
+        Self {
+            health: Health::new(),
+            page_loads: PageLoads::default(),
+            window_changes: WindowChanges::default(),
+            window_events: WindowEventLog::new(config.window_event_capacity),
+            executions: PendingExecutions::default(),
+            bridges: Bridges::default(),
+            coverage: CoverageTransfers::default(),
+            self_tests: PendingSelfTests::default(),
+            preamble: ExecutePreamble::new(config.execute_preamble.clone()),
+            metrics: Metrics::new(),
+            clipboard: Clipboard::new(),
+            notifications: NotificationCapture::new(config.capture_notifications),
+            events: EventRecorder::new(config.event_recording_capacity),
+            invoke_trace: InvokeTracer::new(
+                config.invoke_trace_capacity,
+                config.invoke_trace_args_max_bytes.min(config.max_payload_bytes),
+            ),
+            invoke_conditions: ActiveInvokeConditions::default(),
+            invoke_replay: InvokeReplay::default(),
+            sessions: Sessions::default(),
+            config,
+            gate,
+            logs,
+            crashes,
+        }
+    }
+
+    /// Put everything a test can leave behind back as setup left it, except
+    /// executions waiting on `except_window`, where reset_all may itself be
+    /// running. The session lock is held throughout, so no session begins
+    /// halfway; each part is then reset in field order, taking only its own
+    /// lock. What the app owns (handlers, state providers, bridges, crash and
+    /// health records) is left alone. The summary's `pages_reset` is left to
+    /// the caller, which sends pages [`page_reset_script`].
+    pub(crate) fn reset<R: Runtime>(&self, app: &AppHandle<R>, except_window: Option<&str>) -> ResetSummary {
+        let (session_ended, mut summary) = self.sessions.reset(&self.logs, &self.invoke_trace, || {
+            self.logs.set_context(None);
+            let logs_cleared = self.logs.buffer().clear();
+            let window_events_cleared = self.window_events.clear();
+            let (executions_cancelled, listeners) = self
+                .executions
+                .cancel_all(except_window, || Error::ExecuteError("cancelled by reset_all".to_string()));
+            for listener in listeners {
+                app.unlisten(listener);
+            }
+            let preamble_cleared = self.preamble.reset(self.config.execute_preamble.clone());
+            self.notifications.set_enabled(self.config.capture_notifications);
+            ResetSummary {
+                logs_cleared,
+                window_events_cleared,
+                executions_cancelled,
+                preamble_cleared,
+                notifications_cleared: self.notifications.clear(),
+                recorded_events_cleared: self.events.reset(app),
+                invoke_trace_cleared: self.invoke_trace.reset(),
+                invoke_conditions_cleared: self.invoke_conditions.clear(),
+                invoke_replay_stopped: self.invoke_replay.reset(),
+                ..ResetSummary::default()
+            }
+        });
+        summary.session_ended = session_ended;
+        summary
+    }
+}
+
+/// Script that resets a page to match a reset [`WdioState`]: no mocks or
+/// mock calls, session baseline, tracing, invoke conditions, or replay
+pub(crate) fn page_reset_script() -> String {
+    [
+        sessions::reset_script(),
+        invoke_trace::tracing_script(None),
+        invoke_conditions::conditions_script(None),
+        invoke_replay::mode_script(None),
+    ]
+    .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use tauri::{Emitter, Manager};
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::models::{EventRecordFilter, InvokeConditions, InvokeOutcome, InvokeTraceEntry, LogFilter, LogLevel, LogSource, NotifyOptions};
+
+    #[test]
+    fn test_new_takes_its_defaults_from_the_config() {
+        let config = WdioConfig {
+            execute_preamble: Some("const helper = 1;".to_string()),
+            capture_notifications: true,
+            ..WdioConfig::default()
+        };
+        let gate = PluginGate::new(true, &config, None);
+        let logs = LogPipeline::new(&config);
+
+        let state = WdioState::new(config, gate, logs, CrashStore::default());
+
+        assert_eq!(state.preamble.get().as_deref(), Some("const helper = 1;"));
+        assert!(state.notifications.capture(NotifyOptions::default()));
+        assert!(state.gate.is_enabled());
+        assert!(state.executions.list().is_empty());
+    }
+
+    #[test]
+    fn test_reset_clears_every_kind_of_test_state() {
+        let app = tauri::test::mock_builder()
+            .plugin(crate::init())
+            .build(tauri::test::mock_context(tauri::test::noop_assets()))
+            .unwrap();
+        let handle = app.handle();
+        let state = app.state::<WdioState>();
+        let recording = std::env::temp_dir().join(format!("wdio-reset-{}.json", uuid::Uuid::new_v4()));
+
+        state.sessions.begin("spec", &state.logs, &state.invoke_trace).unwrap();
+        state.logs.set_context(Some("a test".to_string()));
+        state.logs.emit(LogSource::Backend, LogLevel::Info, "left behind".to_string(), None);
+        state.invoke_trace.start(None);
+        state.invoke_trace.record(InvokeTraceEntry {
+            command: "greet".to_string(),
+            args_summary: String::new(),
+            args_truncated: false,
+            started_at_ms: 0,
+            duration_ms: 0.0,
+            outcome: InvokeOutcome::Ok,
+            window_label: "main".to_string(),
+            session_id: None,
+        });
+        state.preamble.set("const helper = 1;".to_string());
+        let (caller_tx, mut caller_rx) = oneshot::channel();
+        let (other_tx, mut other_rx) = oneshot::channel();
+        let _caller = state.executions.register(handle, "a", "main", caller_tx);
+        let _other = state.executions.register(handle, "b", "settings", other_tx);
+        state.notifications.set_enabled(true);
+        state.notifications.capture(NotifyOptions::default());
+        state.events.start(handle, vec!["saved".to_string()]).unwrap();
+        handle.emit("saved", 1).unwrap();
+        state.invoke_conditions.set(InvokeConditions::default()).unwrap();
+        state.invoke_replay.start_recording(recording.clone()).unwrap();
+
+        let summary = state.reset(handle, Some("main"));
+
+        assert_eq!(
+            summary,
+            ResetSummary {
+                logs_cleared: 1,
+                window_events_cleared: 0,
+                invoke_trace_cleared: 1,
+                session_ended: Some("spec".to_string()),
+                preamble_cleared: true,
+                executions_cancelled: 1,
+                notifications_cleared: 1,
+                recorded_events_cleared: 1,
+                invoke_conditions_cleared: true,
+                invoke_replay_stopped: true,
+                pages_reset: 0,
+            }
+        );
+        assert!(state.logs.buffer().query(&LogFilter::default()).is_empty());
+        assert_eq!((state.logs.context(), state.logs.session()), (None, None));
+        assert!(state.invoke_trace.trace().entries.is_empty() && state.invoke_trace.active().is_none());
+        assert_eq!(state.preamble.get(), None);
+        assert!(matches!(other_rx.try_recv(), Ok(Err(Error::ExecuteError(_)))));
+        assert!(caller_rx.try_recv().is_err());
+        assert_eq!(state.executions.list().len(), 1);
+        assert!(state.notifications.records().is_empty());
+        assert!(!state.notifications.capture(NotifyOptions::default()));
+        handle.emit("saved", 2).unwrap();
+        assert!(state.events.query(&EventRecordFilter::default()).events.is_empty());
+        assert!(state.invoke_conditions.current().is_none());
+        assert!(state.invoke_replay.page_mode().is_none());
+        // A new session can begin once the reset is done
+        state.sessions.begin("next", &state.logs, &state.invoke_trace).unwrap();
+        std::fs::remove_file(recording).unwrap();
+    }
+
+    #[test]
+    fn test_page_reset_script_clears_mocks_and_interceptor_settings() {
+        let script = page_reset_script();
+
+        assert!(script.contains("delete mocks[command];"));
+        assert!(script.contains("window.__wdio_session__ = null;"));
+        assert!(script.contains("window.__wdio_invoke_tracing__ = null;"));
+        assert!(script.ends_with(" = null;"));
+    }
+}
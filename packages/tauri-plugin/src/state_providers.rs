@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value as JsonValue;
+use tauri::{AppHandle, Runtime};
+
+use crate::{Error, Result};
+
+pub(crate) type StateProvider<R> = Arc<dyn Fn(&AppHandle<R>) -> JsonValue + Send + Sync>;
+
+/// Named snapshots of app state that the app opts into test visibility
+pub(crate) struct StateProviders<R: Runtime> {
+    providers: Mutex<BTreeMap<String, StateProvider<R>>>,
+}
+
+impl<R: Runtime> StateProviders<R> {
+    pub(crate) fn new() -> Self {
+        Self {
+            providers: Mutex::default(),
+        }
+    }
+
+    /// Add a provider, replacing any previous one with the same name
+    pub(crate) fn register(&self, name: String, provider: StateProvider<R>) {
+        self.providers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(name, provider);
+    }
+
+    /// The provider for `name`. The registry lock is released before it
+    /// returns, so the provider can take its own locks while it runs.
+    pub(crate) fn get(&self, name: &str) -> Result<StateProvider<R>> {
+        self.providers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(name)
+            .cloned()
+            .ok_or_else(|| Error::StateProviderNotFound(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_by_name() {
+        let providers = StateProviders::<tauri::Wry>::new();
+        providers.register("todos".to_string(), Arc::new(|_| serde_json::json!([])));
+
+        assert!(providers.get("todos").is_ok());
+        assert!(matches!(
+            providers.get("missing"),
+            Err(Error::StateProviderNotFound(name)) if name == "missing"
+        ));
+    }
+}
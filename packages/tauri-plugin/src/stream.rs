@@ -0,0 +1,363 @@
+// Clients only connect through the control server; without it the hub is
+// always empty and publishing to it does nothing
+#![cfg_attr(not(feature = "control-server"), allow(dead_code))]
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+
+use serde_json::Value as JsonValue;
+use tauri::{AppHandle, EventId, Listener, Runtime};
+use tokio::sync::Notify;
+
+use crate::event_recorder::event_payload;
+use crate::log_buffer::now_ms;
+use crate::models::{EventRecord, InvokeTraceEntry, LogEntry, StreamFrame, StreamSubscription};
+
+/// Handler called with the payload of each matching event
+pub(crate) type EventHandler = Box<dyn Fn(JsonValue) + Send + Sync>;
+
+/// Listens for Tauri events on behalf of stream clients. Set once the app
+/// handle is available so the hub stays runtime-agnostic.
+pub(crate) trait EventSource: Send + Sync {
+    fn listen(&self, name: String, handler: EventHandler) -> EventId;
+    fn unlisten(&self, id: EventId);
+}
+
+impl<R: Runtime> EventSource for AppHandle<R> {
+    fn listen(&self, name: String, handler: EventHandler) -> EventId {
+        self.listen_any(name, move |event| handler(event_payload(event.payload())))
+    }
+
+    fn unlisten(&self, id: EventId) {
+        Listener::unlisten(self, id);
+    }
+}
+
+type SharedEventSource = Arc<OnceLock<Box<dyn EventSource>>>;
+
+/// Clients of the control server's `/stream` endpoint. Log and invoke trace
+/// entries are published to every client whose subscription wants them;
+/// events reach a client through listeners it registers for its names.
+#[derive(Clone)]
+pub(crate) struct StreamHub {
+    clients: Arc<Mutex<Vec<Weak<StreamClient>>>>,
+    events: SharedEventSource,
+    capacity: usize,
+}
+
+impl StreamHub {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            clients: Arc::default(),
+            events: Arc::new(OnceLock::new()),
+            capacity,
+        }
+    }
+
+    pub(crate) fn set_event_source(&self, source: Box<dyn EventSource>) {
+        let _ = self.events.set(source);
+    }
+
+    /// Add a client that receives nothing until it subscribes. It's removed
+    /// once dropped.
+    pub(crate) fn connect(&self) -> Arc<StreamClient> {
+        let client = Arc::new(StreamClient {
+            queue: Mutex::new(Queue {
+                subscription: StreamSubscription::default(),
+                frames: VecDeque::new(),
+                capacity: self.capacity,
+                seq: 0,
+                dropped: 0,
+            }),
+            ready: Notify::new(),
+            events: self.events.clone(),
+            listeners: Mutex::default(),
+        });
+        let mut clients = self.clients.lock().unwrap_or_else(|e| e.into_inner());
+        clients.retain(|client| client.strong_count() > 0);
+        clients.push(Arc::downgrade(&client));
+        client
+    }
+
+    pub(crate) fn publish_log(&self, entry: &LogEntry) {
+        for client in self.clients() {
+            client.push(
+                |subscription| subscription.logs.as_ref().is_some_and(|filter| filter.matches(entry)),
+                |seq| StreamFrame::Log {
+                    seq,
+                    entry: entry.clone(),
+                },
+            );
+        }
+    }
+
+    pub(crate) fn publish_invoke(&self, entry: &InvokeTraceEntry) {
+        for client in self.clients() {
+            client.push(
+                |subscription| subscription.invoke_trace,
+                |seq| StreamFrame::InvokeTrace {
+                    seq,
+                    entry: entry.clone(),
+                },
+            );
+        }
+    }
+
+    fn clients(&self) -> Vec<Arc<StreamClient>> {
+        let clients = self.clients.lock().unwrap_or_else(|e| e.into_inner());
+        clients.iter().filter_map(Weak::upgrade).collect()
+    }
+}
+
+struct Queue {
+    subscription: StreamSubscription,
+    frames: VecDeque<StreamFrame>,
+    capacity: usize,
+    /// Sequence number of the last frame queued
+    seq: u64,
+    /// Frames dropped since the client last took its queue
+    dropped: u64,
+}
+
+/// One `/stream` connection. Frames wait in a bounded queue until the
+/// connection sends them; when the client falls behind, the oldest are
+/// dropped instead of holding up whoever published them.
+pub(crate) struct StreamClient {
+    queue: Mutex<Queue>,
+    ready: Notify,
+    events: SharedEventSource,
+    listeners: Mutex<Vec<EventId>>,
+}
+
+impl StreamClient {
+    /// Replace the subscription, listening for its event names instead of
+    /// the previous ones
+    pub(crate) fn subscribe(self: &Arc<Self>, subscription: StreamSubscription) {
+        let event_names = subscription.event_names.clone().unwrap_or_default();
+        self.queue.lock().unwrap_or_else(|e| e.into_inner()).subscription = subscription;
+
+        let Some(source) = self.events.get() else {
+            return;
+        };
+        let mut listeners = self.listeners.lock().unwrap_or_else(|e| e.into_inner());
+        for id in listeners.drain(..) {
+            source.unlisten(id);
+        }
+        for name in event_names {
+            // The listener lives in the app, so it mustn't keep the client alive
+            let client = Arc::downgrade(self);
+            let event_name = name.clone();
+            listeners.push(source.listen(
+                name,
+                Box::new(move |payload| {
+                    if let Some(client) = client.upgrade() {
+                        client.push_event(EventRecord {
+                            name: event_name.clone(),
+                            payload,
+                            timestamp_ms: now_ms(),
+                        });
+                    }
+                }),
+            ));
+        }
+    }
+
+    fn push_event(&self, event: EventRecord) {
+        self.push(|_| true, |seq| StreamFrame::Event { seq, event });
+    }
+
+    /// Queue the frame built by `frame` if the subscription `wants` it,
+    /// dropping the oldest queued frame once the queue is full
+    fn push(&self, wants: impl FnOnce(&StreamSubscription) -> bool, frame: impl FnOnce(u64) -> StreamFrame) {
+        {
+            let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+            if !wants(&queue.subscription) {
+                return;
+            }
+            queue.seq += 1;
+            let frame = frame(queue.seq);
+            if queue.capacity == 0 {
+                queue.dropped += 1;
+            } else {
+                while queue.frames.len() >= queue.capacity {
+                    queue.frames.pop_front();
+                    queue.dropped += 1;
+                }
+                queue.frames.push_back(frame);
+            }
+        }
+        self.ready.notify_one();
+    }
+
+    /// Wait for frames and take everything queued, led by a `dropped` frame
+    /// when frames were dropped since the last call
+    pub(crate) async fn next_frames(&self) -> Vec<StreamFrame> {
+        loop {
+            {
+                let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+                if queue.dropped > 0 || !queue.frames.is_empty() {
+                    let mut frames = Vec::with_capacity(queue.frames.len() + 1);
+                    if queue.dropped > 0 {
+                        frames.push(StreamFrame::Dropped {
+                            count: std::mem::take(&mut queue.dropped),
+                        });
+                    }
+                    frames.extend(queue.frames.drain(..));
+                    return frames;
+                }
+            }
+            // A push since the check above leaves a permit, so this can't miss it
+            self.ready.notified().await;
+        }
+    }
+}
+
+impl Drop for StreamClient {
+    fn drop(&mut self) {
+        if let Some(source) = self.events.get() {
+            for id in self.listeners.get_mut().unwrap_or_else(|e| e.into_inner()).drain(..) {
+                source.unlisten(id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::models::{LogFilter, LogLevel, LogSource};
+
+    type Handlers = HashMap<EventId, (String, Arc<EventHandler>)>;
+
+    /// Event source whose events are fired by the test
+    #[derive(Clone, Default)]
+    pub(crate) struct FakeEvents {
+        handlers: Arc<Mutex<Handlers>>,
+    }
+
+    impl FakeEvents {
+        pub(crate) fn emit(&self, name: &str, payload: JsonValue) {
+            let handlers: Vec<Arc<EventHandler>> = {
+                let handlers = self.handlers.lock().unwrap();
+                handlers.values().filter(|(n, _)| n == name).map(|(_, h)| h.clone()).collect()
+            };
+            for handler in handlers {
+                handler(payload.clone());
+            }
+        }
+
+        pub(crate) fn listener_count(&self) -> usize {
+            self.handlers.lock().unwrap().len()
+        }
+    }
+
+    impl EventSource for FakeEvents {
+        fn listen(&self, name: String, handler: EventHandler) -> EventId {
+            let mut handlers = self.handlers.lock().unwrap();
+            let id = handlers.keys().max().map_or(0, |id| id + 1);
+            handlers.insert(id, (name, Arc::new(handler)));
+            id
+        }
+
+        fn unlisten(&self, id: EventId) {
+            self.handlers.lock().unwrap().remove(&id);
+        }
+    }
+
+    pub(crate) fn log_entry(message: &str, level: LogLevel) -> LogEntry {
+        LogEntry {
+            source: LogSource::Backend,
+            level,
+            message: message.to_string(),
+            target: None,
+            timestamp_ms: 0,
+            context: None,
+            session_id: None,
+        }
+    }
+
+    fn seqs(frames: &[StreamFrame]) -> Vec<u64> {
+        frames
+            .iter()
+            .filter_map(|frame| match frame {
+                StreamFrame::Log { seq, .. } | StreamFrame::Event { seq, .. } | StreamFrame::InvokeTrace { seq, .. } => {
+                    Some(*seq)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sends_only_subscribed_frames() {
+        let hub = StreamHub::new(10);
+        let events = FakeEvents::default();
+        hub.set_event_source(Box::new(events.clone()));
+        let client = hub.connect();
+
+        hub.publish_log(&log_entry("before subscribing", LogLevel::Error));
+        client.subscribe(StreamSubscription {
+            logs: Some(LogFilter {
+                level: Some(LogLevel::Warn),
+                ..Default::default()
+            }),
+            event_names: Some(vec!["sync".to_string()]),
+            invoke_trace: false,
+        });
+        hub.publish_log(&log_entry("skipped", LogLevel::Info));
+        hub.publish_log(&log_entry("kept", LogLevel::Warn));
+        events.emit("sync", serde_json::json!({ "done": true }));
+        events.emit("other", JsonValue::Null);
+
+        let frames = tauri::async_runtime::block_on(client.next_frames());
+        assert_eq!(seqs(&frames), vec![1, 2]);
+        assert!(matches!(&frames[0], StreamFrame::Log { entry, .. } if entry.message == "kept"));
+        assert!(matches!(&frames[1], StreamFrame::Event { event, .. } if event.payload["done"] == true));
+
+        client.subscribe(StreamSubscription::default());
+        assert_eq!(events.listener_count(), 0);
+    }
+
+    #[test]
+    fn test_full_queue_drops_oldest_and_reports_it() {
+        let hub = StreamHub::new(3);
+        let client = hub.connect();
+        client.subscribe(StreamSubscription {
+            logs: Some(LogFilter::default()),
+            ..Default::default()
+        });
+
+        for i in 0..5 {
+            hub.publish_log(&log_entry(&format!("msg {}", i), LogLevel::Info));
+        }
+        let frames = tauri::async_runtime::block_on(client.next_frames());
+
+        assert!(matches!(frames[0], StreamFrame::Dropped { count: 2 }));
+        assert_eq!(seqs(&frames), vec![3, 4, 5]);
+
+        hub.publish_log(&log_entry("next", LogLevel::Info));
+        let frames = tauri::async_runtime::block_on(client.next_frames());
+        assert_eq!(seqs(&frames), vec![6]);
+    }
+
+    #[test]
+    fn test_dropping_a_client_removes_its_listeners() {
+        let hub = StreamHub::new(10);
+        let events = FakeEvents::default();
+        hub.set_event_source(Box::new(events.clone()));
+
+        let client = hub.connect();
+        client.subscribe(StreamSubscription {
+            event_names: Some(vec!["a".to_string(), "b".to_string()]),
+            ..Default::default()
+        });
+        assert_eq!(events.listener_count(), 2);
+
+        drop(client);
+        assert_eq!(events.listener_count(), 0);
+        assert!(hub.clients().is_empty());
+        events.emit("a", JsonValue::Null);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::models::{Point, TrayInfo, TrayMouseButton};
+use crate::Result;
+
+#[cfg(feature = "tray")]
+pub(crate) use registry::Trays;
+
+#[cfg(not(feature = "tray"))]
+pub(crate) use unsupported::Trays;
+
+#[cfg(feature = "tray")]
+mod registry {
+    use std::sync::{Arc, Mutex};
+
+    use tauri::menu::{Menu, MenuItemKind, MenuId};
+    use tauri::tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconEvent};
+    use tauri::{AppHandle, PhysicalPosition, PhysicalSize, Rect, Runtime};
+
+    use super::*;
+    use crate::models::MenuItemInfo;
+    use crate::Error;
+
+    type TrayHandler<R> = Arc<dyn Fn(&AppHandle<R>, TrayIconEvent) + Send + Sync>;
+    type RegisteredTray<R> = (TrayIcon<R>, Option<Menu<R>>);
+
+    /// Tray icons and tray event handlers registered through the plugin, so
+    /// tests can inspect them and synthesize their events. Tauri doesn't
+    /// expose a list of an app's trays or their menus.
+    pub(crate) struct Trays<R: Runtime> {
+        app: AppHandle<R>,
+        trays: Mutex<Vec<RegisteredTray<R>>>,
+        handlers: Arc<Mutex<Vec<TrayHandler<R>>>>,
+    }
+
+    impl<R: Runtime> Trays<R> {
+        pub(crate) fn new(app: &AppHandle<R>) -> Self {
+            Self {
+                app: app.clone(),
+                trays: Mutex::default(),
+                handlers: Arc::default(),
+            }
+        }
+
+        pub(crate) fn register(&self, tray: &TrayIcon<R>, menu: Option<&Menu<R>>) {
+            let mut trays = self.trays.lock().unwrap_or_else(|e| e.into_inner());
+            trays.retain(|(existing, _)| existing.id() != tray.id());
+            trays.push((tray.clone(), menu.cloned()));
+        }
+
+        pub(crate) fn on_tray_icon_event<F>(&self, handler: F)
+        where
+            F: Fn(&AppHandle<R>, TrayIconEvent) + Send + Sync + 'static,
+        {
+            let handler: TrayHandler<R> = Arc::new(handler);
+            let native = handler.clone();
+            self.app
+                .on_tray_icon_event(move |app, event| native(app, event));
+            self.handlers
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(handler);
+        }
+
+        pub(crate) fn items(&self) -> Result<Vec<TrayInfo>> {
+            let trays = self.trays.lock().unwrap_or_else(|e| e.into_inner());
+            if trays.is_empty() {
+                return Err(Error::TrayNotConfigured);
+            }
+            trays
+                .iter()
+                .map(|(tray, menu)| {
+                    Ok(TrayInfo {
+                        id: tray.id().as_ref().to_string(),
+                        menu: describe_menu(menu.as_ref())?,
+                    })
+                })
+                .collect()
+        }
+
+        /// The live menu item of a tray's menu, by id or by label path
+        pub(crate) fn menu_item(
+            &self,
+            tray_id: Option<&str>,
+            id: Option<&str>,
+            path: Option<&[String]>,
+        ) -> Result<MenuItemKind<R>> {
+            let (_, menu) = self.find(tray_id)?;
+            let menu = menu.ok_or_else(|| Error::MenuError("the tray has no menu".to_string()))?;
+            let items = menu.items().map_err(|e| Error::MenuError(e.to_string()))?;
+            let target = crate::menu::find(&crate::menu::describe(&items)?, id, path)?.id.clone();
+            crate::menu::find_kind(items, &MenuId::new(&target)).ok_or(Error::MenuItemNotFound(target))
+        }
+
+        /// Send the press and release events of a click to the handlers
+        /// registered with [`Trays::on_tray_icon_event`], on the main thread
+        pub(crate) fn click(
+            &self,
+            tray_id: Option<&str>,
+            button: TrayMouseButton,
+            position: Option<Point>,
+        ) -> Result<()> {
+            let (tray, _) = self.find(tray_id)?;
+            let rect = tray.rect().ok().flatten().unwrap_or(Rect {
+                position: PhysicalPosition::new(0, 0).into(),
+                size: PhysicalSize::new(0, 0).into(),
+            });
+            // Default to the middle of the icon, as a real click would be
+            let position = match position {
+                Some(point) => PhysicalPosition::new(point.x as f64, point.y as f64),
+                None => {
+                    let origin = rect.position.to_physical::<f64>(1.0);
+                    let size = rect.size.to_physical::<f64>(1.0);
+                    PhysicalPosition::new(origin.x + size.width / 2.0, origin.y + size.height / 2.0)
+                }
+            };
+            let button = match button {
+                TrayMouseButton::Left => MouseButton::Left,
+                TrayMouseButton::Right => MouseButton::Right,
+                TrayMouseButton::Middle => MouseButton::Middle,
+            };
+
+            let handlers = self
+                .handlers
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone();
+            let app = self.app.clone();
+            let id = tray.id().clone();
+            self.app
+                .run_on_main_thread(move || {
+                    for button_state in [MouseButtonState::Down, MouseButtonState::Up] {
+                        for handler in &handlers {
+                            handler(
+                                &app,
+                                TrayIconEvent::Click {
+                                    id: id.clone(),
+                                    position,
+                                    rect,
+                                    button,
+                                    button_state,
+                                },
+                            );
+                        }
+                    }
+                })
+                .map_err(|e| Error::TrayError(e.to_string()))
+        }
+
+        /// The tray with `id`, or the first registered tray
+        fn find(&self, id: Option<&str>) -> Result<RegisteredTray<R>> {
+            let trays = self.trays.lock().unwrap_or_else(|e| e.into_inner());
+            if trays.is_empty() {
+                return Err(Error::TrayNotConfigured);
+            }
+            match id {
+                Some(id) => trays
+                    .iter()
+                    .find(|(tray, _)| tray.id().as_ref() == id)
+                    .cloned()
+                    .ok_or_else(|| Error::TrayNotFound(id.to_string())),
+                None => Ok(trays[0].clone()),
+            }
+        }
+    }
+
+    fn describe_menu<R: Runtime>(menu: Option<&Menu<R>>) -> Result<Vec<MenuItemInfo>> {
+        match menu {
+            Some(menu) => crate::menu::describe(&menu.items().map_err(|e| Error::MenuError(e.to_string()))?),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[cfg(not(feature = "tray"))]
+mod unsupported {
+    use std::marker::PhantomData;
+
+    use tauri::menu::MenuItemKind;
+    use tauri::{AppHandle, Runtime};
+
+    use super::*;
+    use crate::Error;
+
+    fn unsupported<T>() -> Result<T> {
+        Err(Error::UnsupportedInBuild(
+            "tray commands require the tauri-plugin-wdio `tray` feature".to_string(),
+        ))
+    }
+
+    /// Stand-in used when the `tray` feature is disabled
+    pub(crate) struct Trays<R: Runtime> {
+        _phantom: PhantomData<fn() -> R>,
+    }
+
+    impl<R: Runtime> Trays<R> {
+        pub(crate) fn new(_app: &AppHandle<R>) -> Self {
+            Self {
+                _phantom: PhantomData,
+            }
+        }
+
+        pub(crate) fn items(&self) -> Result<Vec<TrayInfo>> {
+            unsupported()
+        }
+
+        pub(crate) fn menu_item(
+            &self,
+            _tray_id: Option<&str>,
+            _id: Option<&str>,
+            _path: Option<&[String]>,
+        ) -> Result<MenuItemKind<R>> {
+            unsupported()
+        }
+
+        pub(crate) fn click(
+            &self,
+            _tray_id: Option<&str>,
+            _button: TrayMouseButton,
+            _position: Option<Point>,
+        ) -> Result<()> {
+            unsupported()
+        }
+    }
+}
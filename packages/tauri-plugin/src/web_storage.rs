@@ -0,0 +1,50 @@
+use serde_json::Value as JsonValue;
+
+/// Async function that snapshots, clears, and restores a page's storage
+const WEB_STORAGE_SCRIPT: &str = include_str!("js/web_storage.js");
+
+/// Script that runs `operation` of the web storage helper on `input` and
+/// resolves to its result. Both are embedded as JSON literals, so keys,
+/// values, and database names never need quoting by hand.
+pub(crate) fn script(operation: &str, input: &JsonValue) -> String {
+    format!(
+        "(async () => ({})({}, {}))()",
+        WEB_STORAGE_SCRIPT.trim_end(),
+        JsonValue::from(operation),
+        input
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{WebStorageKind, WebStorageSnapshot};
+
+    #[test]
+    fn test_script_embeds_the_input_as_json() {
+        let input = serde_json::json!({ "kinds": ["localStorage"], "note": "it's \"quoted\"\n" });
+        let script = script("clear", &input);
+
+        // Passed through as-is by execute, which only wraps non-function scripts
+        assert!(script.starts_with("(async () => (// Evaluated by tauri-plugin-wdio"));
+        assert!(script.ends_with(r#")("clear", {"kinds":["localStorage"],"note":"it's \"quoted\"\n"}))()"#));
+    }
+
+    #[test]
+    fn test_snapshot_round_trips_through_json() {
+        let report = serde_json::json!({
+            "origin": "tauri://localhost",
+            "local_storage": { "theme": "dark" },
+            "session_storage": {},
+            "indexed_db": null,
+            "cookies": { "token": "a%20b" },
+            "errors": { "indexedDb": "indexedDB.databases() is not supported by this webview" },
+        });
+        let snapshot: WebStorageSnapshot = serde_json::from_value(report.clone()).unwrap();
+
+        assert_eq!(snapshot.local_storage.as_ref().unwrap()["theme"], "dark");
+        assert_eq!(snapshot.indexed_db, None);
+        assert!(snapshot.errors.contains_key(&WebStorageKind::IndexedDb));
+        assert_eq!(serde_json::to_value(&snapshot).unwrap(), report);
+    }
+}
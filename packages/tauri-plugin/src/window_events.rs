@@ -0,0 +1,290 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde_json::json;
+use tauri::WindowEvent;
+use tokio::sync::watch;
+
+use crate::log_buffer::now_ms;
+use crate::models::{WindowEventKind, WindowEventRecord, WindowEvents};
+
+/// Moves and resizes within this long of the first in a burst are coalesced
+/// into that first one and the latest one
+const COALESCE_MS: u64 = 50;
+
+/// Window events from every window, recorded from the plugin's window event
+/// hook for get_window_events
+pub(crate) struct WindowEventLog {
+    state: Mutex<LogState>,
+    /// Bumped on every record, so waits can recheck the log
+    recorded: watch::Sender<u64>,
+}
+
+struct LogState {
+    /// Records with the sequence number they were appended under
+    entries: VecDeque<(u64, WindowEventRecord)>,
+    capacity: usize,
+    dropped: u64,
+    next_seq: u64,
+    /// Burst of moves or resizes in progress for each window
+    bursts: HashMap<(String, WindowEventKind), Burst>,
+}
+
+struct Burst {
+    started_ms: u64,
+    /// Sequence number of the burst's latest record, once it has one
+    /// besides the first
+    last: Option<u64>,
+}
+
+impl WindowEventLog {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(LogState {
+                entries: VecDeque::with_capacity(capacity.min(1024)),
+                capacity,
+                dropped: 0,
+                next_seq: 0,
+                bursts: HashMap::new(),
+            }),
+            recorded: watch::channel(0).0,
+        }
+    }
+
+    /// Record `event` of window `label` if it's a kind that's recorded
+    pub(crate) fn record(&self, label: &str, event: &WindowEvent) {
+        if let Some((kind, detail)) = describe(event) {
+            self.push(WindowEventRecord {
+                label: label.to_string(),
+                kind,
+                detail,
+                timestamp_ms: now_ms(),
+            });
+        }
+    }
+
+    /// Add a record, keeping only the first and latest move or resize of a
+    /// window within `COALESCE_MS` of the first; the oldest records are
+    /// dropped and counted once the log is full
+    pub(crate) fn push(&self, record: WindowEventRecord) {
+        self.push_record(record);
+        self.recorded.send_modify(|count| *count += 1);
+    }
+
+    fn push_record(&self, record: WindowEventRecord) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if !matches!(record.kind, WindowEventKind::Moved | WindowEventKind::Resized) {
+            state.append(record);
+            return;
+        }
+        let key = (record.label.clone(), record.kind);
+        let timestamp_ms = record.timestamp_ms;
+        match state.bursts.get(&key) {
+            Some(burst) if timestamp_ms < burst.started_ms.saturating_add(COALESCE_MS) => {
+                let previous = burst.last;
+                if let Some(index) = previous.and_then(|seq| state.entries.iter().position(|(s, _)| *s == seq)) {
+                    state.entries.remove(index);
+                }
+                let seq = state.append(record);
+                if let Some(burst) = state.bursts.get_mut(&key) {
+                    burst.last = seq;
+                }
+            }
+            _ => {
+                state.append(record);
+                state.bursts.insert(
+                    key,
+                    Burst {
+                        started_ms: timestamp_ms,
+                        last: None,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Events of window `label`, or of every window, at or after `since`
+    /// (ms since epoch), oldest first
+    pub(crate) fn query(&self, label: Option<&str>, since: Option<u64>) -> WindowEvents {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        WindowEvents {
+            events: state
+                .entries
+                .iter()
+                .map(|(_, record)| record)
+                .filter(|record| label.map_or(true, |label| record.label == label))
+                .filter(|record| since.map_or(true, |since| record.timestamp_ms >= since))
+                .cloned()
+                .collect(),
+            dropped: state.dropped,
+        }
+    }
+
+    /// Wait up to `timeout` for a `kind` event of window `label` at or after
+    /// `since`; returns whether one was recorded
+    pub(crate) async fn wait_for(&self, label: &str, kind: WindowEventKind, since: u64, timeout: Duration) -> bool {
+        // Subscribed before the first check, so a record right after it isn't missed
+        let mut rx = self.recorded.subscribe();
+        let recorded = || {
+            let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            state
+                .entries
+                .iter()
+                .any(|(_, record)| record.label == label && record.kind == kind && record.timestamp_ms >= since)
+        };
+        let wait = async {
+            loop {
+                if recorded() {
+                    return true;
+                }
+                if rx.changed().await.is_err() {
+                    return false;
+                }
+            }
+        };
+        tokio::time::timeout(timeout, wait).await.unwrap_or(false)
+    }
+
+    /// Remove every record, returning how many there were
+    pub(crate) fn clear(&self) -> usize {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let count = state.entries.len();
+        state.entries.clear();
+        state.bursts.clear();
+        state.dropped = 0;
+        count
+    }
+}
+
+impl LogState {
+    /// Append `record`, returning its sequence number unless it was dropped
+    fn append(&mut self, record: WindowEventRecord) -> Option<u64> {
+        if self.capacity == 0 {
+            self.dropped += 1;
+            return None;
+        }
+        while self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+            self.dropped += 1;
+        }
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.entries.push_back((seq, record));
+        Some(seq)
+    }
+}
+
+/// Kind and detail of the window events that are recorded
+fn describe(event: &WindowEvent) -> Option<(WindowEventKind, serde_json::Value)> {
+    Some(match event {
+        WindowEvent::Moved(position) => (WindowEventKind::Moved, json!({ "x": position.x, "y": position.y })),
+        WindowEvent::Resized(size) => (
+            WindowEventKind::Resized,
+            json!({ "width": size.width, "height": size.height }),
+        ),
+        WindowEvent::Focused(focused) => (WindowEventKind::Focused, json!({ "focused": focused })),
+        WindowEvent::ScaleFactorChanged {
+            scale_factor,
+            new_inner_size,
+            ..
+        } => (
+            WindowEventKind::ScaleFactorChanged,
+            json!({
+                "scale_factor": scale_factor,
+                "width": new_inner_size.width,
+                "height": new_inner_size.height,
+            }),
+        ),
+        WindowEvent::CloseRequested { .. } => (WindowEventKind::CloseRequested, serde_json::Value::Null),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tauri::PhysicalSize;
+
+    use super::*;
+
+    fn record(label: &str, kind: WindowEventKind, width: u32, timestamp_ms: u64) -> WindowEventRecord {
+        WindowEventRecord {
+            label: label.to_string(),
+            kind,
+            detail: json!({ "width": width }),
+            timestamp_ms,
+        }
+    }
+
+    fn widths(events: &WindowEvents) -> Vec<u64> {
+        events.events.iter().map(|record| record.detail["width"].as_u64().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_coalesces_bursts_to_their_first_and_latest_record() {
+        let log = WindowEventLog::new(100);
+        for (width, timestamp_ms) in [(100, 1_000), (110, 1_010), (120, 1_020)] {
+            log.push(record("main", WindowEventKind::Resized, width, timestamp_ms));
+        }
+        // Other kinds and windows have bursts of their own
+        log.push(record("main", WindowEventKind::Moved, 1, 1_030));
+        log.push(record("other", WindowEventKind::Resized, 2, 1_040));
+        log.push(record("main", WindowEventKind::Focused, 3, 1_045));
+        // The latest record of the burst moves to where it happened
+        log.push(record("main", WindowEventKind::Resized, 130, 1_049));
+        // Past the burst, so the next one starts
+        log.push(record("main", WindowEventKind::Resized, 140, 1_050));
+
+        assert_eq!(widths(&log.query(None, None)), vec![100, 1, 2, 3, 130, 140]);
+        assert_eq!(widths(&log.query(Some("main"), Some(1_040))), vec![3, 130, 140]);
+    }
+
+    #[test]
+    fn test_drops_the_oldest_records_once_full() {
+        let log = WindowEventLog::new(2);
+        for (width, timestamp_ms) in [(1, 0), (2, 100), (3, 200)] {
+            log.push(record("main", WindowEventKind::Resized, width, timestamp_ms));
+        }
+
+        let events = log.query(None, None);
+        assert_eq!(widths(&events), vec![2, 3]);
+        assert_eq!(events.dropped, 1);
+        assert_eq!(log.clear(), 2);
+        assert_eq!(log.query(None, None).dropped, 0);
+    }
+
+    #[test]
+    fn test_wait_for_sees_records_made_while_waiting() {
+        let log = std::sync::Arc::new(WindowEventLog::new(10));
+        log.push(record("main", WindowEventKind::CloseRequested, 0, 500));
+
+        let recorder = log.clone();
+        let closing = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            recorder.push(record("other", WindowEventKind::CloseRequested, 0, 1_000));
+            recorder.push(record("main", WindowEventKind::CloseRequested, 0, 1_000));
+        });
+
+        let seen = tauri::async_runtime::block_on(log.wait_for("main", WindowEventKind::CloseRequested, 1_000, Duration::from_secs(5)));
+        closing.join().unwrap();
+
+        assert!(seen);
+        let missed = log.wait_for("main", WindowEventKind::Focused, 0, Duration::from_millis(20));
+        assert!(!tauri::async_runtime::block_on(missed));
+    }
+
+    #[test]
+    fn test_records_only_the_tracked_kinds() {
+        let log = WindowEventLog::new(10);
+
+        log.record("main", &WindowEvent::Resized(PhysicalSize::new(800, 600)));
+        log.record("main", &WindowEvent::Focused(false));
+        log.record("main", &WindowEvent::Destroyed);
+
+        let events = log.query(None, None).events;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, WindowEventKind::Resized);
+        assert_eq!(events[0].detail, json!({ "width": 800, "height": 600 }));
+        assert_eq!(events[1].detail, json!({ "focused": false }));
+    }
+}
@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+/// Counts webview windows created and destroyed, updated from the plugin's
+/// webview-ready and window-destroyed hooks, so waits can recheck the open
+/// windows whenever they change
+pub(crate) struct WindowChanges {
+    tx: watch::Sender<u64>,
+}
+
+impl Default for WindowChanges {
+    fn default() -> Self {
+        Self {
+            tx: watch::channel(0).0,
+        }
+    }
+}
+
+impl WindowChanges {
+    /// Record that a window was created or destroyed
+    pub(crate) fn changed(&self) {
+        self.tx.send_modify(|count| *count += 1);
+    }
+
+    /// Run `check` now and after every window change until it returns a
+    /// value, for at most `timeout`
+    pub(crate) async fn wait_until<T>(&self, timeout: Duration, mut check: impl FnMut() -> Option<T>) -> Option<T> {
+        // Subscribed before the first check, so a change right after it isn't missed
+        let mut rx = self.tx.subscribe();
+        let wait = async {
+            loop {
+                if let Some(value) = check() {
+                    return Some(value);
+                }
+                if rx.changed().await.is_err() {
+                    return None;
+                }
+            }
+        };
+        tokio::time::timeout(timeout, wait).await.ok().flatten()
+    }
+}
+
+/// Whether `label` matches `pattern`, where `*` matches any run of
+/// characters and `?` any single one. A pattern without either is an exact label.
+pub(crate) fn label_matches(pattern: &str, label: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let label: Vec<char> = label.chars().collect();
+    let (mut p, mut l) = (0, 0);
+    // Position after the last `*`, and the label position it's matched up to
+    let mut star: Option<(usize, usize)> = None;
+    while l < label.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p + 1, l));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == label[l] => {
+                p += 1;
+                l += 1;
+            }
+            _ => match star {
+                // Let the last `*` take one more character and retry
+                Some((after_star, matched)) => {
+                    p = after_star;
+                    l = matched + 1;
+                    star = Some((after_star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn test_label_matches_globs() {
+        assert!(label_matches("main", "main"));
+        assert!(!label_matches("main", "main-2"));
+        assert!(label_matches("popup-*", "popup-"));
+        assert!(label_matches("popup-*", "popup-7f3a"));
+        assert!(label_matches("*-settings", "user-settings"));
+        assert!(label_matches("dialog-?", "dialog-1"));
+        assert!(!label_matches("dialog-?", "dialog-12"));
+        assert!(label_matches("a*b*c", "a-x-b-y-b-c"));
+        assert!(!label_matches("a*b*c", "a-x-c-b"));
+        assert!(label_matches("*", ""));
+    }
+
+    #[test]
+    fn test_wait_until_rechecks_on_every_change() {
+        let changes = Arc::new(WindowChanges::default());
+        let open = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+
+        let (notifier, windows) = (changes.clone(), open.clone());
+        let opening = std::thread::spawn(move || {
+            for label in ["splash", "popup-1"] {
+                std::thread::sleep(Duration::from_millis(20));
+                windows.lock().unwrap().push(label.to_string());
+                notifier.changed();
+            }
+        });
+
+        let found = tauri::async_runtime::block_on(changes.wait_until(Duration::from_secs(5), || {
+            open.lock().unwrap().iter().find(|label| label_matches("popup-*", label)).cloned()
+        }));
+        opening.join().unwrap();
+
+        assert_eq!(found.as_deref(), Some("popup-1"));
+    }
+
+    #[test]
+    fn test_wait_until_gives_up_after_the_timeout() {
+        let changes = WindowChanges::default();
+
+        let found = tauri::async_runtime::block_on(changes.wait_until(Duration::from_millis(20), || None::<()>));
+
+        assert_eq!(found, None);
+    }
+}
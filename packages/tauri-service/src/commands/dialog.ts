@@ -0,0 +1,80 @@
+import type { TauriDialogInvocation, TauriDialogKind, TauriMock } from '@wdio/native-types';
+import { createLogger } from '@wdio/native-utils';
+import mockStore from '../mockStore.js';
+import { mock, restoreAllMocks } from './mock.js';
+
+const log = createLogger('tauri-service', 'dialog');
+
+/** Commands of the official dialog plugin are invoked as `plugin:dialog|<kind>` */
+export const DIALOG_COMMAND_PREFIX = 'plugin:dialog|';
+
+export const DIALOG_KINDS: readonly TauriDialogKind[] = ['open', 'save', 'message', 'ask', 'confirm'];
+
+interface TauriServiceContext {
+  browser?: WebdriverIO.Browser | WebdriverIO.MultiRemoteBrowser;
+}
+
+function assertDialogKind(kind: string): asserts kind is TauriDialogKind {
+  if (!DIALOG_KINDS.includes(kind as TauriDialogKind)) {
+    throw new Error(`Unknown dialog kind "${kind}", expected one of: ${DIALOG_KINDS.join(', ')}`);
+  }
+}
+
+/**
+ * Get the mock for a dialog command without resetting it, so responses queued
+ * by earlier mockDialog calls are kept
+ */
+async function getDialogMock(context: TauriServiceContext, kind: TauriDialogKind): Promise<TauriMock> {
+  const command = `${DIALOG_COMMAND_PREFIX}${kind}`;
+  try {
+    return mockStore.getMock(`tauri.${command}`);
+  } catch (_e) {
+    return mock.call(context, command);
+  }
+}
+
+/**
+ * open/save pass their settings as `{ options }`; message/ask/confirm pass
+ * `{ message, title, kind, ... }` directly
+ */
+function dialogOptions(args: unknown): Record<string, unknown> {
+  if (!args || typeof args !== 'object') {
+    return {};
+  }
+  const { options } = args as { options?: unknown };
+  if (options && typeof options === 'object') {
+    return options as Record<string, unknown>;
+  }
+  return args as Record<string, unknown>;
+}
+
+export async function mockDialog(this: TauriServiceContext, kind: TauriDialogKind, response: unknown): Promise<void> {
+  assertDialogKind(kind);
+  log.debug(`[${kind}] queueing dialog response`);
+  const dialogMock = await getDialogMock(this, kind);
+  await dialogMock.mockResolvedValueOnce(response);
+}
+
+export async function getDialogInvocations(this: TauriServiceContext): Promise<TauriDialogInvocation[]> {
+  const invocations: (TauriDialogInvocation & { order: number })[] = [];
+
+  for (const [mockName, dialogMock] of mockStore.getMocks()) {
+    if (!mockName.startsWith(`tauri.${DIALOG_COMMAND_PREFIX}`)) {
+      continue;
+    }
+    const kind = mockName.slice(`tauri.${DIALOG_COMMAND_PREFIX}`.length) as TauriDialogKind;
+    await dialogMock.update();
+
+    const { calls, invocationCallOrder } = dialogMock.mock;
+    calls.forEach((args, i) => {
+      invocations.push({ kind, options: dialogOptions(args[0]), order: invocationCallOrder[i] ?? i });
+    });
+  }
+
+  return invocations.sort((a, b) => a.order - b.order).map(({ kind, options }) => ({ kind, options }));
+}
+
+export async function clearDialogMocks(this: TauriServiceContext): Promise<void> {
+  log.debug('clearDialogMocks command called');
+  await restoreAllMocks.call(this, DIALOG_COMMAND_PREFIX);
+}
@@ -1,6 +1,7 @@
 import { createIpcInterceptor } from '@wdio/native-spy/interceptor';
-import type { TauriAPIs, TauriServiceAPI } from '@wdio/native-types';
+import type { TauriAPIs, TauriDialogInvocation, TauriDialogKind, TauriServiceAPI } from '@wdio/native-types';
 import { createLogger, hasSemicolonOutsideQuotes, waitUntilWindowAvailable } from '@wdio/native-utils';
+import { clearDialogMocks, getDialogInvocations, mockDialog } from './commands/dialog.js';
 import { execute } from './commands/execute.js';
 import { clearAllMocks, isMockFunction, mock, resetAllMocks, restoreAllMocks } from './commands/mock.js';
 import { triggerDeeplink } from './commands/triggerDeeplink.js';
@@ -384,6 +385,18 @@ export default class TauriWorkerService {
         return restoreAllMocks.call({ browser }, commandPrefix);
       },
 
+      mockDialog: async (kind: TauriDialogKind, response: unknown): Promise<void> => {
+        return mockDialog.call({ browser }, kind, response);
+      },
+
+      getDialogInvocations: async (): Promise<TauriDialogInvocation[]> => {
+        return getDialogInvocations.call({ browser });
+      },
+
+      clearDialogMocks: async (): Promise<void> => {
+        return clearDialogMocks.call({ browser });
+      },
+
       triggerDeeplink: async (url: string): Promise<void> => {
         if (browserMode) {
           throw new Error('browser.tauri.triggerDeeplink() is not supported in browser mode.');
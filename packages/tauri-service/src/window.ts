@@ -136,7 +136,8 @@ export async function listWindowLabels(browser: WebdriverIO.Browser): Promise<st
   if (!Array.isArray(result)) {
     throw new Error(`Expected array but got ${typeof result}`);
   }
-  return result as string[];
+  // Newer plugin versions return window info objects, older ones plain labels
+  return result.map((window: string | { label: string }) => (typeof window === 'string' ? window : window.label));
 }
 
 export async function getCurrentDevtoolsPort(browser: WebdriverIO.Browser): Promise<number | undefined> {
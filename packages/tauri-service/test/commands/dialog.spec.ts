@@ -0,0 +1,93 @@
+import { beforeEach, describe, expect, it, type Mock, vi } from 'vitest';
+
+import { clearDialogMocks, getDialogInvocations, mockDialog } from '../../src/commands/dialog.js';
+import { mock, restoreAllMocks } from '../../src/commands/mock.js';
+import mockStore from '../../src/mockStore.js';
+
+vi.mock('../../src/commands/mock.js', () => ({
+  mock: vi.fn(),
+  restoreAllMocks: vi.fn(),
+}));
+vi.mock('../../src/mockStore.js', () => ({
+  default: {
+    getMock: vi.fn(),
+    getMocks: vi.fn(),
+  },
+}));
+
+function dialogMock(calls: unknown[][], invocationCallOrder: number[]) {
+  return {
+    mockResolvedValueOnce: vi.fn(),
+    update: vi.fn(),
+    mock: { calls, invocationCallOrder },
+  };
+}
+
+describe('dialog commands', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  describe('mockDialog', () => {
+    it('should queue the response on an existing dialog mock without resetting it', async () => {
+      const existing = dialogMock([], []);
+      (mockStore.getMock as Mock).mockReturnValue(existing);
+
+      await mockDialog.call({}, 'open', ['/tmp/a.txt']);
+
+      expect(mockStore.getMock).toHaveBeenCalledWith('tauri.plugin:dialog|open');
+      expect(mock).not.toHaveBeenCalled();
+      expect(existing.mockResolvedValueOnce).toHaveBeenCalledWith(['/tmp/a.txt']);
+    });
+
+    it('should create the dialog mock when none exists', async () => {
+      const created = dialogMock([], []);
+      (mockStore.getMock as Mock).mockImplementation(() => {
+        throw new Error('No mock registered');
+      });
+      (mock as Mock).mockResolvedValue(created);
+
+      await mockDialog.call({}, 'confirm', true);
+
+      expect(mock).toHaveBeenCalledWith('plugin:dialog|confirm');
+      expect(created.mockResolvedValueOnce).toHaveBeenCalledWith(true);
+    });
+
+    it('should reject unknown dialog kinds', async () => {
+      await expect(mockDialog.call({}, 'prompt' as any, 'x')).rejects.toThrow('Unknown dialog kind "prompt"');
+    });
+  });
+
+  describe('getDialogInvocations', () => {
+    it('should merge dialog calls in invocation order and unwrap open/save options', async () => {
+      const open = dialogMock([[{ options: { title: 'Pick a file' } }]], [3]);
+      const confirm = dialogMock([[{ message: 'Sure?', title: 'Confirm' }], [{ message: 'Really?' }]], [1, 5]);
+      const other = dialogMock([[{ content: 'x' }]], [2]);
+      (mockStore.getMocks as Mock).mockReturnValue([
+        ['tauri.plugin:dialog|open', open],
+        ['tauri.plugin:dialog|confirm', confirm],
+        ['tauri.write_clipboard', other],
+      ]);
+
+      const invocations = await getDialogInvocations.call({});
+
+      expect(open.update).toHaveBeenCalled();
+      expect(other.update).not.toHaveBeenCalled();
+      expect(invocations).toEqual([
+        { kind: 'confirm', options: { message: 'Sure?', title: 'Confirm' } },
+        { kind: 'open', options: { title: 'Pick a file' } },
+        { kind: 'confirm', options: { message: 'Really?' } },
+      ]);
+    });
+  });
+
+  describe('clearDialogMocks', () => {
+    it('should restore only dialog mocks', async () => {
+      const context = { browser: {} as WebdriverIO.Browser };
+
+      await clearDialogMocks.call(context);
+
+      expect(restoreAllMocks).toHaveBeenCalledWith('plugin:dialog|');
+    });
+  });
+});
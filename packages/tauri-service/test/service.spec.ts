@@ -233,6 +233,9 @@ describe('TauriWorkerService', () => {
       expect(typeof (mockBrowser as any).tauri.clearAllMocks).toBe('function');
       expect(typeof (mockBrowser as any).tauri.resetAllMocks).toBe('function');
       expect(typeof (mockBrowser as any).tauri.restoreAllMocks).toBe('function');
+      expect(typeof (mockBrowser as any).tauri.mockDialog).toBe('function');
+      expect(typeof (mockBrowser as any).tauri.getDialogInvocations).toBe('function');
+      expect(typeof (mockBrowser as any).tauri.clearDialogMocks).toBe('function');
       expect(typeof (mockBrowser as any).tauri.triggerDeeplink).toBe('function');
       expect(typeof (mockBrowser as any).tauri.switchWindow).toBe('function');
       expect(typeof (mockBrowser as any).tauri.listWindows).toBe('function');
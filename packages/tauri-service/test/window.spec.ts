@@ -61,6 +61,20 @@ describe('window management', () => {
       expect(result).toEqual(['main', 'splash']);
     });
 
+    it('should return labels from window info objects', async () => {
+      const mockBrowser = {
+        tauri: {
+          execute: vi.fn().mockResolvedValue([
+            { label: 'main', title: 'Main', focused: true },
+            { label: 'splash', title: 'Splash Screen', focused: false },
+          ]),
+        },
+      } as unknown as WebdriverIO.Browser;
+
+      const result = await listWindowLabels(mockBrowser);
+      expect(result).toEqual(['main', 'splash']);
+    });
+
     it('should propagate errors', async () => {
       const mockBrowser = {
         tauri: {